@@ -1,3 +1,79 @@
 //! CLI command implementations.
 
-// Placeholder for command implementations
+use std::sync::Arc;
+
+use devboy_core::bulk;
+use devboy_core::keys::KeyResolver;
+use devboy_core::{BulkUpdateReport, Error, IssueProvider, Provider, UpdateIssueInput};
+
+/// Update, or with `dry_run` preview, issues of the configured providers.
+///
+/// Keys are routed to the provider owning them (see [`KeyResolver`]), or all
+/// sent to the provider named by `provider`.
+pub async fn bulk_update(
+    providers: &[Arc<dyn Provider>],
+    keys: &[String],
+    input: &UpdateIssueInput,
+    provider: Option<&str>,
+    dry_run: bool,
+) -> BulkUpdateReport {
+    let mut resolver = KeyResolver::new();
+    for p in providers {
+        resolver.register(IssueProvider::provider_name(p.as_ref()), p.key_patterns());
+    }
+
+    bulk::update_routed(keys, input, dry_run, |key| {
+        let index = match provider {
+            Some(name) => providers
+                .iter()
+                .position(|p| IssueProvider::provider_name(p.as_ref()) == name)
+                .ok_or_else(|| Error::ProviderNotFound(name.to_string()))?,
+            None => resolver.resolve(key)?,
+        };
+        Ok(providers[index].as_ref())
+    })
+    .await
+}
+
+/// Format a bulk update report as plain text, one line per issue.
+pub fn format_bulk_report(report: &BulkUpdateReport) -> String {
+    let mut output = if report.dry_run {
+        format!(
+            "Dry run: {} of {} issues would be updated\n\n",
+            report.succeeded(),
+            report.items.len()
+        )
+    } else {
+        format!(
+            "Updated {} of {} issues\n\n",
+            report.succeeded(),
+            report.items.len()
+        )
+    };
+
+    for item in &report.items {
+        match &item.error {
+            Some(error) => output.push_str(&format!("  {} - failed: {}\n", item.key, error)),
+            None if item.changes.is_empty() => {
+                output.push_str(&format!("  {} - no changes\n", item.key))
+            }
+            None => output.push_str(&format!("  {} - {}\n", item.key, item.changes.join("; "))),
+        }
+        if item.error.is_none() && !item.skipped.is_empty() {
+            output.push_str(&format!(
+                "  {} - not applied by the provider: {}\n",
+                item.key,
+                item.skipped.join(", ")
+            ));
+        }
+    }
+
+    if !report.duplicates.is_empty() {
+        output.push_str(&format!(
+            "\nDuplicate keys updated once: {}\n",
+            report.duplicates.join(", ")
+        ));
+    }
+
+    output
+}
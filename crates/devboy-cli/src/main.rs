@@ -4,8 +4,11 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use devboy_cli::commands::{bulk_update, format_bulk_report};
 use devboy_clickup::ClickUpClient;
-use devboy_core::{Config, IssueFilter, IssueProvider, MergeRequestProvider, MrFilter, Provider};
+use devboy_core::{
+    Config, IssueFilter, IssueProvider, MergeRequestProvider, MrFilter, Provider, UpdateIssueInput,
+};
 use devboy_github::GitHubClient;
 use devboy_gitlab::GitLabClient;
 use devboy_jira::JiraClient;
@@ -47,6 +50,33 @@ enum Commands {
         limit: u32,
     },
 
+    /// Apply the same change to several issues
+    BulkUpdate {
+        /// Issue keys (e.g., gh#1 gh#2)
+        #[arg(required = true)]
+        keys: Vec<String>,
+
+        /// New state (e.g., closed)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// New labels, comma-separated (replaces existing)
+        #[arg(long, value_delimiter = ',')]
+        labels: Option<Vec<String>>,
+
+        /// New assignees, comma-separated (replaces existing)
+        #[arg(long, value_delimiter = ',')]
+        assignees: Option<Vec<String>>,
+
+        /// Provider of the issues (github, gitlab, clickup, jira; default: by key)
+        #[arg(short, long)]
+        provider: Option<String>,
+
+        /// Only show the changes that would be made
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Get information about merge requests / pull requests
     Mrs {
         /// Filter by state
@@ -122,6 +152,23 @@ async fn main() -> Result<()> {
             handle_issues_command(&state, limit).await?;
         }
 
+        Some(Commands::BulkUpdate {
+            keys,
+            state,
+            labels,
+            assignees,
+            provider,
+            dry_run,
+        }) => {
+            let input = UpdateIssueInput {
+                state,
+                labels,
+                assignees,
+                ..Default::default()
+            };
+            handle_bulk_update_command(&keys, input, provider.as_deref(), dry_run).await?;
+        }
+
         Some(Commands::Mrs { state, limit }) => {
             handle_mrs_command(&state, limit).await?;
         }
@@ -225,6 +272,9 @@ fn handle_config_command(command: ConfigCommands) -> Result<()> {
                 } else {
                     println!("  team_id = (not set, recommended for custom task IDs)");
                 }
+                if !cu.lists.is_empty() {
+                    println!("  lists = {}", cu.lists.join(","));
+                }
                 if store.exists("clickup.token") {
                     println!("  token = ******* (in keychain)");
                 } else {
@@ -323,6 +373,46 @@ async fn handle_issues_command(state: &str, limit: u32) -> Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Bulk Update Command
+// =============================================================================
+
+async fn handle_bulk_update_command(
+    keys: &[String],
+    input: UpdateIssueInput,
+    provider: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    if input.state.is_none() && input.labels.is_none() && input.assignees.is_none() {
+        anyhow::bail!("Nothing to update: pass --state, --labels or --assignees");
+    }
+
+    let config = Config::load().context("Failed to load config")?;
+    let store = KeychainStore::new();
+    let providers = configured_providers(&config, &store);
+
+    if providers.is_empty() {
+        anyhow::bail!("No provider configured");
+    }
+    if let Some(name) = provider {
+        if !providers
+            .iter()
+            .any(|p| IssueProvider::provider_name(p.as_ref()) == name)
+        {
+            anyhow::bail!("Provider '{}' not configured", name);
+        }
+    }
+
+    let report = bulk_update(&providers, keys, &input, provider, dry_run).await;
+    print!("{}", format_bulk_report(&report));
+
+    let failed = report.failed().count();
+    if failed > 0 {
+        anyhow::bail!("{} of {} issues failed", failed, report.items.len());
+    }
+    Ok(())
+}
+
 // =============================================================================
 // MRs Command
 // =============================================================================
@@ -563,12 +653,32 @@ async fn handle_mcp_command() -> Result<()> {
     let store = KeychainStore::new();
 
     let mut server = McpServer::new();
+    for provider in configured_providers(&config, &store) {
+        server.add_provider(provider);
+    }
+
+    server.set_workflow_config(config.workflow.clone().unwrap_or_default());
+
+    if server.providers().is_empty() {
+        tracing::warn!("No providers configured. MCP server will have limited functionality.");
+        tracing::info!("Configure GitHub: devboy config set github.owner <owner>");
+    }
+
+    // Run the MCP server (reads from stdin, writes to stdout)
+    server.run().await.context("MCP server error")?;
+
+    Ok(())
+}
+
+/// Create the configured providers that have a token in the keychain.
+fn configured_providers(config: &Config, store: &KeychainStore) -> Vec<Arc<dyn Provider>> {
+    let mut providers: Vec<Arc<dyn Provider>> = Vec::new();
 
     // Add GitHub provider if configured
     if let Some(gh) = &config.github {
         if let Some(token) = store.get("github.token").ok().flatten() {
             let client = GitHubClient::new(&gh.owner, &gh.repo, token);
-            server.add_provider(Arc::new(client));
+            providers.push(Arc::new(client));
             tracing::info!("Added GitHub provider: {}/{}", gh.owner, gh.repo);
         } else {
             tracing::warn!("GitHub configured but no token found");
@@ -579,7 +689,7 @@ async fn handle_mcp_command() -> Result<()> {
     if let Some(gl) = &config.gitlab {
         if let Some(token) = store.get("gitlab.token").ok().flatten() {
             let client = GitLabClient::with_base_url(&gl.url, &gl.project_id, token);
-            server.add_provider(Arc::new(client));
+            providers.push(Arc::new(client));
             tracing::info!(
                 "Added GitLab provider: {} (project {})",
                 gl.url,
@@ -593,11 +703,11 @@ async fn handle_mcp_command() -> Result<()> {
     // Add ClickUp provider if configured
     if let Some(cu) = &config.clickup {
         if let Some(token) = store.get("clickup.token").ok().flatten() {
-            let mut client = ClickUpClient::new(&cu.list_id, token);
+            let mut client = ClickUpClient::new(&cu.list_id, token).with_lists(cu.lists.clone());
             if let Some(team_id) = &cu.team_id {
                 client = client.with_team_id(team_id);
             }
-            server.add_provider(Arc::new(client));
+            providers.push(Arc::new(client));
             tracing::info!("Added ClickUp provider (list {})", cu.list_id);
         } else {
             tracing::warn!("ClickUp configured but no token found");
@@ -608,7 +718,7 @@ async fn handle_mcp_command() -> Result<()> {
     if let Some(jira) = &config.jira {
         if let Some(token) = store.get("jira.token").ok().flatten() {
             let client = JiraClient::new(&jira.url, &jira.project_key, &jira.email, token);
-            server.add_provider(Arc::new(client));
+            providers.push(Arc::new(client));
             tracing::info!(
                 "Added Jira provider: {} (project {})",
                 jira.url,
@@ -619,13 +729,5 @@ async fn handle_mcp_command() -> Result<()> {
         }
     }
 
-    if server.providers().is_empty() {
-        tracing::warn!("No providers configured. MCP server will have limited functionality.");
-        tracing::info!("Configure GitHub: devboy config set github.owner <owner>");
-    }
-
-    // Run the MCP server (reads from stdin, writes to stdout)
-    server.run().await.context("MCP server error")?;
-
-    Ok(())
+    providers
 }
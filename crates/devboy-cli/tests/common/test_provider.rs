@@ -6,9 +6,10 @@ use std::env;
 
 use async_trait::async_trait;
 use devboy_core::{
-    Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff, Issue, IssueFilter,
-    IssueProvider, MergeRequest, MergeRequestProvider, MrFilter, Provider, Result,
-    UpdateIssueInput, User,
+    AgileProvider, Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff,
+    Issue, IssueFilter, IssueProvider, MergeRequest, MergeRequestProvider, MrFilter,
+    PipelineProvider, Provider, RepositoryProvider, Result, TimeTrackingProvider, UpdateIssueInput,
+    User,
 };
 use devboy_github::GitHubClient;
 
@@ -273,6 +274,7 @@ impl IssueProvider for TestProvider {
                 created_at: Some("2024-01-01T00:00:00Z".to_string()),
                 updated_at: None,
                 position: None,
+                attachments: vec![],
             }])
         }
     }
@@ -324,6 +326,7 @@ impl MergeRequestProvider for TestProvider {
                     created_at: Some("2024-01-01T00:00:00Z".to_string()),
                     updated_at: None,
                     position: None,
+                    attachments: vec![],
                 }],
                 position: None,
             }])
@@ -362,6 +365,34 @@ impl MergeRequestProvider for TestProvider {
     }
 }
 
+/// Pipelines are not recorded in fixtures, all operations are unsupported.
+impl PipelineProvider for TestProvider {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+/// Repository content is not recorded in fixtures, all operations are unsupported.
+impl RepositoryProvider for TestProvider {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+/// Boards and sprints are not recorded in fixtures, all operations are unsupported.
+impl AgileProvider for TestProvider {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+/// GitHub has no time tracking, all operations are unsupported.
+impl TimeTrackingProvider for TestProvider {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
 /// Implement Provider for TestProvider.
 #[async_trait]
 impl Provider for TestProvider {
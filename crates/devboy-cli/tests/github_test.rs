@@ -21,7 +21,10 @@
 
 mod common;
 
+use std::sync::Arc;
+
 use common::TestProvider;
+use devboy_cli::commands::{bulk_update, format_bulk_report};
 use devboy_core::{
     CreateCommentInput, CreateIssueInput, IssueFilter, IssueProvider, MergeRequestProvider,
     MrFilter, Provider, UpdateIssueInput,
@@ -463,6 +466,10 @@ async fn test_create_issue_not_supported() {
         labels: vec!["test".to_string()],
         assignees: vec![],
         priority: None,
+        custom_fields: Default::default(),
+        issue_type: None,
+        parent: None,
+        container: None,
     };
 
     let result = provider.create_issue(input).await;
@@ -500,6 +507,7 @@ async fn test_update_issue_not_supported() {
         labels: Some(vec!["test".to_string()]),
         assignees: None,
         priority: None,
+        custom_fields: Default::default(),
     };
 
     let result = provider.update_issue(key, input).await;
@@ -541,3 +549,33 @@ async fn test_add_comment_via_pr_key_not_supported() {
         err_msg
     );
 }
+
+/// Test that a bulk dry run previews each issue once without updating it.
+#[tokio::test]
+async fn test_bulk_update_dry_run() {
+    let provider: Arc<dyn Provider> = Arc::new(TestProvider::github());
+
+    let issues = provider.get_issues(IssueFilter::default()).await.unwrap();
+    let key = issues[0].key.clone();
+    let keys = vec![key.clone(), key.clone(), "gh#999999".to_string()];
+    let input = UpdateIssueInput {
+        state: Some("closed".to_string()),
+        ..Default::default()
+    };
+
+    let report = bulk_update(&[provider], &keys, &input, None, true).await;
+
+    assert!(report.dry_run);
+    assert_eq!(
+        report.items.len(),
+        2,
+        "Repeated keys should be previewed once"
+    );
+    assert_eq!(report.duplicates, vec![key.clone()]);
+    assert!(report.items[0].error.is_none());
+    assert!(report.items[1].error.is_some(), "Unknown issue should fail");
+
+    let output = format_bulk_report(&report);
+    assert!(output.starts_with("Dry run: 1 of 2 issues would be updated"));
+    assert!(output.contains(&format!("Duplicate keys updated once: {}", key)));
+}
@@ -0,0 +1,278 @@
+//! Attachment helpers shared by providers.
+//!
+//! GitHub and GitLab keep no attachment list: uploaded files are linked from
+//! Markdown, so their attachments are found by scanning issue and comment
+//! bodies. Uploads are sent as `multipart/form-data`, built here because the
+//! HTTP client is compiled without multipart support.
+
+use crate::types::Attachment;
+
+/// Boundary of multipart bodies; a suffix is added if the content contains it.
+const MULTIPART_BOUNDARY: &str = "devboy-tools-attachment-7d3b0c4f";
+
+/// Build a `multipart/form-data` body holding a single file in `field`.
+///
+/// Returns the `Content-Type` header value (with the boundary) and the body.
+pub fn multipart_file(field: &str, file_name: &str, content: &[u8]) -> (String, Vec<u8>) {
+    let mut boundary = MULTIPART_BOUNDARY.to_string();
+    let mut suffix = 0;
+    while content
+        .windows(boundary.len())
+        .any(|w| w == boundary.as_bytes())
+    {
+        suffix += 1;
+        boundary = format!("{}-{}", MULTIPART_BOUNDARY, suffix);
+    }
+
+    // Quotes and line breaks would end the header value early
+    let file_name: String = file_name
+        .chars()
+        .filter(|c| *c != '\r' && *c != '\n')
+        .map(|c| if c == '"' { '\'' } else { c })
+        .collect();
+    let mime_type = mime_type_for(&file_name).unwrap_or("application/octet-stream");
+
+    let mut body = Vec::with_capacity(content.len() + 256);
+    body.extend_from_slice(
+        format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+            boundary, field, file_name, mime_type
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    (format!("multipart/form-data; boundary={}", boundary), body)
+}
+
+/// Guess a MIME type from the extension of a file name.
+pub fn mime_type_for(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let mime_type = match extension.to_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        "pdf" => "application/pdf",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "yaml" | "yml" => "application/yaml",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        "webm" => "video/webm",
+        _ => return None,
+    };
+    Some(mime_type)
+}
+
+/// Scheme and host (with port) of an absolute URL, e.g. "https://example.com:8443".
+pub fn url_origin(url: &str) -> Option<&str> {
+    let host_start = url.find("://")? + 3;
+    let host_end = url[host_start..]
+        .find(['/', '?', '#'])
+        .map_or(url.len(), |i| host_start + i);
+    (host_end > host_start).then(|| &url[..host_end])
+}
+
+/// Check whether two URLs share scheme, host and port.
+pub fn is_same_origin(url: &str, other: &str) -> bool {
+    match (url_origin(url), url_origin(other)) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => false,
+    }
+}
+
+/// Last path segment of a URL, with percent-encoded bytes decoded.
+pub fn file_name_from_url(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let segment = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok());
+        match (bytes[i], hex.and_then(|h| u8::from_str_radix(h, 16).ok())) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Find files linked from Markdown (`[name](url)`, `![alt](url)`) or HTML
+/// `<img>` tags whose URL passes `is_attachment`.
+///
+/// The link text names the file unless it is empty or a generic "image";
+/// the URL's last segment is used then.
+pub fn markdown_attachments(text: &str, is_attachment: impl Fn(&str) -> bool) -> Vec<Attachment> {
+    let mut links: Vec<(&str, &str)> = Vec::new();
+
+    let mut rest = text;
+    while let Some(pos) = rest.find("](") {
+        let label = rest[..pos]
+            .rfind('[')
+            .map_or("", |start| &rest[start + 1..pos]);
+        let target = &rest[pos + 2..];
+        let end = target.find(')').unwrap_or(target.len());
+        // Drop an optional link title: [name](url "title")
+        let url = target[..end].split_whitespace().next().unwrap_or_default();
+        links.push((label, url.trim_start_matches('<').trim_end_matches('>')));
+        rest = &target[end..];
+    }
+
+    let mut rest = text;
+    while let Some(pos) = rest.find("<img") {
+        let end = rest[pos..].find('>').map_or(rest.len(), |e| pos + e);
+        let tag = &rest[pos..end];
+        if let Some(src) = html_attribute(tag, "src") {
+            links.push((html_attribute(tag, "alt").unwrap_or_default(), src));
+        }
+        rest = &rest[end..];
+    }
+
+    let mut attachments: Vec<Attachment> = Vec::new();
+    for (label, url) in links {
+        if url.is_empty() || !is_attachment(url) || attachments.iter().any(|a| a.url == url) {
+            continue;
+        }
+        let file_name = file_name_from_url(url);
+        let name = if label.trim().is_empty() || label.eq_ignore_ascii_case("image") {
+            file_name.clone()
+        } else {
+            label.trim().to_string()
+        };
+        attachments.push(Attachment {
+            id: url.to_string(),
+            mime_type: mime_type_for(&file_name)
+                .or_else(|| mime_type_for(&name))
+                .map(str::to_string),
+            name,
+            size: None,
+            url: url.to_string(),
+            created_at: None,
+        });
+    }
+    attachments
+}
+
+/// Value of a double-quoted attribute in an HTML tag.
+fn html_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let marker = format!(" {}=\"", name);
+    let start = tag.find(&marker)? + marker.len();
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multipart_file() {
+        let (content_type, body) = multipart_file("file", "crash \"1\".log", b"boom");
+        assert_eq!(
+            content_type,
+            "multipart/form-data; boundary=devboy-tools-attachment-7d3b0c4f"
+        );
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "--devboy-tools-attachment-7d3b0c4f\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"crash '1'.log\"\r\n\
+             Content-Type: text/plain\r\n\r\n\
+             boom\r\n--devboy-tools-attachment-7d3b0c4f--\r\n"
+        );
+    }
+
+    #[test]
+    fn test_multipart_file_boundary_in_content() {
+        let content = format!("x--{}y", MULTIPART_BOUNDARY);
+        let (content_type, _) = multipart_file("file", "data.bin", content.as_bytes());
+        assert!(content_type.ends_with(&format!("{}-1", MULTIPART_BOUNDARY)));
+    }
+
+    #[test]
+    fn test_mime_type_for() {
+        assert_eq!(mime_type_for("Screen Shot.PNG"), Some("image/png"));
+        assert_eq!(mime_type_for("app.log"), Some("text/plain"));
+        assert_eq!(mime_type_for("archive.tar.gz"), Some("application/gzip"));
+        assert_eq!(mime_type_for("README"), None);
+        assert_eq!(mime_type_for("data.xyz"), None);
+    }
+
+    #[test]
+    fn test_url_origin() {
+        assert_eq!(
+            url_origin("https://example.com/a/b?c"),
+            Some("https://example.com")
+        );
+        assert_eq!(
+            url_origin("http://localhost:8080"),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(url_origin("/uploads/abc/file.png"), None);
+        assert!(is_same_origin(
+            "https://Jira.example.com/secure/attachment/1",
+            "https://jira.example.com/rest/api/2"
+        ));
+        assert!(!is_same_origin(
+            "https://jira.example.com.evil.io/x",
+            "https://jira.example.com/rest/api/2"
+        ));
+    }
+
+    #[test]
+    fn test_file_name_from_url() {
+        assert_eq!(
+            file_name_from_url("https://x.io/uploads/abc/my%20file.png?raw=1"),
+            "my file.png"
+        );
+        assert_eq!(file_name_from_url("/uploads/abc/log.txt"), "log.txt");
+        assert_eq!(file_name_from_url("https://x.io/a/100%"), "100%");
+    }
+
+    #[test]
+    fn test_markdown_attachments() {
+        let text = "Steps:\n\
+            ![screenshot.png](/uploads/abc/screenshot.png)\n\
+            See [crash log](/uploads/def/crash.log \"log\") and [docs](https://docs.rs).\n\
+            <img width=\"300\" alt=\"Image\" src=\"https://github.com/user-attachments/assets/1234-abcd\" />\n\
+            Again: [screenshot.png](/uploads/abc/screenshot.png)";
+
+        let attachments = markdown_attachments(text, |url| {
+            url.starts_with("/uploads/") || url.contains("/user-attachments/")
+        });
+
+        assert_eq!(attachments.len(), 3);
+        assert_eq!(attachments[0].name, "screenshot.png");
+        assert_eq!(attachments[0].url, "/uploads/abc/screenshot.png");
+        assert_eq!(attachments[0].mime_type.as_deref(), Some("image/png"));
+        assert_eq!(attachments[1].name, "crash log");
+        assert_eq!(attachments[1].url, "/uploads/def/crash.log");
+        assert_eq!(attachments[1].mime_type.as_deref(), Some("text/plain"));
+        assert_eq!(attachments[2].name, "1234-abcd");
+        assert_eq!(attachments[2].mime_type, None);
+    }
+}
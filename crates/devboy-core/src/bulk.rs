@@ -0,0 +1,559 @@
+//! Bulk issue updates.
+//!
+//! Triage sessions relabel, reassign or close dozens of issues at once.
+//! [`IssueProvider::bulk_update_issues`] applies one [`UpdateIssueInput`] to
+//! many keys and reports the outcome per key instead of stopping at the first
+//! failure. [`preview`] computes the same report without changing anything.
+//! Both update each issue once and check labels and state against the
+//! provider's catalogs first. [`update_routed`] spreads keys of several
+//! providers over them, for the MCP tool and the CLI alike.
+//!
+//! [`IssueProvider::bulk_update_issues`]: crate::IssueProvider::bulk_update_issues
+
+use std::collections::HashMap;
+
+use futures_util::stream::{self, StreamExt};
+
+use crate::catalog;
+use crate::error::Result;
+use crate::provider::IssueProvider;
+use crate::types::{BulkUpdateItem, BulkUpdateReport, Issue, UpdateIssueInput};
+
+/// Number of issues updated at the same time by the default implementation.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Update issues one by one, at most `concurrency` at a time.
+///
+/// Changes are checked against the updated issue the provider returns, so
+/// fields it ignores (e.g. custom fields on GitHub) are reported as skipped.
+pub async fn update_each<P: IssueProvider + ?Sized>(
+    provider: &P,
+    keys: &[String],
+    input: &UpdateIssueInput,
+    concurrency: usize,
+) -> Result<BulkUpdateReport> {
+    check_catalogs(provider, input).await?;
+    let (keys, duplicates) = dedupe_keys(keys);
+
+    let items = stream::iter(keys)
+        .map(|key| async move {
+            match provider.update_issue(&key, input.clone()).await {
+                Ok(updated) => {
+                    let skipped = unapplied_fields(&updated, input);
+                    BulkUpdateItem {
+                        key,
+                        changes: changes(None, input, &skipped),
+                        skipped,
+                        error: None,
+                    }
+                }
+                Err(e) => BulkUpdateItem {
+                    key,
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                },
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    Ok(BulkUpdateReport {
+        dry_run: false,
+        items,
+        duplicates,
+    })
+}
+
+/// Dry run: load each issue and report the changes an update would make.
+pub async fn preview<P: IssueProvider + ?Sized>(
+    provider: &P,
+    keys: &[String],
+    input: &UpdateIssueInput,
+) -> Result<BulkUpdateReport> {
+    check_catalogs(provider, input).await?;
+    let (keys, duplicates) = dedupe_keys(keys);
+
+    let items = stream::iter(keys)
+        .map(|key| async move {
+            match provider.get_issue(&key).await {
+                Ok(issue) => BulkUpdateItem {
+                    key,
+                    changes: describe_changes(Some(&issue), input),
+                    ..Default::default()
+                },
+                Err(e) => BulkUpdateItem {
+                    key,
+                    error: Some(e.to_string()),
+                    ..Default::default()
+                },
+            }
+        })
+        .buffered(DEFAULT_CONCURRENCY)
+        .collect()
+        .await;
+
+    Ok(BulkUpdateReport {
+        dry_run: true,
+        items,
+        duplicates,
+    })
+}
+
+/// Update, or with `dry_run` preview, issues owned by different providers.
+///
+/// Keys are deduplicated and sent to the provider `route` picks for them.
+/// An error of a whole provider, such as an unknown label, fails all of its
+/// keys. Items keep the order of the requested keys.
+pub async fn update_routed<'p, P, R>(
+    keys: &[String],
+    input: &UpdateIssueInput,
+    dry_run: bool,
+    route: R,
+) -> BulkUpdateReport
+where
+    P: IssueProvider + ?Sized + 'p,
+    R: Fn(&str) -> Result<&'p P>,
+{
+    let (keys, duplicates) = dedupe_keys(keys);
+    let failure = |key: &str, error: String| BulkUpdateItem {
+        key: key.to_string(),
+        error: Some(error),
+        ..Default::default()
+    };
+
+    let mut outcomes: HashMap<String, BulkUpdateItem> = HashMap::new();
+    let mut groups: Vec<(&P, Vec<String>)> = Vec::new();
+    for key in &keys {
+        let provider = match route(key) {
+            Ok(provider) => provider,
+            Err(e) => {
+                outcomes.insert(key.clone(), failure(key, e.to_string()));
+                continue;
+            }
+        };
+        match groups.iter_mut().find(|(p, _)| std::ptr::eq(*p, provider)) {
+            Some((_, keys)) => keys.push(key.clone()),
+            None => groups.push((provider, vec![key.clone()])),
+        }
+    }
+
+    for (provider, keys) in groups {
+        let name = provider.provider_name();
+        let report = if dry_run {
+            preview(provider, &keys, input).await
+        } else {
+            provider.bulk_update_issues(&keys, input.clone()).await
+        };
+        match report {
+            Ok(report) => {
+                for item in report.items {
+                    outcomes.insert(item.key.clone(), item);
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Bulk update failed on {}: {}", name, e);
+                for key in &keys {
+                    outcomes.insert(key.clone(), failure(key, format!("{}: {}", name, e)));
+                }
+            }
+        }
+    }
+
+    BulkUpdateReport {
+        dry_run,
+        items: keys.iter().filter_map(|key| outcomes.remove(key)).collect(),
+        duplicates,
+    }
+}
+
+/// Split keys into the distinct keys, in order, and those given more than once.
+/// Keys are trimmed.
+pub fn dedupe_keys(keys: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut distinct: Vec<String> = Vec::new();
+    let mut duplicates: Vec<String> = Vec::new();
+    for key in keys {
+        let key = key.trim();
+        if !distinct.iter().any(|k| k == key) {
+            distinct.push(key.to_string());
+        } else if !duplicates.iter().any(|k| k == key) {
+            duplicates.push(key.to_string());
+        }
+    }
+    (distinct, duplicates)
+}
+
+/// Check the labels and state of an update against the provider's catalogs.
+/// Providers that cannot list a catalog accept any value.
+pub async fn check_catalogs<P: IssueProvider + ?Sized>(
+    provider: &P,
+    input: &UpdateIssueInput,
+) -> Result<()> {
+    if let Some(labels) = input.labels.as_ref().filter(|l| !l.is_empty()) {
+        match provider.list_labels().await {
+            Ok(labels_catalog) => catalog::check_labels(labels, &labels_catalog)?,
+            Err(e) => tracing::debug!("Labels not checked: {}", e),
+        }
+    }
+    if let Some(state) = &input.state {
+        match provider.list_statuses().await {
+            Ok(statuses) => catalog::check_status(state, &statuses)?,
+            Err(e) => tracing::debug!("State not checked: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Describe the changes of an update: "state: open → closed" when the current
+/// issue is known (unchanged values are left out), "state → closed" otherwise.
+pub fn describe_changes(issue: Option<&Issue>, input: &UpdateIssueInput) -> Vec<String> {
+    changes(issue, input, &[])
+}
+
+/// Requested fields that an updated issue does not reflect.
+///
+/// Only fields that can be compared reliably are checked: labels, assignees,
+/// priority and custom fields. States and titles are left to the provider,
+/// which reports its own errors for them.
+pub fn unapplied_fields(updated: &Issue, input: &UpdateIssueInput) -> Vec<String> {
+    let mut skipped = Vec::new();
+
+    if let Some(labels) = &input.labels {
+        let applied = labels.len() == updated.labels.len()
+            && labels
+                .iter()
+                .all(|l| updated.labels.iter().any(|u| u.eq_ignore_ascii_case(l)));
+        if !applied {
+            skipped.push("labels".to_string());
+        }
+    }
+    if let Some(assignees) = &input.assignees {
+        let applied = if assignees.is_empty() {
+            updated.assignees.is_empty()
+        } else {
+            assignees.iter().all(|wanted| {
+                let wanted = wanted.trim().trim_start_matches('@');
+                updated.assignees.iter().any(|user| {
+                    wanted.eq_ignore_ascii_case("me")
+                        || [
+                            Some(&user.username),
+                            user.name.as_ref(),
+                            user.email.as_ref(),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .any(|v| v.eq_ignore_ascii_case(wanted))
+                })
+            })
+        };
+        if !applied {
+            skipped.push("assignees".to_string());
+        }
+    }
+    if input.priority.is_some() && updated.priority.is_none() {
+        skipped.push("priority".to_string());
+    }
+    // Custom fields may be requested by ID, so only check that the provider
+    // reports custom fields at all
+    if updated.custom_fields.is_empty() {
+        skipped.extend(
+            input
+                .custom_fields
+                .iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(name, _)| name.clone()),
+        );
+    }
+
+    skipped
+}
+
+fn changes(issue: Option<&Issue>, input: &UpdateIssueInput, skipped: &[String]) -> Vec<String> {
+    let mut changes = Vec::new();
+    let mut change = |field: &str, before: Option<String>, after: String| match before {
+        _ if skipped.iter().any(|s| s == field) => {}
+        Some(before) if before.eq_ignore_ascii_case(&after) => {}
+        Some(before) => changes.push(format!("{}: {} → {}", field, before, after)),
+        None => changes.push(format!("{} → {}", field, after)),
+    };
+
+    if let Some(title) = &input.title {
+        change("title", issue.map(|i| i.title.clone()), title.clone());
+    }
+    if let Some(description) = &input.description {
+        let unchanged = issue.is_some_and(|i| i.description.as_ref() == Some(description));
+        if !unchanged {
+            change("description", None, "updated".to_string());
+        }
+    }
+    if let Some(state) = &input.state {
+        change("state", issue.map(|i| i.state.clone()), state.clone());
+    }
+    if let Some(labels) = &input.labels {
+        change(
+            "labels",
+            issue.map(|i| list(i.labels.iter().map(String::as_str))),
+            list(labels.iter().map(String::as_str)),
+        );
+    }
+    if let Some(assignees) = &input.assignees {
+        change(
+            "assignees",
+            issue.map(|i| list(i.assignees.iter().map(|u| u.username.as_str()))),
+            list(assignees.iter().map(String::as_str)),
+        );
+    }
+    if let Some(priority) = &input.priority {
+        change(
+            "priority",
+            issue.map(|i| i.priority.clone().unwrap_or_else(|| "none".to_string())),
+            priority.clone(),
+        );
+    }
+    for (name, value) in &input.custom_fields {
+        let before = issue.map(|i| i.custom_fields.get(name).cloned().unwrap_or_default());
+        change(name, before.map(|v| v.to_string()), value.to_string());
+    }
+
+    changes
+}
+
+fn list<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let values: Vec<&str> = values.collect();
+    if values.is_empty() {
+        "none".to_string()
+    } else {
+        values.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Error, Result};
+    use crate::types::{Comment, CreateIssueInput, IssueFilter, Label, User};
+    use async_trait::async_trait;
+
+    struct TestProvider;
+
+    fn issue(key: &str) -> Issue {
+        Issue {
+            key: key.to_string(),
+            title: "Crash on start".to_string(),
+            state: "open".to_string(),
+            labels: vec!["bug".to_string()],
+            assignees: vec![User {
+                username: "jdoe".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[async_trait]
+    impl IssueProvider for TestProvider {
+        async fn get_issues(&self, _filter: IssueFilter) -> Result<Vec<Issue>> {
+            Ok(vec![])
+        }
+
+        async fn get_issue(&self, key: &str) -> Result<Issue> {
+            match key {
+                "gh#404" => Err(Error::NotFound(key.to_string())),
+                _ => Ok(issue(key)),
+            }
+        }
+
+        async fn create_issue(&self, _input: CreateIssueInput) -> Result<Issue> {
+            Err(Error::NotFound("create".to_string()))
+        }
+
+        async fn update_issue(&self, key: &str, _input: UpdateIssueInput) -> Result<Issue> {
+            self.get_issue(key).await
+        }
+
+        async fn get_comments(&self, _issue_key: &str) -> Result<Vec<Comment>> {
+            Ok(vec![])
+        }
+
+        async fn add_comment(&self, issue_key: &str, _body: &str) -> Result<Comment> {
+            Err(Error::NotFound(issue_key.to_string()))
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "test"
+        }
+    }
+
+    fn keys(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|k| k.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_reports_per_key() {
+        let input = UpdateIssueInput {
+            state: Some("closed".to_string()),
+            ..Default::default()
+        };
+        let report = TestProvider
+            .bulk_update_issues(&keys(&["gh#1", "gh#404", "gh#2"]), input)
+            .await
+            .unwrap();
+
+        assert!(!report.dry_run);
+        let order: Vec<&str> = report.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(order, vec!["gh#1", "gh#404", "gh#2"]);
+        assert_eq!(report.succeeded(), 2);
+        assert_eq!(report.items[0].changes, vec!["state → closed"]);
+        let failed: Vec<&str> = report.failed().map(|i| i.key.as_str()).collect();
+        assert_eq!(failed, vec!["gh#404"]);
+        assert_eq!(report.items[1].error.as_deref(), Some("Not found: gh#404"));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_reports_unapplied_fields() {
+        let mut custom_fields = serde_json::Map::new();
+        custom_fields.insert("Team".to_string(), serde_json::json!("Core"));
+        let input = UpdateIssueInput {
+            state: Some("closed".to_string()),
+            labels: Some(vec!["Bug".to_string()]),
+            assignees: Some(vec!["alice".to_string()]),
+            custom_fields,
+            ..Default::default()
+        };
+        // The returned issue keeps its assignee and has no custom fields
+        let report = TestProvider
+            .bulk_update_issues(&keys(&["gh#1"]), input)
+            .await
+            .unwrap();
+
+        let item = &report.items[0];
+        assert_eq!(item.changes, vec!["state → closed", "labels → Bug"]);
+        assert_eq!(item.skipped, vec!["assignees", "Team"]);
+    }
+
+    #[tokio::test]
+    async fn test_preview_skips_unchanged_values() {
+        let input = UpdateIssueInput {
+            state: Some("Open".to_string()),
+            labels: Some(vec!["bug".to_string(), "triage".to_string()]),
+            assignees: Some(vec![]),
+            ..Default::default()
+        };
+        let report = preview(&TestProvider, &keys(&["gh#1", "gh#404"]), &input)
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(
+            report.items[0].changes,
+            vec!["labels: bug → bug, triage", "assignees: jdoe → none"]
+        );
+        assert!(report.items[1].error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_preview_updates_repeated_keys_once() {
+        let input = UpdateIssueInput {
+            state: Some("closed".to_string()),
+            ..Default::default()
+        };
+        let report = preview(
+            &TestProvider,
+            &keys(&["gh#1", " gh#1", "gh#2", "gh#1"]),
+            &input,
+        )
+        .await
+        .unwrap();
+
+        let order: Vec<&str> = report.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(order, vec!["gh#1", "gh#2"]);
+        assert_eq!(report.duplicates, vec!["gh#1"]);
+    }
+
+    struct CatalogProvider;
+
+    #[async_trait]
+    impl IssueProvider for CatalogProvider {
+        async fn get_issues(&self, _filter: IssueFilter) -> Result<Vec<Issue>> {
+            Ok(vec![])
+        }
+
+        async fn get_issue(&self, key: &str) -> Result<Issue> {
+            Ok(issue(key))
+        }
+
+        async fn create_issue(&self, _input: CreateIssueInput) -> Result<Issue> {
+            Err(Error::NotFound("create".to_string()))
+        }
+
+        async fn update_issue(&self, key: &str, _input: UpdateIssueInput) -> Result<Issue> {
+            Ok(issue(key))
+        }
+
+        async fn get_comments(&self, _issue_key: &str) -> Result<Vec<Comment>> {
+            Ok(vec![])
+        }
+
+        async fn add_comment(&self, issue_key: &str, _body: &str) -> Result<Comment> {
+            Err(Error::NotFound(issue_key.to_string()))
+        }
+
+        async fn list_labels(&self) -> Result<Vec<Label>> {
+            Ok(vec![Label {
+                name: "bug".to_string(),
+                ..Default::default()
+            }])
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "catalog"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_unknown_labels() {
+        let input = UpdateIssueInput {
+            labels: Some(vec!["bgu".to_string()]),
+            ..Default::default()
+        };
+        assert!(preview(&CatalogProvider, &keys(&["gh#1"]), &input)
+            .await
+            .is_err());
+        assert!(CatalogProvider
+            .bulk_update_issues(&keys(&["gh#1"]), input)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_routed_groups_keys_by_provider() {
+        let providers: Vec<&dyn IssueProvider> = vec![&TestProvider, &CatalogProvider];
+        let route = |key: &str| match key.split_once('#') {
+            Some(("gh", _)) => Ok(providers[0]),
+            Some(("jira", _)) => Ok(providers[1]),
+            _ => Err(Error::ProviderNotFound(key.to_string())),
+        };
+        let input = UpdateIssueInput {
+            labels: Some(vec!["triage".to_string()]),
+            ..Default::default()
+        };
+        let report = update_routed(
+            &keys(&["jira#1", "gh#1", "CU-1", "jira#1"]),
+            &input,
+            true,
+            route,
+        )
+        .await;
+
+        let order: Vec<&str> = report.items.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(order, vec!["jira#1", "gh#1", "CU-1"]);
+        // The unknown label only fails the keys of the provider with a catalog
+        assert!(report.items[0]
+            .error
+            .as_deref()
+            .is_some_and(|e| e.starts_with("catalog: ")));
+        assert!(report.items[1].error.is_none());
+        assert!(report.items[2].error.is_some());
+        assert_eq!(report.duplicates, vec!["jira#1"]);
+    }
+}
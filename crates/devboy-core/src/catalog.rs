@@ -0,0 +1,162 @@
+//! Label and status catalogs.
+//!
+//! Agents often guess label names and states that do not exist in a project.
+//! Providers list their catalogs with [`IssueProvider::list_labels`] and
+//! [`IssueProvider::list_statuses`]; these helpers check values against them
+//! and suggest the closest known name.
+//!
+//! [`IssueProvider::list_labels`]: crate::IssueProvider::list_labels
+//! [`IssueProvider::list_statuses`]: crate::IssueProvider::list_statuses
+
+use crate::error::{Error, Result};
+use crate::types::{IssueStatus, Label};
+
+/// States every provider accepts in `update_issue`, whatever its workflow.
+pub const GENERIC_STATES: &[&str] = &["open", "opened", "closed"];
+
+/// Check that every label exists in the catalog.
+pub fn check_labels(labels: &[String], catalog: &[Label]) -> Result<()> {
+    let names: Vec<&str> = catalog.iter().map(|l| l.name.as_str()).collect();
+    for label in labels {
+        if !names.iter().any(|name| name.eq_ignore_ascii_case(label)) {
+            return Err(unknown("label", label, &names));
+        }
+    }
+    Ok(())
+}
+
+/// Check that a state is a status name, a status category or a generic state.
+pub fn check_status(state: &str, catalog: &[IssueStatus]) -> Result<()> {
+    let known = GENERIC_STATES
+        .iter()
+        .copied()
+        .chain(catalog.iter().map(|s| s.name.as_str()))
+        .chain(catalog.iter().map(|s| s.category.as_str()))
+        .any(|name| name.eq_ignore_ascii_case(state));
+    if known {
+        return Ok(());
+    }
+    let names: Vec<&str> = catalog.iter().map(|s| s.name.as_str()).collect();
+    Err(unknown("state", state, &names))
+}
+
+/// The candidate closest to `value`: a case-insensitive match within a third
+/// of its length in edits, or a name containing it (or contained in it).
+pub fn suggest<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let value = value.to_lowercase();
+    let max_distance = (value.chars().count() / 3).max(1);
+    candidates
+        .iter()
+        .map(|c| (*c, edit_distance(&value, &c.to_lowercase())))
+        .filter(|(_, d)| *d <= max_distance)
+        .min_by_key(|(_, d)| *d)
+        .map(|(c, _)| c)
+        .or_else(|| {
+            candidates.iter().copied().find(|c| {
+                let c = c.to_lowercase();
+                c.contains(&value) || value.contains(&c)
+            })
+        })
+}
+
+/// Error for a value missing from a catalog, suggesting the closest name.
+pub fn unknown(kind: &str, value: &str, names: &[&str]) -> Error {
+    let hint = suggest(value, names)
+        .map(|s| format!(", did you mean '{}'?", s))
+        .unwrap_or_else(|| ".".to_string());
+    Error::InvalidData(format!(
+        "Unknown {} '{}'{} Available: {}",
+        kind,
+        value,
+        hint,
+        names.join(", ")
+    ))
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+        prev = current;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels() -> Vec<Label> {
+        ["bug", "feature", "good first issue"]
+            .into_iter()
+            .map(|name| Label {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn statuses() -> Vec<IssueStatus> {
+        [
+            ("To Do", "todo"),
+            ("In Review", "in_progress"),
+            ("Done", "done"),
+        ]
+        .into_iter()
+        .map(|(name, category)| IssueStatus {
+            name: name.to_string(),
+            category: category.to_string(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("bug", "bug"), 0);
+        assert_eq!(edit_distance("bgu", "bug"), 2);
+        assert_eq!(edit_distance("feature", "features"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let names = ["bug", "feature", "good first issue"];
+        assert_eq!(suggest("Featrue", &names), Some("feature"));
+        assert_eq!(suggest("first issue", &names), Some("good first issue"));
+        assert_eq!(suggest("docs", &names), None);
+    }
+
+    #[test]
+    fn test_check_labels() {
+        let catalog = labels();
+        assert!(check_labels(&["BUG".to_string()], &catalog).is_ok());
+
+        let err = check_labels(&["bug".to_string(), "feture".to_string()], &catalog)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown label 'feture', did you mean 'feature'?"));
+        assert!(err.contains("Available: bug, feature, good first issue"));
+
+        let err = check_labels(&["docs".to_string()], &catalog)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown label 'docs'. Available:"));
+    }
+
+    #[test]
+    fn test_check_status() {
+        let catalog = statuses();
+        for state in ["in review", "closed", "open", "in_progress", "Done"] {
+            assert!(check_status(state, &catalog).is_ok(), "{}", state);
+        }
+
+        let err = check_status("In Reveiw", &catalog).unwrap_err().to_string();
+        assert!(err.contains("Unknown state 'In Reveiw', did you mean 'In Review'?"));
+    }
+}
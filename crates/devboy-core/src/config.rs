@@ -58,6 +58,10 @@ pub struct Config {
     /// Jira configuration
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub jira: Option<JiraConfig>,
+
+    /// Workflow settings (branch naming, etc.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<WorkflowConfig>,
 }
 
 /// GitHub provider configuration.
@@ -90,6 +94,9 @@ pub struct ClickUpConfig {
     /// ClickUp team (workspace) ID — required for custom task ID resolution
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_id: Option<String>,
+    /// Additional list IDs searched together with `list_id` (requires `team_id`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lists: Vec<String>,
 }
 
 /// Jira provider configuration.
@@ -103,6 +110,15 @@ pub struct JiraConfig {
     pub email: String,
 }
 
+/// Workflow settings shared by all providers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    /// Branch name pattern for issues, e.g. "{id}-{slug}" or "feature/{key}-{slug}".
+    /// See [`crate::workflow::branch_name_for_issue`] for placeholders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_pattern: Option<String>,
+}
+
 fn default_gitlab_url() -> String {
     "https://gitlab.com".to_string()
 }
@@ -258,10 +274,19 @@ impl Config {
                 let config = self.clickup.get_or_insert_with(|| ClickUpConfig {
                     list_id: String::new(),
                     team_id: None,
+                    lists: Vec::new(),
                 });
                 match field {
                     "list_id" | "list" => config.list_id = value.to_string(),
                     "team_id" | "team" => config.team_id = Some(value.to_string()),
+                    "lists" => {
+                        config.lists = value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|id| !id.is_empty())
+                            .map(String::from)
+                            .collect()
+                    }
                     _ => {
                         return Err(Error::Config(format!(
                             "Unknown ClickUp config field: {}",
@@ -288,6 +313,18 @@ impl Config {
                     }
                 }
             }
+            "workflow" => {
+                let config = self.workflow.get_or_insert_with(WorkflowConfig::default);
+                match field {
+                    "branch_pattern" => config.branch_pattern = Some(value.to_string()),
+                    _ => {
+                        return Err(Error::Config(format!(
+                            "Unknown workflow config field: {}",
+                            field
+                        )))
+                    }
+                }
+            }
             _ => {
                 return Err(Error::Config(format!("Unknown provider: {}", provider)));
             }
@@ -345,6 +382,7 @@ impl Config {
                 match field {
                     "list_id" | "list" => Ok(Some(config.list_id.clone())),
                     "team_id" | "team" => Ok(config.team_id.clone()),
+                    "lists" => Ok(Some(config.lists.join(","))),
                     _ => Err(Error::Config(format!(
                         "Unknown ClickUp config field: {}",
                         field
@@ -365,6 +403,18 @@ impl Config {
                     ))),
                 }
             }
+            "workflow" => {
+                let Some(config) = &self.workflow else {
+                    return Ok(None);
+                };
+                match field {
+                    "branch_pattern" => Ok(config.branch_pattern.clone()),
+                    _ => Err(Error::Config(format!(
+                        "Unknown workflow config field: {}",
+                        field
+                    ))),
+                }
+            }
             _ => Err(Error::Config(format!("Unknown provider: {}", provider))),
         }
     }
@@ -544,6 +594,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_and_get_clickup_lists() {
+        let mut config = Config::default();
+
+        config.set("clickup.lists", "901, 902,").unwrap();
+
+        assert_eq!(config.clickup.as_ref().unwrap().lists, vec!["901", "902"]);
+        assert_eq!(
+            config.get("clickup.lists").unwrap(),
+            Some("901,902".to_string())
+        );
+    }
+
     #[test]
     fn test_set_and_get_jira() {
         let mut config = Config::default();
@@ -651,6 +714,23 @@ mod tests {
         assert_eq!(config.get("jira.url").unwrap(), None);
     }
 
+    #[test]
+    fn test_set_and_get_workflow() {
+        let mut config = Config::default();
+        assert_eq!(config.get("workflow.branch_pattern").unwrap(), None);
+
+        config
+            .set("workflow.branch_pattern", "feature/{key}-{slug}")
+            .unwrap();
+        assert_eq!(
+            config.get("workflow.branch_pattern").unwrap(),
+            Some("feature/{key}-{slug}".to_string())
+        );
+        assert!(config.set("workflow.unknown", "x").is_err());
+        // Workflow settings are not a provider
+        assert!(!config.has_any_provider());
+    }
+
     #[test]
     fn test_unknown_provider_set() {
         let mut config = Config::default();
@@ -695,12 +775,14 @@ mod tests {
             clickup: Some(ClickUpConfig {
                 list_id: "l".to_string(),
                 team_id: None,
+                lists: vec![],
             }),
             jira: Some(JiraConfig {
                 url: "u".to_string(),
                 project_key: "k".to_string(),
                 email: "e".to_string(),
             }),
+            workflow: None,
         };
 
         let providers = config.configured_providers();
@@ -774,6 +856,7 @@ mod tests {
             }),
             clickup: None,
             jira: None,
+            workflow: None,
         };
 
         let toml_str = toml::to_string_pretty(&config).unwrap();
@@ -0,0 +1,198 @@
+//! Custom field helpers shared by providers.
+//!
+//! Custom field values arrive from agents as loose JSON ("5" for a number,
+//! a single string for a multi-select). These helpers resolve fields by name
+//! and coerce values before providers convert them to their API shapes.
+
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::time::{format_date, parse_date};
+use crate::types::CustomField;
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Find a custom field by ID or by case-insensitive name.
+pub fn find_custom_field<'a>(fields: &'a [CustomField], key: &str) -> Result<&'a CustomField> {
+    if let Some(field) = fields.iter().find(|f| f.id == key) {
+        return Ok(field);
+    }
+
+    let matches: Vec<&CustomField> = fields
+        .iter()
+        .filter(|f| f.name.eq_ignore_ascii_case(key))
+        .collect();
+    match matches.as_slice() {
+        [field] => Ok(field),
+        [] => {
+            let names: Vec<&str> = fields.iter().map(|f| f.name.as_str()).collect();
+            Err(Error::InvalidData(format!(
+                "Unknown custom field '{}'. Available: {}",
+                key,
+                names.join(", ")
+            )))
+        }
+        _ => {
+            let ids: Vec<&str> = matches.iter().map(|f| f.id.as_str()).collect();
+            Err(Error::InvalidData(format!(
+                "Custom field name '{}' is ambiguous, use a field ID: {}",
+                key,
+                ids.join(", ")
+            )))
+        }
+    }
+}
+
+/// Error for a value that does not fit the field type.
+pub fn invalid_value(field: &CustomField, expected: &str, value: &Value) -> Error {
+    Error::InvalidData(format!(
+        "Custom field '{}' expects {}, got {}",
+        field.name, expected, value
+    ))
+}
+
+/// Coerce a number or a numeric string to a JSON number.
+pub fn as_number(value: &Value) -> Option<Value> {
+    match value {
+        Value::Number(_) => Some(value.clone()),
+        Value::String(s) => {
+            let s = s.trim();
+            if let Ok(n) = s.parse::<i64>() {
+                Some(Value::from(n))
+            } else {
+                s.parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Coerce a scalar to text.
+pub fn as_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Coerce a scalar or an array of scalars to a list of strings.
+pub fn as_list(value: &Value) -> Option<Vec<String>> {
+    match value {
+        Value::Array(items) => items.iter().map(as_text).collect(),
+        other => as_text(other).map(|text| vec![text]),
+    }
+}
+
+/// Coerce a boolean or "true"/"false" string to a boolean.
+pub fn as_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(b) => Some(*b),
+        Value::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" => Some(true),
+            "false" | "no" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Parse a "YYYY-MM-DD" date to a Unix timestamp in milliseconds (UTC midnight).
+pub fn date_to_unix_ms(date: &str) -> Option<i64> {
+    parse_date(date).map(|days| days * MILLIS_PER_DAY)
+}
+
+/// Format a Unix timestamp in milliseconds as a "YYYY-MM-DD" date (UTC).
+pub fn unix_ms_to_date(millis: i64) -> String {
+    format_date(millis.div_euclid(MILLIS_PER_DAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(id: &str, name: &str) -> CustomField {
+        CustomField {
+            id: id.to_string(),
+            name: name.to_string(),
+            field_type: "number".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_find_custom_field() {
+        let fields = vec![
+            field("customfield_1", "Story Points"),
+            field("customfield_2", "Team"),
+            field("customfield_3", "Team"),
+        ];
+
+        assert_eq!(
+            find_custom_field(&fields, "story points").unwrap().id,
+            "customfield_1"
+        );
+        assert_eq!(
+            find_custom_field(&fields, "customfield_3").unwrap().id,
+            "customfield_3"
+        );
+
+        let err = find_custom_field(&fields, "Team").unwrap_err().to_string();
+        assert!(err.contains("ambiguous"), "{}", err);
+        assert!(err.contains("customfield_2, customfield_3"), "{}", err);
+
+        let err = find_custom_field(&fields, "Severity")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown custom field 'Severity'"), "{}", err);
+    }
+
+    #[test]
+    fn test_as_number() {
+        assert_eq!(as_number(&json!(5)), Some(json!(5)));
+        assert_eq!(as_number(&json!("8")), Some(json!(8)));
+        assert_eq!(as_number(&json!(" 2.5 ")), Some(json!(2.5)));
+        assert_eq!(as_number(&json!("many")), None);
+        assert_eq!(as_number(&json!(true)), None);
+    }
+
+    #[test]
+    fn test_as_text_and_list() {
+        assert_eq!(as_text(&json!("a")), Some("a".to_string()));
+        assert_eq!(as_text(&json!(3)), Some("3".to_string()));
+        assert_eq!(as_text(&json!({"a": 1})), None);
+
+        assert_eq!(as_list(&json!("a")), Some(vec!["a".to_string()]));
+        assert_eq!(
+            as_list(&json!(["a", 2])),
+            Some(vec!["a".to_string(), "2".to_string()])
+        );
+        assert_eq!(as_list(&json!([{"a": 1}])), None);
+    }
+
+    #[test]
+    fn test_as_bool() {
+        assert_eq!(as_bool(&json!(true)), Some(true));
+        assert_eq!(as_bool(&json!("False")), Some(false));
+        assert_eq!(as_bool(&json!("maybe")), None);
+    }
+
+    #[test]
+    fn test_dates() {
+        assert_eq!(date_to_unix_ms("1970-01-01"), Some(0));
+        assert_eq!(date_to_unix_ms("2024-01-15"), Some(1_705_276_800_000));
+        assert_eq!(date_to_unix_ms("2000-02-29"), Some(951_782_400_000));
+        assert_eq!(date_to_unix_ms("2023-02-29"), None);
+        assert_eq!(date_to_unix_ms("2024-13-01"), None);
+        assert_eq!(date_to_unix_ms("15.01.2024"), None);
+
+        assert_eq!(unix_ms_to_date(0), "1970-01-01");
+        assert_eq!(unix_ms_to_date(1_705_276_800_000), "2024-01-15");
+        assert_eq!(unix_ms_to_date(1_705_320_000_000), "2024-01-15");
+    }
+}
@@ -0,0 +1,149 @@
+//! Small encoding helpers shared by providers.
+//!
+//! Repository file APIs return file content as base64 and expect file paths
+//! as URL-encoded path segments. These helpers avoid pulling in extra crates
+//! for such small tasks.
+
+use crate::error::{Error, Result};
+
+/// Number of leading bytes inspected when detecting binary content.
+const BINARY_SNIFF_LEN: usize = 8000;
+
+/// Decode standard base64 (RFC 4648), ignoring whitespace and line breaks.
+pub fn decode_base64(input: &str) -> Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in input.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            b' ' | b'\n' | b'\r' | b'\t' => continue,
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "Invalid base64 character: {:?}",
+                    c as char
+                )))
+            }
+        };
+
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+        }
+    }
+
+    Ok(output)
+}
+
+/// Encode bytes as standard base64 (RFC 4648) with padding.
+pub fn encode_base64(input: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+
+    for chunk in input.chunks(3) {
+        let triple = (u32::from(chunk[0]) << 16)
+            | (u32::from(chunk.get(1).copied().unwrap_or(0)) << 8)
+            | u32::from(chunk.get(2).copied().unwrap_or(0));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[((triple >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+
+    output
+}
+
+/// Check whether content looks binary (contains NUL bytes or is not UTF-8).
+pub fn is_binary(bytes: &[u8]) -> bool {
+    let sample = &bytes[..bytes.len().min(BINARY_SNIFF_LEN)];
+    if sample.contains(&0) {
+        return true;
+    }
+
+    match std::str::from_utf8(sample) {
+        Ok(_) => false,
+        // A multi-byte character cut off by the sample boundary is still text
+        Err(e) => e.error_len().is_some(),
+    }
+}
+
+/// Percent-encode a string for use as a single URL path segment or query value.
+///
+/// Only unreserved characters (RFC 3986) are kept, so `/` becomes `%2F`.
+pub fn encode_uri_component(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                output.push(byte as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("aGVsbG8=").unwrap(), b"hello");
+        assert_eq!(decode_base64("aGVsbG8gd29ybGQ=").unwrap(), b"hello world");
+        assert_eq!(decode_base64("YQ==").unwrap(), b"a");
+        assert_eq!(decode_base64("").unwrap(), b"");
+    }
+
+    #[test]
+    fn test_decode_base64_with_line_breaks() {
+        // GitHub wraps base64 content at 60 characters
+        assert_eq!(
+            decode_base64("Zm4gbWFp\nbigpIHt9\n").unwrap(),
+            b"fn main() {}"
+        );
+    }
+
+    #[test]
+    fn test_decode_base64_invalid() {
+        assert!(decode_base64("aGV$bG8=").is_err());
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b"hello"), "aGVsbG8=");
+        assert_eq!(encode_base64(b"hello world"), "aGVsbG8gd29ybGQ=");
+        assert_eq!(encode_base64(b"a"), "YQ==");
+        assert_eq!(encode_base64(b"abc"), "YWJj");
+        assert_eq!(encode_base64(b""), "");
+
+        let bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode_base64(&encode_base64(&bytes)).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_is_binary() {
+        assert!(!is_binary(b"fn main() {}\n"));
+        assert!(!is_binary("привет".as_bytes()));
+        assert!(is_binary(&[0x89, b'P', b'N', b'G', 0x00, 0x1a]));
+        assert!(is_binary(&[0xff, 0xfe, 0xfd]));
+    }
+
+    #[test]
+    fn test_encode_uri_component() {
+        assert_eq!(encode_uri_component("src/main.rs"), "src%2Fmain.rs");
+        assert_eq!(encode_uri_component("a b+c"), "a%20b%2Bc");
+        assert_eq!(encode_uri_component("feature/x-1"), "feature%2Fx-1");
+    }
+}
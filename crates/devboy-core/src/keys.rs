@@ -0,0 +1,384 @@
+//! Routing of issue and merge request keys to providers.
+//!
+//! Each provider declares the keys it owns with [`Provider::key_patterns`]
+//! (`gh#12`, `gitlab#12`, `CU-abc`, `PROJ-1`, web URLs). A [`KeyResolver`]
+//! picks the one provider a key belongs to, so handlers call a single
+//! provider instead of trying every one, and report keys that several
+//! providers claim instead of silently using the first.
+//!
+//! [`Provider::key_patterns`]: crate::Provider::key_patterns
+
+use crate::error::{Error, Result};
+
+/// Keys owned by a provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyPattern {
+    /// Keys starting with a prefix, e.g. `gh#` or `CU-`
+    Prefix(String),
+    /// Issue keys of a project: `PROJ` owns `PROJ-123` (case-insensitive)
+    Project(String),
+    /// Web URLs starting with a prefix, e.g. `https://github.com/org/repo/`
+    Url(String),
+    /// Keys no other provider claims (e.g. ClickUp custom task IDs)
+    Fallback,
+}
+
+impl KeyPattern {
+    /// Whether the pattern claims `key`; [`KeyPattern::Fallback`] claims no key by itself.
+    pub fn matches(&self, key: &str) -> bool {
+        match self {
+            KeyPattern::Prefix(prefix) => key.len() > prefix.len() && key.starts_with(prefix),
+            KeyPattern::Project(project) => key.split_once('-').is_some_and(|(p, number)| {
+                p.eq_ignore_ascii_case(project)
+                    && !number.is_empty()
+                    && number.chars().all(|c| c.is_ascii_digit())
+            }),
+            KeyPattern::Url(prefix) => {
+                key.len() > prefix.len()
+                    && key
+                        .get(..prefix.len())
+                        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+            }
+            KeyPattern::Fallback => false,
+        }
+    }
+
+    /// Example of the keys the pattern owns, for error messages.
+    fn example(&self) -> String {
+        match self {
+            KeyPattern::Prefix(prefix) => format!("{}<id>", prefix),
+            KeyPattern::Project(project) => format!("{}-<number>", project),
+            KeyPattern::Url(prefix) => format!("{}...", prefix),
+            KeyPattern::Fallback => "other keys".to_string(),
+        }
+    }
+}
+
+/// Resolves keys to the providers that own them.
+///
+/// Providers are registered in order and identified by their index.
+#[derive(Debug, Default, Clone)]
+pub struct KeyResolver {
+    providers: Vec<(String, Vec<KeyPattern>)>,
+}
+
+impl KeyResolver {
+    /// Create an empty resolver.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a provider and the keys it owns; returns its index.
+    pub fn register(&mut self, name: &str, patterns: Vec<KeyPattern>) -> usize {
+        self.providers.push((name.to_string(), patterns));
+        self.providers.len() - 1
+    }
+
+    /// Index of the provider owning `key`.
+    ///
+    /// Keys matched by no pattern go to the provider with a
+    /// [`KeyPattern::Fallback`]; web URLs never do, they must point to a
+    /// configured repository or project. Keys owned by several providers are
+    /// reported as ambiguous.
+    pub fn resolve(&self, key: &str) -> Result<usize> {
+        let key = key.trim();
+        let owners = self.owners(|p| p.matches(key));
+        if owners.is_empty() && is_url(key) {
+            return Err(Error::ProviderNotFound(format!(
+                "'{}' does not point to a configured repository or project. Configured URLs: {}",
+                key,
+                self.urls()
+            )));
+        }
+        let owners = if owners.is_empty() {
+            self.owners(|p| *p == KeyPattern::Fallback)
+        } else {
+            owners
+        };
+
+        match owners.as_slice() {
+            [index] => Ok(*index),
+            [] => Err(Error::ProviderNotFound(format!(
+                "no configured provider handles key '{}'. Known key formats: {}",
+                key,
+                self.formats()
+            ))),
+            _ => {
+                let names: Vec<&str> = owners
+                    .iter()
+                    .map(|i| self.providers[*i].0.as_str())
+                    .collect();
+                Err(Error::InvalidData(format!(
+                    "Key '{}' is ambiguous: it matches {}. Use a prefixed key ({}) or pass the provider",
+                    key,
+                    names.join(", "),
+                    self.formats()
+                )))
+            }
+        }
+    }
+
+    fn owners(&self, matches: impl Fn(&KeyPattern) -> bool) -> Vec<usize> {
+        self.providers
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, patterns))| patterns.iter().any(&matches))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn urls(&self) -> String {
+        let urls: Vec<String> = self
+            .providers
+            .iter()
+            .flat_map(|(name, patterns)| {
+                patterns.iter().filter_map(move |p| match p {
+                    KeyPattern::Url(prefix) => Some(format!("{} ({})", prefix, name)),
+                    _ => None,
+                })
+            })
+            .collect();
+        if urls.is_empty() {
+            "none".to_string()
+        } else {
+            urls.join(", ")
+        }
+    }
+
+    fn formats(&self) -> String {
+        self.providers
+            .iter()
+            .flat_map(|(name, patterns)| {
+                patterns
+                    .iter()
+                    .filter(|p| **p != KeyPattern::Fallback)
+                    .map(move |p| format!("{} ({})", p.example(), name))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Whether a key is a web URL rather than a short key.
+pub fn is_url(key: &str) -> bool {
+    let key = key.trim();
+    key.starts_with("https://") || key.starts_with("http://")
+}
+
+/// Path segment following `marker` in a web URL, without query or fragment:
+/// `45` for `https://github.com/org/repo/pull/45/files` and `/pull/`.
+/// Returns `None` for short keys.
+pub fn url_path_id<'a>(key: &'a str, marker: &str) -> Option<&'a str> {
+    let key = key.trim();
+    if !is_url(key) {
+        return None;
+    }
+    let path = key.split(['?', '#']).next()?;
+    let (_, rest) = path.split_once(marker)?;
+    rest.split('/').next().filter(|id| !id.is_empty())
+}
+
+/// Parse the number of a short key with `prefix` (`gh#12`) or of a web URL
+/// starting with `url_prefix` (`https://github.com/org/repo/issues/12`).
+///
+/// `kind` names the key in errors ("issue", "PR"). URLs of other
+/// repositories are rejected instead of being read as a local number.
+pub fn parse_key_number(key: &str, prefix: &str, url_prefix: &str, kind: &str) -> Result<u64> {
+    let invalid = || Error::InvalidData(format!("Invalid {} key: {}", kind, key));
+    let trimmed = key.trim();
+    let id = if is_url(trimmed) {
+        let in_repo = trimmed
+            .get(..url_prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(url_prefix));
+        if !in_repo {
+            return Err(Error::InvalidData(format!(
+                "'{}' is not a {} URL of this repository, expected {}<number>",
+                trimmed, kind, url_prefix
+            )));
+        }
+        trimmed[url_prefix.len()..]
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or_default()
+    } else {
+        key.strip_prefix(prefix).ok_or_else(invalid)?
+    };
+    id.parse::<u64>().map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver() -> KeyResolver {
+        let mut resolver = KeyResolver::new();
+        resolver.register(
+            "github",
+            vec![
+                KeyPattern::Prefix("gh#".to_string()),
+                KeyPattern::Prefix("pr#".to_string()),
+            ],
+        );
+        resolver.register(
+            "jira",
+            vec![
+                KeyPattern::Prefix("jira#".to_string()),
+                KeyPattern::Project("PROJ".to_string()),
+                KeyPattern::Url("https://example.atlassian.net/browse/".to_string()),
+            ],
+        );
+        resolver.register(
+            "clickup",
+            vec![KeyPattern::Prefix("CU-".to_string()), KeyPattern::Fallback],
+        );
+        resolver
+    }
+
+    #[test]
+    fn test_key_pattern_matches() {
+        let project = KeyPattern::Project("PROJ".to_string());
+        assert!(project.matches("PROJ-12"));
+        assert!(project.matches("proj-12"));
+        assert!(!project.matches("PROJX-12"));
+        assert!(!project.matches("PROJ-"));
+        assert!(!project.matches("PROJ-1a"));
+
+        let prefix = KeyPattern::Prefix("gh#".to_string());
+        assert!(prefix.matches("gh#1"));
+        assert!(!prefix.matches("gh#"));
+        assert!(!prefix.matches("pr#1"));
+
+        let url = KeyPattern::Url("https://github.com/org/repo/".to_string());
+        assert!(url.matches("https://GitHub.com/org/repo/pull/45"));
+        assert!(!url.matches("https://github.com/org/other/pull/45"));
+        assert!(!KeyPattern::Fallback.matches("anything"));
+    }
+
+    #[test]
+    fn test_resolve_by_pattern() {
+        let resolver = resolver();
+        assert_eq!(resolver.resolve("gh#12").unwrap(), 0);
+        assert_eq!(resolver.resolve("pr#3").unwrap(), 0);
+        assert_eq!(resolver.resolve("PROJ-1").unwrap(), 1);
+        assert_eq!(resolver.resolve(" jira#OTHER-5 ").unwrap(), 1);
+        assert_eq!(
+            resolver
+                .resolve("https://example.atlassian.net/browse/PROJ-1")
+                .unwrap(),
+            1
+        );
+        assert_eq!(resolver.resolve("CU-abc").unwrap(), 2);
+        // Unclaimed keys go to the fallback provider
+        assert_eq!(resolver.resolve("DEV-42").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_resolve_ambiguous() {
+        let mut resolver = resolver();
+        resolver.register("gitlab", vec![KeyPattern::Project("PROJ".to_string())]);
+
+        let err = resolver.resolve("PROJ-1").unwrap_err().to_string();
+        assert!(
+            err.contains("Key 'PROJ-1' is ambiguous: it matches jira, gitlab"),
+            "got: {}",
+            err
+        );
+        assert!(err.contains("gh#<id> (github)"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_url_path_id() {
+        assert_eq!(
+            url_path_id("https://github.com/org/repo/pull/45/files?w=1", "/pull/"),
+            Some("45")
+        );
+        assert_eq!(
+            url_path_id(
+                "https://gitlab.example.com/group/proj/-/merge_requests/7#note_1",
+                "/-/merge_requests/"
+            ),
+            Some("7")
+        );
+        assert_eq!(
+            url_path_id("https://github.com/org/repo/pull/", "/pull/"),
+            None
+        );
+        assert_eq!(url_path_id("pr#45", "/pull/"), None);
+        assert_eq!(
+            url_path_id("https://github.com/org/repo/issues/4", "/pull/"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_key_number() {
+        let issues = "https://github.com/org/repo/issues/";
+        assert_eq!(
+            parse_key_number("gh#12", "gh#", issues, "issue").unwrap(),
+            12
+        );
+        assert_eq!(
+            parse_key_number(
+                "https://github.com/Org/Repo/issues/12#issuecomment-1",
+                "gh#",
+                issues,
+                "issue"
+            )
+            .unwrap(),
+            12
+        );
+        assert_eq!(
+            parse_key_number("gh#x", "gh#", issues, "issue")
+                .unwrap_err()
+                .to_string(),
+            "Invalid data: Invalid issue key: gh#x"
+        );
+        assert!(parse_key_number(
+            "https://github.com/org/repo/issues/",
+            "gh#",
+            issues,
+            "issue"
+        )
+        .is_err());
+        assert_eq!(
+            parse_key_number(
+                "https://github.com/org/other/pull/12",
+                "pr#",
+                "https://github.com/org/repo/pull/",
+                "PR"
+            )
+            .unwrap_err()
+            .to_string(),
+            "Invalid data: 'https://github.com/org/other/pull/12' is not a PR URL of this \
+             repository, expected https://github.com/org/repo/pull/<number>"
+        );
+    }
+
+    #[test]
+    fn test_resolve_unconfigured_url() {
+        let resolver = resolver();
+        // URLs never go to the fallback provider
+        let err = resolver
+            .resolve("https://github.com/org/other/pull/45")
+            .unwrap_err();
+        assert!(matches!(err, Error::ProviderNotFound(_)));
+        assert_eq!(
+            err.to_string(),
+            "Provider not found: 'https://github.com/org/other/pull/45' does not point to a configured \
+             repository or project. Configured URLs: https://example.atlassian.net/browse/ (jira)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_unknown() {
+        let mut resolver = KeyResolver::new();
+        resolver.register("github", vec![KeyPattern::Prefix("gh#".to_string())]);
+
+        let err = resolver.resolve("#12").unwrap_err();
+        assert!(matches!(err, Error::ProviderNotFound(_)));
+        assert_eq!(
+            err.to_string(),
+            "Provider not found: no configured provider handles key '#12'. Known key formats: gh#<id> (github)"
+        );
+    }
+}
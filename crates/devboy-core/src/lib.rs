@@ -2,7 +2,8 @@
 //!
 //! This crate provides the foundational abstractions used across all devboy components:
 //!
-//! - **Provider traits**: [`IssueProvider`], [`MergeRequestProvider`], [`Provider`]
+//! - **Provider traits**: [`IssueProvider`], [`MergeRequestProvider`], [`PipelineProvider`],
+//!   [`RepositoryProvider`], [`AgileProvider`], [`TimeTrackingProvider`], [`Provider`]
 //! - **Unified types**: [`Issue`], [`MergeRequest`], [`Discussion`], [`Comment`], [`FileDiff`]
 //! - **Configuration**: [`Config`], [`GitHubConfig`], [`GitLabConfig`]
 //! - **Error handling**: [`Error`], [`Result`]
@@ -22,22 +23,37 @@
 //! }
 //! ```
 
+pub mod attachments;
+pub mod bulk;
+pub mod catalog;
 pub mod config;
+pub mod custom_fields;
+pub mod encoding;
 pub mod error;
+pub mod keys;
+pub mod members;
 pub mod provider;
+pub mod time;
 pub mod types;
+pub mod workflow;
 
 // Re-export error types
 pub use error::{Error, Result};
 
 // Re-export provider traits
-pub use provider::{IssueProvider, MergeRequestProvider, Provider};
+pub use provider::{
+    AgileProvider, IssueProvider, MergeRequestProvider, PipelineProvider, Provider,
+    RepositoryProvider, TimeTrackingProvider,
+};
 
 // Re-export all types
 pub use types::{
-    CodePosition, Comment, CreateCommentInput, CreateIssueInput, Discussion, FileDiff, Issue,
-    IssueFilter, MergeRequest, MrFilter, Pagination, UpdateIssueInput, User,
+    Attachment, Board, Branch, BulkUpdateItem, BulkUpdateReport, Checklist, ChecklistItem, CiJob,
+    CiPipeline, CodePosition, Comment, Commit, Container, CreateCommentInput, CreateIssueInput,
+    CustomField, Discussion, FileDiff, Issue, IssueFilter, IssueLink, IssueRef, IssueStatus,
+    IssueTransition, Label, MergeRequest, Milestone, MrFilter, Pagination, RepositoryFile,
+    RunPipelineInput, Sprint, TimeEntry, TransitionIssueInput, TreeEntry, UpdateIssueInput, User,
 };
 
 // Re-export config types
-pub use config::{ClickUpConfig, Config, GitHubConfig, GitLabConfig, JiraConfig};
+pub use config::{ClickUpConfig, Config, GitHubConfig, GitLabConfig, JiraConfig, WorkflowConfig};
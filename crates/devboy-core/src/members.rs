@@ -0,0 +1,250 @@
+//! Member directory shared by providers.
+//!
+//! Agents refer to people by username, email or display name, while ClickUp
+//! and Jira Cloud filter and assign by user ID. Providers load their members
+//! into a [`MemberDirectory`] once and resolve names with [`find_member`].
+//! The [`ME`] alias stands for the authenticated user of each provider.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::RwLock;
+
+use crate::error::{Error, Result};
+use crate::types::User;
+
+/// Alias for the authenticated user in user filters and assignees.
+pub const ME: &str = "@me";
+
+/// Whether `user` is the [`ME`] alias.
+pub fn is_me(user: &str) -> bool {
+    user.trim().eq_ignore_ascii_case(ME)
+}
+
+/// In-memory cache of provider members, keyed by the lookup that loaded them:
+/// `""` for a provider's full member list, the search text for user searches.
+#[derive(Debug, Default)]
+pub struct MemberDirectory {
+    members: RwLock<HashMap<String, Vec<User>>>,
+}
+
+impl MemberDirectory {
+    /// Create an empty directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the members cached under `key`, loading and caching them first if needed.
+    pub async fn get_or_load<F, Fut>(&self, key: &str, load: F) -> Result<Vec<User>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Vec<User>>>,
+    {
+        if let Some(members) = self.members.read().ok().and_then(|m| m.get(key).cloned()) {
+            return Ok(members);
+        }
+
+        let members = load().await?;
+        if let Ok(mut cache) = self.members.write() {
+            cache.insert(key.to_string(), members.clone());
+        }
+        Ok(members)
+    }
+
+    /// Return the authenticated user, loading it once (cached under [`ME`]).
+    pub async fn current_user<F, Fut>(&self, load: F) -> Result<User>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<User>>,
+    {
+        self.get_or_load(ME, || async { Ok(vec![load().await?]) })
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::NotFound("Current user not found".to_string()))
+    }
+}
+
+/// Users whose username, display name or email contains `query`
+/// (case-insensitive); an empty query matches everyone.
+pub fn search_members(members: &[User], query: &str) -> Vec<User> {
+    let query = query.trim().trim_start_matches('@').to_lowercase();
+    members
+        .iter()
+        .filter(|u| {
+            [
+                Some(u.username.as_str()),
+                u.name.as_deref(),
+                u.email.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|value| value.to_lowercase().contains(&query))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Find the member `query` refers to: an ID, username, email or display name
+/// (case-insensitive, a leading `@` is ignored), falling back to a unique
+/// partial match of the username or display name.
+pub fn find_member<'a>(members: &'a [User], query: &str) -> Result<&'a User> {
+    let query = query.trim().trim_start_matches('@');
+    let eq = |value: Option<&str>| value.is_some_and(|v| v.eq_ignore_ascii_case(query));
+    let contains = |value: Option<&str>| {
+        value.is_some_and(|v| v.to_lowercase().contains(&query.to_lowercase()))
+    };
+
+    let tiers: [&dyn Fn(&User) -> bool; 5] = [
+        &|u| u.id == query,
+        &|u| eq(Some(&u.username)),
+        &|u| eq(u.email.as_deref()),
+        &|u| eq(u.name.as_deref()),
+        &|u| contains(Some(&u.username)) || contains(u.name.as_deref()),
+    ];
+    for matches_query in tiers {
+        let matches: Vec<&User> = members.iter().filter(|u| matches_query(u)).collect();
+        match matches.as_slice() {
+            [] => continue,
+            [member] => return Ok(member),
+            _ => {
+                let candidates: Vec<String> = matches.iter().map(|u| member_label(u)).collect();
+                return Err(Error::InvalidData(format!(
+                    "'{}' matches several users, use a username, email or ID: {}",
+                    query,
+                    candidates.join(", ")
+                )));
+            }
+        }
+    }
+
+    Err(Error::NotFound(format!("No user matches '{}'", query)))
+}
+
+/// Describe a member for an ambiguity error: "jdoe (John Doe, jdoe@example.com, id 42)".
+fn member_label(member: &User) -> String {
+    let details: Vec<String> = member
+        .name
+        .iter()
+        .filter(|name| **name != member.username)
+        .chain(member.email.iter())
+        .cloned()
+        .chain(std::iter::once(format!("id {}", member.id)))
+        .collect();
+    format!("{} ({})", member.username, details.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str, username: &str, name: &str, email: &str) -> User {
+        User {
+            id: id.to_string(),
+            username: username.to_string(),
+            name: Some(name.to_string()),
+            email: Some(email.to_string()),
+            avatar_url: None,
+        }
+    }
+
+    fn members() -> Vec<User> {
+        vec![
+            user("1", "jdoe", "John Doe", "john@example.com"),
+            user("2", "jane", "Jane Doe", "jane@example.com"),
+            user("3", "bob", "Bob Smith", "bob@example.com"),
+        ]
+    }
+
+    #[test]
+    fn test_find_member_exact() {
+        let members = members();
+        assert_eq!(find_member(&members, "2").unwrap().username, "jane");
+        assert_eq!(find_member(&members, "@JDoe").unwrap().id, "1");
+        assert_eq!(find_member(&members, "bob@example.com").unwrap().id, "3");
+        assert_eq!(find_member(&members, "jane doe").unwrap().id, "2");
+        assert_eq!(find_member(&members, "smith").unwrap().id, "3");
+    }
+
+    #[test]
+    fn test_find_member_ambiguous() {
+        let members = members();
+        let err = find_member(&members, "doe").unwrap_err().to_string();
+        assert!(err.contains("'doe' matches several users"));
+        assert!(err.contains("jdoe (John Doe, john@example.com, id 1)"));
+        assert!(err.contains("jane (Jane Doe, jane@example.com, id 2)"));
+    }
+
+    #[test]
+    fn test_find_member_not_found() {
+        let err = find_member(&members(), "alice").unwrap_err();
+        assert!(matches!(err, Error::NotFound(_)));
+    }
+
+    #[test]
+    fn test_is_me() {
+        assert!(is_me("@me"));
+        assert!(is_me(" @Me "));
+        assert!(!is_me("me"));
+        assert!(!is_me("@meg"));
+    }
+
+    #[test]
+    fn test_search_members() {
+        let members = members();
+        let found: Vec<String> = search_members(&members, "DOE")
+            .into_iter()
+            .map(|u| u.username)
+            .collect();
+        assert_eq!(found, vec!["jdoe", "jane"]);
+        assert_eq!(search_members(&members, "bob@").len(), 1);
+        assert_eq!(search_members(&members, "").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_directory_current_user() {
+        let directory = MemberDirectory::new();
+
+        let me = directory
+            .current_user(|| async { Ok(members().remove(0)) })
+            .await
+            .unwrap();
+        assert_eq!(me.username, "jdoe");
+
+        let cached = directory
+            .current_user(|| async { Err(Error::Network("not called".to_string())) })
+            .await
+            .unwrap();
+        assert_eq!(cached.username, "jdoe");
+    }
+
+    #[tokio::test]
+    async fn test_directory_caches_by_key() {
+        let directory = MemberDirectory::new();
+
+        let loaded = directory
+            .get_or_load("", || async { Ok(members()) })
+            .await
+            .unwrap();
+        assert_eq!(loaded.len(), 3);
+
+        // A cached key does not load again
+        let cached = directory
+            .get_or_load("", || async {
+                Err(Error::Network("not called".to_string()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(cached.len(), 3);
+
+        // Failed loads are not cached
+        assert!(directory
+            .get_or_load("bob", || async { Err(Error::Network("down".to_string())) })
+            .await
+            .is_err());
+        let found = directory
+            .get_or_load("bob", || async { Ok(vec![members().remove(2)]) })
+            .await
+            .unwrap();
+        assert_eq!(found[0].username, "bob");
+    }
+}
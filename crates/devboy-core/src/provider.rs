@@ -5,12 +5,24 @@
 
 use async_trait::async_trait;
 
-use crate::error::Result;
+use crate::bulk;
+use crate::error::{Error, Result};
+use crate::keys::KeyPattern;
 use crate::types::{
-    Comment, CreateCommentInput, CreateIssueInput, Discussion, FileDiff, Issue, IssueFilter,
-    MergeRequest, MrFilter, UpdateIssueInput, User,
+    Attachment, Board, Branch, BulkUpdateReport, Checklist, CiPipeline, Comment, Commit, Container,
+    CreateCommentInput, CreateIssueInput, CustomField, Discussion, FileDiff, Issue, IssueFilter,
+    IssueStatus, IssueTransition, Label, MergeRequest, Milestone, MrFilter, RepositoryFile,
+    RunPipelineInput, Sprint, TimeEntry, TransitionIssueInput, TreeEntry, UpdateIssueInput, User,
 };
 
+/// Build the error returned by default implementations of optional operations.
+fn unsupported(provider: &str, operation: &str) -> Error {
+    Error::ProviderUnsupported {
+        provider: provider.to_string(),
+        operation: operation.to_string(),
+    }
+}
+
 /// Provider for working with issues.
 ///
 /// Implementations include GitLab, GitHub, ClickUp, and Jira providers.
@@ -22,18 +34,135 @@ pub trait IssueProvider: Send + Sync {
     /// Get a single issue by key (e.g., "gitlab#123", "gh#456").
     async fn get_issue(&self, key: &str) -> Result<Issue>;
 
-    /// Create a new issue.
+    /// Create a new issue, under [`CreateIssueInput::parent`] when given.
+    ///
+    /// GitLab only creates issues under an epic: linking a task to a parent
+    /// issue needs the GraphQL work item API, so such parents are rejected.
     async fn create_issue(&self, input: CreateIssueInput) -> Result<Issue>;
 
     /// Update an existing issue.
     async fn update_issue(&self, key: &str, input: UpdateIssueInput) -> Result<Issue>;
 
+    /// Apply the same update to several issues, reporting the outcome per key.
+    ///
+    /// Issues are updated one by one with bounded concurrency, each once,
+    /// after checking labels and state against the catalogs (see
+    /// [`bulk::update_each`]). No provider
+    /// uses a native bulk endpoint: Jira's bulk edit runs as an asynchronous
+    /// task without per-issue results, and GitLab's REST API has none.
+    async fn bulk_update_issues(
+        &self,
+        keys: &[String],
+        input: UpdateIssueInput,
+    ) -> Result<BulkUpdateReport> {
+        bulk::update_each(self, keys, &input, bulk::DEFAULT_CONCURRENCY).await
+    }
+
     /// Get comments for an issue.
     async fn get_comments(&self, issue_key: &str) -> Result<Vec<Comment>>;
 
     /// Add a comment to an issue.
     async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment>;
 
+    /// Get the custom fields available on issues.
+    async fn get_custom_fields(&self) -> Result<Vec<CustomField>> {
+        Err(unsupported(self.provider_name(), "get_custom_fields"))
+    }
+
+    /// Get milestones (versions, iterations) with their issue counts.
+    ///
+    /// `state` is "open" (default), "closed" or "all".
+    async fn get_milestones(&self, _state: Option<&str>) -> Result<Vec<Milestone>> {
+        Err(unsupported(self.provider_name(), "get_milestones"))
+    }
+
+    /// Get the containers issues live in, one hierarchy level at a time.
+    ///
+    /// `parent` is a `(kind, id)` pair (see [`Container::kind`]); `None` lists the top level.
+    async fn get_containers(&self, _parent: Option<(&str, &str)>) -> Result<Vec<Container>> {
+        Err(unsupported(self.provider_name(), "get_containers"))
+    }
+
+    /// List the labels that can be put on issues.
+    async fn list_labels(&self) -> Result<Vec<Label>> {
+        Err(unsupported(self.provider_name(), "list_labels"))
+    }
+
+    /// List the statuses issues can be moved to with `update_issue`.
+    async fn list_statuses(&self) -> Result<Vec<IssueStatus>> {
+        Err(unsupported(self.provider_name(), "list_statuses"))
+    }
+
+    /// Add an item to a checklist of an issue, creating the checklist if needed.
+    ///
+    /// `checklist` is a checklist name; `None` uses the first checklist.
+    /// Returns the updated checklist.
+    async fn add_checklist_item(
+        &self,
+        _issue_key: &str,
+        _checklist: Option<&str>,
+        _item: &str,
+    ) -> Result<Checklist> {
+        Err(unsupported(self.provider_name(), "add_checklist_item"))
+    }
+
+    /// Check or uncheck a checklist item given by ID or text.
+    ///
+    /// Returns the updated checklist.
+    async fn set_checklist_item(
+        &self,
+        _issue_key: &str,
+        _item: &str,
+        _resolved: bool,
+    ) -> Result<Checklist> {
+        Err(unsupported(self.provider_name(), "set_checklist_item"))
+    }
+
+    /// Link two issues, e.g. `kind = "blocks"` makes `key` block `target_key`.
+    ///
+    /// Kinds follow [`IssueLink::kind`](crate::IssueLink::kind).
+    async fn link_issues(&self, _key: &str, _target_key: &str, _kind: &str) -> Result<()> {
+        Err(unsupported(self.provider_name(), "link_issues"))
+    }
+
+    /// Remove the links between two issues, optionally only those of `kind`.
+    async fn unlink_issues(
+        &self,
+        _key: &str,
+        _target_key: &str,
+        _kind: Option<&str>,
+    ) -> Result<()> {
+        Err(unsupported(self.provider_name(), "unlink_issues"))
+    }
+
+    /// Get the workflow transitions currently available for an issue.
+    async fn get_transitions(&self, _issue_key: &str) -> Result<Vec<IssueTransition>> {
+        Err(unsupported(self.provider_name(), "get_transitions"))
+    }
+
+    /// Apply a workflow transition, setting its screen fields and adding a comment.
+    async fn transition_issue(&self, _issue_key: &str, _input: TransitionIssueInput) -> Result<()> {
+        Err(unsupported(self.provider_name(), "transition_issue"))
+    }
+
+    /// Download an attachment by its [`Attachment::url`].
+    ///
+    /// Implementations only send credentials to their own hosts and reject
+    /// other URLs.
+    async fn download_attachment(&self, _url: &str) -> Result<Vec<u8>> {
+        Err(unsupported(self.provider_name(), "download_attachment"))
+    }
+
+    /// Upload a file and attach it to an issue.
+    async fn upload_attachment(
+        &self,
+        _issue_key: &str,
+        _file_name: &str,
+        _content: &[u8],
+    ) -> Result<Attachment> {
+        Err(unsupported(self.provider_name(), "upload_attachment"))
+    }
+
     /// Get the provider name for logging (e.g., "gitlab", "github").
     fn provider_name(&self) -> &'static str;
 }
@@ -53,6 +182,16 @@ pub trait MergeRequestProvider: Send + Sync {
     /// Get file diffs for a merge request.
     async fn get_diffs(&self, mr_key: &str) -> Result<Vec<FileDiff>>;
 
+    /// Get the commits of a merge request, oldest first.
+    async fn get_commits(&self, _mr_key: &str) -> Result<Vec<Commit>> {
+        Err(unsupported(self.provider_name(), "get_commits"))
+    }
+
+    /// Get the file diffs introduced by a single commit.
+    async fn get_commit_diff(&self, _sha: &str) -> Result<Vec<FileDiff>> {
+        Err(unsupported(self.provider_name(), "get_commit_diff"))
+    }
+
     /// Add a comment to a merge request.
     async fn add_comment(&self, mr_key: &str, input: CreateCommentInput) -> Result<Comment>;
 
@@ -60,11 +199,217 @@ pub trait MergeRequestProvider: Send + Sync {
     fn provider_name(&self) -> &'static str;
 }
 
+/// Provider for CI pipelines (GitLab CI/CD, GitHub Actions).
+///
+/// All operations default to [`Error::ProviderUnsupported`], so providers
+/// without CI (ClickUp, Jira) only need to implement [`provider_name`].
+///
+/// [`provider_name`]: PipelineProvider::provider_name
+#[async_trait]
+pub trait PipelineProvider: Send + Sync {
+    /// Get pipelines for a merge request, newest first. Jobs are not populated.
+    async fn get_pipelines(&self, _mr_key: &str) -> Result<Vec<CiPipeline>> {
+        Err(unsupported(self.provider_name(), "get_pipelines"))
+    }
+
+    /// Get a single pipeline by ID, including its jobs.
+    async fn get_pipeline(&self, _pipeline_id: &str) -> Result<CiPipeline> {
+        Err(unsupported(self.provider_name(), "get_pipeline"))
+    }
+
+    /// Get the pipelines that ran for the latest commit of a merge request,
+    /// including their jobs.
+    ///
+    /// GitHub runs one workflow run per workflow for the same commit, so this
+    /// may return more than one pipeline. Only the newest run of each workflow
+    /// is returned, and only failed runs carry their jobs.
+    async fn get_latest_pipelines(&self, mr_key: &str) -> Result<Vec<CiPipeline>> {
+        let pipelines = self.get_pipelines(mr_key).await?;
+        let Some(latest_sha) = pipelines.first().map(|p| p.sha.clone()) else {
+            return Ok(vec![]);
+        };
+
+        let mut result = Vec::new();
+        for pipeline in pipelines.iter().filter(|p| p.sha == latest_sha) {
+            result.push(self.get_pipeline(&pipeline.id).await?);
+        }
+        Ok(result)
+    }
+
+    /// Get the raw log (trace) of a CI job.
+    async fn get_job_log(&self, _job_id: &str) -> Result<String> {
+        Err(unsupported(self.provider_name(), "get_job_log"))
+    }
+
+    /// Whether the provider runs CI pipelines. Pipeline IDs are not
+    /// prefixed, so actions on them need the one provider with CI.
+    fn has_ci(&self) -> bool {
+        false
+    }
+
+    /// Retry the failed jobs of a pipeline.
+    ///
+    /// Returns the pipeline to poll: GitLab retries in place, GitHub starts a
+    /// new attempt of the same workflow run.
+    async fn retry_pipeline(&self, _pipeline_id: &str) -> Result<CiPipeline> {
+        Err(unsupported(self.provider_name(), "retry_pipeline"))
+    }
+
+    /// Retry a single job. Returns the pipeline the retried job belongs to.
+    async fn retry_job(&self, _job_id: &str) -> Result<CiPipeline> {
+        Err(unsupported(self.provider_name(), "retry_job"))
+    }
+
+    /// Cancel a running pipeline.
+    async fn cancel_pipeline(&self, _pipeline_id: &str) -> Result<CiPipeline> {
+        Err(unsupported(self.provider_name(), "cancel_pipeline"))
+    }
+
+    /// Start a new pipeline for a ref. Returns the created pipeline.
+    async fn run_pipeline(&self, _input: RunPipelineInput) -> Result<CiPipeline> {
+        Err(unsupported(self.provider_name(), "run_pipeline"))
+    }
+
+    /// Get the provider name for logging.
+    fn provider_name(&self) -> &'static str;
+}
+
+/// Provider for reading repository content (files and directory listings).
+///
+/// All operations default to [`Error::ProviderUnsupported`], so providers
+/// without repositories (ClickUp, Jira) only need to implement [`provider_name`].
+///
+/// [`provider_name`]: RepositoryProvider::provider_name
+#[async_trait]
+pub trait RepositoryProvider: Send + Sync {
+    /// Get a file at a ref (branch, tag or commit SHA). Without a ref the
+    /// default branch is used.
+    async fn get_file(&self, _path: &str, _ref_name: Option<&str>) -> Result<RepositoryFile> {
+        Err(unsupported(self.provider_name(), "get_file"))
+    }
+
+    /// List the entries of a directory at a ref. An empty path lists the
+    /// repository root.
+    async fn list_tree(&self, _path: &str, _ref_name: Option<&str>) -> Result<Vec<TreeEntry>> {
+        Err(unsupported(self.provider_name(), "list_tree"))
+    }
+
+    /// Create a branch from a ref (branch, tag or commit SHA). Without a ref
+    /// the branch is created from the default branch.
+    async fn create_branch(&self, _name: &str, _from_ref: Option<&str>) -> Result<Branch> {
+        Err(unsupported(self.provider_name(), "create_branch"))
+    }
+
+    /// Whether the provider hosts a code repository. Branches are not tied
+    /// to an issue key, so creating one needs a single provider with code.
+    fn has_repository(&self) -> bool {
+        false
+    }
+
+    /// Get the provider name for logging.
+    fn provider_name(&self) -> &'static str;
+}
+
+/// Provider for agile planning (boards, sprints and the backlog).
+///
+/// All operations default to [`Error::ProviderUnsupported`], so providers
+/// without sprints (GitLab, GitHub, ClickUp) only need to implement [`provider_name`].
+///
+/// [`provider_name`]: AgileProvider::provider_name
+#[async_trait]
+pub trait AgileProvider: Send + Sync {
+    /// List the boards of the configured project.
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        Err(unsupported(self.provider_name(), "get_boards"))
+    }
+
+    /// List the sprints of a board, optionally filtered by state
+    /// ("active", "future", "closed").
+    async fn get_sprints(&self, _board_id: &str, _state: Option<&str>) -> Result<Vec<Sprint>> {
+        Err(unsupported(self.provider_name(), "get_sprints"))
+    }
+
+    /// Get the issues planned for a sprint.
+    async fn get_sprint_issues(&self, _sprint_id: &str) -> Result<Vec<Issue>> {
+        Err(unsupported(self.provider_name(), "get_sprint_issues"))
+    }
+
+    /// Move issues into a sprint.
+    async fn move_issues_to_sprint(&self, _sprint_id: &str, _issue_keys: &[String]) -> Result<()> {
+        Err(unsupported(self.provider_name(), "move_issues_to_sprint"))
+    }
+
+    /// Move issues out of their sprints into the backlog.
+    async fn move_issues_to_backlog(&self, _issue_keys: &[String]) -> Result<()> {
+        Err(unsupported(self.provider_name(), "move_issues_to_backlog"))
+    }
+
+    /// Get the provider name for logging.
+    fn provider_name(&self) -> &'static str;
+}
+
+/// Provider for time tracking (logged time and estimates).
+///
+/// All operations default to [`Error::ProviderUnsupported`], so providers
+/// without time tracking (GitHub) only need to implement [`provider_name`].
+///
+/// [`provider_name`]: TimeTrackingProvider::provider_name
+#[async_trait]
+pub trait TimeTrackingProvider: Send + Sync {
+    /// Log time spent on an issue.
+    ///
+    /// `started_at` is an ISO 8601 timestamp and defaults to now.
+    async fn log_time(
+        &self,
+        _issue_key: &str,
+        _duration_seconds: u64,
+        _comment: Option<&str>,
+        _started_at: Option<&str>,
+    ) -> Result<()> {
+        Err(unsupported(self.provider_name(), "log_time"))
+    }
+
+    /// List the time logged on an issue.
+    async fn get_time_entries(&self, _issue_key: &str) -> Result<Vec<TimeEntry>> {
+        Err(unsupported(self.provider_name(), "get_time_entries"))
+    }
+
+    /// Set the time estimate of an issue.
+    async fn set_time_estimate(&self, _issue_key: &str, _estimate_seconds: u64) -> Result<()> {
+        Err(unsupported(self.provider_name(), "set_time_estimate"))
+    }
+
+    /// Get the provider name for logging.
+    fn provider_name(&self) -> &'static str;
+}
+
 /// Combined provider trait for services that support both issues and merge requests.
 ///
 /// This is implemented by GitLab and GitHub providers.
 #[async_trait]
-pub trait Provider: IssueProvider + MergeRequestProvider {
+pub trait Provider:
+    IssueProvider
+    + MergeRequestProvider
+    + PipelineProvider
+    + RepositoryProvider
+    + AgileProvider
+    + TimeTrackingProvider
+{
     /// Get the current authenticated user.
     async fn get_current_user(&self) -> Result<User>;
+
+    /// Issue and merge request keys owned by the provider (see [`crate::keys::KeyResolver`]).
+    ///
+    /// The default claims only keys no other provider owns.
+    fn key_patterns(&self) -> Vec<KeyPattern> {
+        vec![KeyPattern::Fallback]
+    }
+
+    /// Search users by username, display name or email.
+    async fn search_users(&self, _query: &str) -> Result<Vec<User>> {
+        Err(unsupported(
+            IssueProvider::provider_name(self),
+            "search_users",
+        ))
+    }
 }
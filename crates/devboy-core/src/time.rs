@@ -0,0 +1,264 @@
+//! Duration and timestamp helpers for time tracking.
+//!
+//! Durations are written the way Jira and GitLab accept them ("1h30m",
+//! "2d 4h", "1.5h"), with a day being 8 hours and a week 5 days of work.
+//! Timestamps are ISO 8601 strings, converted without pulling in a date crate.
+
+use crate::error::{Error, Result};
+
+/// Seconds in a work day (8 hours).
+pub const SECONDS_PER_DAY: u64 = 8 * 3600;
+
+/// Seconds in a work week (5 days).
+pub const SECONDS_PER_WEEK: u64 = 5 * SECONDS_PER_DAY;
+
+/// Parse a human duration ("1h30m", "2d 4h", "1.5h", "45m") to seconds.
+///
+/// A number without unit is read as minutes, as Jira does.
+pub fn parse_duration(input: &str) -> Result<u64> {
+    let invalid = || {
+        Error::InvalidData(format!(
+            "Invalid duration '{}', expected e.g. '1h30m', '2d' or '45m'",
+            input
+        ))
+    };
+
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut total = 0.0;
+    let mut number = String::new();
+    for c in compact.to_lowercase().chars() {
+        if c.is_ascii_digit() || c == '.' {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'w' => SECONDS_PER_WEEK,
+            'd' => SECONDS_PER_DAY,
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(invalid()),
+        };
+        let value: f64 = number.parse().map_err(|_| invalid())?;
+        total += value * unit as f64;
+        number.clear();
+    }
+    if !number.is_empty() {
+        let minutes: f64 = number.parse().map_err(|_| invalid())?;
+        total += minutes * 60.0;
+    }
+
+    let seconds = total.round() as u64;
+    if seconds == 0 {
+        return Err(invalid());
+    }
+    Ok(seconds)
+}
+
+/// Format seconds as hours and minutes ("1h 30m", "45m", "10h").
+///
+/// Days are not used: a "day" means 8 hours to Jira but 24 to a reader.
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = (seconds + 30) / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, 0) if seconds > 0 => format!("{}s", seconds),
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+/// Parse an ISO 8601 timestamp ("2024-01-15T10:30:00Z", "2024-01-15T12:30:00.000+02:00")
+/// or a date ("2024-01-15") to Unix seconds.
+pub fn parse_iso8601(timestamp: &str) -> Option<i64> {
+    let (date, time) = timestamp
+        .split_once('T')
+        .unwrap_or((timestamp, "00:00:00Z"));
+    let midnight = parse_date(date)? * 86_400;
+
+    // Split off the UTC offset ("Z", "+02:00", "-0500"); no offset means UTC
+    let offset_start = time.find(['Z', 'z', '+', '-']).unwrap_or(time.len());
+    let (clock, offset) = time.split_at(offset_start);
+    let offset_secs = match offset.chars().next() {
+        Some(sign @ ('+' | '-')) => {
+            let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+            if digits.len() != 4 {
+                return None;
+            }
+            let hours: i64 = digits[..2].parse().ok()?;
+            let minutes: i64 = digits[2..].parse().ok()?;
+            let secs = hours * 3600 + minutes * 60;
+            if sign == '-' {
+                -secs
+            } else {
+                secs
+            }
+        }
+        _ => 0,
+    };
+
+    let clock = clock.split('.').next()?;
+    let mut clock_parts = clock.splitn(3, ':').map(|p| p.parse::<i64>());
+    let hour = clock_parts.next()?.ok()?;
+    let minute = clock_parts.next()?.ok()?;
+    let second = clock_parts.next().unwrap_or(Ok(0)).ok()?;
+
+    Some(midnight + hour * 3600 + minute * 60 + second - offset_secs)
+}
+
+/// Format Unix seconds as an ISO 8601 UTC timestamp ("2024-01-15T10:30:00Z").
+pub fn unix_to_iso8601(secs: i64) -> String {
+    let rem = secs.rem_euclid(86400);
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        format_date(secs.div_euclid(86_400)),
+        rem / 3600,
+        (rem % 3600) / 60,
+        rem % 60
+    )
+}
+
+/// Parse a "YYYY-MM-DD" date to days since the Unix epoch.
+pub fn parse_date(date: &str) -> Option<i64> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year: i64 = date[..4].parse().ok()?;
+    let month: i64 = date[5..7].parse().ok()?;
+    let day: i64 = date[8..].parse().ok()?;
+    days_from_civil(year, month, day)
+}
+
+/// Format days since the Unix epoch as a "YYYY-MM-DD" date.
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Days since the Unix epoch of a proleptic Gregorian date, or `None` for
+/// an invalid date (Howard Hinnant's days-from-civil).
+pub fn days_from_civil(year: i64, month: i64, day: i64) -> Option<i64> {
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146_097 + doe - 719_468)
+}
+
+/// Year, month and day of a day count since the Unix epoch
+/// (Howard Hinnant's civil-from-days).
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Current time as Unix seconds.
+pub fn now_unix() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1h30m").unwrap(), 5400);
+        assert_eq!(parse_duration("1h 30m").unwrap(), 5400);
+        assert_eq!(parse_duration("1.5h").unwrap(), 5400);
+        assert_eq!(parse_duration("45m").unwrap(), 2700);
+        assert_eq!(parse_duration("90").unwrap(), 5400);
+        assert_eq!(parse_duration("2d").unwrap(), 16 * 3600);
+        assert_eq!(parse_duration("1w 1d").unwrap(), 48 * 3600);
+        assert_eq!(parse_duration("30s").unwrap(), 30);
+        assert_eq!(parse_duration("1H30M").unwrap(), 5400);
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        for input in ["", "abc", "1x", "h", "0m", "1..5h"] {
+            assert!(parse_duration(input).is_err(), "{:?} should fail", input);
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(5400), "1h 30m");
+        assert_eq!(format_duration(2700), "45m");
+        assert_eq!(format_duration(36000), "10h");
+        assert_eq!(format_duration(20), "20s");
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(89), "1m");
+    }
+
+    #[test]
+    fn test_parse_iso8601() {
+        assert_eq!(parse_iso8601("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_iso8601("2024-01-15T10:00:00Z"), Some(1705312800));
+        assert_eq!(
+            parse_iso8601("2024-01-15T10:00:00.000+0000"),
+            Some(1705312800)
+        );
+        assert_eq!(parse_iso8601("2024-01-15T12:00:00+02:00"), Some(1705312800));
+        assert_eq!(parse_iso8601("2024-01-15T05:00:00-05:00"), Some(1705312800));
+        assert_eq!(parse_iso8601("2024-01-15T10:00"), Some(1705312800));
+        assert_eq!(parse_iso8601("2024-01-15"), Some(1705276800));
+        assert_eq!(parse_iso8601("yesterday"), None);
+        assert_eq!(parse_iso8601("2024-13-01"), None);
+        assert_eq!(parse_iso8601("2023-02-29T10:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_civil_days_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), Some(0));
+        assert_eq!(days_from_civil(2000, 2, 29), Some(11_016));
+        assert_eq!(days_from_civil(1969, 12, 31), Some(-1));
+        assert_eq!(days_from_civil(2023, 2, 29), None);
+        for days in [-719_468, -1, 0, 11_016, 19_737, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), Some(days));
+        }
+        assert_eq!(format_date(-1), "1969-12-31");
+        assert_eq!(parse_date("2024-01-15"), Some(19_737));
+    }
+
+    #[test]
+    fn test_unix_to_iso8601() {
+        assert_eq!(unix_to_iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(unix_to_iso8601(1705312800), "2024-01-15T10:00:00Z");
+        assert_eq!(
+            parse_iso8601(&unix_to_iso8601(1709210096)),
+            Some(1709210096)
+        );
+    }
+}
@@ -3,6 +3,8 @@
 //! These types are provider-agnostic and represent unified data structures
 //! that can be populated from GitLab, GitHub, ClickUp, or Jira APIs.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -55,6 +57,198 @@ pub struct Issue {
     pub created_at: Option<String>,
     /// Updated at timestamp (ISO 8601)
     pub updated_at: Option<String>,
+    /// Custom field values by field name (e.g., "Story Points" -> 5)
+    #[serde(default)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+    /// Issue type (e.g., "Bug", "Story", "Epic", "Sub-task", "task")
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// Parent issue or epic. On GitLab only the epic: the parent issue of a
+    /// task is part of the work item hierarchy, which REST does not expose.
+    #[serde(default)]
+    pub parent: Option<IssueRef>,
+    /// Child issues (subtasks, sub-issues, issues of an epic). Always empty
+    /// on GitLab, whose REST API does not list the tasks of an issue.
+    #[serde(default)]
+    pub children: Vec<IssueRef>,
+    /// Relations to other issues and merge requests
+    #[serde(default)]
+    pub links: Vec<IssueLink>,
+    /// Attached files
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Original time estimate in seconds
+    #[serde(default)]
+    pub estimate_seconds: Option<u64>,
+    /// Total time logged in seconds
+    #[serde(default)]
+    pub time_spent_seconds: Option<u64>,
+    /// Milestone, version or iteration the issue is planned for
+    #[serde(default)]
+    pub milestone: Option<Milestone>,
+    /// Checklists, e.g. acceptance criteria (ClickUp)
+    #[serde(default)]
+    pub checklists: Vec<Checklist>,
+}
+
+/// Checklist of an issue.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Checklist {
+    /// Checklist ID
+    pub id: String,
+    /// Checklist name
+    pub name: String,
+    /// Items in display order
+    #[serde(default)]
+    pub items: Vec<ChecklistItem>,
+}
+
+/// Item of a [`Checklist`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ChecklistItem {
+    /// Item ID
+    pub id: String,
+    /// Item text
+    pub name: String,
+    /// Whether the item is checked
+    pub resolved: bool,
+    /// Assignee
+    #[serde(default)]
+    pub assignee: Option<User>,
+}
+
+/// Milestone that issues and merge requests are planned for: a GitLab milestone
+/// or iteration, a GitHub milestone, a Jira fix version or a ClickUp sprint list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Milestone {
+    /// Milestone ID
+    pub id: String,
+    /// Title (e.g., "2.4"); used to filter issues and merge requests
+    pub title: String,
+    /// What the provider calls it: "milestone", "iteration", "version" or "list"
+    pub kind: String,
+    /// State ("open" or "closed")
+    pub state: String,
+    /// Description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Start date (YYYY-MM-DD)
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Due or release date (YYYY-MM-DD)
+    #[serde(default)]
+    pub due_date: Option<String>,
+    /// Number of open issues, when listed with progress
+    #[serde(default)]
+    pub open_issues: Option<u32>,
+    /// Number of closed issues, when listed with progress
+    #[serde(default)]
+    pub closed_issues: Option<u32>,
+    /// URL to view in browser
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Node of a provider's work hierarchy, e.g. ClickUp workspace → space → folder → list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Container {
+    /// Container ID
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Level in the hierarchy: "workspace", "space", "folder" or "list"
+    pub kind: String,
+    /// ID of the parent container, if any
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Whether the container is archived
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Label (tag) that can be put on issues.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Label {
+    /// Label name
+    pub name: String,
+    /// Color, e.g. "#d73a4a"
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Description
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Status an issue can be moved to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IssueStatus {
+    /// Status name as accepted by `update_issue`
+    pub name: String,
+    /// Workflow category: "todo", "in_progress" or "done"
+    pub category: String,
+}
+
+/// File attached to an issue or comment.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Attachment {
+    /// Provider attachment ID; the URL for files linked from Markdown (GitHub, GitLab)
+    pub id: String,
+    /// File name
+    pub name: String,
+    /// Size in bytes, if known
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// MIME type (e.g., "image/png"), if known
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// Download URL, accepted by `download_attachment`
+    pub url: String,
+    /// Created at timestamp (ISO 8601)
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Reference to a related issue (parent or child).
+///
+/// Providers that only return the related key leave the other fields empty.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IssueRef {
+    /// Issue key (e.g., "PROJ-1", "gh#12", "gitlab&3" for a GitLab epic)
+    pub key: String,
+    /// Issue title
+    #[serde(default)]
+    pub title: Option<String>,
+    /// State (e.g., "open", "closed")
+    #[serde(default)]
+    pub state: Option<String>,
+    /// Issue type (e.g., "Sub-task", "Epic")
+    #[serde(default)]
+    pub issue_type: Option<String>,
+}
+
+impl From<&Issue> for IssueRef {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            key: issue.key.clone(),
+            title: Some(issue.title.clone()),
+            state: Some(issue.state.clone()),
+            issue_type: issue.issue_type.clone(),
+        }
+    }
+}
+
+/// Relation between an issue and another issue or merge request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IssueLink {
+    /// Relation as seen from the issue: "blocks", "blocked_by", "relates_to",
+    /// "duplicates", "duplicated_by", "closed_by" (merge request / pull request),
+    /// "mentioned_in", or the provider's own relation name
+    pub kind: String,
+    /// Linked issue or merge request (e.g., "PROJ-2", "gh#12", "pr#7", "mr#3")
+    pub target: IssueRef,
+    /// Provider link ID, if the link itself has an identity
+    #[serde(default)]
+    pub id: Option<String>,
 }
 
 /// Filter parameters for listing issues.
@@ -66,7 +260,7 @@ pub struct IssueFilter {
     pub search: Option<String>,
     /// Filter by labels
     pub labels: Option<Vec<String>>,
-    /// Filter by assignee username
+    /// Filter by assignee username, email or display name (`@me` for the authenticated user)
     pub assignee: Option<String>,
     /// Maximum number of results
     pub limit: Option<u32>,
@@ -76,6 +270,17 @@ pub struct IssueFilter {
     pub sort_by: Option<String>,
     /// Sort order ("asc" or "desc")
     pub sort_order: Option<String>,
+    /// Provider-native query, combined with the other filters: JQL (Jira),
+    /// search qualifiers (GitHub), `key=value&...` list parameters (GitLab)
+    #[serde(default)]
+    pub query: Option<String>,
+    /// Filter by milestone title (see [`Milestone::title`])
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// Container to search instead of the configured one (see [`Container::id`]);
+    /// `"workspace"` searches the whole workspace
+    #[serde(default)]
+    pub container: Option<String>,
 }
 
 /// Input for creating a new issue.
@@ -87,10 +292,23 @@ pub struct CreateIssueInput {
     pub description: Option<String>,
     /// Labels to add
     pub labels: Vec<String>,
-    /// Assignee usernames
+    /// Assignees by username, email or display name
     pub assignees: Vec<String>,
     /// Priority
     pub priority: Option<String>,
+    /// Custom field values by field name or ID
+    #[serde(default)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+    /// Issue type (e.g., "Bug", "Epic", "Sub-task"); ignored by providers without types
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// Parent issue key: creates a subtask, sub-issue or issue of an epic.
+    /// GitLab only accepts an epic (`gitlab&<iid>`), not an issue.
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Container to create the issue in instead of the configured one (see [`Container::id`])
+    #[serde(default)]
+    pub container: Option<String>,
 }
 
 /// Input for updating an existing issue.
@@ -108,6 +326,97 @@ pub struct UpdateIssueInput {
     pub assignees: Option<Vec<String>>,
     /// New priority
     pub priority: Option<String>,
+    /// Custom field values to set by field name or ID (`null` clears a field)
+    #[serde(default)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Workflow transition that can be applied to an issue.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IssueTransition {
+    /// Transition ID
+    pub id: String,
+    /// Transition name (e.g., "Start Progress")
+    pub name: String,
+    /// Status the issue moves to
+    pub to_status: String,
+    /// Category of the target status: "todo", "in_progress" or "done"
+    pub to_category: String,
+    /// Fields of the transition screen; `required` ones have no default value
+    #[serde(default)]
+    pub fields: Vec<CustomField>,
+}
+
+/// Input for applying a workflow transition.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransitionIssueInput {
+    /// Transition ID or name (see [`IssueTransition`])
+    pub transition: String,
+    /// Values of transition screen fields by field name or ID
+    #[serde(default)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+    /// Comment to add with the transition
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// Outcome of one issue in a bulk update.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BulkUpdateItem {
+    /// Issue key
+    pub key: String,
+    /// Changes applied (or, in a dry run, that would be applied), e.g. "state: open → closed"
+    #[serde(default)]
+    pub changes: Vec<String>,
+    /// Requested fields the provider did not apply, e.g. "assignees"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<String>,
+    /// Why the issue was not updated
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Per-key report of a bulk update.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BulkUpdateReport {
+    /// Whether the changes were only previewed
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Outcome of each issue, in the order of the requested keys
+    pub items: Vec<BulkUpdateItem>,
+    /// Keys requested more than once; each issue is updated once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<String>,
+}
+
+impl BulkUpdateReport {
+    /// Number of issues updated (or that would be updated).
+    pub fn succeeded(&self) -> usize {
+        self.items.iter().filter(|i| i.error.is_none()).count()
+    }
+
+    /// Issues that could not be updated.
+    pub fn failed(&self) -> impl Iterator<Item = &BulkUpdateItem> {
+        self.items.iter().filter(|i| i.error.is_some())
+    }
+}
+
+/// Custom field metadata of an issue tracker.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CustomField {
+    /// Provider field ID (e.g., "customfield_10016", ClickUp field UUID)
+    pub id: String,
+    /// Field name, used as the key in `Issue::custom_fields`
+    pub name: String,
+    /// Value type: "text", "number", "date", "datetime", "checkbox", "option",
+    /// "multi_option", "user", "multi_user", "labels" or "other"
+    pub field_type: String,
+    /// Allowed values of option fields
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// Whether the field must be set when creating an issue (or applying a transition)
+    #[serde(default)]
+    pub required: bool,
 }
 
 // =============================================================================
@@ -147,6 +456,12 @@ pub struct MergeRequest {
     pub created_at: Option<String>,
     /// Updated at timestamp (ISO 8601)
     pub updated_at: Option<String>,
+    /// CI pipelines for the latest commit (only populated when requested)
+    #[serde(default)]
+    pub pipelines: Vec<CiPipeline>,
+    /// Milestone the merge request is planned for
+    #[serde(default)]
+    pub milestone: Option<Milestone>,
 }
 
 /// Filter parameters for listing merge requests.
@@ -158,12 +473,78 @@ pub struct MrFilter {
     pub source_branch: Option<String>,
     /// Filter by target branch
     pub target_branch: Option<String>,
-    /// Filter by author username
+    /// Filter by author username (`@me` for the authenticated user)
     pub author: Option<String>,
     /// Filter by labels
     pub labels: Option<Vec<String>>,
     /// Maximum number of results
     pub limit: Option<u32>,
+    /// Filter by milestone title (see [`Milestone::title`])
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// Filter by reviewer username (`@me` for the authenticated user)
+    #[serde(default)]
+    pub reviewer: Option<String>,
+}
+
+// =============================================================================
+// CI Pipelines
+// =============================================================================
+
+/// Represents a CI pipeline (GitLab pipeline / GitHub Actions workflow run).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CiPipeline {
+    /// Pipeline ID
+    pub id: String,
+    /// Pipeline name (e.g., the workflow name on GitHub)
+    pub name: Option<String>,
+    /// Status ("success", "failed", "running", "pending", "canceled", "skipped", "manual")
+    pub status: String,
+    /// Git ref the pipeline ran for
+    pub ref_name: Option<String>,
+    /// Commit SHA
+    pub sha: Option<String>,
+    /// Duration in seconds
+    pub duration: Option<u64>,
+    /// Web URL for the pipeline
+    pub url: Option<String>,
+    /// Jobs / check runs (only populated for a single pipeline)
+    #[serde(default)]
+    pub jobs: Vec<CiJob>,
+    /// Created at timestamp (ISO 8601)
+    pub created_at: Option<String>,
+    /// Updated at timestamp (ISO 8601)
+    pub updated_at: Option<String>,
+}
+
+/// Represents a single job of a CI pipeline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CiJob {
+    /// Job ID
+    pub id: String,
+    /// Job name
+    pub name: String,
+    /// Stage name (GitLab only)
+    pub stage: Option<String>,
+    /// Status (same values as [`CiPipeline::status`])
+    pub status: String,
+    /// Whether a failure of this job is allowed
+    pub allow_failure: bool,
+    /// Duration in seconds
+    pub duration: Option<u64>,
+    /// Web URL for the job
+    pub url: Option<String>,
+}
+
+/// Input for starting a new CI pipeline.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunPipelineInput {
+    /// Branch or tag to run the pipeline for
+    pub ref_name: String,
+    /// Pipeline variables (GitLab) / workflow inputs (GitHub)
+    pub variables: HashMap<String, String>,
+    /// Workflow file name or ID (required for GitHub, ignored by GitLab)
+    pub workflow: Option<String>,
 }
 
 // =============================================================================
@@ -200,6 +581,9 @@ pub struct Comment {
     pub updated_at: Option<String>,
     /// Code position (for inline comments)
     pub position: Option<CodePosition>,
+    /// Files attached to or linked from the comment
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
 }
 
 /// Position in code for inline comments.
@@ -226,6 +610,30 @@ pub struct CreateCommentInput {
     pub discussion_id: Option<String>,
 }
 
+// =============================================================================
+// Commits
+// =============================================================================
+
+/// Represents a commit of a merge request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Commit {
+    /// Full commit SHA
+    pub sha: String,
+    /// First line of the commit message
+    pub title: String,
+    /// Full commit message
+    pub message: String,
+    /// Commit author (username falls back to the git author name when the
+    /// commit is not linked to an account)
+    pub author: Option<User>,
+    /// Authored at timestamp (ISO 8601)
+    pub authored_at: Option<String>,
+    /// Committed at timestamp (ISO 8601)
+    pub committed_at: Option<String>,
+    /// Web URL for the commit
+    pub url: Option<String>,
+}
+
 // =============================================================================
 // File Diff
 // =============================================================================
@@ -251,6 +659,110 @@ pub struct FileDiff {
     pub deletions: Option<u32>,
 }
 
+// =============================================================================
+// Repository
+// =============================================================================
+
+/// Represents a file read from a repository at a given ref.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RepositoryFile {
+    /// File path relative to the repository root
+    pub path: String,
+    /// Branch, tag or commit SHA the file was read at (None for the default branch)
+    pub ref_name: Option<String>,
+    /// Decoded file content (empty for binary files)
+    pub content: String,
+    /// File size in bytes
+    pub size: u64,
+    /// Whether the file is binary (content is not returned)
+    pub binary: bool,
+    /// Blob SHA
+    pub sha: Option<String>,
+    /// Web URL for the file
+    pub url: Option<String>,
+}
+
+/// Represents a branch of a repository.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Branch {
+    /// Branch name
+    pub name: String,
+    /// SHA of the commit the branch points to
+    pub sha: Option<String>,
+    /// Web URL for the branch
+    pub url: Option<String>,
+}
+
+/// Represents an entry of a repository directory listing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TreeEntry {
+    /// Path relative to the repository root
+    pub path: String,
+    /// Entry name (last path component)
+    pub name: String,
+    /// Entry kind ("file", "dir", "symlink", "submodule")
+    pub kind: String,
+    /// File size in bytes (GitHub only)
+    pub size: Option<u64>,
+}
+
+// =============================================================================
+// Agile (boards and sprints)
+// =============================================================================
+
+/// Represents an agile board (e.g., a Jira Scrum or Kanban board).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Board {
+    /// Board ID
+    pub id: String,
+    /// Board name
+    pub name: String,
+    /// Board kind ("scrum", "kanban", "simple")
+    pub kind: String,
+    /// Key of the project the board belongs to
+    pub project_key: Option<String>,
+}
+
+/// Represents a sprint of a board.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Sprint {
+    /// Sprint ID
+    pub id: String,
+    /// Sprint name
+    pub name: String,
+    /// State ("active", "future", "closed")
+    pub state: String,
+    /// Sprint goal
+    pub goal: Option<String>,
+    /// ID of the board the sprint was created on
+    pub board_id: Option<String>,
+    /// Start date (ISO 8601)
+    pub start_date: Option<String>,
+    /// Planned end date (ISO 8601)
+    pub end_date: Option<String>,
+    /// Date the sprint was completed (ISO 8601)
+    pub complete_date: Option<String>,
+}
+
+// =============================================================================
+// Time tracking
+// =============================================================================
+
+/// Time logged on an issue (a Jira worklog or a ClickUp time entry).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TimeEntry {
+    /// Entry ID
+    pub id: String,
+    /// User who logged the time
+    pub author: Option<User>,
+    /// Logged time in seconds
+    pub duration_seconds: u64,
+    /// When the work started (ISO 8601)
+    pub started_at: Option<String>,
+    /// Description of the work
+    pub comment: Option<String>,
+}
+
 // =============================================================================
 // Pagination
 // =============================================================================
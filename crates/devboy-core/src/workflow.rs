@@ -0,0 +1,218 @@
+//! Developer workflow helpers ("start work on an issue").
+//!
+//! Generates branch names from issues and checks branches out in the local
+//! git repository.
+//!
+//! # Branch patterns
+//!
+//! | Placeholder | Value                                               |
+//! |-------------|-----------------------------------------------------|
+//! | `{id}`      | Issue number without prefix (`123`, `PROJ-42`, `CU-abc`) |
+//! | `{key}`     | Full issue key, made branch-safe (`gitlab-123`)     |
+//! | `{slug}`    | Slugified issue title (`fix-login-on-safari`)       |
+//!
+//! The default pattern `{id}-{slug}` lets GitLab relate the branch to the
+//! issue automatically.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::{Error, Result};
+use crate::types::Issue;
+
+/// Default branch name pattern.
+pub const DEFAULT_BRANCH_PATTERN: &str = "{id}-{slug}";
+
+/// Maximum length of the title slug.
+const MAX_SLUG_LEN: usize = 40;
+
+/// Issue number without the provider prefix ("gitlab#123" -> "123").
+pub fn issue_id(key: &str) -> &str {
+    key.rsplit_once('#').map(|(_, id)| id).unwrap_or(key)
+}
+
+/// Turn a title into a lowercase ASCII slug ("Fix login on Safari!" -> "fix-login-on-safari").
+///
+/// The slug is cut at a word boundary to at most `max_len` characters.
+pub fn slugify(text: &str, max_len: usize) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+
+    if slug.len() <= max_len {
+        return slug.to_string();
+    }
+
+    // The slug is ASCII, so byte indices are char boundaries
+    let cut = &slug[..max_len];
+    if slug.as_bytes()[max_len] == b'-' {
+        return cut.to_string();
+    }
+    match cut.rfind('-') {
+        Some(pos) if pos > max_len / 2 => cut[..pos].to_string(),
+        _ => cut.trim_end_matches('-').to_string(),
+    }
+}
+
+/// Generate a branch name for an issue from a pattern (see the module docs).
+pub fn branch_name_for_issue(issue: &Issue, pattern: &str) -> Result<String> {
+    let mut name = String::new();
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        name.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            return Err(Error::InvalidData(format!(
+                "Unclosed placeholder in branch pattern '{}'",
+                pattern
+            )));
+        };
+
+        let placeholder = &rest[start + 1..start + len];
+        match placeholder {
+            "id" => name.push_str(issue_id(&issue.key)),
+            "key" => name.push_str(&issue.key.replace('#', "-")),
+            "slug" => name.push_str(&slugify(&issue.title, MAX_SLUG_LEN)),
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "Unknown placeholder '{{{}}}' in branch pattern. Available: {{id}}, {{key}}, {{slug}}",
+                    placeholder
+                )))
+            }
+        }
+        rest = &rest[start + len + 1..];
+    }
+    name.push_str(rest);
+
+    // An empty slug leaves a dangling separator ("123-")
+    let name = name.trim_end_matches(['-', '_', '/', '.']).to_string();
+    validate_branch_name(&name)?;
+    Ok(name)
+}
+
+/// Check a branch name against the git ref name rules.
+pub fn validate_branch_name(name: &str) -> Result<()> {
+    let invalid = name.is_empty()
+        || name.starts_with(['-', '/', '.'])
+        || name.ends_with(['/', '.'])
+        || name.ends_with(".lock")
+        || name.contains("..")
+        || name.contains("//")
+        || name.contains("@{")
+        || name
+            .chars()
+            .any(|c| c.is_ascii_control() || " ~^:?*[\\".contains(c));
+
+    if invalid {
+        return Err(Error::InvalidData(format!(
+            "Invalid branch name: '{}'",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Fetch a remote branch and check it out in the git repository at `repo_dir`.
+pub fn checkout_branch(repo_dir: &Path, name: &str) -> Result<()> {
+    run_git(repo_dir, &["fetch", "origin", name])?;
+    // Creates a local tracking branch from origin/<name> when it does not exist yet
+    run_git(repo_dir, &["checkout", name])
+}
+
+fn run_git(repo_dir: &Path, args: &[&str]) -> Result<()> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::Other(anyhow::anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, title: &str) -> Issue {
+        Issue {
+            key: key.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_issue_id() {
+        assert_eq!(issue_id("gitlab#123"), "123");
+        assert_eq!(issue_id("gh#7"), "7");
+        assert_eq!(issue_id("PROJ-42"), "PROJ-42");
+        assert_eq!(issue_id("CU-abc"), "CU-abc");
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Fix login on Safari!", 40), "fix-login-on-safari");
+        assert_eq!(slugify("  [API] v2: add /users  ", 40), "api-v2-add-users");
+        assert_eq!(slugify("Исправить вход", 40), "");
+        assert_eq!(
+            slugify("Make the dashboard load faster on slow networks", 30),
+            "make-the-dashboard-load-faster"
+        );
+    }
+
+    #[test]
+    fn test_branch_name_default_pattern() {
+        let name = branch_name_for_issue(&issue("gitlab#123", "Fix login"), DEFAULT_BRANCH_PATTERN)
+            .unwrap();
+        assert_eq!(name, "123-fix-login");
+    }
+
+    #[test]
+    fn test_branch_name_custom_pattern() {
+        let name = branch_name_for_issue(
+            &issue("PROJ-42", "Add OAuth support"),
+            "feature/{key}_{slug}",
+        )
+        .unwrap();
+        assert_eq!(name, "feature/PROJ-42_add-oauth-support");
+
+        let name = branch_name_for_issue(&issue("gh#5", "Docs"), "{key}").unwrap();
+        assert_eq!(name, "gh-5");
+    }
+
+    #[test]
+    fn test_branch_name_empty_slug() {
+        let name = branch_name_for_issue(&issue("gitlab#9", "Баг"), "{id}-{slug}").unwrap();
+        assert_eq!(name, "9");
+    }
+
+    #[test]
+    fn test_branch_name_invalid_pattern() {
+        let issue = issue("gitlab#1", "Title");
+        assert!(branch_name_for_issue(&issue, "{id}-{title}").is_err());
+        assert!(branch_name_for_issue(&issue, "{id").is_err());
+        assert!(branch_name_for_issue(&issue, "a b/{id}").is_err());
+    }
+
+    #[test]
+    fn test_validate_branch_name() {
+        assert!(validate_branch_name("feature/123-login").is_ok());
+        assert!(validate_branch_name("").is_err());
+        assert!(validate_branch_name("a..b").is_err());
+        assert!(validate_branch_name("branch.lock").is_err());
+        assert!(validate_branch_name("/leading").is_err());
+        assert!(validate_branch_name("with:colon").is_err());
+    }
+}
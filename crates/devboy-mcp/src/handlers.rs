@@ -4,21 +4,49 @@
 //! calling providers and transforming output through the pipeline.
 //!
 //! Tools are organized by category:
-//! - **Issues**: get_issues, get_issue, get_issue_comments, create_issue, update_issue, add_issue_comment
+//! - **Issues**: get_issues, get_issue, get_issue_comments, create_issue, update_issue,
+//!   bulk_update_issues, add_issue_comment, get_custom_fields, get_milestones, get_containers,
+//!   get_labels_and_statuses, link_issues, unlink_issues, get_transitions, transition_issue,
+//!   add_checklist_item, set_checklist_item, download_attachment, upload_attachment
 //! - **Merge Requests**: get_merge_requests, get_merge_request, get_merge_request_discussions,
-//!   get_merge_request_diffs, create_merge_request_comment
-
+//!   get_merge_request_diffs, get_merge_request_commits, get_commit_diff,
+//!   create_merge_request_comment
+//! - **CI Pipelines**: get_pipeline, get_job_log, retry_pipeline, retry_job, cancel_pipeline,
+//!   run_pipeline
+//! - **Repository**: get_file, list_tree
+//! - **Workflow**: create_branch_for_issue
+//! - **Agile**: get_boards, get_sprints, get_current_sprint, get_sprint_issues,
+//!   move_issues_to_sprint, move_issues_to_backlog
+//! - **Time tracking**: log_time, get_time_entries, set_time_estimate
+//! - **Users**: get_current_user, search_users
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use devboy_core::attachments::{file_name_from_url, mime_type_for};
+use devboy_core::bulk;
+use devboy_core::encoding::{encode_base64, is_binary};
+use devboy_core::keys::{is_url, KeyResolver};
+use devboy_core::time::{format_duration, parse_duration};
+use devboy_core::workflow;
 use devboy_core::{
-    CodePosition, CreateCommentInput, CreateIssueInput, IssueFilter, IssueProvider,
-    MergeRequestProvider, MrFilter, Provider, UpdateIssueInput,
+    Checklist, CiPipeline, CodePosition, CreateCommentInput, CreateIssueInput, IssueFilter,
+    IssueProvider, MergeRequestProvider, MrFilter, Provider, RunPipelineInput,
+    TransitionIssueInput, UpdateIssueInput, User, WorkflowConfig,
+};
+use devboy_pipeline::job_log::DEFAULT_CONTEXT_LINES;
+use devboy_pipeline::markdown::{
+    bulk_report_to_markdown, catalog_to_markdown, transitions_to_markdown,
 };
 use devboy_pipeline::{OutputFormat, Pipeline, PipelineConfig};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::protocol::{ToolCallResult, ToolDefinition};
+use crate::protocol::{EmbeddedResource, ToolCallResult, ToolDefinition, ToolResultContent};
+
+/// Default size limit of a downloaded attachment (1 MiB).
+const DEFAULT_ATTACHMENT_MAX_BYTES: usize = 1024 * 1024;
 
 /// Helper to get provider name without ambiguity.
 fn get_provider_name(provider: &dyn Provider) -> &'static str {
@@ -29,6 +57,8 @@ fn get_provider_name(provider: &dyn Provider) -> &'static str {
 pub struct ToolHandler {
     providers: Vec<Arc<dyn Provider>>,
     pipeline_config: PipelineConfig,
+    workflow_config: WorkflowConfig,
+    upload_root: Option<PathBuf>,
 }
 
 impl ToolHandler {
@@ -37,6 +67,8 @@ impl ToolHandler {
         Self {
             providers,
             pipeline_config: PipelineConfig::default(),
+            workflow_config: WorkflowConfig::default(),
+            upload_root: None,
         }
     }
 
@@ -46,6 +78,19 @@ impl ToolHandler {
         self
     }
 
+    /// Create with custom workflow configuration (branch naming).
+    pub fn with_workflow_config(mut self, config: WorkflowConfig) -> Self {
+        self.workflow_config = config;
+        self
+    }
+
+    /// Directory files uploaded by `upload_attachment` must live in
+    /// (default: the working directory).
+    pub fn with_upload_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.upload_root = Some(root.into());
+        self
+    }
+
     /// Get available tool definitions, grouped by category.
     pub fn available_tools(&self) -> Vec<ToolDefinition> {
         let mut tools = Vec::new();
@@ -76,7 +121,15 @@ impl ToolHandler {
                     },
                     "assignee": {
                         "type": "string",
-                        "description": "Filter by assignee username"
+                        "description": "Filter by assignee username, email or display name (resolved to user IDs for ClickUp and Jira Cloud), or '@me' for the authenticated user"
+                    },
+                    "milestone": {
+                        "type": "string",
+                        "description": "Filter by milestone title as listed by get_milestones (GitLab/GitHub milestone, Jira fix version, ClickUp list of the folder)"
+                    },
+                    "container": {
+                        "type": "string",
+                        "description": "ClickUp only: list ID to search instead of the configured lists (see get_containers), or 'workspace' to search the whole workspace"
                     },
                     "limit": {
                         "type": "integer",
@@ -108,6 +161,10 @@ impl ToolHandler {
                         "type": "string",
                         "enum": ["asc", "desc"],
                         "description": "Sort order (default: desc)"
+                    },
+                    "query": {
+                        "type": "string",
+                        "description": "Provider-native query combined with the other filters (requires 'provider' when several are configured). Jira: JQL, e.g. 'fixVersion = 2.3 AND component = API AND updated >= -7d' (may end with ORDER BY). GitHub: search qualifiers, e.g. 'milestone:v2.3 comments:>5'. GitLab: list parameters, e.g. 'milestone=v2.3&weight=3'. Not supported for ClickUp."
                     }
                 }
             }),
@@ -115,14 +172,14 @@ impl ToolHandler {
 
         tools.push(ToolDefinition {
             name: "get_issue".to_string(),
-            description: "Get a single issue by key (e.g., 'gh#123', 'gitlab#456', 'CU-abc', 'DEV-42', 'jira#PROJ-123'). Returns full issue details.".to_string(),
+            description: "Get a single issue by key (e.g., 'gh#123', 'gitlab#456', 'CU-abc', 'DEV-42', 'jira#PROJ-123') or web URL (e.g., 'https://github.com/org/repo/issues/123'). Returns full issue details, including parent and child issues (GitLab shows only the epic, not task hierarchy).".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "required": ["key"],
                 "properties": {
                     "key": {
                         "type": "string",
-                        "description": "Issue key (e.g., 'gh#123' for GitHub, 'gitlab#456' for GitLab, 'CU-abc' or custom ID like 'DEV-42' for ClickUp, 'jira#PROJ-123' for Jira)"
+                        "description": "Issue key (e.g., 'gh#123' for GitHub, 'gitlab#456' for GitLab, 'CU-abc' or custom ID like 'DEV-42' for ClickUp, 'jira#PROJ-123' for Jira) or its web URL"
                     },
                     "format": {
                         "type": "string",
@@ -178,7 +235,23 @@ impl ToolHandler {
                     "assignees": {
                         "type": "array",
                         "items": { "type": "string" },
-                        "description": "Assignee usernames"
+                        "description": "Assignee usernames, emails or display names"
+                    },
+                    "custom_fields": {
+                        "type": "object",
+                        "description": "Custom field values by field name or ID (Jira, ClickUp), e.g. {\"Story Points\": 5, \"Team\": \"Backend\"}. Options are set by name, dates as YYYY-MM-DD. See get_custom_fields."
+                    },
+                    "issue_type": {
+                        "type": "string",
+                        "description": "Issue type, e.g. 'Bug', 'Story', 'Epic', 'Sub-task' (Jira), 'task' or 'incident' (GitLab), an organization issue type (GitHub)"
+                    },
+                    "parent": {
+                        "type": "string",
+                        "description": "Parent issue key: creates a subtask (Jira, ClickUp), a sub-issue (GitHub) or an issue in an epic (Jira epic key, GitLab 'gitlab&<epic iid>'). GitLab cannot create tasks under an issue"
+                    },
+                    "container": {
+                        "type": "string",
+                        "description": "ClickUp only: list ID to create the task in instead of the configured list (see get_containers)"
                     },
                     "provider": {
                         "type": "string",
@@ -211,18 +284,64 @@ impl ToolHandler {
                     },
                     "state": {
                         "type": "string",
-                        "enum": ["open", "closed"],
-                        "description": "New state"
+                        "description": "New state: 'open', 'closed' or a status from get_labels_and_statuses. Use transition_issue for transitions that require fields"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "New labels (replaces existing), checked against get_labels_and_statuses"
+                    },
+                    "assignees": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "New assignees by username, email or display name (replaces existing)"
+                    },
+                    "custom_fields": {
+                        "type": "object",
+                        "description": "Custom field values by field name or ID (Jira, ClickUp); null clears a field. Options are set by name, dates as YYYY-MM-DD. See get_custom_fields."
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "bulk_update_issues".to_string(),
+            description: "Apply the same change to many issues (relabel, reassign, close) and report the outcome per issue. Use dry_run to preview the changes first.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["keys"],
+                "properties": {
+                    "keys": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys (e.g., ['gh#1', 'gh#2'])"
+                    },
+                    "state": {
+                        "type": "string",
+                        "description": "New state: 'open', 'closed' or a status from get_labels_and_statuses"
                     },
                     "labels": {
                         "type": "array",
                         "items": { "type": "string" },
-                        "description": "New labels (replaces existing)"
+                        "description": "New labels (replaces existing), checked against get_labels_and_statuses"
                     },
                     "assignees": {
                         "type": "array",
                         "items": { "type": "string" },
-                        "description": "New assignees (replaces existing)"
+                        "description": "New assignees by username, email or display name (replaces existing)"
+                    },
+                    "custom_fields": {
+                        "type": "object",
+                        "description": "Custom field values by field name or ID (Jira, ClickUp); null clears a field"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Only report the changes that would be made (default: false)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "clickup", "jira"],
+                        "description": "Provider of the issues (recommended when several issue trackers are configured)"
                     }
                 }
             }),
@@ -247,44 +366,70 @@ impl ToolHandler {
             }),
         });
 
-        // =================================================================
-        // MERGE REQUESTS GROUP
-        // =================================================================
+        tools.push(ToolDefinition {
+            name: "get_custom_fields".to_string(),
+            description: "List the custom fields of the issue tracker (Jira project / ClickUp list) with their types and options. Use the names with custom_fields in create_issue and update_issue.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira", "clickup"],
+                        "description": "Provider to query (default: all providers with custom fields, one section each)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
 
         tools.push(ToolDefinition {
-            name: "get_merge_requests".to_string(),
-            description: "Get merge requests / pull requests from configured providers."
-                .to_string(),
+            name: "get_milestones".to_string(),
+            description: "List milestones with progress (open/closed issue counts): GitLab milestones and iterations, GitHub milestones, Jira fix versions, ClickUp lists of the configured list's folder. Use the titles with the milestone filter of get_issues.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "properties": {
                     "state": {
                         "type": "string",
-                        "enum": ["open", "closed", "merged", "all"],
-                        "description": "Filter by MR/PR state (default: open)"
+                        "enum": ["open", "closed", "all"],
+                        "description": "Filter by state (default: open). Released Jira versions and archived ClickUp lists are closed"
                     },
-                    "author": {
+                    "provider": {
                         "type": "string",
-                        "description": "Filter by author username"
-                    },
-                    "labels": {
-                        "type": "array",
-                        "items": { "type": "string" },
-                        "description": "Filter by label names"
+                        "enum": ["gitlab", "github", "clickup", "jira"],
+                        "description": "Provider to query. If not specified, lists milestones of all configured providers."
                     },
-                    "source_branch": {
+                    "format": {
                         "type": "string",
-                        "description": "Filter by source branch"
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_containers".to_string(),
+            description: "Navigate the ClickUp hierarchy one level at a time: without a parent lists workspaces, a workspace lists its spaces, a space its folders and folderless lists, a folder its lists. Use list IDs with the container parameter of get_issues and create_issue.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "parent_kind": {
+                        "type": "string",
+                        "enum": ["workspace", "space", "folder"],
+                        "description": "Kind of the parent container (requires parent_id)"
                     },
-                    "target_branch": {
+                    "parent_id": {
                         "type": "string",
-                        "description": "Filter by target branch"
+                        "description": "ID of the parent container (requires parent_kind)"
                     },
-                    "limit": {
-                        "type": "integer",
-                        "description": "Maximum number of results (default: 20)",
-                        "minimum": 1,
-                        "maximum": 100
+                    "provider": {
+                        "type": "string",
+                        "enum": ["clickup"],
+                        "description": "Provider to query. If not specified, queries all configured providers."
                     },
                     "format": {
                         "type": "string",
@@ -296,1208 +441,5924 @@ impl ToolHandler {
         });
 
         tools.push(ToolDefinition {
-            name: "get_merge_request".to_string(),
-            description:
-                "Get a single merge request / pull request by key (e.g., 'pr#123', 'mr#456')."
-                    .to_string(),
+            name: "get_labels_and_statuses".to_string(),
+            description: "List the labels and issue statuses each provider knows (GitHub/GitLab labels, Jira Cloud labels and project statuses, ClickUp space tags and list statuses). update_issue rejects labels and states not listed here.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
-                "required": ["key"],
+                "properties": {
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "jira", "clickup"],
+                        "description": "Provider to query. If not specified, queries all configured providers."
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "link_issues".to_string(),
+            description: "Link two issues, e.g. mark that one blocks another. Jira supports its configured link types, GitLab relates_to/blocks/blocked_by, ClickUp relates_to and dependencies (blocks/blocked_by, also named blocking/waiting_on), GitHub issue dependencies (blocks/blocked_by).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "target", "kind"],
                 "properties": {
                     "key": {
                         "type": "string",
-                        "description": "MR/PR key (e.g., 'pr#123' for GitHub, 'mr#456' for GitLab)"
+                        "description": "Issue key (e.g., 'jira#PROJ-2', 'gh#123')"
                     },
-                    "format": {
+                    "target": {
                         "type": "string",
-                        "enum": ["markdown", "compact", "json"],
-                        "description": "Output format (default: markdown)"
+                        "description": "Key of the issue to link to"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "description": "Relation of key to target: 'blocks', 'blocked_by', 'relates_to', 'duplicates', 'duplicated_by', or another Jira link type"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "clickup", "jira"],
+                        "description": "Provider of the issues (recommended when several issue trackers are configured)"
                     }
                 }
             }),
         });
 
         tools.push(ToolDefinition {
-            name: "get_merge_request_discussions".to_string(),
-            description: "Get discussions/review comments for a merge request. Includes code review threads with positions.".to_string(),
+            name: "unlink_issues".to_string(),
+            description: "Remove the links between two issues.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
-                "required": ["key"],
+                "required": ["key", "target"],
                 "properties": {
                     "key": {
                         "type": "string",
-                        "description": "MR/PR key (e.g., 'pr#123')"
+                        "description": "Issue key (e.g., 'jira#PROJ-2', 'gh#123')"
                     },
-                    "format": {
+                    "target": {
                         "type": "string",
-                        "enum": ["markdown", "compact", "json"],
-                        "description": "Output format (default: markdown)"
+                        "description": "Key of the linked issue"
+                    },
+                    "kind": {
+                        "type": "string",
+                        "description": "Only remove links of this kind (default: all links between the issues)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "clickup", "jira"],
+                        "description": "Provider of the issues (recommended when several issue trackers are configured)"
                     }
                 }
             }),
         });
 
         tools.push(ToolDefinition {
-            name: "get_merge_request_diffs".to_string(),
-            description:
-                "Get file diffs for a merge request. Shows changed files with additions/deletions."
-                    .to_string(),
+            name: "get_transitions".to_string(),
+            description: "List the workflow transitions available for an issue (Jira) with the fields their screens require, e.g. the resolution of a Done transition.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
                 "required": ["key"],
                 "properties": {
                     "key": {
                         "type": "string",
-                        "description": "MR/PR key (e.g., 'pr#123')"
+                        "description": "Issue key (e.g., 'jira#PROJ-123')"
                     },
-                    "format": {
+                    "provider": {
                         "type": "string",
-                        "enum": ["markdown", "compact", "json"],
-                        "description": "Output format (default: markdown)"
+                        "enum": ["jira"],
+                        "description": "Provider of the issue"
                     }
                 }
             }),
         });
 
         tools.push(ToolDefinition {
-            name: "create_merge_request_comment".to_string(),
-            description: "Add a comment to a merge request. Can be a general comment or an inline code review comment.".to_string(),
+            name: "transition_issue".to_string(),
+            description: "Apply a workflow transition from get_transitions to an issue (Jira), setting its screen fields (resolution, fix versions, ...) and an optional comment.".to_string(),
             input_schema: serde_json::json!({
                 "type": "object",
-                "required": ["key", "body"],
+                "required": ["key", "transition"],
                 "properties": {
                     "key": {
                         "type": "string",
-                        "description": "MR/PR key (e.g., 'pr#123')"
+                        "description": "Issue key (e.g., 'jira#PROJ-123')"
                     },
-                    "body": {
+                    "transition": {
                         "type": "string",
-                        "description": "Comment text"
+                        "description": "Transition ID or name, or the name of its target status"
                     },
-                    "file_path": {
+                    "fields": {
+                        "type": "object",
+                        "description": "Screen field values by field name or ID, e.g. {\"Resolution\": \"Fixed\"}"
+                    },
+                    "comment": {
                         "type": "string",
-                        "description": "File path for inline comment (optional)"
+                        "description": "Comment to add with the transition (markdown)"
                     },
-                    "line": {
-                        "type": "integer",
-                        "description": "Line number for inline comment (required if file_path is set)"
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider of the issue"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "add_checklist_item".to_string(),
+            description: "Add an item to a checklist of an issue (ClickUp), e.g. an acceptance criterion. The checklist is created if it does not exist.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "item"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'CU-abc', 'DEV-42')"
                     },
-                    "line_type": {
+                    "item": {
                         "type": "string",
-                        "enum": ["old", "new"],
-                        "description": "Line type: 'old' for deleted line, 'new' for added line (default: new)"
+                        "description": "Item text"
                     },
-                    "commit_sha": {
+                    "checklist": {
                         "type": "string",
-                        "description": "Commit SHA for inline comment (required for GitHub)"
+                        "description": "Checklist name (default: the first checklist of the issue)"
                     },
-                    "discussion_id": {
+                    "provider": {
                         "type": "string",
-                        "description": "Reply to existing discussion (optional)"
+                        "enum": ["clickup"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
                     }
                 }
             }),
         });
 
-        tools
-    }
-
-    /// Execute a tool by name with arguments.
-    pub async fn execute(&self, name: &str, arguments: Option<Value>) -> ToolCallResult {
-        match name {
-            // Issues
-            "get_issues" => self.handle_get_issues(arguments).await,
-            "get_issue" => self.handle_get_issue(arguments).await,
-            "get_issue_comments" => self.handle_get_issue_comments(arguments).await,
-            "create_issue" => self.handle_create_issue(arguments).await,
-            "update_issue" => self.handle_update_issue(arguments).await,
-            "add_issue_comment" => self.handle_add_issue_comment(arguments).await,
-            // Merge Requests
-            "get_merge_requests" => self.handle_get_merge_requests(arguments).await,
-            "get_merge_request" => self.handle_get_merge_request(arguments).await,
-            "get_merge_request_discussions" => {
-                self.handle_get_merge_request_discussions(arguments).await
-            }
-            "get_merge_request_diffs" => self.handle_get_merge_request_diffs(arguments).await,
-            "create_merge_request_comment" => {
-                self.handle_create_merge_request_comment(arguments).await
-            }
-            _ => ToolCallResult::error(format!("Unknown tool: {}", name)),
-        }
-    }
-
-    // =========================================================================
-    // ISSUES HANDLERS
-    // =========================================================================
-
-    async fn handle_get_issues(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetIssuesParams = arguments
-            .map(|v| serde_json::from_value(v).unwrap_or_default())
-            .unwrap_or_default();
+        tools.push(ToolDefinition {
+            name: "set_checklist_item".to_string(),
+            description: "Check or uncheck a checklist item of an issue (ClickUp).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "item"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'CU-abc', 'DEV-42')"
+                    },
+                    "item": {
+                        "type": "string",
+                        "description": "Item text or ID"
+                    },
+                    "checked": {
+                        "type": "boolean",
+                        "description": "true to check the item, false to uncheck it (default: true)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["clickup"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
+                    }
+                }
+            }),
+        });
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+        tools.push(ToolDefinition {
+            name: "download_attachment".to_string(),
+            description: "Download a file attached to an issue or comment, by the URL listed under its attachments. Text files are returned as text (truncated at max_bytes), images as image content and other files as an embedded base64 resource.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["url"],
+                "properties": {
+                    "url": {
+                        "type": "string",
+                        "description": "Attachment URL as listed by get_issue or get_issue_comments"
+                    },
+                    "max_bytes": {
+                        "type": "integer",
+                        "description": "Size limit in bytes (default: 1048576). Longer text is truncated, larger binary files are refused"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "clickup", "jira"],
+                        "description": "Provider hosting the attachment (recommended when several issue trackers are configured)"
+                    }
+                }
+            }),
+        });
 
-        let filter = IssueFilter {
-            state: params.state,
-            search: params.search,
-            labels: params.labels,
-            assignee: params.assignee,
-            limit: Some(params.limit.unwrap_or(20) as u32),
+        tools.push(ToolDefinition {
+            name: "upload_attachment".to_string(),
+            description: "Attach a file to an issue. Pass a local file_path, or file_name with text content. Supported by Jira, ClickUp and GitLab (the upload is linked from a new comment).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'jira#PROJ-2', 'gitlab#123')"
+                    },
+                    "file_path": {
+                        "type": "string",
+                        "description": "Path of a local file to upload, relative to the working directory; files outside it are refused"
+                    },
+                    "file_name": {
+                        "type": "string",
+                        "description": "Name of the attachment (default: the name of file_path)"
+                    },
+                    "content": {
+                        "type": "string",
+                        "description": "Text content to upload instead of a local file"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "clickup", "jira"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // MERGE REQUESTS GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "get_merge_requests".to_string(),
+            description: "Get merge requests / pull requests from configured providers."
+                .to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "state": {
+                        "type": "string",
+                        "enum": ["open", "closed", "merged", "all"],
+                        "description": "Filter by MR/PR state (default: open)"
+                    },
+                    "author": {
+                        "type": "string",
+                        "description": "Filter by author username, or '@me' for the authenticated user"
+                    },
+                    "reviewer": {
+                        "type": "string",
+                        "description": "Filter by reviewer username, or '@me' for the authenticated user (on GitHub applied to the fetched page of pull requests, like author)"
+                    },
+                    "labels": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Filter by label names"
+                    },
+                    "source_branch": {
+                        "type": "string",
+                        "description": "Filter by source branch"
+                    },
+                    "target_branch": {
+                        "type": "string",
+                        "description": "Filter by target branch"
+                    },
+                    "milestone": {
+                        "type": "string",
+                        "description": "Filter by milestone title (on GitHub applied to the fetched page of pull requests)"
+                    },
+                    "limit": {
+                        "type": "integer",
+                        "description": "Maximum number of results (default: 20)",
+                        "minimum": 1,
+                        "maximum": 100
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_merge_request".to_string(),
+            description:
+                "Get a single merge request / pull request by key (e.g., 'pr#123', 'mr#456') or web URL (e.g., 'https://github.com/org/repo/pull/45'). Includes a CI summary with failed jobs."
+                    .to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "MR/PR key (e.g., 'pr#123' for GitHub, 'mr#456' for GitLab) or its web URL"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_merge_request_discussions".to_string(),
+            description: "Get discussions/review comments for a merge request. Includes code review threads with positions.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "MR/PR key (e.g., 'pr#123')"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_merge_request_diffs".to_string(),
+            description:
+                "Get file diffs for a merge request. Shows changed files with additions/deletions."
+                    .to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "MR/PR key (e.g., 'pr#123')"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_merge_request_commits".to_string(),
+            description: "Get the commits of a merge request, oldest first. Use get_commit_diff to review a single commit.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "MR/PR key (e.g., 'pr#123')"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_commit_diff".to_string(),
+            description: "Get file diffs introduced by a single commit. Commit SHAs are shown in get_merge_request_commits output.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["sha"],
+                "properties": {
+                    "sha": {
+                        "type": "string",
+                        "description": "Commit SHA (full or abbreviated)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to query (recommended when both GitLab and GitHub are configured)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "create_merge_request_comment".to_string(),
+            description: "Add a comment to a merge request. Can be a general comment or an inline code review comment.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "body"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "MR/PR key (e.g., 'pr#123')"
+                    },
+                    "body": {
+                        "type": "string",
+                        "description": "Comment text"
+                    },
+                    "file_path": {
+                        "type": "string",
+                        "description": "File path for inline comment (optional)"
+                    },
+                    "line": {
+                        "type": "integer",
+                        "description": "Line number for inline comment (required if file_path is set)"
+                    },
+                    "line_type": {
+                        "type": "string",
+                        "enum": ["old", "new"],
+                        "description": "Line type: 'old' for deleted line, 'new' for added line (default: new)"
+                    },
+                    "commit_sha": {
+                        "type": "string",
+                        "description": "Commit SHA for inline comment (required for GitHub)"
+                    },
+                    "discussion_id": {
+                        "type": "string",
+                        "description": "Reply to existing discussion (optional)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // CI PIPELINES GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "get_pipeline".to_string(),
+            description: "Get a CI pipeline (GitLab pipeline / GitHub Actions workflow run) with all its jobs. Pipeline IDs are shown in get_merge_request output.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": {
+                        "type": "string",
+                        "description": "Pipeline ID (GitLab pipeline ID or GitHub workflow run ID)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to query (recommended when both GitLab and GitHub are configured)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_job_log".to_string(),
+            description: "Get the log of a CI job (GitLab job trace / GitHub Actions job log). By default returns only the failing sections: error lines with context, test failure summaries and compiler errors.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["job_id"],
+                "properties": {
+                    "job_id": {
+                        "type": "string",
+                        "description": "Job ID (shown in get_merge_request and get_pipeline output)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to query (recommended when both GitLab and GitHub are configured)"
+                    },
+                    "full": {
+                        "type": "boolean",
+                        "description": "Return the whole cleaned log instead of the failing sections (default: false)"
+                    },
+                    "context_lines": {
+                        "type": "integer",
+                        "description": "Lines of context around each error line (default: 5)",
+                        "minimum": 0,
+                        "maximum": 50
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "retry_pipeline".to_string(),
+            description: "Retry the failed and canceled jobs of a CI pipeline (GitLab pipeline retry / GitHub re-run failed jobs). Returns immediately; poll with get_pipeline to follow the new run.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": {
+                        "type": "string",
+                        "description": "Pipeline ID (GitLab pipeline ID or GitHub workflow run ID)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to use (required when both GitLab and GitHub are configured)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "retry_job".to_string(),
+            description: "Retry a single CI job, e.g. a flaky test job. Returns immediately; poll with get_pipeline to follow the new run.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["job_id"],
+                "properties": {
+                    "job_id": {
+                        "type": "string",
+                        "description": "Job ID (shown in get_merge_request and get_pipeline output)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to use (required when both GitLab and GitHub are configured)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "cancel_pipeline".to_string(),
+            description: "Cancel a running CI pipeline (GitLab pipeline / GitHub Actions workflow run).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": {
+                        "type": "string",
+                        "description": "Pipeline ID (GitLab pipeline ID or GitHub workflow run ID)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to use (required when both GitLab and GitHub are configured)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "run_pipeline".to_string(),
+            description: "Start a new CI pipeline for a branch or tag. On GitHub this dispatches a workflow (requires `workflow` and a workflow_dispatch trigger). Returns immediately; poll with get_pipeline to follow the run.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["ref"],
+                "properties": {
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch or tag to run the pipeline for"
+                    },
+                    "variables": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Pipeline variables (GitLab) or workflow inputs (GitHub)"
+                    },
+                    "workflow": {
+                        "type": "string",
+                        "description": "Workflow file name or ID, e.g. 'ci.yml' (required for GitHub, ignored by GitLab)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to use (required when both GitLab and GitHub are configured)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // REPOSITORY GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "get_file".to_string(),
+            description: "Get a repository file with line numbers, e.g. to see the code around a diff hunk at the MR's head or base commit. Large files are cut at a line boundary; use start_line/end_line to read a range.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["path"],
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "File path relative to the repository root (e.g., 'src/main.rs')"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag or commit SHA (default: default branch)"
+                    },
+                    "start_line": {
+                        "type": "integer",
+                        "description": "First line to return (1-based)",
+                        "minimum": 1
+                    },
+                    "end_line": {
+                        "type": "integer",
+                        "description": "Last line to return (inclusive)",
+                        "minimum": 1
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to query (recommended when both GitLab and GitHub are configured)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "list_tree".to_string(),
+            description: "List the files and directories of a repository directory.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "path": {
+                        "type": "string",
+                        "description": "Directory path relative to the repository root (default: root)"
+                    },
+                    "ref": {
+                        "type": "string",
+                        "description": "Branch, tag or commit SHA (default: default branch)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Provider to query (recommended when both GitLab and GitHub are configured)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // WORKFLOW GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "create_branch_for_issue".to_string(),
+            description: "Start work on an issue: create a branch named after it (e.g. '123-fix-login') in the GitLab/GitHub repository, link it back to the issue and optionally check it out locally.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'gitlab#123', 'gh#45', 'CU-abc', 'jira#PROJ-42')"
+                    },
+                    "pattern": {
+                        "type": "string",
+                        "description": "Branch name pattern with {id}, {key} and {slug} placeholders (default: workflow.branch_pattern config or '{id}-{slug}')"
+                    },
+                    "base": {
+                        "type": "string",
+                        "description": "Branch, tag or commit SHA to branch from (default: default branch)"
+                    },
+                    "repository_provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github"],
+                        "description": "Repository provider to create the branch in (default: the issue's provider if it hosts code, otherwise the only one that does; required when both GitLab and GitHub are configured for a Jira or ClickUp issue)"
+                    },
+                    "checkout": {
+                        "type": "boolean",
+                        "description": "Fetch and check out the branch in the local git repository (default: false)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // AGILE GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "get_boards".to_string(),
+            description: "List the agile boards (Scrum/Kanban) of the configured Jira project."
+                .to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to query"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_sprints".to_string(),
+            description: "List the sprints of a board with their state, dates and goal.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "Board ID from get_boards (default: the project's first Scrum board)"
+                    },
+                    "state": {
+                        "type": "string",
+                        "description": "Comma-separated sprint states to include: active, future, closed (default: all)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to query"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_current_sprint".to_string(),
+            description: "Get the active sprint of a board together with its issues.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "Board ID from get_boards (default: the project's first Scrum board)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to query"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_sprint_issues".to_string(),
+            description: "Get a sprint together with the issues planned for it.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["sprint_id"],
+                "properties": {
+                    "sprint_id": {
+                        "type": "string",
+                        "description": "Sprint ID from get_sprints"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to query"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "move_issues_to_sprint".to_string(),
+            description:
+                "Move issues into a sprint (e.g., plan backlog items for the next sprint)."
+                    .to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["sprint_id", "keys"],
+                "properties": {
+                    "sprint_id": {
+                        "type": "string",
+                        "description": "Target sprint ID from get_sprints"
+                    },
+                    "keys": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys to move (e.g., ['jira#PROJ-1', 'jira#PROJ-2'])"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to use"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "move_issues_to_backlog".to_string(),
+            description: "Move issues out of their sprints into the backlog.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["keys"],
+                "properties": {
+                    "keys": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Issue keys to move (e.g., ['jira#PROJ-1', 'jira#PROJ-2'])"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira"],
+                        "description": "Provider to use"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // TIME TRACKING GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "log_time".to_string(),
+            description: "Log time spent on an issue (Jira worklog, ClickUp time entry, GitLab spent time).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "duration"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'jira#PROJ-1', 'gitlab#123')"
+                    },
+                    "duration": {
+                        "type": "string",
+                        "description": "Time spent, e.g. '1h30m', '2d', '45m' (a day is 8h, a bare number means minutes)"
+                    },
+                    "comment": {
+                        "type": "string",
+                        "description": "What the time was spent on"
+                    },
+                    "started": {
+                        "type": "string",
+                        "description": "When the work started, ISO 8601 (default: now). Not supported by GitLab"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira", "clickup", "gitlab"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "get_time_entries".to_string(),
+            description: "List the time logged on an issue (Jira worklogs, ClickUp time entries).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'jira#PROJ-1', 'CU-abc123')"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira", "clickup"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "set_time_estimate".to_string(),
+            description: "Set the time estimate of an issue (Jira original estimate, ClickUp time estimate, GitLab estimate).".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["key", "estimate"],
+                "properties": {
+                    "key": {
+                        "type": "string",
+                        "description": "Issue key (e.g., 'jira#PROJ-1', 'gitlab#123')"
+                    },
+                    "estimate": {
+                        "type": "string",
+                        "description": "Estimated time, e.g. '4h', '1d 2h' (a day is 8h, a bare number means minutes)"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["jira", "clickup", "gitlab"],
+                        "description": "Provider of the issue (recommended when several issue trackers are configured)"
+                    }
+                }
+            }),
+        });
+
+        // =================================================================
+        // USERS GROUP
+        // =================================================================
+
+        tools.push(ToolDefinition {
+            name: "get_current_user".to_string(),
+            description: "Get the authenticated user of each configured provider. Filters also accept '@me' for this user.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "jira", "clickup"],
+                        "description": "Provider to query. If not specified, queries all configured providers."
+                    }
+                }
+            }),
+        });
+
+        tools.push(ToolDefinition {
+            name: "search_users".to_string(),
+            description: "Search users by username, display name or email: GitHub assignable users, GitLab project members, Jira assignable users, ClickUp workspace members. Use the result for assignee and author filters.".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["query"],
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Part of a username, display name or email"
+                    },
+                    "provider": {
+                        "type": "string",
+                        "enum": ["gitlab", "github", "jira", "clickup"],
+                        "description": "Provider to search. If not specified, searches all configured providers."
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "compact", "json"],
+                        "description": "Output format (default: markdown)"
+                    }
+                }
+            }),
+        });
+
+        tools
+    }
+
+    /// Execute a tool by name with arguments.
+    pub async fn execute(&self, name: &str, arguments: Option<Value>) -> ToolCallResult {
+        match name {
+            // Issues
+            "get_issues" => self.handle_get_issues(arguments).await,
+            "get_issue" => self.handle_get_issue(arguments).await,
+            "get_issue_comments" => self.handle_get_issue_comments(arguments).await,
+            "create_issue" => self.handle_create_issue(arguments).await,
+            "update_issue" => self.handle_update_issue(arguments).await,
+            "bulk_update_issues" => self.handle_bulk_update_issues(arguments).await,
+            "add_issue_comment" => self.handle_add_issue_comment(arguments).await,
+            "get_custom_fields" => self.handle_get_custom_fields(arguments).await,
+            "get_milestones" => self.handle_get_milestones(arguments).await,
+            "get_containers" => self.handle_get_containers(arguments).await,
+            "get_labels_and_statuses" => self.handle_get_labels_and_statuses(arguments).await,
+            "link_issues" => self.handle_link_issues(arguments).await,
+            "unlink_issues" => self.handle_unlink_issues(arguments).await,
+            "get_transitions" => self.handle_get_transitions(arguments).await,
+            "transition_issue" => self.handle_transition_issue(arguments).await,
+            "add_checklist_item" => self.handle_add_checklist_item(arguments).await,
+            "set_checklist_item" => self.handle_set_checklist_item(arguments).await,
+            "download_attachment" => self.handle_download_attachment(arguments).await,
+            "upload_attachment" => self.handle_upload_attachment(arguments).await,
+            // Merge Requests
+            "get_merge_requests" => self.handle_get_merge_requests(arguments).await,
+            "get_merge_request" => self.handle_get_merge_request(arguments).await,
+            "get_merge_request_discussions" => {
+                self.handle_get_merge_request_discussions(arguments).await
+            }
+            "get_merge_request_diffs" => self.handle_get_merge_request_diffs(arguments).await,
+            "get_merge_request_commits" => self.handle_get_merge_request_commits(arguments).await,
+            "get_commit_diff" => self.handle_get_commit_diff(arguments).await,
+            "create_merge_request_comment" => {
+                self.handle_create_merge_request_comment(arguments).await
+            }
+            // CI Pipelines
+            "get_pipeline" => self.handle_get_pipeline(arguments).await,
+            "get_job_log" => self.handle_get_job_log(arguments).await,
+            "retry_pipeline" => self.handle_retry_pipeline(arguments).await,
+            "retry_job" => self.handle_retry_job(arguments).await,
+            "cancel_pipeline" => self.handle_cancel_pipeline(arguments).await,
+            "run_pipeline" => self.handle_run_pipeline(arguments).await,
+            // Repository
+            "get_file" => self.handle_get_file(arguments).await,
+            "list_tree" => self.handle_list_tree(arguments).await,
+            // Workflow
+            "create_branch_for_issue" => self.handle_create_branch_for_issue(arguments).await,
+            // Agile
+            "get_boards" => self.handle_get_boards(arguments).await,
+            "get_sprints" => self.handle_get_sprints(arguments).await,
+            "get_current_sprint" => self.handle_get_current_sprint(arguments).await,
+            "get_sprint_issues" => self.handle_get_sprint_issues(arguments).await,
+            "move_issues_to_sprint" => self.handle_move_issues_to_sprint(arguments).await,
+            "move_issues_to_backlog" => self.handle_move_issues_to_backlog(arguments).await,
+            // Time tracking
+            "log_time" => self.handle_log_time(arguments).await,
+            "get_time_entries" => self.handle_get_time_entries(arguments).await,
+            "set_time_estimate" => self.handle_set_time_estimate(arguments).await,
+            // Users
+            "get_current_user" => self.handle_get_current_user(arguments).await,
+            "search_users" => self.handle_search_users(arguments).await,
+            _ => ToolCallResult::error(format!("Unknown tool: {}", name)),
+        }
+    }
+
+    // =========================================================================
+    // ISSUES HANDLERS
+    // =========================================================================
+
+    async fn handle_get_issues(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetIssuesParams = arguments
+            .map(|v| serde_json::from_value(v).unwrap_or_default())
+            .unwrap_or_default();
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let filter = IssueFilter {
+            state: params.state,
+            search: params.search,
+            labels: params.labels,
+            assignee: params.assignee,
+            limit: Some(params.limit.unwrap_or(20) as u32),
             offset: Some(params.offset.unwrap_or(0) as u32),
             sort_by: params.sort_by,
             sort_order: params.sort_order,
+            query: params.query,
+            milestone: params.milestone,
+            container: params.container,
+        };
+
+        // A native query only makes sense for the provider it was written for
+        if filter.query.is_some() && params.provider.is_none() && self.providers.len() > 1 {
+            return ToolCallResult::error(
+                "Parameter 'query' is provider-specific, specify 'provider' as well".to_string(),
+            );
+        }
+
+        let mut all_issues = Vec::new();
+        let mut errors = Vec::new();
+
+        let providers: Vec<_> = if let Some(ref name) = params.provider {
+            match self.find_provider_by_name(name) {
+                Some(p) => vec![p],
+                None => {
+                    let available: Vec<_> = self
+                        .providers
+                        .iter()
+                        .map(|p| get_provider_name(p.as_ref()))
+                        .collect();
+                    return ToolCallResult::error(format!(
+                        "Provider '{}' not configured. Available: {}",
+                        name,
+                        available.join(", ")
+                    ));
+                }
+            }
+        } else {
+            self.providers.iter().collect()
+        };
+
+        for provider in &providers {
+            match provider.get_issues(filter.clone()).await {
+                Ok(issues) => {
+                    tracing::debug!(
+                        "Got {} issues from {}",
+                        issues.len(),
+                        get_provider_name(provider.as_ref())
+                    );
+                    all_issues.extend(issues);
+                }
+                Err(e) => {
+                    let name = get_provider_name(provider.as_ref());
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if all_issues.is_empty() && !errors.is_empty() {
+            return ToolCallResult::error(format!("Failed to get issues: {}", errors.join(", ")));
+        }
+
+        let pipeline = self.create_pipeline(&params.format);
+        match pipeline.transform_issues(all_issues) {
+            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+        }
+    }
+
+    async fn handle_get_issue(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetIssueParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_issue(&params.key).await {
+            Ok(issue) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_issues(vec![issue]) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to get issue {}: {}", params.key, e)),
+        }
+    }
+
+    async fn handle_get_issue_comments(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetIssueCommentsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_comments(&params.key).await {
+            Ok(comments) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_comments(comments) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => {
+                ToolCallResult::error(format!("Failed to get comments of {}: {}", params.key, e))
+            }
+        }
+    }
+
+    async fn handle_create_issue(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: CreateIssueParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: title".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let input = CreateIssueInput {
+            title: params.title,
+            description: params.description,
+            labels: params.labels.unwrap_or_default(),
+            assignees: params.assignees.unwrap_or_default(),
+            priority: None,
+            custom_fields: params.custom_fields.unwrap_or_default(),
+            issue_type: params.issue_type,
+            parent: params.parent,
+            container: params.container,
+        };
+
+        let provider = if let Some(ref name) = params.provider {
+            match self.find_provider_by_name(name) {
+                Some(p) => p,
+                None => {
+                    let available: Vec<_> = self
+                        .providers
+                        .iter()
+                        .map(|p| get_provider_name(p.as_ref()))
+                        .collect();
+                    return ToolCallResult::error(format!(
+                        "Provider '{}' not configured. Available: {}",
+                        name,
+                        available.join(", ")
+                    ));
+                }
+            }
+        } else {
+            &self.providers[0]
+        };
+        match provider.create_issue(input).await {
+            Ok(issue) => {
+                let msg = format!(
+                    "Created issue {} - {}\nURL: {}",
+                    issue.key,
+                    issue.title,
+                    issue.url.unwrap_or_default()
+                );
+                ToolCallResult::text(msg)
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to create issue: {}", e)),
+        }
+    }
+
+    async fn handle_update_issue(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: UpdateIssueParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let input = UpdateIssueInput {
+            title: params.title,
+            description: params.description,
+            state: params.state,
+            labels: params.labels,
+            assignees: params.assignees,
+            priority: None,
+            custom_fields: params.custom_fields.unwrap_or_default(),
+        };
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        // Values unknown to the provider's catalog are reported instead of created
+        if let Err(e) = bulk::check_catalogs(provider.as_ref(), &input).await {
+            return ToolCallResult::error(format!(
+                "Failed to update issue {}: {}: {}",
+                params.key,
+                get_provider_name(provider.as_ref()),
+                e
+            ));
+        }
+
+        match provider.update_issue(&params.key, input).await {
+            Ok(issue) => {
+                let msg = format!("Updated issue {} - {}", issue.key, issue.title);
+                ToolCallResult::text(msg)
+            }
+            Err(e) => {
+                ToolCallResult::error(format!("Failed to update issue {}: {}", params.key, e))
+            }
+        }
+    }
+
+    async fn handle_bulk_update_issues(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: BulkUpdateIssuesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: keys".to_string()),
+        };
+
+        if params.keys.is_empty() {
+            return ToolCallResult::error("No issue keys given".to_string());
+        }
+        if params.state.is_none()
+            && params.labels.is_none()
+            && params.assignees.is_none()
+            && params.custom_fields.as_ref().is_none_or(|f| f.is_empty())
+        {
+            return ToolCallResult::error(
+                "Nothing to update: pass state, labels, assignees or custom_fields".to_string(),
+            );
+        }
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        if let Err(result) = self.select_providers(params.provider.as_deref()) {
+            return result;
+        }
+
+        let input = UpdateIssueInput {
+            state: params.state,
+            labels: params.labels,
+            assignees: params.assignees,
+            custom_fields: params.custom_fields.unwrap_or_default(),
+            ..Default::default()
+        };
+
+        let name = params.provider.as_deref();
+        let report = bulk::update_routed(&params.keys, &input, params.dry_run, |key| {
+            self.resolve_key(key, name).map(|p| p.as_ref())
+        })
+        .await;
+
+        let text = bulk_report_to_markdown(&report);
+        if report.succeeded() == 0 {
+            ToolCallResult::error(text)
+        } else {
+            ToolCallResult::text(text)
+        }
+    }
+
+    async fn handle_add_issue_comment(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: AddIssueCommentParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error("Missing required parameters: key, body".to_string())
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match IssueProvider::add_comment(provider.as_ref(), &params.key, &params.body).await {
+            Ok(comment) => {
+                let msg = format!("Added comment {} to issue {}", comment.id, params.key);
+                ToolCallResult::text(msg)
+            }
+            Err(e) => ToolCallResult::error(format!(
+                "Failed to add comment to issue {}: {}",
+                params.key, e
+            )),
+        }
+    }
+
+    async fn handle_get_custom_fields(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetCustomFieldsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => GetCustomFieldsParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut sections = Vec::new();
+        let mut errors = Vec::new();
+        for provider in providers {
+            let name = get_provider_name(provider.as_ref());
+            let fields = match provider.get_custom_fields().await {
+                Ok(fields) => fields,
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                    continue;
+                }
+            };
+            let pipeline = self.create_pipeline(&params.format);
+            match pipeline.transform_custom_fields(fields) {
+                Ok(output) => sections.push((name, output.to_string_with_hints())),
+                Err(e) => return ToolCallResult::error(format!("Pipeline error: {}", e)),
+            }
+        }
+
+        match sections.len() {
+            0 if !errors.is_empty() => ToolCallResult::error(format!(
+                "Failed to get custom fields: {}",
+                errors.join(", ")
+            )),
+            0 => ToolCallResult::error("No configured provider supports custom fields".to_string()),
+            1 => ToolCallResult::text(sections.remove(0).1),
+            _ => ToolCallResult::text(
+                sections
+                    .iter()
+                    .map(|(name, output)| format!("## {}\n\n{}", name, output))
+                    .collect::<Vec<_>>()
+                    .join("\n\n"),
+            ),
+        }
+    }
+
+    async fn handle_get_milestones(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetMilestonesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => GetMilestonesParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut all_milestones = Vec::new();
+        let mut errors = Vec::new();
+        let mut supported = false;
+        for provider in providers {
+            match provider.get_milestones(params.state.as_deref()).await {
+                Ok(milestones) => {
+                    supported = true;
+                    all_milestones.extend(milestones);
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    let name = get_provider_name(provider.as_ref());
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if !errors.is_empty() && !supported {
+            return ToolCallResult::error(format!(
+                "Failed to get milestones: {}",
+                errors.join(", ")
+            ));
+        }
+        if !supported {
+            return ToolCallResult::error("No configured provider supports milestones".to_string());
+        }
+
+        let pipeline = self.create_pipeline(&params.format);
+        match pipeline.transform_milestones(all_milestones) {
+            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+        }
+    }
+
+    async fn handle_get_containers(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetContainersParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => GetContainersParams::default(),
+        };
+
+        let parent = match (params.parent_kind.as_deref(), params.parent_id.as_deref()) {
+            (Some(kind), Some(id)) => Some((kind, id)),
+            (None, None) => None,
+            _ => {
+                return ToolCallResult::error(
+                    "Parameters 'parent_kind' and 'parent_id' must be given together".to_string(),
+                )
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut all_containers = Vec::new();
+        let mut errors = Vec::new();
+        let mut supported = false;
+        for provider in providers {
+            match provider.get_containers(parent).await {
+                Ok(containers) => {
+                    supported = true;
+                    all_containers.extend(containers);
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    let name = get_provider_name(provider.as_ref());
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if !errors.is_empty() && !supported {
+            return ToolCallResult::error(format!(
+                "Failed to get containers: {}",
+                errors.join(", ")
+            ));
+        }
+        if !supported {
+            return ToolCallResult::error("No configured provider supports containers".to_string());
+        }
+
+        let pipeline = self.create_pipeline(&params.format);
+        match pipeline.transform_containers(all_containers) {
+            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+        }
+    }
+
+    async fn handle_get_labels_and_statuses(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetLabelsAndStatusesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => GetLabelsAndStatusesParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut sections = Vec::new();
+        let mut errors = Vec::new();
+        for provider in providers {
+            let name = get_provider_name(provider.as_ref());
+            let statuses = match provider.list_statuses().await {
+                Ok(statuses) => Some(statuses),
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => None,
+                Err(e) => {
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                    continue;
+                }
+            };
+            let labels = match provider.list_labels().await {
+                Ok(labels) => Some(labels),
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => None,
+                Err(e) => {
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                    continue;
+                }
+            };
+            if statuses.is_none() && labels.is_none() {
+                continue;
+            }
+            sections.push(format!(
+                "## {}\n\n{}",
+                name,
+                catalog_to_markdown(statuses.as_deref(), labels.as_deref())
+            ));
+        }
+
+        if sections.is_empty() {
+            if !errors.is_empty() {
+                return ToolCallResult::error(format!(
+                    "Failed to get labels and statuses: {}",
+                    errors.join(", ")
+                ));
+            }
+            return ToolCallResult::error(
+                "No configured provider supports label and status catalogs".to_string(),
+            );
+        }
+
+        ToolCallResult::text(sections.join("\n\n"))
+    }
+
+    async fn handle_link_issues(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: LinkIssuesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: key, target, kind".to_string(),
+                )
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider
+            .link_issues(&params.key, &params.target, &params.kind)
+            .await
+        {
+            Ok(()) => ToolCallResult::text(format!(
+                "Linked {} {} {}",
+                params.key,
+                params.kind.replace('_', " "),
+                params.target
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support issue links",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to link issues: {}", e)),
+        }
+    }
+
+    async fn handle_get_transitions(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetTransitionsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider.get_transitions(&params.key).await {
+            Ok(transitions) => {
+                ToolCallResult::text(transitions_to_markdown(&params.key, &transitions))
+            }
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support transitions",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to get transitions: {}", e)),
+        }
+    }
+
+    async fn handle_transition_issue(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: TransitionIssueParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: key, transition".to_string(),
+                )
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        let input = TransitionIssueInput {
+            transition: params.transition,
+            fields: params.fields,
+            comment: params.comment,
+        };
+
+        match provider.transition_issue(&params.key, input.clone()).await {
+            Ok(()) => ToolCallResult::text(format!(
+                "Applied transition '{}' to {}",
+                input.transition, params.key
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support transitions",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to transition issue: {}", e)),
+        }
+    }
+
+    async fn handle_unlink_issues(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: UnlinkIssuesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: key, target".to_string(),
+                )
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider
+            .unlink_issues(&params.key, &params.target, params.kind.as_deref())
+            .await
+        {
+            Ok(()) => ToolCallResult::text(format!(
+                "Removed links between {} and {}",
+                params.key, params.target
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support issue links",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to unlink issues: {}", e)),
+        }
+    }
+
+    async fn handle_add_checklist_item(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: AddChecklistItemParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error("Missing required parameters: key, item".to_string())
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider
+            .add_checklist_item(&params.key, params.checklist.as_deref(), &params.item)
+            .await
+        {
+            Ok(checklist) => ToolCallResult::text(format!(
+                "Added '{}' to {}",
+                params.item,
+                checklist_summary(&params.key, &checklist)
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support checklists",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to add checklist item: {}", e)),
+        }
+    }
+
+    async fn handle_set_checklist_item(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: SetChecklistItemParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error("Missing required parameters: key, item".to_string())
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        let checked = params.checked.unwrap_or(true);
+        match provider
+            .set_checklist_item(&params.key, &params.item, checked)
+            .await
+        {
+            Ok(checklist) => ToolCallResult::text(format!(
+                "{} '{}' in {}",
+                if checked { "Checked" } else { "Unchecked" },
+                params.item,
+                checklist_summary(&params.key, &checklist)
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support checklists",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to update checklist item: {}", e)),
+        }
+    }
+
+    async fn handle_download_attachment(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: DownloadAttachmentParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: url".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        // Each provider only accepts its own attachment URLs
+        let mut last_error = None;
+        for provider in providers {
+            match provider.download_attachment(&params.url).await {
+                Ok(content) => {
+                    let max_bytes = params.max_bytes.unwrap_or(DEFAULT_ATTACHMENT_MAX_BYTES);
+                    return attachment_result(&params.url, content, max_bytes);
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for attachment {}: {}",
+                        get_provider_name(provider.as_ref()),
+                        params.url,
+                        e
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) => ToolCallResult::error(format!("Failed to download attachment: {}", e)),
+            None => ToolCallResult::error(
+                "No configured provider supports attachment downloads".to_string(),
+            ),
+        }
+    }
+
+    async fn handle_upload_attachment(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: UploadAttachmentParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        let (file_name, content) = match (&params.file_path, params.content) {
+            (Some(path), _) => {
+                let root = match &self.upload_root {
+                    Some(root) => root.clone(),
+                    None => match std::env::current_dir() {
+                        Ok(dir) => dir,
+                        Err(e) => {
+                            return ToolCallResult::error(format!(
+                                "Failed to get working directory: {}",
+                                e
+                            ))
+                        }
+                    },
+                };
+                let file = match resolve_upload_path(&root, path) {
+                    Ok(file) => file,
+                    Err(message) => return ToolCallResult::error(message),
+                };
+                let content = match tokio::fs::read(&file).await {
+                    Ok(c) => c,
+                    Err(e) => {
+                        return ToolCallResult::error(format!("Failed to read {}: {}", path, e))
+                    }
+                };
+                let file_name = params.file_name.unwrap_or_else(|| {
+                    std::path::Path::new(path)
+                        .file_name()
+                        .map(|n| n.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| path.clone())
+                });
+                (file_name, content)
+            }
+            (None, Some(content)) => match params.file_name {
+                Some(file_name) => (file_name, content.into_bytes()),
+                None => {
+                    return ToolCallResult::error(
+                        "file_name is required when uploading content".to_string(),
+                    )
+                }
+            },
+            (None, None) => {
+                return ToolCallResult::error(
+                    "Either file_path or file_name with content is required".to_string(),
+                )
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider
+            .upload_attachment(&params.key, &file_name, &content)
+            .await
+        {
+            Ok(attachment) => ToolCallResult::text(format!(
+                "Attached {} to {}\n{}",
+                attachment.name, params.key, attachment.url
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support attachment uploads",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to upload attachment: {}", e)),
+        }
+    }
+
+    // =========================================================================
+    // MERGE REQUESTS HANDLERS
+    // =========================================================================
+
+    async fn handle_get_merge_requests(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetMergeRequestsParams = arguments
+            .map(|v| serde_json::from_value(v).unwrap_or_default())
+            .unwrap_or_default();
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let filter = MrFilter {
+            state: params.state,
+            author: params.author,
+            labels: params.labels,
+            source_branch: params.source_branch,
+            target_branch: params.target_branch,
+            limit: Some(params.limit.unwrap_or(20) as u32),
+            milestone: params.milestone,
+            reviewer: params.reviewer,
+        };
+
+        let mut all_mrs = Vec::new();
+        let mut errors = Vec::new();
+
+        for provider in &self.providers {
+            match provider.get_merge_requests(filter.clone()).await {
+                Ok(mrs) => {
+                    tracing::debug!(
+                        "Got {} MRs from {}",
+                        mrs.len(),
+                        get_provider_name(provider.as_ref())
+                    );
+                    all_mrs.extend(mrs);
+                }
+                Err(e) => {
+                    let name = get_provider_name(provider.as_ref());
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if all_mrs.is_empty() && !errors.is_empty() {
+            return ToolCallResult::error(format!(
+                "Failed to get merge requests: {}",
+                errors.join(", ")
+            ));
+        }
+
+        let pipeline = self.create_pipeline(&params.format);
+        match pipeline.transform_merge_requests(all_mrs) {
+            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+        }
+    }
+
+    async fn handle_get_merge_request(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetMergeRequestParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_merge_request(&params.key).await {
+            Ok(mut mr) => {
+                // CI status is best-effort: the MR is still useful without it
+                match provider.get_latest_pipelines(&params.key).await {
+                    Ok(pipelines) => mr.pipelines = pipelines,
+                    Err(e) => {
+                        tracing::debug!("Failed to get pipelines for {}: {}", params.key, e)
+                    }
+                }
+
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_merge_requests(vec![mr]) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => {
+                ToolCallResult::error(format!("Failed to get merge request {}: {}", params.key, e))
+            }
+        }
+    }
+
+    async fn handle_get_merge_request_discussions(
+        &self,
+        arguments: Option<Value>,
+    ) -> ToolCallResult {
+        let params: GetMergeRequestDiscussionsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_discussions(&params.key).await {
+            Ok(discussions) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_discussions(discussions) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => ToolCallResult::error(format!(
+                "Failed to get discussions of {}: {}",
+                params.key, e
+            )),
+        }
+    }
+
+    async fn handle_get_merge_request_diffs(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetMergeRequestDiffsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_diffs(&params.key).await {
+            Ok(diffs) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_diffs(diffs) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => {
+                ToolCallResult::error(format!("Failed to get diffs of {}: {}", params.key, e))
+            }
+        }
+    }
+
+    async fn handle_get_merge_request_commits(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetMergeRequestCommitsParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.get_commits(&params.key).await {
+            Ok(commits) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_commits(commits) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(e) => {
+                ToolCallResult::error(format!("Failed to get commits of {}: {}", params.key, e))
+            }
+        }
+    }
+
+    async fn handle_get_commit_diff(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetCommitDiffParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: sha".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.get_commit_diff(&params.sha).await {
+                Ok(diffs) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_diffs(diffs) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for commit {}: {}",
+                        get_provider_name(provider.as_ref()),
+                        params.sha,
+                        e
+                    );
+                }
+            }
+        }
+
+        ToolCallResult::error(format!("Commit not found: {}", params.sha))
+    }
+
+    async fn handle_create_merge_request_comment(
+        &self,
+        arguments: Option<Value>,
+    ) -> ToolCallResult {
+        let params: CreateMergeRequestCommentParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error("Missing required parameters: key, body".to_string())
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        // Build position if file_path is provided
+        let position = params.file_path.map(|file_path| CodePosition {
+            file_path,
+            line: params.line.unwrap_or(1),
+            line_type: params.line_type.unwrap_or_else(|| "new".to_string()),
+            commit_sha: params.commit_sha,
+        });
+
+        let input = CreateCommentInput {
+            body: params.body,
+            position,
+            discussion_id: params.discussion_id,
+        };
+
+        let provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match MergeRequestProvider::add_comment(provider.as_ref(), &params.key, input).await {
+            Ok(comment) => {
+                let msg = format!("Added comment {} to {}", comment.id, params.key);
+                ToolCallResult::text(msg)
+            }
+            Err(e) => ToolCallResult::error(format!(
+                "Failed to add comment to merge request {}: {}",
+                params.key, e
+            )),
+        }
+    }
+
+    // =========================================================================
+    // CI PIPELINES HANDLERS
+    // =========================================================================
+
+    async fn handle_get_pipeline(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetPipelineParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: id".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.get_pipeline(&params.id).await {
+                Ok(ci_pipeline) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_pipelines(vec![ci_pipeline]) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for pipeline {}: {}",
+                        get_provider_name(provider.as_ref()),
+                        params.id,
+                        e
+                    );
+                }
+            }
+        }
+
+        ToolCallResult::error(format!("Pipeline not found: {}", params.id))
+    }
+
+    async fn handle_get_job_log(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetJobLogParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: job_id".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.get_job_log(&params.job_id).await {
+                Ok(log) => {
+                    let pipeline = self.create_pipeline(&None);
+                    let output = pipeline.transform_job_log(
+                        &log,
+                        params.full.unwrap_or(false),
+                        params.context_lines.unwrap_or(DEFAULT_CONTEXT_LINES),
+                    );
+                    return ToolCallResult::text(output.to_string_with_hints());
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for job {}: {}",
+                        get_provider_name(provider.as_ref()),
+                        params.job_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        ToolCallResult::error(format!("Job not found: {}", params.job_id))
+    }
+
+    async fn handle_retry_pipeline(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: PipelineActionParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: id".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.ci_provider(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.retry_pipeline(&params.id).await {
+            Ok(ci_pipeline) => {
+                ToolCallResult::text(format_pipeline_action("Retried", &ci_pipeline))
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to retry pipeline: {}", e)),
+        }
+    }
+
+    async fn handle_retry_job(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: RetryJobParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: job_id".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.ci_provider(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.retry_job(&params.job_id).await {
+            Ok(ci_pipeline) => {
+                ToolCallResult::text(format_pipeline_action("Retried job in", &ci_pipeline))
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to retry job: {}", e)),
+        }
+    }
+
+    async fn handle_cancel_pipeline(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: PipelineActionParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: id".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.ci_provider(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        match provider.cancel_pipeline(&params.id).await {
+            Ok(ci_pipeline) => {
+                ToolCallResult::text(format_pipeline_action("Canceled", &ci_pipeline))
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to cancel pipeline: {}", e)),
+        }
+    }
+
+    async fn handle_run_pipeline(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: RunPipelineParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: ref".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.ci_provider(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let input = RunPipelineInput {
+            ref_name: params.ref_name,
+            variables: params.variables.unwrap_or_default(),
+            workflow: params.workflow,
+        };
+
+        match provider.run_pipeline(input).await {
+            Ok(ci_pipeline) => {
+                ToolCallResult::text(format_pipeline_action("Started", &ci_pipeline))
+            }
+            Err(e) => ToolCallResult::error(format!("Failed to run pipeline: {}", e)),
+        }
+    }
+
+    // =========================================================================
+    // REPOSITORY HANDLERS
+    // =========================================================================
+
+    async fn handle_get_file(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetFileParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: path".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider
+                .get_file(&params.path, params.ref_name.as_deref())
+                .await
+            {
+                Ok(file) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_file(file, params.start_line, params.end_line) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for file {}: {}",
+                        get_provider_name(provider.as_ref()),
+                        params.path,
+                        e
+                    );
+                }
+            }
+        }
+
+        ToolCallResult::error(format!("File not found: {}", params.path))
+    }
+
+    async fn handle_list_tree(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: ListTreeParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => ListTreeParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let path = params.path.as_deref().unwrap_or_default();
+        for provider in providers {
+            match provider.list_tree(path, params.ref_name.as_deref()).await {
+                Ok(entries) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_tree(entries) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(e) => {
+                    tracing::debug!(
+                        "Provider {} failed for directory '{}': {}",
+                        get_provider_name(provider.as_ref()),
+                        path,
+                        e
+                    );
+                }
+            }
+        }
+
+        ToolCallResult::error(format!("Directory not found: '{}'", path))
+    }
+
+    // =========================================================================
+    // WORKFLOW HANDLERS
+    // =========================================================================
+
+    async fn handle_create_branch_for_issue(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: CreateBranchForIssueParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let issue_provider = match self.provider_for_key(&params.key) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+        let issue = match issue_provider.get_issue(&params.key).await {
+            Ok(issue) => issue,
+            Err(e) => {
+                return ToolCallResult::error(format!("Failed to get issue {}: {}", params.key, e))
+            }
+        };
+
+        let pattern = params
+            .pattern
+            .as_deref()
+            .or(self.workflow_config.branch_pattern.as_deref())
+            .unwrap_or(workflow::DEFAULT_BRANCH_PATTERN);
+        let name = match workflow::branch_name_for_issue(&issue, pattern) {
+            Ok(name) => name,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        let repo_provider =
+            match self.repository_provider(params.repository_provider.as_deref(), issue_provider) {
+                Ok(p) => p,
+                Err(result) => return result,
+            };
+        let branch = match repo_provider
+            .create_branch(&name, params.base.as_deref())
+            .await
+        {
+            Ok(branch) => branch,
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => {
+                return ToolCallResult::error(
+                    "No configured provider supports repositories".to_string(),
+                )
+            }
+            Err(e) => return ToolCallResult::error(format!("Failed to create branch: {}", e)),
+        };
+
+        let mut text = format!("Created branch `{}` for {}", branch.name, issue.key);
+        if let Some(url) = &branch.url {
+            text.push_str(&format!("\n{}", url));
+        }
+
+        // GitLab relates "<iid>-..." branches to the project's issue on its own
+        let auto_linked = issue.source == "gitlab"
+            && get_provider_name(repo_provider.as_ref()) == "gitlab"
+            && branch
+                .name
+                .starts_with(&format!("{}-", workflow::issue_id(&issue.key)));
+        if auto_linked {
+            text.push_str("\nGitLab links the branch to the issue automatically.");
+        } else {
+            let body = format!("Branch `{}` created for this issue.", branch.name);
+            match IssueProvider::add_comment(issue_provider.as_ref(), &issue.key, &body).await {
+                Ok(_) => text.push_str("\nLinked the branch in an issue comment."),
+                Err(e) => text.push_str(&format!(
+                    "\nWarning: failed to link the branch to the issue: {}",
+                    e
+                )),
+            }
+        }
+
+        if params.checkout {
+            let result = std::env::current_dir()
+                .map_err(devboy_core::Error::from)
+                .and_then(|dir| workflow::checkout_branch(&dir, &branch.name));
+            match result {
+                Ok(()) => text.push_str(&format!("\nChecked out `{}` locally.", branch.name)),
+                Err(e) => text.push_str(&format!("\nWarning: local checkout failed: {}", e)),
+            }
+        }
+
+        ToolCallResult::text(text)
+    }
+
+    // =========================================================================
+    // AGILE HANDLERS
+    // =========================================================================
+
+    async fn handle_get_boards(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: AgileListParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => AgileListParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.get_boards().await {
+                Ok(boards) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_boards(boards) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => return ToolCallResult::error(format!("Failed to get boards: {}", e)),
+            }
+        }
+
+        ToolCallResult::error("No configured provider supports sprints".to_string())
+    }
+
+    async fn handle_get_sprints(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: AgileListParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => AgileListParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            let result = match resolve_board_id(provider.as_ref(), params.board_id.as_deref()).await
+            {
+                Ok(board_id) => {
+                    provider
+                        .get_sprints(&board_id, params.state.as_deref())
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(sprints) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_sprints(sprints) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => return ToolCallResult::error(format!("Failed to get sprints: {}", e)),
+            }
+        }
+
+        ToolCallResult::error("No configured provider supports sprints".to_string())
+    }
+
+    async fn handle_get_current_sprint(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: AgileListParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => AgileListParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            let board_id = match resolve_board_id(provider.as_ref(), params.board_id.as_deref())
+                .await
+            {
+                Ok(id) => id,
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => return ToolCallResult::error(format!("Failed to get sprints: {}", e)),
+            };
+
+            let sprint = match provider.get_sprints(&board_id, Some("active")).await {
+                Ok(sprints) => match sprints.into_iter().next() {
+                    Some(sprint) => sprint,
+                    None => {
+                        return ToolCallResult::error(format!(
+                            "No active sprint on board {}",
+                            board_id
+                        ))
+                    }
+                },
+                Err(e) => return ToolCallResult::error(format!("Failed to get sprints: {}", e)),
+            };
+
+            return match provider.get_sprint_issues(&sprint.id).await {
+                Ok(issues) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    match pipeline.transform_sprint(sprint, issues) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    }
+                }
+                Err(e) => ToolCallResult::error(format!("Failed to get sprint issues: {}", e)),
+            };
+        }
+
+        ToolCallResult::error("No configured provider supports sprints".to_string())
+    }
+
+    async fn handle_get_sprint_issues(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetSprintIssuesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error("Missing required parameter: sprint_id".to_string())
+            }
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.get_sprint_issues(&params.sprint_id).await {
+                Ok(issues) => {
+                    let pipeline = self.create_pipeline(&params.format);
+                    return match pipeline.transform_issues(issues) {
+                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                    };
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    return ToolCallResult::error(format!("Failed to get sprint issues: {}", e))
+                }
+            }
+        }
+
+        ToolCallResult::error("No configured provider supports sprints".to_string())
+    }
+
+    async fn handle_move_issues_to_sprint(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: MoveIssuesToSprintParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: sprint_id, keys".to_string(),
+                )
+            }
+        };
+
+        if params.keys.is_empty() {
+            return ToolCallResult::error("No issue keys given".to_string());
+        }
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        // The sprint belongs to the provider owning the issues
+        let mut provider: Option<&Arc<dyn Provider>> = None;
+        for key in &params.keys {
+            let owner = match self.resolve_key(key, params.provider.as_deref()) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(e.to_string()),
+            };
+            match provider {
+                Some(p) if !Arc::ptr_eq(p, owner) => {
+                    return ToolCallResult::error(format!(
+                        "Issues {} and {} belong to different providers ({} and {}); move them separately",
+                        params.keys[0],
+                        key,
+                        get_provider_name(p.as_ref()),
+                        get_provider_name(owner.as_ref())
+                    ));
+                }
+                _ => provider = Some(owner),
+            }
+        }
+        let Some(provider) = provider else {
+            return ToolCallResult::error("No issue keys given".to_string());
+        };
+
+        match provider
+            .move_issues_to_sprint(&params.sprint_id, &params.keys)
+            .await
+        {
+            Ok(()) => ToolCallResult::text(format!(
+                "Moved {} issue(s) to sprint {}: {}",
+                params.keys.len(),
+                params.sprint_id,
+                params.keys.join(", ")
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support sprints",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to move issues: {}", e)),
+        }
+    }
+
+    async fn handle_move_issues_to_backlog(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: MoveIssuesToBacklogParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: keys".to_string()),
+        };
+
+        if params.keys.is_empty() {
+            return ToolCallResult::error("No issue keys given".to_string());
+        }
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        for provider in providers {
+            match provider.move_issues_to_backlog(&params.keys).await {
+                Ok(()) => {
+                    return ToolCallResult::text(format!(
+                        "Moved {} issue(s) to the backlog: {}",
+                        params.keys.len(),
+                        params.keys.join(", ")
+                    ));
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => return ToolCallResult::error(format!("Failed to move issues: {}", e)),
+            }
+        }
+
+        ToolCallResult::error("No configured provider supports sprints".to_string())
+    }
+
+    // =========================================================================
+    // TIME TRACKING HANDLERS
+    // =========================================================================
+
+    async fn handle_log_time(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: LogTimeParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: key, duration".to_string(),
+                )
+            }
+        };
+
+        let duration = match parse_duration(&params.duration) {
+            Ok(d) => d,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider
+            .log_time(
+                &params.key,
+                duration,
+                params.comment.as_deref(),
+                params.started.as_deref(),
+            )
+            .await
+        {
+            Ok(()) => ToolCallResult::text(format!(
+                "Logged {} on {}",
+                format_duration(duration),
+                params.key
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support time tracking",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to log time: {}", e)),
+        }
+    }
+
+    async fn handle_get_time_entries(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetTimeEntriesParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider.get_time_entries(&params.key).await {
+            Ok(entries) => {
+                let pipeline = self.create_pipeline(&params.format);
+                match pipeline.transform_time_entries(entries) {
+                    Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+                    Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+                }
+            }
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support listing time entries",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to get time entries: {}", e)),
+        }
+    }
+
+    async fn handle_set_time_estimate(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: SetTimeEstimateParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => {
+                return ToolCallResult::error(
+                    "Missing required parameters: key, estimate".to_string(),
+                )
+            }
+        };
+
+        let estimate = match parse_duration(&params.estimate) {
+            Ok(d) => d,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let provider = match self.resolve_key(&params.key, params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(e) => return ToolCallResult::error(e.to_string()),
+        };
+
+        match provider.set_time_estimate(&params.key, estimate).await {
+            Ok(()) => ToolCallResult::text(format!(
+                "Set estimate of {} to {}",
+                params.key,
+                format_duration(estimate)
+            )),
+            Err(devboy_core::Error::ProviderUnsupported { .. }) => ToolCallResult::error(format!(
+                "{} does not support time tracking",
+                get_provider_name(provider.as_ref())
+            )),
+            Err(e) => ToolCallResult::error(format!("Failed to set time estimate: {}", e)),
+        }
+    }
+
+    // =========================================================================
+    // USERS HANDLERS
+    // =========================================================================
+
+    async fn handle_get_current_user(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: GetCurrentUserParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => GetCurrentUserParams::default(),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut lines = Vec::new();
+        let mut errors = Vec::new();
+        for provider in providers {
+            let name = get_provider_name(provider.as_ref());
+            match provider.get_current_user().await {
+                Ok(user) => lines.push(format!("- **{}**: {}", name, user_summary(&user))),
+                Err(e) => {
+                    tracing::warn!("Error from {}: {}", name, e);
+                    lines.push(format!("- **{}**: error: {}", name, e));
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if errors.len() == lines.len() {
+            return ToolCallResult::error(format!(
+                "Failed to get current user: {}",
+                errors.join(", ")
+            ));
+        }
+
+        ToolCallResult::text(format!("# Current user\n\n{}", lines.join("\n")))
+    }
+
+    async fn handle_search_users(&self, arguments: Option<Value>) -> ToolCallResult {
+        let params: SearchUsersParams = match arguments {
+            Some(v) => match serde_json::from_value(v) {
+                Ok(p) => p,
+                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
+            },
+            None => return ToolCallResult::error("Missing required parameter: query".to_string()),
+        };
+
+        if self.providers.is_empty() {
+            return ToolCallResult::error("No providers configured".to_string());
+        }
+
+        let providers = match self.select_providers(params.provider.as_deref()) {
+            Ok(p) => p,
+            Err(result) => return result,
+        };
+
+        let mut all_users = Vec::new();
+        let mut errors = Vec::new();
+        let mut supported = false;
+        for provider in providers {
+            match provider.search_users(&params.query).await {
+                Ok(users) => {
+                    supported = true;
+                    all_users.extend(users);
+                }
+                Err(devboy_core::Error::ProviderUnsupported { .. }) => continue,
+                Err(e) => {
+                    let name = get_provider_name(provider.as_ref());
+                    tracing::warn!("Error from {}: {}", name, e);
+                    errors.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+
+        if !errors.is_empty() && !supported {
+            return ToolCallResult::error(format!("Failed to search users: {}", errors.join(", ")));
+        }
+        if !supported {
+            return ToolCallResult::error(
+                "No configured provider supports user search".to_string(),
+            );
+        }
+
+        let pipeline = self.create_pipeline(&params.format);
+        match pipeline.transform_users(all_users) {
+            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
+            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
+        }
+    }
+
+    // =========================================================================
+    // HELPER METHODS
+    // =========================================================================
+
+    fn find_provider_by_name(&self, name: &str) -> Option<&Arc<dyn Provider>> {
+        self.providers
+            .iter()
+            .find(|p| get_provider_name(p.as_ref()) == name)
+    }
+
+    /// The provider owning `key`, or the named provider when `name` is given.
+    /// A URL must point to the named provider's repository or project.
+    fn resolve_key(
+        &self,
+        key: &str,
+        name: Option<&str>,
+    ) -> devboy_core::Result<&Arc<dyn Provider>> {
+        if let Some(name) = name {
+            let provider = self
+                .find_provider_by_name(name)
+                .ok_or_else(|| devboy_core::Error::ProviderNotFound(name.to_string()))?;
+            let key = key.trim();
+            if is_url(key) && !provider.key_patterns().iter().any(|p| p.matches(key)) {
+                return Err(devboy_core::Error::ProviderNotFound(format!(
+                    "'{}' does not point to the repository or project configured for {}",
+                    key, name
+                )));
+            }
+            return Ok(provider);
+        }
+
+        let mut resolver = KeyResolver::new();
+        for provider in &self.providers {
+            resolver.register(
+                get_provider_name(provider.as_ref()),
+                provider.key_patterns(),
+            );
+        }
+        resolver.resolve(key).map(|index| &self.providers[index])
+    }
+
+    /// Route a key to the provider owning it (see [`KeyResolver`]).
+    fn provider_for_key(
+        &self,
+        key: &str,
+    ) -> std::result::Result<&Arc<dyn Provider>, ToolCallResult> {
+        self.resolve_key(key, None)
+            .map_err(|e| ToolCallResult::error(e.to_string()))
+    }
+
+    /// The provider to run CI actions on: the named one, or the only
+    /// configured provider with CI. Pipeline and job IDs carry no provider
+    /// prefix, so with several CI providers the caller has to choose.
+    fn ci_provider(
+        &self,
+        name: Option<&str>,
+    ) -> std::result::Result<&Arc<dyn Provider>, ToolCallResult> {
+        if let Some(name) = name {
+            return self
+                .select_providers(Some(name))
+                .map(|providers| providers[0]);
+        }
+
+        let candidates: Vec<&Arc<dyn Provider>> =
+            self.providers.iter().filter(|p| p.has_ci()).collect();
+        match candidates.as_slice() {
+            [provider] => Ok(provider),
+            [] => Err(ToolCallResult::error(
+                "No configured provider supports CI pipelines".to_string(),
+            )),
+            _ => {
+                let names: Vec<&str> = candidates
+                    .iter()
+                    .map(|p| get_provider_name(p.as_ref()))
+                    .collect();
+                Err(ToolCallResult::error(format!(
+                    "Several providers run CI ({}); pass provider to choose one",
+                    names.join(", ")
+                )))
+            }
+        }
+    }
+
+    /// The provider to create branches in: the named one, else the issue's
+    /// own provider when it hosts code, else the only provider that does.
+    fn repository_provider<'a>(
+        &'a self,
+        name: Option<&str>,
+        issue_provider: &'a Arc<dyn Provider>,
+    ) -> std::result::Result<&'a Arc<dyn Provider>, ToolCallResult> {
+        if let Some(name) = name {
+            return self
+                .select_providers(Some(name))
+                .map(|providers| providers[0]);
+        }
+        if issue_provider.has_repository() {
+            return Ok(issue_provider);
+        }
+
+        let candidates: Vec<&Arc<dyn Provider>> = self
+            .providers
+            .iter()
+            .filter(|p| p.has_repository())
+            .collect();
+        match candidates.as_slice() {
+            [provider] => Ok(provider),
+            [] => Err(ToolCallResult::error(
+                "No configured provider supports repositories".to_string(),
+            )),
+            _ => {
+                let names: Vec<&str> = candidates
+                    .iter()
+                    .map(|p| get_provider_name(p.as_ref()))
+                    .collect();
+                Err(ToolCallResult::error(format!(
+                    "Several providers host repositories ({}); pass repository_provider to choose one",
+                    names.join(", ")
+                )))
+            }
+        }
+    }
+
+    /// Select the named provider, or all providers when no name is given.
+    fn select_providers(
+        &self,
+        name: Option<&str>,
+    ) -> std::result::Result<Vec<&Arc<dyn Provider>>, ToolCallResult> {
+        let Some(name) = name else {
+            return Ok(self.providers.iter().collect());
+        };
+
+        match self.find_provider_by_name(name) {
+            Some(p) => Ok(vec![p]),
+            None => {
+                let available: Vec<_> = self
+                    .providers
+                    .iter()
+                    .map(|p| get_provider_name(p.as_ref()))
+                    .collect();
+                Err(ToolCallResult::error(format!(
+                    "Provider '{}' not configured. Available: {}",
+                    name,
+                    available.join(", ")
+                )))
+            }
+        }
+    }
+
+    fn create_pipeline(&self, format: &Option<String>) -> Pipeline {
+        let output_format = match format.as_deref() {
+            Some("json") => OutputFormat::Json,
+            Some("compact") => OutputFormat::Compact,
+            _ => OutputFormat::Markdown,
+        };
+
+        Pipeline::with_config(PipelineConfig {
+            format: output_format,
+            ..self.pipeline_config.clone()
+        })
+    }
+}
+
+/// Use the given board, or pick the provider's first Scrum board (sprints
+/// only exist on Scrum boards).
+async fn resolve_board_id(
+    provider: &dyn Provider,
+    board_id: Option<&str>,
+) -> devboy_core::Result<String> {
+    if let Some(id) = board_id {
+        return Ok(id.to_string());
+    }
+
+    let boards = provider.get_boards().await?;
+    boards
+        .iter()
+        .find(|b| b.kind == "scrum")
+        .or(boards.first())
+        .map(|b| b.id.clone())
+        .ok_or_else(|| devboy_core::Error::NotFound("No boards found".to_string()))
+}
+
+/// Return a downloaded attachment as text, an image or an embedded resource.
+///
+/// Text is truncated at `max_bytes`; binary files over the limit are refused,
+/// since a partial binary file is of no use.
+fn attachment_result(url: &str, content: Vec<u8>, max_bytes: usize) -> ToolCallResult {
+    let size = content.len();
+
+    if !is_binary(&content) {
+        let mut text = String::from_utf8_lossy(&content).into_owned();
+        if text.len() > max_bytes {
+            let mut end = max_bytes;
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            text.truncate(end);
+            text.push_str(&format!(
+                "\n\n[Truncated: showing {} of {} bytes]",
+                end, size
+            ));
+        }
+        return ToolCallResult::text(text);
+    }
+
+    if size > max_bytes {
+        return ToolCallResult::error(format!(
+            "Attachment is {} bytes, over the limit of {} bytes (raise max_bytes to download it)",
+            size, max_bytes
+        ));
+    }
+
+    let mime_type = mime_type_for(&file_name_from_url(url));
+    let content = match mime_type {
+        Some(mime_type) if mime_type.starts_with("image/") => ToolResultContent::Image {
+            data: encode_base64(&content),
+            mime_type: mime_type.to_string(),
+        },
+        _ => ToolResultContent::Resource {
+            resource: EmbeddedResource {
+                uri: url.to_string(),
+                mime_type: Some(mime_type.unwrap_or("application/octet-stream").to_string()),
+                text: None,
+                blob: Some(encode_base64(&content)),
+            },
+        },
+    };
+    ToolCallResult {
+        content: vec![content],
+        is_error: None,
+    }
+}
+
+/// Resolve a file to upload, relative to `root`. Files outside `root`
+/// (after following symlinks) are refused so that keys or `.env` files
+/// elsewhere on the machine cannot be attached to an issue.
+fn resolve_upload_path(root: &Path, path: &str) -> std::result::Result<PathBuf, String> {
+    let root = root
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve {}: {}", root.display(), e))?;
+    let file = root
+        .join(path)
+        .canonicalize()
+        .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    if !file.starts_with(&root) {
+        return Err(format!(
+            "Refusing to upload {}: only files under {} can be attached",
+            path,
+            root.display()
+        ));
+    }
+    Ok(file)
+}
+
+/// Checklist progress for a confirmation: "checklist 'Name' of KEY (2/3 done)".
+fn checklist_summary(key: &str, checklist: &Checklist) -> String {
+    let done = checklist.items.iter().filter(|i| i.resolved).count();
+    format!(
+        "checklist '{}' of {} ({}/{} done)",
+        checklist.name,
+        key,
+        done,
+        checklist.items.len()
+    )
+}
+
+/// One-line description of a user: "jdoe (John Doe, john@example.com, id 42)".
+fn user_summary(user: &User) -> String {
+    let details: Vec<String> = user
+        .name
+        .iter()
+        .filter(|name| **name != user.username)
+        .chain(user.email.iter())
+        .cloned()
+        .chain(std::iter::once(format!("id {}", user.id)))
+        .collect();
+    format!("{} ({})", user.username, details.join(", "))
+}
+
+/// Short confirmation for a pipeline action with a hint to poll for progress.
+fn format_pipeline_action(action: &str, pipeline: &CiPipeline) -> String {
+    let mut text = format!(
+        "{} pipeline #{} (status: {})",
+        action, pipeline.id, pipeline.status
+    );
+    if let Some(url) = &pipeline.url {
+        text.push_str(&format!("\n{}", url));
+    }
+    text.push_str(&format!(
+        "\n\nUse get_pipeline with id \"{}\" to follow progress.",
+        pipeline.id
+    ));
+    text
+}
+
+// =============================================================================
+// PARAMETER TYPES
+// =============================================================================
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetIssuesParams {
+    state: Option<String>,
+    search: Option<String>,
+    labels: Option<Vec<String>>,
+    assignee: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    format: Option<String>,
+    provider: Option<String>,
+    sort_by: Option<String>,
+    sort_order: Option<String>,
+    query: Option<String>,
+    milestone: Option<String>,
+    container: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetIssueParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetIssueCommentsParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateIssueParams {
+    title: String,
+    description: Option<String>,
+    labels: Option<Vec<String>>,
+    assignees: Option<Vec<String>>,
+    custom_fields: Option<serde_json::Map<String, Value>>,
+    issue_type: Option<String>,
+    parent: Option<String>,
+    container: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateIssueParams {
+    key: String,
+    title: Option<String>,
+    description: Option<String>,
+    state: Option<String>,
+    labels: Option<Vec<String>>,
+    assignees: Option<Vec<String>>,
+    custom_fields: Option<serde_json::Map<String, Value>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BulkUpdateIssuesParams {
+    keys: Vec<String>,
+    state: Option<String>,
+    labels: Option<Vec<String>>,
+    assignees: Option<Vec<String>>,
+    custom_fields: Option<serde_json::Map<String, Value>>,
+    #[serde(default)]
+    dry_run: bool,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AddIssueCommentParams {
+    key: String,
+    body: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetMergeRequestsParams {
+    state: Option<String>,
+    author: Option<String>,
+    labels: Option<Vec<String>>,
+    source_branch: Option<String>,
+    target_branch: Option<String>,
+    limit: Option<usize>,
+    format: Option<String>,
+    milestone: Option<String>,
+    reviewer: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetMergeRequestParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetMergeRequestDiscussionsParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetMergeRequestDiffsParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetMergeRequestCommitsParams {
+    key: String,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetCommitDiffParams {
+    sha: String,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateMergeRequestCommentParams {
+    key: String,
+    body: String,
+    file_path: Option<String>,
+    line: Option<u32>,
+    line_type: Option<String>,
+    commit_sha: Option<String>,
+    discussion_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetPipelineParams {
+    id: String,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetJobLogParams {
+    job_id: String,
+    provider: Option<String>,
+    full: Option<bool>,
+    context_lines: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetFileParams {
+    path: String,
+    #[serde(rename = "ref")]
+    ref_name: Option<String>,
+    start_line: Option<usize>,
+    end_line: Option<usize>,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ListTreeParams {
+    path: Option<String>,
+    #[serde(rename = "ref")]
+    ref_name: Option<String>,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PipelineActionParams {
+    id: String,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RetryJobParams {
+    job_id: String,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RunPipelineParams {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    variables: Option<HashMap<String, String>>,
+    workflow: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetCustomFieldsParams {
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetMilestonesParams {
+    state: Option<String>,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetContainersParams {
+    parent_kind: Option<String>,
+    parent_id: Option<String>,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetLabelsAndStatusesParams {
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LinkIssuesParams {
+    key: String,
+    target: String,
+    kind: String,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UnlinkIssuesParams {
+    key: String,
+    target: String,
+    kind: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetTransitionsParams {
+    key: String,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TransitionIssueParams {
+    key: String,
+    transition: String,
+    #[serde(default)]
+    fields: serde_json::Map<String, Value>,
+    comment: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AddChecklistItemParams {
+    key: String,
+    item: String,
+    checklist: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SetChecklistItemParams {
+    key: String,
+    item: String,
+    checked: Option<bool>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DownloadAttachmentParams {
+    url: String,
+    max_bytes: Option<usize>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UploadAttachmentParams {
+    key: String,
+    file_path: Option<String>,
+    file_name: Option<String>,
+    content: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LogTimeParams {
+    key: String,
+    duration: String,
+    comment: Option<String>,
+    started: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetTimeEntriesParams {
+    key: String,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SetTimeEstimateParams {
+    key: String,
+    estimate: String,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct GetCurrentUserParams {
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SearchUsersParams {
+    query: String,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AgileListParams {
+    board_id: Option<String>,
+    state: Option<String>,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GetSprintIssuesParams {
+    sprint_id: String,
+    provider: Option<String>,
+    format: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MoveIssuesToSprintParams {
+    sprint_id: String,
+    keys: Vec<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MoveIssuesToBacklogParams {
+    keys: Vec<String>,
+    provider: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CreateBranchForIssueParams {
+    key: String,
+    pattern: Option<String>,
+    base: Option<String>,
+    #[serde(alias = "provider")]
+    repository_provider: Option<String>,
+    #[serde(default)]
+    checkout: bool,
+}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use devboy_core::keys::KeyPattern;
+    use devboy_core::{
+        AgileProvider, Attachment, Board, Branch, ChecklistItem, CiJob, CiPipeline, Comment,
+        Commit, Container, CustomField, Discussion, FileDiff, Issue, IssueStatus, IssueTransition,
+        Label, MergeRequest, Milestone, PipelineProvider, RepositoryFile, RepositoryProvider,
+        Sprint, TimeEntry, TimeTrackingProvider, TreeEntry, User,
+    };
+
+    struct MockProvider {
+        issues: Vec<Issue>,
+        mrs: Vec<MergeRequest>,
+    }
+
+    impl MockProvider {
+        fn new() -> Self {
+            Self {
+                issues: vec![Issue {
+                    key: "gh#1".to_string(),
+                    title: "Test Issue".to_string(),
+                    description: Some("Test description".to_string()),
+                    state: "open".to_string(),
+                    source: "github".to_string(),
+                    priority: None,
+                    labels: vec!["bug".to_string()],
+                    author: None,
+                    assignees: vec![],
+                    url: Some("https://github.com/test/repo/issues/1".to_string()),
+                    created_at: Some("2024-01-01T00:00:00Z".to_string()),
+                    updated_at: Some("2024-01-02T00:00:00Z".to_string()),
+                    custom_fields: Default::default(),
+                    children: vec![],
+                    issue_type: None,
+                    parent: None,
+                    links: vec![],
+                    attachments: vec![],
+                    estimate_seconds: None,
+                    time_spent_seconds: None,
+                    milestone: None,
+                    checklists: vec![],
+                }],
+                mrs: vec![MergeRequest {
+                    key: "pr#1".to_string(),
+                    title: "Test PR".to_string(),
+                    description: Some("Test PR description".to_string()),
+                    state: "open".to_string(),
+                    source: "github".to_string(),
+                    source_branch: "feature".to_string(),
+                    target_branch: "main".to_string(),
+                    author: None,
+                    assignees: vec![],
+                    reviewers: vec![],
+                    labels: vec![],
+                    url: Some("https://github.com/test/repo/pull/1".to_string()),
+                    created_at: Some("2024-01-01T00:00:00Z".to_string()),
+                    updated_at: Some("2024-01-02T00:00:00Z".to_string()),
+                    draft: false,
+                    pipelines: vec![],
+                    milestone: None,
+                }],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl IssueProvider for MockProvider {
+        async fn get_issues(&self, _filter: IssueFilter) -> devboy_core::Result<Vec<Issue>> {
+            Ok(self.issues.clone())
+        }
+
+        async fn get_issue(&self, _key: &str) -> devboy_core::Result<Issue> {
+            Ok(self.issues[0].clone())
+        }
+
+        async fn create_issue(&self, _input: CreateIssueInput) -> devboy_core::Result<Issue> {
+            Ok(self.issues[0].clone())
+        }
+
+        async fn update_issue(
+            &self,
+            key: &str,
+            _input: UpdateIssueInput,
+        ) -> devboy_core::Result<Issue> {
+            self.issues
+                .iter()
+                .find(|i| i.key == key)
+                .cloned()
+                .ok_or_else(|| devboy_core::Error::NotFound(key.to_string()))
+        }
+
+        async fn get_comments(&self, _issue_key: &str) -> devboy_core::Result<Vec<Comment>> {
+            Ok(vec![Comment {
+                id: "1".to_string(),
+                body: "Test comment".to_string(),
+                author: None,
+                created_at: None,
+                updated_at: None,
+                position: None,
+                attachments: vec![],
+            }])
+        }
+
+        async fn add_comment(&self, _issue_key: &str, _body: &str) -> devboy_core::Result<Comment> {
+            Ok(Comment {
+                id: "1".to_string(),
+                body: "test".to_string(),
+                author: None,
+                created_at: None,
+                updated_at: None,
+                position: None,
+                attachments: vec![],
+            })
+        }
+
+        async fn get_custom_fields(&self) -> devboy_core::Result<Vec<CustomField>> {
+            Ok(vec![CustomField {
+                id: "cf_1".to_string(),
+                name: "Story Points".to_string(),
+                field_type: "number".to_string(),
+                ..Default::default()
+            }])
+        }
+
+        async fn get_milestones(&self, state: Option<&str>) -> devboy_core::Result<Vec<Milestone>> {
+            if state == Some("closed") {
+                return Ok(vec![]);
+            }
+            Ok(vec![Milestone {
+                id: "3".to_string(),
+                title: "v2.4".to_string(),
+                kind: "milestone".to_string(),
+                state: "open".to_string(),
+                open_issues: Some(1),
+                closed_issues: Some(3),
+                ..Default::default()
+            }])
+        }
+
+        async fn add_checklist_item(
+            &self,
+            _issue_key: &str,
+            checklist: Option<&str>,
+            item: &str,
+        ) -> devboy_core::Result<Checklist> {
+            Ok(Checklist {
+                id: "cl1".to_string(),
+                name: checklist.unwrap_or("Checklist").to_string(),
+                items: vec![ChecklistItem {
+                    id: "it1".to_string(),
+                    name: item.to_string(),
+                    ..Default::default()
+                }],
+            })
+        }
+
+        async fn set_checklist_item(
+            &self,
+            _issue_key: &str,
+            item: &str,
+            resolved: bool,
+        ) -> devboy_core::Result<Checklist> {
+            Ok(Checklist {
+                id: "cl1".to_string(),
+                name: "Checklist".to_string(),
+                items: vec![
+                    ChecklistItem {
+                        id: "it1".to_string(),
+                        name: item.to_string(),
+                        resolved,
+                        ..Default::default()
+                    },
+                    ChecklistItem {
+                        id: "it2".to_string(),
+                        name: "Docs updated".to_string(),
+                        ..Default::default()
+                    },
+                ],
+            })
+        }
+
+        async fn list_labels(&self) -> devboy_core::Result<Vec<Label>> {
+            Ok(["bug", "feature"]
+                .into_iter()
+                .map(|name| Label {
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+                .collect())
+        }
+
+        async fn list_statuses(&self) -> devboy_core::Result<Vec<IssueStatus>> {
+            Ok([
+                ("open", "todo"),
+                ("In Review", "in_progress"),
+                ("closed", "done"),
+            ]
+            .into_iter()
+            .map(|(name, category)| IssueStatus {
+                name: name.to_string(),
+                category: category.to_string(),
+            })
+            .collect())
+        }
+
+        async fn get_containers(
+            &self,
+            parent: Option<(&str, &str)>,
+        ) -> devboy_core::Result<Vec<Container>> {
+            Ok(vec![Container {
+                id: "901".to_string(),
+                name: "Backlog".to_string(),
+                kind: "list".to_string(),
+                parent_id: parent.map(|(_, id)| id.to_string()),
+                ..Default::default()
+            }])
+        }
+
+        async fn get_transitions(
+            &self,
+            issue_key: &str,
+        ) -> devboy_core::Result<Vec<IssueTransition>> {
+            if !self.issues.iter().any(|i| i.key == issue_key) {
+                return Err(devboy_core::Error::NotFound(issue_key.to_string()));
+            }
+            Ok(vec![IssueTransition {
+                id: "31".to_string(),
+                name: "Done".to_string(),
+                to_status: "closed".to_string(),
+                to_category: "done".to_string(),
+                fields: vec![CustomField {
+                    id: "resolution".to_string(),
+                    name: "Resolution".to_string(),
+                    field_type: "option".to_string(),
+                    options: vec!["Fixed".to_string()],
+                    required: true,
+                }],
+            }])
+        }
+
+        async fn transition_issue(
+            &self,
+            issue_key: &str,
+            input: TransitionIssueInput,
+        ) -> devboy_core::Result<()> {
+            if !self.issues.iter().any(|i| i.key == issue_key) {
+                return Err(devboy_core::Error::NotFound(issue_key.to_string()));
+            }
+            if !input.fields.contains_key("Resolution") {
+                return Err(devboy_core::Error::InvalidData(
+                    "Transition 'Done' requires Resolution".to_string(),
+                ));
+            }
+            Ok(())
+        }
+
+        async fn link_issues(
+            &self,
+            key: &str,
+            _target_key: &str,
+            _kind: &str,
+        ) -> devboy_core::Result<()> {
+            if self.issues.iter().any(|i| i.key == key) {
+                Ok(())
+            } else {
+                Err(devboy_core::Error::NotFound(key.to_string()))
+            }
+        }
+
+        async fn download_attachment(&self, url: &str) -> devboy_core::Result<Vec<u8>> {
+            match url.rsplit('/').next() {
+                Some("notes.txt") => Ok("line 1\nline 2\nline 3".as_bytes().to_vec()),
+                Some("screen.png") => Ok(vec![0x89, b'P', b'N', b'G', 0, 0]),
+                Some("report.pdf") => Ok(vec![b'%', b'P', b'D', b'F', 0, 1, 2, 3]),
+                _ => Err(devboy_core::Error::NotFound(url.to_string())),
+            }
+        }
+
+        async fn upload_attachment(
+            &self,
+            issue_key: &str,
+            file_name: &str,
+            content: &[u8],
+        ) -> devboy_core::Result<Attachment> {
+            if !self.issues.iter().any(|i| i.key == issue_key) {
+                return Err(devboy_core::Error::NotFound(issue_key.to_string()));
+            }
+            Ok(Attachment {
+                id: "1".to_string(),
+                name: file_name.to_string(),
+                size: Some(content.len() as u64),
+                mime_type: None,
+                url: format!("https://example.com/files/{}", file_name),
+                created_at: None,
+            })
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    #[async_trait]
+    impl MergeRequestProvider for MockProvider {
+        async fn get_merge_requests(
+            &self,
+            _filter: MrFilter,
+        ) -> devboy_core::Result<Vec<MergeRequest>> {
+            Ok(self.mrs.clone())
+        }
+
+        async fn get_merge_request(&self, _key: &str) -> devboy_core::Result<MergeRequest> {
+            Ok(self.mrs[0].clone())
+        }
+
+        async fn get_discussions(&self, _mr_key: &str) -> devboy_core::Result<Vec<Discussion>> {
+            Ok(vec![Discussion {
+                id: "1".to_string(),
+                resolved: false,
+                resolved_by: None,
+                comments: vec![Comment {
+                    id: "1".to_string(),
+                    body: "Review comment".to_string(),
+                    author: None,
+                    created_at: None,
+                    updated_at: None,
+                    position: None,
+                    attachments: vec![],
+                }],
+                position: None,
+            }])
+        }
+
+        async fn get_diffs(&self, _mr_key: &str) -> devboy_core::Result<Vec<FileDiff>> {
+            Ok(vec![FileDiff {
+                file_path: "src/main.rs".to_string(),
+                old_path: None,
+                new_file: false,
+                deleted_file: false,
+                renamed_file: false,
+                diff: "+added line\n-removed line".to_string(),
+                additions: Some(1),
+                deletions: Some(1),
+            }])
+        }
+
+        async fn get_commits(&self, _mr_key: &str) -> devboy_core::Result<Vec<Commit>> {
+            Ok(vec![Commit {
+                sha: "abc123def456".to_string(),
+                title: "Add feature".to_string(),
+                message: "Add feature\n\nWith details".to_string(),
+                ..Default::default()
+            }])
+        }
+
+        async fn get_commit_diff(&self, _sha: &str) -> devboy_core::Result<Vec<FileDiff>> {
+            Ok(vec![FileDiff {
+                file_path: "src/feature.rs".to_string(),
+                new_file: true,
+                diff: "+pub fn feature() {}".to_string(),
+                ..Default::default()
+            }])
+        }
+
+        async fn add_comment(
+            &self,
+            _mr_key: &str,
+            _input: CreateCommentInput,
+        ) -> devboy_core::Result<Comment> {
+            Ok(Comment {
+                id: "1".to_string(),
+                body: "test".to_string(),
+                author: None,
+                created_at: None,
+                updated_at: None,
+                position: None,
+                attachments: vec![],
+            })
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    #[async_trait]
+    impl PipelineProvider for MockProvider {
+        async fn get_pipelines(&self, _mr_key: &str) -> devboy_core::Result<Vec<CiPipeline>> {
+            Ok(vec![sample_pipeline()])
+        }
+
+        async fn get_pipeline(&self, _pipeline_id: &str) -> devboy_core::Result<CiPipeline> {
+            Ok(sample_pipeline())
+        }
+
+        async fn get_job_log(&self, _job_id: &str) -> devboy_core::Result<String> {
+            let mut lines: Vec<String> = (1..=100).map(|i| format!("step {}", i)).collect();
+            lines[40] = "\x1b[31merror: test failed\x1b[0m".to_string();
+            Ok(lines.join("\n"))
+        }
+
+        async fn retry_pipeline(&self, _pipeline_id: &str) -> devboy_core::Result<CiPipeline> {
+            Ok(CiPipeline {
+                status: "running".to_string(),
+                ..sample_pipeline()
+            })
+        }
+
+        async fn cancel_pipeline(&self, _pipeline_id: &str) -> devboy_core::Result<CiPipeline> {
+            Ok(CiPipeline {
+                status: "canceled".to_string(),
+                ..sample_pipeline()
+            })
+        }
+
+        async fn run_pipeline(&self, input: RunPipelineInput) -> devboy_core::Result<CiPipeline> {
+            Ok(CiPipeline {
+                id: "202".to_string(),
+                status: "pending".to_string(),
+                ref_name: Some(input.ref_name),
+                ..sample_pipeline()
+            })
+        }
+
+        fn has_ci(&self) -> bool {
+            true
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    #[async_trait]
+    impl RepositoryProvider for MockProvider {
+        async fn get_file(
+            &self,
+            path: &str,
+            ref_name: Option<&str>,
+        ) -> devboy_core::Result<RepositoryFile> {
+            Ok(RepositoryFile {
+                path: path.to_string(),
+                ref_name: ref_name.map(String::from),
+                content: "fn main() {\n    println!(\"hi\");\n}".to_string(),
+                size: 34,
+                ..Default::default()
+            })
+        }
+
+        async fn list_tree(
+            &self,
+            path: &str,
+            _ref_name: Option<&str>,
+        ) -> devboy_core::Result<Vec<TreeEntry>> {
+            Ok(vec![TreeEntry {
+                path: format!("{}/main.rs", path),
+                name: "main.rs".to_string(),
+                kind: "file".to_string(),
+                size: Some(34),
+            }])
+        }
+
+        async fn create_branch(
+            &self,
+            name: &str,
+            from_ref: Option<&str>,
+        ) -> devboy_core::Result<Branch> {
+            Ok(Branch {
+                name: name.to_string(),
+                sha: from_ref.map(|_| "abc123".to_string()),
+                url: Some(format!("https://github.com/test/repo/tree/{}", name)),
+            })
+        }
+
+        fn has_repository(&self) -> bool {
+            true
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    #[async_trait]
+    impl AgileProvider for MockProvider {
+        async fn get_boards(&self) -> devboy_core::Result<Vec<Board>> {
+            Ok(vec![
+                Board {
+                    id: "3".to_string(),
+                    name: "Kanban".to_string(),
+                    kind: "kanban".to_string(),
+                    project_key: Some("PROJ".to_string()),
+                },
+                Board {
+                    id: "7".to_string(),
+                    name: "Scrum".to_string(),
+                    kind: "scrum".to_string(),
+                    project_key: Some("PROJ".to_string()),
+                },
+            ])
+        }
+
+        async fn get_sprints(
+            &self,
+            board_id: &str,
+            state: Option<&str>,
+        ) -> devboy_core::Result<Vec<Sprint>> {
+            let sprints = vec![
+                Sprint {
+                    id: "37".to_string(),
+                    name: format!("Sprint 12 (board {})", board_id),
+                    state: "active".to_string(),
+                    ..Default::default()
+                },
+                Sprint {
+                    id: "38".to_string(),
+                    name: "Sprint 13".to_string(),
+                    state: "future".to_string(),
+                    ..Default::default()
+                },
+            ];
+            Ok(sprints
+                .into_iter()
+                .filter(|s| state.is_none_or(|st| st.split(',').any(|x| x == s.state)))
+                .collect())
+        }
+
+        async fn get_sprint_issues(&self, _sprint_id: &str) -> devboy_core::Result<Vec<Issue>> {
+            Ok(self.issues.clone())
+        }
+
+        async fn move_issues_to_sprint(
+            &self,
+            _sprint_id: &str,
+            _issue_keys: &[String],
+        ) -> devboy_core::Result<()> {
+            Ok(())
+        }
+
+        async fn move_issues_to_backlog(&self, _issue_keys: &[String]) -> devboy_core::Result<()> {
+            Ok(())
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    #[async_trait]
+    impl TimeTrackingProvider for MockProvider {
+        async fn log_time(
+            &self,
+            _issue_key: &str,
+            _duration_seconds: u64,
+            _comment: Option<&str>,
+            _started_at: Option<&str>,
+        ) -> devboy_core::Result<()> {
+            Ok(())
+        }
+
+        async fn get_time_entries(&self, _issue_key: &str) -> devboy_core::Result<Vec<TimeEntry>> {
+            Ok(vec![TimeEntry {
+                id: "10100".to_string(),
+                author: Some(User {
+                    username: "alice".to_string(),
+                    ..Default::default()
+                }),
+                duration_seconds: 5400,
+                started_at: Some("2024-01-15T10:00:00Z".to_string()),
+                comment: Some("Code review".to_string()),
+            }])
+        }
+
+        async fn set_time_estimate(
+            &self,
+            _issue_key: &str,
+            _estimate_seconds: u64,
+        ) -> devboy_core::Result<()> {
+            Ok(())
+        }
+
+        fn provider_name(&self) -> &'static str {
+            "mock"
+        }
+    }
+
+    fn sample_pipeline() -> CiPipeline {
+        CiPipeline {
+            id: "101".to_string(),
+            name: None,
+            status: "failed".to_string(),
+            ref_name: Some("feature".to_string()),
+            sha: Some("abc123".to_string()),
+            duration: Some(120),
+            url: None,
+            jobs: vec![CiJob {
+                id: "12".to_string(),
+                name: "test".to_string(),
+                stage: Some("test".to_string()),
+                status: "failed".to_string(),
+                allow_failure: false,
+                duration: Some(60),
+                url: None,
+            }],
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[async_trait]
+    impl Provider for MockProvider {
+        fn key_patterns(&self) -> Vec<KeyPattern> {
+            vec![
+                KeyPattern::Prefix("gh#".to_string()),
+                KeyPattern::Prefix("pr#".to_string()),
+                KeyPattern::Url("https://github.com/test/repo/".to_string()),
+            ]
+        }
+
+        async fn get_current_user(&self) -> devboy_core::Result<User> {
+            Ok(User {
+                id: "1".to_string(),
+                username: "test".to_string(),
+                name: Some("Test User".to_string()),
+                email: None,
+                avatar_url: None,
+            })
+        }
+
+        async fn search_users(&self, query: &str) -> devboy_core::Result<Vec<User>> {
+            let users = vec![User {
+                id: "7".to_string(),
+                username: "jdoe".to_string(),
+                name: Some("John Doe".to_string()),
+                ..Default::default()
+            }];
+            Ok(devboy_core::members::search_members(&users, query))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_issues_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let result = handler.execute("get_issues", None).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("gh#1"));
+        assert!(content.contains("Test Issue"));
+    }
+
+    #[tokio::test]
+    async fn test_get_issues_query_requires_provider() {
+        let first = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let second = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![first, second]);
+
+        let args = serde_json::json!({"query": "fixVersion = 2.3"});
+        let result = handler.execute("get_issues", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+
+        let args = serde_json::json!({"query": "fixVersion = 2.3", "provider": "mock"});
+        let result = handler.execute("get_issues", Some(args)).await;
+        assert!(result.is_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("get_issue", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_merge_requests_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let result = handler.execute("get_merge_requests", None).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("pr#1"));
+        assert!(content.contains("Test PR"));
+    }
+
+    #[tokio::test]
+    async fn test_get_merge_request_discussions_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "pr#1"});
+        let result = handler
+            .execute("get_merge_request_discussions", Some(args))
+            .await;
+
+        assert!(result.is_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_merge_request_diffs_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "pr#1"});
+        let result = handler.execute("get_merge_request_diffs", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_merge_request_commits_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "pr#1"});
+        let result = handler
+            .execute("get_merge_request_commits", Some(args))
+            .await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("`abc123de` Add feature"));
+    }
+
+    #[tokio::test]
+    async fn test_get_commit_diff_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"sha": "abc123", "format": "compact"});
+        let result = handler.execute("get_commit_diff", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "[A] src/feature.rs");
+    }
+
+    #[tokio::test]
+    async fn test_get_commit_diff_unsupported_provider() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"sha": "abc123"});
+        let result = handler.execute("get_commit_diff", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Commit not found: abc123"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_tool() {
+        let handler = ToolHandler::new(vec![]);
+        let result = handler.execute("unknown_tool", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_no_providers() {
+        let handler = ToolHandler::new(vec![]);
+        let result = handler.execute("get_issues", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("No providers configured"));
+    }
+
+    #[tokio::test]
+    async fn test_tools_count() {
+        let handler = ToolHandler::new(vec![]);
+        let tools = handler.available_tools();
+
+        // 19 issue + 7 MR + 6 CI + 2 repository + 1 workflow + 6 agile + 3 time tracking
+        // + 2 user tools = 46 total
+        assert_eq!(tools.len(), 46);
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_with_provider() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({
+            "title": "New issue",
+            "provider": "mock"
+        });
+        let result = handler.execute("create_issue", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Created issue"));
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_with_unknown_provider() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({
+            "title": "New issue",
+            "provider": "jira"
+        });
+        let result = handler.execute("create_issue", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Provider 'jira' not configured"));
+        assert!(content.contains("mock"));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_comments_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("get_issue_comments", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Test comment"));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_comments_missing_params() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+
+        let result = handler.execute("get_issue_comments", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Missing required parameter: key"));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_comments_no_providers() {
+        let handler = ToolHandler::new(vec![]);
+
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("get_issue_comments", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("No providers configured"));
+    }
 
-        let mut all_issues = Vec::new();
-        let mut errors = Vec::new();
+    #[tokio::test]
+    async fn test_update_issue_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        let providers: Vec<_> = if let Some(ref name) = params.provider {
-            match self.find_provider_by_name(name) {
-                Some(p) => vec![p],
-                None => {
-                    let available: Vec<_> = self
-                        .providers
-                        .iter()
-                        .map(|p| get_provider_name(p.as_ref()))
-                        .collect();
-                    return ToolCallResult::error(format!(
-                        "Provider '{}' not configured. Available: {}",
-                        name,
-                        available.join(", ")
-                    ));
-                }
-            }
-        } else {
-            self.providers.iter().collect()
+        let args = serde_json::json!({
+            "key": "gh#1",
+            "title": "Updated title",
+            "state": "closed"
+        });
+        let result = handler.execute("update_issue", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Updated issue"));
+    }
 
-        for provider in &providers {
-            match provider.get_issues(filter.clone()).await {
-                Ok(issues) => {
-                    tracing::debug!(
-                        "Got {} issues from {}",
-                        issues.len(),
-                        get_provider_name(provider.as_ref())
-                    );
-                    all_issues.extend(issues);
-                }
-                Err(e) => {
-                    let name = get_provider_name(provider.as_ref());
-                    tracing::warn!("Error from {}: {}", name, e);
-                    errors.push(format!("{}: {}", name, e));
-                }
-            }
-        }
+    #[tokio::test]
+    async fn test_update_issue_checks_catalogs() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        if all_issues.is_empty() && !errors.is_empty() {
-            return ToolCallResult::error(format!("Failed to get issues: {}", errors.join(", ")));
-        }
+        let args = serde_json::json!({"key": "gh#1", "state": "in review", "labels": ["Bug"]});
+        let result = handler.execute("update_issue", Some(args)).await;
+        assert!(result.is_error.is_none());
 
-        let pipeline = self.create_pipeline(&params.format);
-        match pipeline.transform_issues(all_issues) {
-            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-        }
-    }
+        let args = serde_json::json!({"key": "gh#1", "labels": ["bug", "featrue"]});
+        let result = handler.execute("update_issue", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.starts_with("Failed to update issue gh#1: mock: "));
+        assert!(content.contains("Unknown label 'featrue', did you mean 'feature'?"));
 
-    async fn handle_get_issue(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetIssueParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+        let args = serde_json::json!({"key": "gh#1", "state": "In Reveiw"});
+        let result = handler.execute("update_issue", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("did you mean 'In Review'?"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_get_labels_and_statuses_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        // Try to get from appropriate provider based on key prefix
-        for provider in &self.providers {
-            match provider.get_issue(&params.key).await {
-                Ok(issue) => {
-                    let pipeline = self.create_pipeline(&params.format);
-                    return match pipeline.transform_issues(vec![issue]) {
-                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-                    };
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        let result = handler.execute("get_labels_and_statuses", None).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "## mock\n\n**Statuses:** open [todo], In Review [in_progress], closed [done]\n**Labels:** bug, feature"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_labels_and_statuses_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        ToolCallResult::error(format!("Issue not found: {}", params.key))
+        let result = handler.execute("get_labels_and_statuses", None).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("No configured provider supports label and status catalogs"));
     }
 
-    async fn handle_get_issue_comments(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetIssueCommentsParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+    #[tokio::test]
+    async fn test_update_issue_missing_params() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+
+        let result = handler.execute("update_issue", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Missing required parameter: key"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_update_issue_no_providers() {
+        let handler = ToolHandler::new(vec![]);
 
-        for provider in &self.providers {
-            match provider.get_comments(&params.key).await {
-                Ok(comments) => {
-                    let pipeline = self.create_pipeline(&params.format);
-                    return match pipeline.transform_comments(comments) {
-                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-                    };
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("update_issue", Some(args)).await;
 
-        ToolCallResult::error(format!("Issue not found: {}", params.key))
+        assert_eq!(result.is_error, Some(true));
     }
 
-    async fn handle_create_issue(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: CreateIssueParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: title".to_string()),
+    #[tokio::test]
+    async fn test_add_issue_comment_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({
+            "key": "gh#1",
+            "body": "My comment"
+        });
+        let result = handler.execute("add_issue_comment", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Added comment"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_add_issue_comment_missing_params() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
 
-        let input = CreateIssueInput {
-            title: params.title,
-            description: params.description,
-            labels: params.labels.unwrap_or_default(),
-            assignees: params.assignees.unwrap_or_default(),
-            priority: None,
+        let result = handler.execute("add_issue_comment", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Missing required parameters: key, body"));
+    }
 
-        let provider = if let Some(ref name) = params.provider {
-            match self.find_provider_by_name(name) {
-                Some(p) => p,
-                None => {
-                    let available: Vec<_> = self
-                        .providers
-                        .iter()
-                        .map(|p| get_provider_name(p.as_ref()))
-                        .collect();
-                    return ToolCallResult::error(format!(
-                        "Provider '{}' not configured. Available: {}",
-                        name,
-                        available.join(", ")
-                    ));
-                }
-            }
-        } else {
-            &self.providers[0]
+    #[tokio::test]
+    async fn test_add_issue_comment_no_providers() {
+        let handler = ToolHandler::new(vec![]);
+
+        let args = serde_json::json!({"key": "gh#1", "body": "comment"});
+        let result = handler.execute("add_issue_comment", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_merge_request_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "pr#1"});
+        let result = handler.execute("get_merge_request", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        match provider.create_issue(input).await {
-            Ok(issue) => {
-                let msg = format!(
-                    "Created issue {} - {}\nURL: {}",
-                    issue.key,
-                    issue.title,
-                    issue.url.unwrap_or_default()
-                );
-                ToolCallResult::text(msg)
-            }
-            Err(e) => ToolCallResult::error(format!("Failed to create issue: {}", e)),
-        }
+        assert!(content.contains("pr#1"));
+        assert!(content.contains("Test PR"));
+        assert!(content.contains("**CI:** ❌ failed"));
+        assert!(content.contains("Failed jobs: `test` (job 12)"));
     }
 
-    async fn handle_update_issue(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: UpdateIssueParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
-        };
+    #[tokio::test]
+    async fn test_get_merge_request_json_includes_pipelines() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+        let args = serde_json::json!({"key": "pr#1", "format": "json"});
+        let result = handler.execute("get_merge_request", Some(args)).await;
 
-        let input = UpdateIssueInput {
-            title: params.title,
-            description: params.description,
-            state: params.state,
-            labels: params.labels,
-            assignees: params.assignees,
-            priority: None,
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("\"pipelines\""));
+    }
 
-        for provider in &self.providers {
-            match provider.update_issue(&params.key, input.clone()).await {
-                Ok(issue) => {
-                    let msg = format!("Updated issue {} - {}", issue.key, issue.title);
-                    return ToolCallResult::text(msg);
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+    #[tokio::test]
+    async fn test_get_pipeline_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"id": "101"});
+        let result = handler.execute("get_pipeline", Some(args)).await;
 
-        ToolCallResult::error(format!("Failed to update issue: {}", params.key))
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("## Pipeline #101"));
+        assert!(content.contains("(job 12)"));
     }
 
-    async fn handle_add_issue_comment(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: AddIssueCommentParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => {
-                return ToolCallResult::error("Missing required parameters: key, body".to_string())
-            }
+    #[tokio::test]
+    async fn test_get_job_log_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"job_id": "12", "context_lines": 2});
+        let result = handler.execute("get_job_log", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("step 39\nstep 40\nerror: test failed\nstep 42"));
+        assert!(!content.contains("step 10\n"));
+        assert!(content.contains("Use `full: true`"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_get_job_log_unsupported_provider() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        for provider in &self.providers {
-            match IssueProvider::add_comment(provider.as_ref(), &params.key, &params.body).await {
-                Ok(comment) => {
-                    let msg = format!("Added comment {} to issue {}", comment.id, params.key);
-                    return ToolCallResult::text(msg);
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        let args = serde_json::json!({"job_id": "12"});
+        let result = handler.execute("get_job_log", Some(args)).await;
 
-        ToolCallResult::error(format!("Failed to add comment to issue: {}", params.key))
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Job not found: 12"));
     }
 
-    // =========================================================================
-    // MERGE REQUESTS HANDLERS
-    // =========================================================================
+    #[tokio::test]
+    async fn test_retry_pipeline_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-    async fn handle_get_merge_requests(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetMergeRequestsParams = arguments
-            .map(|v| serde_json::from_value(v).unwrap_or_default())
-            .unwrap_or_default();
+        let args = serde_json::json!({"id": "101"});
+        let result = handler.execute("retry_pipeline", Some(args)).await;
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Retried pipeline #101 (status: running)"));
+        assert!(content.contains("Use get_pipeline"));
+    }
 
-        let filter = MrFilter {
-            state: params.state,
-            author: params.author,
-            labels: params.labels,
-            source_branch: params.source_branch,
-            target_branch: params.target_branch,
-            limit: Some(params.limit.unwrap_or(20) as u32),
+    #[tokio::test]
+    async fn test_cancel_pipeline_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"id": "101"});
+        let result = handler.execute("cancel_pipeline", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Canceled pipeline #101 (status: canceled)"));
+    }
 
-        let mut all_mrs = Vec::new();
-        let mut errors = Vec::new();
+    #[tokio::test]
+    async fn test_run_pipeline_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        for provider in &self.providers {
-            match provider.get_merge_requests(filter.clone()).await {
-                Ok(mrs) => {
-                    tracing::debug!(
-                        "Got {} MRs from {}",
-                        mrs.len(),
-                        get_provider_name(provider.as_ref())
-                    );
-                    all_mrs.extend(mrs);
-                }
-                Err(e) => {
-                    let name = get_provider_name(provider.as_ref());
-                    tracing::warn!("Error from {}: {}", name, e);
-                    errors.push(format!("{}: {}", name, e));
-                }
-            }
-        }
+        let args = serde_json::json!({"ref": "main", "variables": {"DEPLOY": "1"}});
+        let result = handler.execute("run_pipeline", Some(args)).await;
 
-        if all_mrs.is_empty() && !errors.is_empty() {
-            return ToolCallResult::error(format!(
-                "Failed to get merge requests: {}",
-                errors.join(", ")
-            ));
-        }
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Started pipeline #202 (status: pending)"));
+    }
 
-        let pipeline = self.create_pipeline(&params.format);
-        match pipeline.transform_merge_requests(all_mrs) {
-            Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-            Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-        }
+    #[tokio::test]
+    async fn test_retry_job_skips_unsupported_provider() {
+        let failing = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let mock = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![failing, mock]);
+
+        // Only the mock runs CI, and it does not override retry_job
+        let args = serde_json::json!({"job_id": "12"});
+        let result = handler.execute("retry_job", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(
+            content.starts_with("Failed to retry job:"),
+            "got: {}",
+            content
+        );
     }
 
-    async fn handle_get_merge_request(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetMergeRequestParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+    #[tokio::test]
+    async fn test_pipeline_actions_need_provider_with_several_ci() {
+        let handler = ToolHandler::new(vec![
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+
+        let args = serde_json::json!({"ref": "main"});
+        let result = handler.execute("run_pipeline", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert_eq!(
+            content,
+            "Several providers run CI (mock, mock); pass provider to choose one"
+        );
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+        let args = serde_json::json!({"ref": "main", "provider": "mock"});
+        let result = handler.execute("run_pipeline", Some(args)).await;
+        assert!(result.is_error.is_none());
+    }
 
-        for provider in &self.providers {
-            match provider.get_merge_request(&params.key).await {
-                Ok(mr) => {
-                    let pipeline = self.create_pipeline(&params.format);
-                    return match pipeline.transform_merge_requests(vec![mr]) {
-                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-                    };
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+    #[tokio::test]
+    async fn test_retry_pipeline_skips_unsupported_provider() {
+        let failing = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let mock = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![failing, mock]);
 
-        ToolCallResult::error(format!("Merge request not found: {}", params.key))
+        let args = serde_json::json!({"id": "101"});
+        let result = handler.execute("retry_pipeline", Some(args)).await;
+
+        assert!(result.is_error.is_none());
     }
 
-    async fn handle_get_merge_request_discussions(
-        &self,
-        arguments: Option<Value>,
-    ) -> ToolCallResult {
-        let params: GetMergeRequestDiscussionsParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+    #[tokio::test]
+    async fn test_run_pipeline_missing_params() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+
+        let result = handler.execute("run_pipeline", None).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Missing required parameter: ref"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_get_file_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        for provider in &self.providers {
-            match provider.get_discussions(&params.key).await {
-                Ok(discussions) => {
-                    let pipeline = self.create_pipeline(&params.format);
-                    return match pipeline.transform_discussions(discussions) {
-                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-                    };
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        let args = serde_json::json!({"path": "src/main.rs", "ref": "abc123", "start_line": 2});
+        let result = handler.execute("get_file", Some(args)).await;
 
-        ToolCallResult::error(format!("Merge request not found: {}", params.key))
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("src/main.rs @ `abc123`"));
+        assert!(content.contains("2 |     println!(\"hi\");"));
+        assert!(!content.contains("fn main()"));
     }
 
-    async fn handle_get_merge_request_diffs(&self, arguments: Option<Value>) -> ToolCallResult {
-        let params: GetMergeRequestDiffsParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => return ToolCallResult::error("Missing required parameter: key".to_string()),
+    #[tokio::test]
+    async fn test_get_file_start_line_out_of_range() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"path": "src/main.rs", "start_line": 10});
+        let result = handler.execute("get_file", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_file_unsupported_provider() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"path": "src/main.rs"});
+        let result = handler.execute("get_file", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("File not found: src/main.rs"));
+    }
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+    #[tokio::test]
+    async fn test_list_tree_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        for provider in &self.providers {
-            match provider.get_diffs(&params.key).await {
-                Ok(diffs) => {
-                    let pipeline = self.create_pipeline(&params.format);
-                    return match pipeline.transform_diffs(diffs) {
-                        Ok(output) => ToolCallResult::text(output.to_string_with_hints()),
-                        Err(e) => ToolCallResult::error(format!("Pipeline error: {}", e)),
-                    };
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        let args = serde_json::json!({"path": "src", "format": "compact"});
+        let result = handler.execute("list_tree", Some(args)).await;
 
-        ToolCallResult::error(format!("Merge request not found: {}", params.key))
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "src/main.rs");
     }
 
-    async fn handle_create_merge_request_comment(
-        &self,
-        arguments: Option<Value>,
-    ) -> ToolCallResult {
-        let params: CreateMergeRequestCommentParams = match arguments {
-            Some(v) => match serde_json::from_value(v) {
-                Ok(p) => p,
-                Err(e) => return ToolCallResult::error(format!("Invalid parameters: {}", e)),
-            },
-            None => {
-                return ToolCallResult::error("Missing required parameters: key, body".to_string())
-            }
-        };
+    #[tokio::test]
+    async fn test_create_branch_for_issue_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        if self.providers.is_empty() {
-            return ToolCallResult::error("No providers configured".to_string());
-        }
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("create_branch_for_issue", Some(args)).await;
 
-        // Build position if file_path is provided
-        let position = params.file_path.map(|file_path| CodePosition {
-            file_path,
-            line: params.line.unwrap_or(1),
-            line_type: params.line_type.unwrap_or_else(|| "new".to_string()),
-            commit_sha: params.commit_sha,
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Created branch `1-test-issue` for gh#1"));
+        assert!(content.contains("Linked the branch in an issue comment."));
+    }
+
+    #[tokio::test]
+    async fn test_create_branch_for_issue_configured_pattern() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]).with_workflow_config(WorkflowConfig {
+            branch_pattern: Some("feature/{id}".to_string()),
         });
 
-        let input = CreateCommentInput {
-            body: params.body,
-            position,
-            discussion_id: params.discussion_id,
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("create_branch_for_issue", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("`feature/1`"));
 
-        for provider in &self.providers {
-            match MergeRequestProvider::add_comment(provider.as_ref(), &params.key, input.clone())
-                .await
-            {
-                Ok(comment) => {
-                    let msg = format!("Added comment {} to {}", comment.id, params.key);
-                    return ToolCallResult::text(msg);
-                }
-                Err(e) => {
-                    tracing::debug!(
-                        "Provider {} failed for key {}: {}",
-                        get_provider_name(provider.as_ref()),
-                        params.key,
-                        e
-                    );
-                }
-            }
-        }
+        // An explicit pattern wins over the configured one
+        let args = serde_json::json!({"key": "gh#1", "pattern": "{key}"});
+        let result = handler.execute("create_branch_for_issue", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("`gh-1`"));
+    }
+
+    #[tokio::test]
+    async fn test_create_branch_for_issue_invalid_pattern() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "gh#1", "pattern": "{id}-{title}"});
+        let result = handler.execute("create_branch_for_issue", Some(args)).await;
 
-        ToolCallResult::error(format!(
-            "Failed to add comment to merge request: {}",
-            params.key
-        ))
+        assert_eq!(result.is_error, Some(true));
     }
 
-    // =========================================================================
-    // HELPER METHODS
-    // =========================================================================
+    #[tokio::test]
+    async fn test_create_branch_for_issue_no_repository_provider() {
+        let issues = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let failing = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![issues, failing]);
 
-    fn find_provider_by_name(&self, name: &str) -> Option<&Arc<dyn Provider>> {
-        self.providers
-            .iter()
-            .find(|p| get_provider_name(p.as_ref()) == name)
+        let args = serde_json::json!({"key": "gh#1", "provider": "failing"});
+        let result = handler.execute("create_branch_for_issue", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("No configured provider supports repositories"));
     }
 
-    fn create_pipeline(&self, format: &Option<String>) -> Pipeline {
-        let output_format = match format.as_deref() {
-            Some("json") => OutputFormat::Json,
-            Some("compact") => OutputFormat::Compact,
-            _ => OutputFormat::Markdown,
+    #[test]
+    fn test_repository_provider_needs_choice_with_several() {
+        let issues = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![
+            issues.clone(),
+            Arc::new(MockProvider::new()),
+            Arc::new(MockProvider::new()),
+        ]);
+
+        let Err(result) = handler.repository_provider(None, &issues) else {
+            panic!("expected an ambiguous repository provider");
+        };
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
+        assert!(content.contains("Several providers host repositories (mock, mock)"));
+
+        let chosen = handler
+            .repository_provider(Some("mock"), &issues)
+            .ok()
+            .unwrap();
+        assert!(chosen.has_repository());
+
+        // The issue's own provider wins when it hosts code
+        let mock = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let chosen = handler.repository_provider(None, &mock).ok().unwrap();
+        assert!(Arc::ptr_eq(chosen, &mock));
+    }
 
-        Pipeline::with_config(PipelineConfig {
-            format: output_format,
-            ..self.pipeline_config.clone()
-        })
+    #[tokio::test]
+    async fn test_get_custom_fields_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"format": "compact"});
+        let result = handler.execute("get_custom_fields", Some(args)).await;
+
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "cf_1 Story Points [number]");
     }
-}
 
-// =============================================================================
-// PARAMETER TYPES
-// =============================================================================
+    #[tokio::test]
+    async fn test_get_custom_fields_from_several_providers() {
+        let failing = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![failing.clone(), Arc::new(MockProvider::new())]);
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-struct GetIssuesParams {
-    state: Option<String>,
-    search: Option<String>,
-    labels: Option<Vec<String>>,
-    assignee: Option<String>,
-    limit: Option<usize>,
-    offset: Option<usize>,
-    format: Option<String>,
-    provider: Option<String>,
-    sort_by: Option<String>,
-    sort_order: Option<String>,
-}
+        // A failing provider does not hide the fields of the others
+        let args = serde_json::json!({"format": "compact"});
+        let result = handler.execute("get_custom_fields", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "cf_1 Story Points [number]");
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GetIssueParams {
-    key: String,
-    format: Option<String>,
-}
+        let handler = ToolHandler::new(vec![
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()),
+        ]);
+        let args = serde_json::json!({"format": "compact"});
+        let result = handler.execute("get_custom_fields", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "## mock\n\ncf_1 Story Points [number]\n\n## mock\n\ncf_1 Story Points [number]"
+        );
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GetIssueCommentsParams {
-    key: String,
-    format: Option<String>,
-}
+        let handler = ToolHandler::new(vec![failing]);
+        let result = handler.execute("get_custom_fields", None).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Failed to get custom fields: failing: "));
+    }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct CreateIssueParams {
-    title: String,
-    description: Option<String>,
-    labels: Option<Vec<String>>,
-    assignees: Option<Vec<String>>,
-    provider: Option<String>,
-}
+    #[tokio::test]
+    async fn test_get_milestones_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-#[derive(Debug, Serialize, Deserialize)]
-struct UpdateIssueParams {
-    key: String,
-    title: Option<String>,
-    description: Option<String>,
-    state: Option<String>,
-    labels: Option<Vec<String>>,
-    assignees: Option<Vec<String>>,
-}
+        let result = handler.execute("get_milestones", None).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("- **v2.4** (milestone, open) · 3/4 done (75%), 1 open"));
 
-#[derive(Debug, Serialize, Deserialize)]
-struct AddIssueCommentParams {
-    key: String,
-    body: String,
-}
+        let args = serde_json::json!({"state": "closed", "format": "compact"});
+        let result = handler.execute("get_milestones", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "No milestones found.");
+    }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
-struct GetMergeRequestsParams {
-    state: Option<String>,
-    author: Option<String>,
-    labels: Option<Vec<String>>,
-    source_branch: Option<String>,
-    target_branch: Option<String>,
-    limit: Option<usize>,
-    format: Option<String>,
-}
+    #[tokio::test]
+    async fn test_get_milestones_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GetMergeRequestParams {
-    key: String,
-    format: Option<String>,
-}
+        let result = handler.execute("get_milestones", None).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("No configured provider supports milestones"));
+    }
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GetMergeRequestDiscussionsParams {
-    key: String,
-    format: Option<String>,
-}
+    #[tokio::test]
+    async fn test_get_current_user_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let failing = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider, failing]);
 
-#[derive(Debug, Serialize, Deserialize)]
-struct GetMergeRequestDiffsParams {
-    key: String,
-    format: Option<String>,
-}
+        let result = handler.execute("get_current_user", None).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("- **mock**: test (Test User, id 1)"));
+        assert!(content.contains("error: "));
 
-#[derive(Debug, Serialize, Deserialize)]
-struct CreateMergeRequestCommentParams {
-    key: String,
-    body: String,
-    file_path: Option<String>,
-    line: Option<u32>,
-    line_type: Option<String>,
-    commit_sha: Option<String>,
-    discussion_id: Option<String>,
-}
+        let handler = ToolHandler::new(vec![Arc::new(FailingProvider) as Arc<dyn Provider>]);
+        let result = handler.execute("get_current_user", None).await;
+        assert_eq!(result.is_error, Some(true));
+    }
 
-// =============================================================================
-// TESTS
-// =============================================================================
+    #[tokio::test]
+    async fn test_search_users_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use async_trait::async_trait;
-    use devboy_core::{Comment, Discussion, FileDiff, Issue, MergeRequest, User};
+        let args = serde_json::json!({"query": "john", "format": "compact"});
+        let result = handler.execute("search_users", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "7 jdoe");
 
-    struct MockProvider {
-        issues: Vec<Issue>,
-        mrs: Vec<MergeRequest>,
+        let result = handler.execute("search_users", None).await;
+        assert_eq!(result.is_error, Some(true));
     }
 
-    impl MockProvider {
-        fn new() -> Self {
-            Self {
-                issues: vec![Issue {
-                    key: "gh#1".to_string(),
-                    title: "Test Issue".to_string(),
-                    description: Some("Test description".to_string()),
-                    state: "open".to_string(),
-                    source: "github".to_string(),
-                    priority: None,
-                    labels: vec!["bug".to_string()],
-                    author: None,
-                    assignees: vec![],
-                    url: Some("https://github.com/test/repo/issues/1".to_string()),
-                    created_at: Some("2024-01-01T00:00:00Z".to_string()),
-                    updated_at: Some("2024-01-02T00:00:00Z".to_string()),
-                }],
-                mrs: vec![MergeRequest {
-                    key: "pr#1".to_string(),
-                    title: "Test PR".to_string(),
-                    description: Some("Test PR description".to_string()),
-                    state: "open".to_string(),
-                    source: "github".to_string(),
-                    source_branch: "feature".to_string(),
-                    target_branch: "main".to_string(),
-                    author: None,
-                    assignees: vec![],
-                    reviewers: vec![],
-                    labels: vec![],
-                    url: Some("https://github.com/test/repo/pull/1".to_string()),
-                    created_at: Some("2024-01-01T00:00:00Z".to_string()),
-                    updated_at: Some("2024-01-02T00:00:00Z".to_string()),
-                    draft: false,
-                }],
-            }
-        }
+    #[tokio::test]
+    async fn test_search_users_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"query": "john"});
+        let result = handler.execute("search_users", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("No configured provider supports user search"));
     }
 
-    #[async_trait]
-    impl IssueProvider for MockProvider {
-        async fn get_issues(&self, _filter: IssueFilter) -> devboy_core::Result<Vec<Issue>> {
-            Ok(self.issues.clone())
-        }
+    #[tokio::test]
+    async fn test_add_checklist_item_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        async fn get_issue(&self, _key: &str) -> devboy_core::Result<Issue> {
-            Ok(self.issues[0].clone())
-        }
+        let args = serde_json::json!({"key": "gh#1", "item": "API returns 201", "checklist": "Acceptance"});
+        let result = handler.execute("add_checklist_item", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "Added 'API returns 201' to checklist 'Acceptance' of gh#1 (0/1 done)"
+        );
+    }
 
-        async fn create_issue(&self, _input: CreateIssueInput) -> devboy_core::Result<Issue> {
-            Ok(self.issues[0].clone())
-        }
+    #[tokio::test]
+    async fn test_set_checklist_item_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        async fn update_issue(
-            &self,
-            _key: &str,
-            _input: UpdateIssueInput,
-        ) -> devboy_core::Result<Issue> {
-            Ok(self.issues[0].clone())
-        }
+        let args = serde_json::json!({"key": "gh#1", "item": "API returns 201"});
+        let result = handler.execute("set_checklist_item", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "Checked 'API returns 201' in checklist 'Checklist' of gh#1 (1/2 done)"
+        );
 
-        async fn get_comments(&self, _issue_key: &str) -> devboy_core::Result<Vec<Comment>> {
-            Ok(vec![Comment {
-                id: "1".to_string(),
-                body: "Test comment".to_string(),
-                author: None,
-                created_at: None,
-                updated_at: None,
-                position: None,
-            }])
-        }
+        let args = serde_json::json!({"key": "gh#1", "item": "API returns 201", "checked": false});
+        let result = handler.execute("set_checklist_item", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.starts_with("Unchecked 'API returns 201'"));
+        assert!(content.ends_with("(0/2 done)"));
+    }
 
-        async fn add_comment(&self, _issue_key: &str, _body: &str) -> devboy_core::Result<Comment> {
-            Ok(Comment {
-                id: "1".to_string(),
-                body: "test".to_string(),
-                author: None,
-                created_at: None,
-                updated_at: None,
-                position: None,
-            })
-        }
+    #[tokio::test]
+    async fn test_checklist_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        fn provider_name(&self) -> &'static str {
-            "mock"
-        }
+        let args = serde_json::json!({"key": "CU-1", "item": "x"});
+        let result = handler.execute("set_checklist_item", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(
+            content.contains("does not support checklists"),
+            "got: {}",
+            content
+        );
     }
 
-    #[async_trait]
-    impl MergeRequestProvider for MockProvider {
-        async fn get_merge_requests(
-            &self,
-            _filter: MrFilter,
-        ) -> devboy_core::Result<Vec<MergeRequest>> {
-            Ok(self.mrs.clone())
-        }
+    #[tokio::test]
+    async fn test_get_containers_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        async fn get_merge_request(&self, _key: &str) -> devboy_core::Result<MergeRequest> {
-            Ok(self.mrs[0].clone())
-        }
+        let args =
+            serde_json::json!({"parent_kind": "folder", "parent_id": "55", "format": "compact"});
+        let result = handler.execute("get_containers", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "901 Backlog [list]");
 
-        async fn get_discussions(&self, _mr_key: &str) -> devboy_core::Result<Vec<Discussion>> {
-            Ok(vec![Discussion {
-                id: "1".to_string(),
-                resolved: false,
-                resolved_by: None,
-                comments: vec![Comment {
-                    id: "1".to_string(),
-                    body: "Review comment".to_string(),
-                    author: None,
-                    created_at: None,
-                    updated_at: None,
-                    position: None,
-                }],
-                position: None,
-            }])
-        }
+        let args = serde_json::json!({"parent_id": "55"});
+        let result = handler.execute("get_containers", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("must be given together"));
+    }
 
-        async fn get_diffs(&self, _mr_key: &str) -> devboy_core::Result<Vec<FileDiff>> {
-            Ok(vec![FileDiff {
-                file_path: "src/main.rs".to_string(),
-                old_path: None,
-                new_file: false,
-                deleted_file: false,
-                renamed_file: false,
-                diff: "+added line\n-removed line".to_string(),
-                additions: Some(1),
-                deletions: Some(1),
-            }])
-        }
+    #[tokio::test]
+    async fn test_link_issues_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        async fn add_comment(
-            &self,
-            _mr_key: &str,
-            _input: CreateCommentInput,
-        ) -> devboy_core::Result<Comment> {
-            Ok(Comment {
-                id: "1".to_string(),
-                body: "test".to_string(),
-                author: None,
-                created_at: None,
-                updated_at: None,
-                position: None,
-            })
-        }
+        let args = serde_json::json!({"key": "gh#1", "target": "gh#2", "kind": "blocked_by"});
+        let result = handler.execute("link_issues", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "Linked gh#1 blocked by gh#2");
 
-        fn provider_name(&self) -> &'static str {
-            "mock"
-        }
+        let args = serde_json::json!({"key": "gh#9", "target": "gh#2", "kind": "blocks"});
+        let result = handler.execute("link_issues", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+
+        // MockProvider does not implement unlink_issues
+        let args = serde_json::json!({"key": "gh#1", "target": "gh#2"});
+        let result = handler.execute("unlink_issues", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(
+            content.contains("does not support issue links"),
+            "got: {}",
+            content
+        );
     }
 
-    #[async_trait]
-    impl Provider for MockProvider {
-        async fn get_current_user(&self) -> devboy_core::Result<User> {
-            Ok(User {
-                id: "1".to_string(),
-                username: "test".to_string(),
-                name: Some("Test User".to_string()),
-                email: None,
-                avatar_url: None,
-            })
-        }
+    #[tokio::test]
+    async fn test_bulk_update_issues_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"keys": ["gh#1", "gh#9", " gh#1"], "state": "closed"});
+        let result = handler.execute("bulk_update_issues", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "# Bulk update: 1 of 2 issues updated\n\n- gh#1: state → closed\n- gh#9: failed: Not found: gh#9\n\
+             \nDuplicate keys updated once: gh#1\n"
+        );
+
+        let args = serde_json::json!({
+            "keys": ["gh#1"],
+            "state": "closed",
+            "labels": ["bug", "feature"],
+            "dry_run": true
+        });
+        let result = handler.execute("bulk_update_issues", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "# Dry run: 1 of 1 issues would be updated\n\n- gh#1: state: open → closed; labels: bug → bug, feature\n"
+        );
     }
 
     #[tokio::test]
-    async fn test_get_issues_handler() {
+    async fn test_bulk_update_issues_rejected() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let result = handler.execute("get_issues", None).await;
+        let args = serde_json::json!({"keys": ["gh#1"]});
+        let result = handler.execute("bulk_update_issues", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+
+        let args = serde_json::json!({"keys": ["gh#1", "gh#2"], "labels": ["featrue"]});
+        let result = handler.execute("bulk_update_issues", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("# Bulk update: 0 of 2 issues updated"));
+        assert!(content.contains(
+            "- gh#2: failed: mock: Invalid data: Unknown label 'featrue', did you mean 'feature'?"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transitions_handlers() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("get_transitions", Some(args)).await;
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("- **Done** → closed [done] (id 31)"));
+        assert!(content.contains("Resolution (option, id resolution, required)"));
+
+        let args = serde_json::json!({"key": "gh#1", "transition": "Done"});
+        let result = handler.execute("transition_issue", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Transition 'Done' requires Resolution"));
 
+        let args = serde_json::json!({
+            "key": "gh#1",
+            "transition": "Done",
+            "fields": {"Resolution": "Fixed"},
+            "comment": "Released in 1.1"
+        });
+        let result = handler.execute("transition_issue", Some(args)).await;
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("gh#1"));
-        assert!(content.contains("Test Issue"));
+        assert_eq!(content, "Applied transition 'Done' to gh#1");
     }
 
     #[tokio::test]
-    async fn test_get_issue_handler() {
-        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+    async fn test_transitions_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({"key": "gh#1"});
-        let result = handler.execute("get_issue", Some(args)).await;
-
-        assert!(result.is_error.is_none());
+        let args = serde_json::json!({"key": "jira#PROJ-1"});
+        let result = handler.execute("get_transitions", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(
+            content.contains("does not support transitions"),
+            "got: {}",
+            content
+        );
     }
 
     #[tokio::test]
-    async fn test_get_merge_requests_handler() {
+    async fn test_download_attachment_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let result = handler.execute("get_merge_requests", None).await;
-
+        let args = serde_json::json!({"url": "https://example.com/files/notes.txt"});
+        let result = handler.execute("download_attachment", Some(args)).await;
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("pr#1"));
-        assert!(content.contains("Test PR"));
-    }
+        assert_eq!(content, "line 1\nline 2\nline 3");
 
-    #[tokio::test]
-    async fn test_get_merge_request_discussions_handler() {
-        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
-        let handler = ToolHandler::new(vec![provider]);
+        let args =
+            serde_json::json!({"url": "https://example.com/files/notes.txt", "max_bytes": 6});
+        let result = handler.execute("download_attachment", Some(args)).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "line 1\n\n[Truncated: showing 6 of 20 bytes]");
+
+        let args = serde_json::json!({"url": "https://example.com/files/screen.png"});
+        let result = handler.execute("download_attachment", Some(args)).await;
+        match &result.content[0] {
+            crate::protocol::ToolResultContent::Image { data, mime_type } => {
+                assert_eq!(data, "iVBORwAA");
+                assert_eq!(mime_type, "image/png");
+            }
+            _ => panic!("expected image content"),
+        }
 
-        let args = serde_json::json!({"key": "pr#1"});
-        let result = handler
-            .execute("get_merge_request_discussions", Some(args))
-            .await;
+        let args = serde_json::json!({"url": "https://example.com/files/report.pdf"});
+        let result = handler.execute("download_attachment", Some(args)).await;
+        match &result.content[0] {
+            crate::protocol::ToolResultContent::Resource { resource } => {
+                assert_eq!(resource.uri, "https://example.com/files/report.pdf");
+                assert_eq!(resource.mime_type.as_deref(), Some("application/pdf"));
+                assert!(resource.blob.is_some());
+            }
+            _ => panic!("expected resource content"),
+        }
 
-        assert!(result.is_error.is_none());
+        let args =
+            serde_json::json!({"url": "https://example.com/files/report.pdf", "max_bytes": 4});
+        let result = handler.execute("download_attachment", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Attachment is 8 bytes, over the limit of 4 bytes"));
+
+        let args = serde_json::json!({"url": "https://example.com/files/missing.bin"});
+        let result = handler.execute("download_attachment", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
     }
 
     #[tokio::test]
-    async fn test_get_merge_request_diffs_handler() {
+    async fn test_upload_attachment_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
-        let handler = ToolHandler::new(vec![provider]);
-
-        let args = serde_json::json!({"key": "pr#1"});
-        let result = handler.execute("get_merge_request_diffs", Some(args)).await;
+        let handler = ToolHandler::new(vec![provider]).with_upload_root(std::env::temp_dir());
 
+        let args =
+            serde_json::json!({"key": "gh#1", "file_name": "notes.md", "content": "# Notes"});
+        let result = handler.execute("upload_attachment", Some(args)).await;
         assert!(result.is_error.is_none());
-    }
-
-    #[tokio::test]
-    async fn test_unknown_tool() {
-        let handler = ToolHandler::new(vec![]);
-        let result = handler.execute("unknown_tool", None).await;
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(
+            content,
+            "Attached notes.md to gh#1\nhttps://example.com/files/notes.md"
+        );
+
+        let path = std::env::temp_dir().join("devboy-upload-attachment-test.log");
+        std::fs::write(&path, "boom").unwrap();
+        let args = serde_json::json!({"key": "gh#1", "file_path": path.to_string_lossy()});
+        let result = handler.execute("upload_attachment", Some(args)).await;
+        std::fs::remove_file(&path).unwrap();
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.starts_with("Attached devboy-upload-attachment-test.log to gh#1"));
 
+        let args = serde_json::json!({"key": "gh#1", "content": "# Notes"});
+        let result = handler.execute("upload_attachment", Some(args)).await;
         assert_eq!(result.is_error, Some(true));
-    }
-
-    #[tokio::test]
-    async fn test_no_providers() {
-        let handler = ToolHandler::new(vec![]);
-        let result = handler.execute("get_issues", None).await;
 
+        let args = serde_json::json!({"key": "gh#9", "file_name": "a.txt", "content": "a"});
+        let result = handler.execute("upload_attachment", Some(args)).await;
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("No providers configured"));
+        assert!(content.contains("Failed to upload attachment"));
     }
 
     #[tokio::test]
-    async fn test_tools_count() {
-        let handler = ToolHandler::new(vec![]);
-        let tools = handler.available_tools();
+    async fn test_upload_attachment_outside_root() {
+        let root = std::env::temp_dir().join("devboy-upload-root-test");
+        std::fs::create_dir_all(&root).unwrap();
+        let secret = std::env::temp_dir().join("devboy-upload-secret-test.env");
+        std::fs::write(&secret, "TOKEN=1").unwrap();
 
-        // 6 issue tools + 5 MR tools = 11 total
-        assert_eq!(tools.len(), 11);
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]).with_upload_root(&root);
+        for path in [
+            secret.to_string_lossy().into_owned(),
+            "../devboy-upload-secret-test.env".to_string(),
+        ] {
+            let args = serde_json::json!({"key": "gh#1", "file_path": path});
+            let result = handler.execute("upload_attachment", Some(args)).await;
+            assert_eq!(result.is_error, Some(true));
+            let content = match &result.content[0] {
+                crate::protocol::ToolResultContent::Text { text } => text,
+                _ => panic!("expected text content"),
+            };
+            assert!(
+                content.starts_with("Refusing to upload"),
+                "got: {}",
+                content
+            );
+        }
+        std::fs::remove_file(&secret).unwrap();
     }
 
     #[tokio::test]
-    async fn test_create_issue_with_provider() {
+    async fn test_log_time_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({
-            "title": "New issue",
-            "provider": "mock"
-        });
-        let result = handler.execute("create_issue", Some(args)).await;
-
+        let args = serde_json::json!({"key": "gh#1", "duration": "1h30m", "comment": "Review"});
+        let result = handler.execute("log_time", Some(args)).await;
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Created issue"));
+        assert_eq!(content, "Logged 1h 30m on gh#1");
+
+        let args = serde_json::json!({"key": "gh#1", "duration": "soon"});
+        let result = handler.execute("log_time", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Invalid duration 'soon'"));
     }
 
     #[tokio::test]
-    async fn test_create_issue_with_unknown_provider() {
+    async fn test_get_time_entries_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({
-            "title": "New issue",
-            "provider": "jira"
-        });
-        let result = handler.execute("create_issue", Some(args)).await;
-
-        assert_eq!(result.is_error, Some(true));
+        let args = serde_json::json!({"key": "gh#1"});
+        let result = handler.execute("get_time_entries", Some(args)).await;
+        assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Provider 'jira' not configured"));
-        assert!(content.contains("mock"));
+        assert!(content.contains("# Time entries (1, 1h 30m total)"));
+        assert!(content.contains("by @alice"));
     }
 
     #[tokio::test]
-    async fn test_get_issue_comments_handler() {
+    async fn test_set_time_estimate_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({"key": "gh#1"});
-        let result = handler.execute("get_issue_comments", Some(args)).await;
-
+        let args = serde_json::json!({"key": "gh#1", "estimate": "1d"});
+        let result = handler.execute("set_time_estimate", Some(args)).await;
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Test comment"));
+        assert_eq!(content, "Set estimate of gh#1 to 8h");
     }
 
     #[tokio::test]
-    async fn test_get_issue_comments_missing_params() {
-        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
-
-        let result = handler.execute("get_issue_comments", None).await;
+    async fn test_log_time_unsupported() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
+        let args = serde_json::json!({"key": "gh#1", "duration": "30m"});
+        let result = handler.execute("log_time", Some(args)).await;
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Missing required parameter: key"));
+        assert!(
+            content.contains("does not support time tracking"),
+            "got: {}",
+            content
+        );
     }
 
     #[tokio::test]
-    async fn test_get_issue_comments_no_providers() {
-        let handler = ToolHandler::new(vec![]);
+    async fn test_create_issue_invalid_custom_fields() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({"key": "gh#1"});
-        let result = handler.execute("get_issue_comments", Some(args)).await;
+        let args = serde_json::json!({
+            "title": "New issue",
+            "custom_fields": ["Story Points", 5]
+        });
+        let result = handler.execute("create_issue", Some(args)).await;
 
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("No providers configured"));
+        assert!(content.contains("Invalid parameters"));
     }
 
     #[tokio::test]
-    async fn test_update_issue_handler() {
+    async fn test_get_boards_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({
-            "key": "gh#1",
-            "title": "Updated title",
-            "state": "closed"
-        });
-        let result = handler.execute("update_issue", Some(args)).await;
+        let args = serde_json::json!({"format": "compact"});
+        let result = handler.execute("get_boards", Some(args)).await;
 
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Updated issue"));
+        assert_eq!(content, "3 Kanban [kanban]\n7 Scrum [scrum]");
     }
 
     #[tokio::test]
-    async fn test_update_issue_missing_params() {
-        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+    async fn test_get_sprints_handler() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        let result = handler.execute("update_issue", None).await;
+        let args = serde_json::json!({"board_id": "9", "state": "future", "format": "compact"});
+        let result = handler.execute("get_sprints", Some(args)).await;
 
-        assert_eq!(result.is_error, Some(true));
+        assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Missing required parameter: key"));
+        assert_eq!(content, "38 Sprint 13 [future]");
     }
 
     #[tokio::test]
-    async fn test_update_issue_no_providers() {
-        let handler = ToolHandler::new(vec![]);
+    async fn test_get_current_sprint_uses_scrum_board() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({"key": "gh#1"});
-        let result = handler.execute("update_issue", Some(args)).await;
+        let result = handler.execute("get_current_sprint", None).await;
 
-        assert_eq!(result.is_error, Some(true));
+        assert!(result.is_error.is_none());
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Sprint 12 (board 7)"));
+        assert!(content.contains("Test Issue"));
     }
 
     #[tokio::test]
-    async fn test_add_issue_comment_handler() {
+    async fn test_move_issues_to_sprint_handler() {
         let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
         let handler = ToolHandler::new(vec![provider]);
 
-        let args = serde_json::json!({
-            "key": "gh#1",
-            "body": "My comment"
-        });
-        let result = handler.execute("add_issue_comment", Some(args)).await;
+        let args = serde_json::json!({"sprint_id": "38", "keys": ["gh#1", "gh#2"]});
+        let result = handler.execute("move_issues_to_sprint", Some(args)).await;
 
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Added comment"));
+        assert_eq!(content, "Moved 2 issue(s) to sprint 38: gh#1, gh#2");
     }
 
     #[tokio::test]
-    async fn test_add_issue_comment_missing_params() {
-        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+    async fn test_move_issues_to_backlog_empty_keys() {
+        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
 
-        let result = handler.execute("add_issue_comment", None).await;
+        let args = serde_json::json!({"keys": []});
+        let result = handler.execute("move_issues_to_backlog", Some(args)).await;
+
+        assert_eq!(result.is_error, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_get_current_sprint_unsupported_provider() {
+        let provider = Arc::new(FailingProvider) as Arc<dyn Provider>;
+        let handler = ToolHandler::new(vec![provider]);
+
+        let result = handler.execute("get_current_sprint", None).await;
 
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Missing required parameters: key, body"));
+        assert!(content.contains("No configured provider supports sprints"));
     }
 
     #[tokio::test]
-    async fn test_add_issue_comment_no_providers() {
-        let handler = ToolHandler::new(vec![]);
+    async fn test_get_pipeline_missing_params() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
 
-        let args = serde_json::json!({"key": "gh#1", "body": "comment"});
-        let result = handler.execute("add_issue_comment", Some(args)).await;
+        let result = handler.execute("get_pipeline", None).await;
 
         assert_eq!(result.is_error, Some(true));
+        let content = match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert!(content.contains("Missing required parameter: id"));
     }
 
     #[tokio::test]
-    async fn test_get_merge_request_handler() {
-        let provider = Arc::new(MockProvider::new()) as Arc<dyn Provider>;
-        let handler = ToolHandler::new(vec![provider]);
+    async fn test_get_pipeline_unknown_provider() {
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
 
-        let args = serde_json::json!({"key": "pr#1"});
-        let result = handler.execute("get_merge_request", Some(args)).await;
+        let args = serde_json::json!({"id": "101", "provider": "gitlab"});
+        let result = handler.execute("get_pipeline", Some(args)).await;
 
-        assert!(result.is_error.is_none());
+        assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("pr#1"));
-        assert!(content.contains("Test PR"));
+        assert!(content.contains("Provider 'gitlab' not configured. Available: mock"));
     }
 
     #[tokio::test]
@@ -1509,6 +6370,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Missing required parameter: key"));
     }
@@ -1539,6 +6401,7 @@ mod tests {
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Added comment"));
     }
@@ -1572,6 +6435,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Missing required parameters: key, body"));
     }
@@ -1599,6 +6463,7 @@ mod tests {
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         // JSON format should contain valid JSON
         assert!(content.contains("gh#1"));
@@ -1615,6 +6480,7 @@ mod tests {
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("gh#1"));
     }
@@ -1664,6 +6530,7 @@ mod tests {
         assert!(result.is_error.is_none());
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Created issue"));
     }
@@ -1696,6 +6563,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Missing required parameter: key"));
     }
@@ -1770,6 +6638,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Invalid parameters"));
     }
@@ -1816,6 +6685,12 @@ mod tests {
                 message: "comment failed".into(),
             })
         }
+        async fn get_custom_fields(&self) -> devboy_core::Result<Vec<CustomField>> {
+            Err(devboy_core::Error::Api {
+                status: 500,
+                message: "fields failed".into(),
+            })
+        }
         fn provider_name(&self) -> &'static str {
             "failing"
         }
@@ -1856,6 +6731,30 @@ mod tests {
         }
     }
 
+    impl PipelineProvider for FailingProvider {
+        fn provider_name(&self) -> &'static str {
+            "failing"
+        }
+    }
+
+    impl RepositoryProvider for FailingProvider {
+        fn provider_name(&self) -> &'static str {
+            "failing"
+        }
+    }
+
+    impl AgileProvider for FailingProvider {
+        fn provider_name(&self) -> &'static str {
+            "failing"
+        }
+    }
+
+    impl TimeTrackingProvider for FailingProvider {
+        fn provider_name(&self) -> &'static str {
+            "failing"
+        }
+    }
+
     #[async_trait]
     impl Provider for FailingProvider {
         async fn get_current_user(&self) -> devboy_core::Result<User> {
@@ -1876,6 +6775,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to get issues"));
     }
@@ -1891,8 +6791,140 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
+        };
+        assert_eq!(content, "Failed to get issue gh#1: Not found: not found");
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_routes_by_key() {
+        let handler = ToolHandler::new(vec![
+            Arc::new(FailingProvider) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+        let text = |result: &ToolCallResult| match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text.clone(),
+            _ => panic!("expected text content"),
+        };
+
+        // gh# keys belong to the mock, other keys fall back to the failing provider
+        let result = handler
+            .execute("get_issue", Some(serde_json::json!({"key": "gh#1"})))
+            .await;
+        assert!(result.is_error.is_none());
+        let result = handler
+            .execute("get_issue", Some(serde_json::json!({"key": "PROJ-1"})))
+            .await;
+        assert_eq!(
+            text(&result),
+            "Failed to get issue PROJ-1: Not found: not found"
+        );
+
+        let handler = ToolHandler::new(vec![
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+        let result = handler
+            .execute("get_issue", Some(serde_json::json!({"key": "gh#1"})))
+            .await;
+        assert_eq!(result.is_error, Some(true));
+        assert!(text(&result).contains("Key 'gh#1' is ambiguous: it matches mock, mock"));
+
+        let handler = ToolHandler::new(vec![Arc::new(MockProvider::new()) as Arc<dyn Provider>]);
+        let result = handler
+            .execute("get_issue", Some(serde_json::json!({"key": "CU-1"})))
+            .await;
+        assert_eq!(
+            text(&result),
+            "Provider not found: no configured provider handles key 'CU-1'. Known key formats: \
+             gh#<id> (mock), pr#<id> (mock), https://github.com/test/repo/... (mock)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_key_mutations_do_not_fall_through() {
+        let text = |result: &ToolCallResult| match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text.clone(),
+            _ => panic!("expected text content"),
+        };
+
+        // A key owned by two providers is rejected instead of logged on the first
+        let handler = ToolHandler::new(vec![
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+        let args = serde_json::json!({"key": "gh#1", "duration": "30m"});
+        let result = handler.execute("log_time", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        assert!(text(&result).contains("Key 'gh#1' is ambiguous"));
+
+        // Keys of different providers cannot share a sprint
+        let handler = ToolHandler::new(vec![
+            Arc::new(FailingProvider) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+        let args = serde_json::json!({"sprint_id": "38", "keys": ["gh#1", "PROJ-2"]});
+        let result = handler.execute("move_issues_to_sprint", Some(args)).await;
+        assert_eq!(result.is_error, Some(true));
+        assert!(
+            text(&result).contains("Issues gh#1 and PROJ-2 belong to different providers"),
+            "got: {}",
+            text(&result)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_routes_urls() {
+        let handler = ToolHandler::new(vec![
+            Arc::new(FailingProvider) as Arc<dyn Provider>,
+            Arc::new(MockProvider::new()) as Arc<dyn Provider>,
+        ]);
+        let text = |result: &ToolCallResult| match &result.content[0] {
+            crate::protocol::ToolResultContent::Text { text } => text.clone(),
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Issue not found"));
+
+        // URLs of the configured repository go to its provider
+        let result = handler
+            .execute(
+                "get_issue",
+                Some(serde_json::json!({"key": "https://github.com/test/repo/issues/1"})),
+            )
+            .await;
+        assert!(result.is_error.is_none());
+
+        // URLs of other repositories are not claimed by the fallback provider
+        let result = handler
+            .execute(
+                "get_issue",
+                Some(serde_json::json!({"key": "https://github.com/other/repo/issues/1"})),
+            )
+            .await;
+        assert_eq!(result.is_error, Some(true));
+        assert_eq!(
+            text(&result),
+            "Provider not found: 'https://github.com/other/repo/issues/1' does not point to a \
+             configured repository or project. Configured URLs: https://github.com/test/repo/ (mock)"
+        );
+
+        // A named provider only accepts URLs of its own repository
+        let result = handler
+            .execute(
+                "bulk_update_issues",
+                Some(serde_json::json!({
+                    "keys": ["gh#1", "https://github.com/other/repo/issues/1"],
+                    "state": "closed",
+                    "provider": "mock"
+                })),
+            )
+            .await;
+        assert!(
+            text(&result).contains(
+                "'https://github.com/other/repo/issues/1' does not point to the repository or project configured for mock"
+            ),
+            "got: {}",
+            text(&result)
+        );
     }
 
     #[tokio::test]
@@ -1906,8 +6938,9 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Issue not found"));
+        assert!(content.starts_with("Failed to get comments of gh#1: "));
     }
 
     #[tokio::test]
@@ -1921,6 +6954,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to create issue"));
     }
@@ -1936,6 +6970,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to update issue"));
     }
@@ -1951,6 +6986,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to add comment to issue"));
     }
@@ -1965,6 +7001,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to get merge requests"));
     }
@@ -1980,8 +7017,9 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Merge request not found"));
+        assert!(content.starts_with("Failed to get merge request pr#1: "));
     }
 
     #[tokio::test]
@@ -1997,8 +7035,9 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Merge request not found"));
+        assert!(content.starts_with("Failed to get discussions of pr#1: "));
     }
 
     #[tokio::test]
@@ -2012,8 +7051,9 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
-        assert!(content.contains("Merge request not found"));
+        assert!(content.starts_with("Failed to get diffs of pr#1: "));
     }
 
     #[tokio::test]
@@ -2029,6 +7069,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to add comment to merge request"));
     }
@@ -2047,6 +7088,7 @@ mod tests {
         assert_eq!(result.is_error, Some(true));
         let content = match &result.content[0] {
             crate::protocol::ToolResultContent::Text { text } => text,
+            _ => panic!("expected text content"),
         };
         assert!(content.contains("Failed to create issue"));
     }
@@ -254,6 +254,29 @@ pub struct ToolCallResult {
 pub enum ToolResultContent {
     #[serde(rename = "text")]
     Text { text: String },
+    /// Base64-encoded image.
+    #[serde(rename = "image")]
+    Image {
+        data: String,
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+    /// File embedded as a resource.
+    #[serde(rename = "resource")]
+    Resource { resource: EmbeddedResource },
+}
+
+/// Resource embedded in a tool result, with either `text` or base64 `blob` content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedResource {
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
 }
 
 impl ToolCallResult {
@@ -321,6 +344,42 @@ mod tests {
         assert!(json.contains("\"text\":\"Hello\""));
     }
 
+    #[test]
+    fn test_tool_call_result_binary_content() {
+        let result = ToolCallResult {
+            content: vec![
+                ToolResultContent::Image {
+                    data: "iVBORw==".to_string(),
+                    mime_type: "image/png".to_string(),
+                },
+                ToolResultContent::Resource {
+                    resource: EmbeddedResource {
+                        uri: "https://example.com/report.pdf".to_string(),
+                        mime_type: Some("application/pdf".to_string()),
+                        text: None,
+                        blob: Some("JVBERg==".to_string()),
+                    },
+                },
+            ],
+            is_error: None,
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["content"],
+            serde_json::json!([
+                {"type": "image", "data": "iVBORw==", "mimeType": "image/png"},
+                {
+                    "type": "resource",
+                    "resource": {
+                        "uri": "https://example.com/report.pdf",
+                        "mimeType": "application/pdf",
+                        "blob": "JVBERg=="
+                    }
+                }
+            ])
+        );
+    }
+
     #[test]
     fn test_tool_call_result_error() {
         let result = ToolCallResult::error("Something failed".to_string());
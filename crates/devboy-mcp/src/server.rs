@@ -7,7 +7,7 @@
 
 use std::sync::Arc;
 
-use devboy_core::Provider;
+use devboy_core::{Provider, WorkflowConfig};
 use serde_json::Value;
 
 use crate::handlers::ToolHandler;
@@ -20,6 +20,7 @@ use crate::transport::{IncomingMessage, StdioTransport};
 /// MCP server for devboy-tools.
 pub struct McpServer {
     providers: Vec<Arc<dyn Provider>>,
+    workflow_config: WorkflowConfig,
     initialized: bool,
 }
 
@@ -28,6 +29,7 @@ impl McpServer {
     pub fn new() -> Self {
         Self {
             providers: Vec::new(),
+            workflow_config: WorkflowConfig::default(),
             initialized: false,
         }
     }
@@ -37,6 +39,11 @@ impl McpServer {
         self.providers.push(provider);
     }
 
+    /// Set the workflow configuration used by workflow tools.
+    pub fn set_workflow_config(&mut self, config: WorkflowConfig) {
+        self.workflow_config = config;
+    }
+
     /// Get all registered providers.
     pub fn providers(&self) -> &[Arc<dyn Provider>] {
         &self.providers
@@ -50,7 +57,8 @@ impl McpServer {
         );
 
         let mut transport = StdioTransport::stdio();
-        let handler = ToolHandler::new(self.providers.clone());
+        let handler = ToolHandler::new(self.providers.clone())
+            .with_workflow_config(self.workflow_config.clone());
 
         loop {
             match transport.read_message() {
@@ -325,9 +333,9 @@ mod tests {
     fn test_add_provider_and_providers() {
         use async_trait::async_trait;
         use devboy_core::{
-            Comment, CreateCommentInput, CreateIssueInput, Discussion, FileDiff, Issue,
-            IssueFilter, IssueProvider, MergeRequest, MergeRequestProvider, MrFilter,
-            UpdateIssueInput, User,
+            AgileProvider, Comment, CreateCommentInput, CreateIssueInput, Discussion, FileDiff,
+            Issue, IssueFilter, IssueProvider, MergeRequest, MergeRequestProvider, MrFilter,
+            PipelineProvider, RepositoryProvider, TimeTrackingProvider, UpdateIssueInput, User,
         };
 
         struct TestProvider;
@@ -394,6 +402,30 @@ mod tests {
             }
         }
 
+        impl PipelineProvider for TestProvider {
+            fn provider_name(&self) -> &'static str {
+                "test"
+            }
+        }
+
+        impl RepositoryProvider for TestProvider {
+            fn provider_name(&self) -> &'static str {
+                "test"
+            }
+        }
+
+        impl AgileProvider for TestProvider {
+            fn provider_name(&self) -> &'static str {
+                "test"
+            }
+        }
+
+        impl TimeTrackingProvider for TestProvider {
+            fn provider_name(&self) -> &'static str {
+                "test"
+            }
+        }
+
         #[async_trait]
         impl Provider for TestProvider {
             async fn get_current_user(&self) -> devboy_core::Result<User> {
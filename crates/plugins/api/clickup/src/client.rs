@@ -1,16 +1,35 @@
 //! ClickUp API client implementation.
 
+use std::borrow::Cow;
+
 use async_trait::async_trait;
+use devboy_core::attachments::{is_same_origin, mime_type_for, multipart_file, url_origin};
+use devboy_core::custom_fields::{
+    as_bool, as_list, as_number, as_text, date_to_unix_ms, find_custom_field, invalid_value,
+    unix_ms_to_date,
+};
+use devboy_core::keys::KeyPattern;
+use devboy_core::members::{find_member, is_me, search_members, MemberDirectory};
+use devboy_core::time::{now_unix, parse_iso8601, unix_to_iso8601};
 use devboy_core::{
-    Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff, Issue, IssueFilter,
-    IssueProvider, MergeRequest, MergeRequestProvider, MrFilter, Provider, Result,
-    UpdateIssueInput, User,
+    AgileProvider, Attachment, Checklist, ChecklistItem, Comment, Container, CreateCommentInput,
+    CreateIssueInput, CustomField, Discussion, Error, FileDiff, Issue, IssueFilter, IssueLink,
+    IssueProvider, IssueRef, IssueStatus, Label, MergeRequest, MergeRequestProvider, Milestone,
+    MrFilter, PipelineProvider, Provider, RepositoryProvider, Result, TimeEntry,
+    TimeTrackingProvider, UpdateIssueInput, User,
 };
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
 use tracing::{debug, warn};
 
 use crate::types::{
-    ClickUpComment, ClickUpCommentList, ClickUpListInfo, ClickUpPriority, ClickUpTask,
-    ClickUpTaskList, ClickUpUser, CreateCommentRequest, CreateCommentResponse, CreateTaskRequest,
+    AddDependencyRequest, ClickUpAttachment, ClickUpAuthorizedUser, ClickUpChecklist,
+    ClickUpChecklistResponse, ClickUpComment, ClickUpCommentList, ClickUpCustomField,
+    ClickUpFieldList, ClickUpFieldOption, ClickUpFolders, ClickUpList, ClickUpListInfo,
+    ClickUpLists, ClickUpMember, ClickUpPriority, ClickUpSpaceTags, ClickUpSpaces, ClickUpTask,
+    ClickUpTaskList, ClickUpTeams, ClickUpTrackedTimeList, ClickUpUser, CreateChecklistRequest,
+    CreateCommentRequest, CreateCommentResponse, CreateTaskRequest, CreateTimeEntryRequest,
+    CustomFieldValue, EditChecklistItemRequest, SetCustomFieldRequest, UpdateAssignees,
     UpdateTaskRequest,
 };
 use crate::DEFAULT_CLICKUP_URL;
@@ -18,11 +37,19 @@ use crate::DEFAULT_CLICKUP_URL;
 /// Maximum number of tasks per page in ClickUp API.
 const PAGE_SIZE: u32 = 100;
 
+/// Number of lists whose tasks are counted at the same time.
+const COUNT_CONCURRENCY: usize = 4;
+
+/// Prefix of task links in the ClickUp web app.
+const TASK_WEB_URL: &str = "https://app.clickup.com/t/";
+
 /// ClickUp API client.
 pub struct ClickUpClient {
     base_url: String,
     list_id: String,
     team_id: Option<String>,
+    lists: Vec<String>,
+    members: MemberDirectory,
     token: String,
     client: reqwest::Client,
 }
@@ -43,6 +70,8 @@ impl ClickUpClient {
             base_url: base_url.into().trim_end_matches('/').to_string(),
             list_id: list_id.into(),
             team_id: None,
+            lists: Vec::new(),
+            members: MemberDirectory::new(),
             token: token.into(),
             client: reqwest::Client::builder()
                 .user_agent("devboy-tools")
@@ -57,6 +86,72 @@ impl ClickUpClient {
         self
     }
 
+    /// Set additional list IDs searched together with the main list — requires a team ID.
+    pub fn with_lists(mut self, lists: Vec<String>) -> Self {
+        self.lists = lists;
+        self
+    }
+
+    /// Members of the configured workspace (of all workspaces without a team ID), cached.
+    async fn members(&self) -> Result<Vec<User>> {
+        self.members
+            .get_or_load("", || async {
+                let response: ClickUpTeams = self.get(&format!("{}/team", self.base_url)).await?;
+                let mut members: Vec<User> = Vec::new();
+                for team in &response.teams {
+                    if self.team_id.as_ref().is_some_and(|id| *id != team.id) {
+                        continue;
+                    }
+                    for member in &team.members {
+                        if members.iter().any(|m| m.id == member.user.id.to_string()) {
+                            continue;
+                        }
+                        members.push(map_member(&member.user));
+                    }
+                }
+                Ok(members)
+            })
+            .await
+    }
+
+    /// Resolve a user ID, username, email, display name or `@me` to a ClickUp user ID.
+    async fn resolve_user_id(&self, user: &str) -> Result<u64> {
+        if let Ok(id) = user.parse() {
+            return Ok(id);
+        }
+        let member = if is_me(user) {
+            self.members
+                .current_user(|| self.get_current_user())
+                .await?
+        } else {
+            find_member(&self.members().await?, user)?.clone()
+        };
+        member
+            .id
+            .parse()
+            .map_err(|_| Error::InvalidData(format!("Invalid ClickUp user ID '{}'", member.id)))
+    }
+
+    /// Resolve several users, see [`Self::resolve_user_id`].
+    async fn resolve_user_ids(&self, users: &[String]) -> Result<Vec<u64>> {
+        let mut ids = Vec::with_capacity(users.len());
+        for user in users {
+            ids.push(self.resolve_user_id(user).await?);
+        }
+        Ok(ids)
+    }
+
+    /// Team (workspace) ID required by workspace-level endpoints.
+    fn require_team_id(&self, action: &str) -> Result<&str> {
+        self.team_id.as_deref().ok_or_else(|| {
+            Error::Config(format!(
+                "team_id is required to {} in ClickUp. \
+                 Run: devboy config set clickup.team_id <team_id>",
+                action
+            ))
+        })
+    }
+
     /// Build request with common headers.
     fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
         self.client
@@ -132,6 +227,95 @@ impl ClickUpClient {
         self.handle_response(response).await
     }
 
+    /// Make an authenticated DELETE request.
+    async fn delete(&self, url: &str) -> Result<()> {
+        debug!(url = url, "ClickUp DELETE request");
+
+        let response = self
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "ClickUp API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+        Ok(())
+    }
+
+    /// Make an authenticated GET request returning the raw response body.
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        debug!(url = url, "ClickUp GET bytes request");
+
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "ClickUp API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
+    /// Make an authenticated `multipart/form-data` POST request uploading one file.
+    async fn post_file<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        field: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<T> {
+        debug!(
+            url = url,
+            file_name = file_name,
+            "ClickUp POST file request"
+        );
+
+        let (content_type, body) = multipart_file(field, file_name, content);
+        let mut request = self
+            .request(reqwest::Method::POST, url)
+            .body(body)
+            .build()
+            .map_err(|e| Error::Http(e.to_string()))?;
+        // Replace the JSON content type set by `request`
+        let content_type = reqwest::header::HeaderValue::from_str(&content_type)
+            .map_err(|e| Error::InvalidData(e.to_string()))?;
+        request
+            .headers_mut()
+            .insert(reqwest::header::CONTENT_TYPE, content_type);
+
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        self.handle_response(response).await
+    }
+
     /// Handle response and map errors.
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
@@ -182,10 +366,68 @@ impl ClickUpClient {
             })
     }
 
+    /// Lists next to the configured list, which ClickUp teams use as sprints
+    /// or milestones: the lists of its folder, or the folderless lists of its space.
+    async fn milestone_lists(&self, state: &str) -> Result<Vec<ClickUpList>> {
+        let url = format!("{}/list/{}", self.base_url, self.list_id);
+        let list_info: ClickUpListInfo = self.get(&url).await?;
+        let parent = match (&list_info.folder, &list_info.space) {
+            (Some(folder), _) if !folder.hidden => format!("folder/{}", folder.id),
+            (_, Some(space)) => format!("space/{}", space.id),
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "List {} has no folder or space",
+                    self.list_id
+                )))
+            }
+        };
+
+        let archived: &[bool] = match state {
+            "closed" => &[true],
+            "all" => &[false, true],
+            _ => &[false],
+        };
+        let mut lists = Vec::new();
+        for archived in archived {
+            let url = format!("{}/{}/list?archived={}", self.base_url, parent, archived);
+            let response: ClickUpLists = self.get(&url).await?;
+            lists.extend(response.lists);
+        }
+        Ok(lists)
+    }
+
+    /// Count the open and closed tasks of a list.
+    async fn count_tasks(&self, list_id: &str) -> Result<(u32, u32)> {
+        let url = format!("{}/list/{}/task", self.base_url, list_id);
+        let (mut open, mut closed) = (0, 0);
+        for page in 0.. {
+            let page = page.to_string();
+            let params = [
+                ("include_closed", "true"),
+                ("subtasks", "true"),
+                ("page", page.as_str()),
+            ];
+            let response: ClickUpTaskList = self.get_with_query(&url, &params).await?;
+            for task in &response.tasks {
+                if map_state(task) == "closed" {
+                    closed += 1;
+                } else {
+                    open += 1;
+                }
+            }
+            if response.tasks.len() < PAGE_SIZE as usize {
+                break;
+            }
+        }
+        Ok((open, closed))
+    }
+
     /// Build the URL for accessing a task by key.
     /// For `CU-{id}` keys, uses the raw task ID directly.
     /// For custom IDs (e.g., `DEV-42`), appends `?custom_task_ids=true&team_id=` params.
     fn task_url(&self, key: &str) -> Result<String> {
+        let key = task_key(key);
+        let key = key.as_ref();
         if let Some(raw_id) = key.strip_prefix("CU-") {
             Ok(format!("{}/task/{}", self.base_url, raw_id))
         } else {
@@ -203,12 +445,73 @@ impl ClickUpClient {
             ))
         }
     }
+
+    /// Resolve an issue key to the raw ClickUp task ID.
+    /// Custom task IDs need a lookup; `CU-{id}` keys are stripped.
+    async fn resolve_task_id(&self, key: &str) -> Result<String> {
+        let key = task_key(key);
+        if let Some(raw_id) = key.strip_prefix("CU-") {
+            return Ok(raw_id.to_string());
+        }
+        let task: ClickUpTask = self.get(&self.task_url(&key)?).await?;
+        Ok(task.id)
+    }
+
+    /// Fetch the custom fields configured for the list.
+    async fn get_list_fields(&self) -> Result<Vec<ClickUpCustomField>> {
+        let url = format!("{}/list/{}/field", self.base_url, self.list_id);
+        let response: ClickUpFieldList = self.get(&url).await?;
+        Ok(response.fields)
+    }
+
+    /// Convert custom field values keyed by name or ID to ClickUp values keyed
+    /// by field ID. `null` values are kept so updates can clear the field.
+    async fn resolve_custom_fields(
+        &self,
+        values: &serde_json::Map<String, Value>,
+        for_update: bool,
+    ) -> Result<Vec<CustomFieldValue>> {
+        if values.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let cu_fields = self.get_list_fields().await?;
+        let fields: Vec<CustomField> = cu_fields.iter().map(map_custom_field).collect();
+
+        let mut resolved = Vec::new();
+        for (key, value) in values {
+            let field = find_custom_field(&fields, key)?;
+            if let Some(cu_field) = cu_fields.iter().find(|f| f.id == field.id) {
+                resolved.push(CustomFieldValue {
+                    id: cu_field.id.clone(),
+                    value: custom_field_to_clickup(cu_field, value, for_update)?,
+                });
+            }
+        }
+        Ok(resolved)
+    }
 }
 
 // =============================================================================
 // Mapping functions: ClickUp types -> Unified types
 // =============================================================================
 
+/// Convert a task link to its key: `https://app.clickup.com/t/abc123` to
+/// `CU-abc123`, `https://app.clickup.com/t/{team_id}/DEV-42` to the custom ID
+/// `DEV-42`. Other keys are returned as-is.
+fn task_key(key: &str) -> Cow<'_, str> {
+    let trimmed = key.trim();
+    let path = match trimmed.get(..TASK_WEB_URL.len()) {
+        Some(start) if start.eq_ignore_ascii_case(TASK_WEB_URL) => &trimmed[TASK_WEB_URL.len()..],
+        _ => return Cow::Borrowed(key),
+    };
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    match path.trim_end_matches('/').split_once('/') {
+        Some((_team_id, custom_id)) => Cow::Owned(custom_id.to_string()),
+        None => Cow::Owned(format!("CU-{}", path.trim_end_matches('/'))),
+    }
+}
+
 fn map_user(cu_user: Option<&ClickUpUser>) -> Option<User> {
     cu_user.map(|u| User {
         id: u.id.to_string(),
@@ -219,6 +522,18 @@ fn map_user(cu_user: Option<&ClickUpUser>) -> Option<User> {
     })
 }
 
+/// Map a team member or the authorized user; invited users have no username yet.
+fn map_member(member: &ClickUpMember) -> User {
+    let username = member.username.clone().unwrap_or_default();
+    User {
+        id: member.id.to_string(),
+        name: Some(username.clone()),
+        username,
+        email: member.email.clone(),
+        avatar_url: member.profile_picture.clone(),
+    }
+}
+
 fn map_user_required(cu_user: Option<&ClickUpUser>) -> User {
     map_user(cu_user).unwrap_or_else(|| User {
         id: "unknown".to_string(),
@@ -262,47 +577,33 @@ fn map_task_key(task: &ClickUpTask) -> String {
 /// Convert ClickUp epoch-millisecond timestamp to ISO 8601 string.
 fn epoch_ms_to_iso8601(epoch_ms: &str) -> Option<String> {
     let ms: i64 = epoch_ms.parse().ok()?;
-    let secs = ms / 1000;
-    let datetime = time_from_unix(secs);
-    Some(datetime)
-}
-
-/// Convert unix timestamp to ISO 8601 string without external crate.
-fn time_from_unix(secs: i64) -> String {
-    // Days from unix epoch
-    let mut days = secs / 86400;
-    let day_secs = secs.rem_euclid(86400);
-    if secs % 86400 < 0 {
-        days -= 1;
-    }
-
-    let hours = day_secs / 3600;
-    let minutes = (day_secs % 3600) / 60;
-    let seconds = day_secs % 60;
-
-    // Convert days since epoch to year-month-day
-    // Algorithm from http://howardhinnant.github.io/date_algorithms.html
-    let z = days + 719468;
-    let era = if z >= 0 { z } else { z - 146096 } / 146097;
-    let doe = (z - era * 146097) as u32;
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
-    let y = yoe as i64 + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-    let mp = (5 * doy + 2) / 153;
-    let d = doy - (153 * mp + 2) / 5 + 1;
-    let m = if mp < 10 { mp + 3 } else { mp - 9 };
-    let y = if m <= 2 { y + 1 } else { y };
-
-    format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        y, m, d, hours, minutes, seconds
-    )
+    Some(unix_to_iso8601(ms.div_euclid(1000)))
 }
 
 fn map_timestamp(ts: &Option<String>) -> Option<String> {
     ts.as_ref().and_then(|s| epoch_ms_to_iso8601(s))
 }
 
+fn map_list(list: &ClickUpList) -> Milestone {
+    let date = |value: Option<&Value>| lenient_number(value).map(|ms| unix_ms_to_date(ms as i64));
+    Milestone {
+        id: list.id.clone(),
+        title: list.name.clone(),
+        kind: "list".to_string(),
+        state: if list.archived {
+            "closed".to_string()
+        } else {
+            "open".to_string()
+        },
+        description: list.content.clone().filter(|c| !c.is_empty()),
+        start_date: date(list.start_date.as_ref()),
+        due_date: date(list.due_date.as_ref()),
+        open_issues: None,
+        closed_issues: None,
+        url: None,
+    }
+}
+
 fn map_task(task: &ClickUpTask) -> Issue {
     Issue {
         key: map_task_key(task),
@@ -324,6 +625,321 @@ fn map_task(task: &ClickUpTask) -> Issue {
         url: Some(task.url.clone()),
         created_at: map_timestamp(&task.date_created),
         updated_at: map_timestamp(&task.date_updated),
+        custom_fields: map_custom_fields(&task.custom_fields),
+        issue_type: Some(map_task_type(task).to_string()),
+        parent: task.parent.as_ref().map(|id| IssueRef {
+            key: format!("CU-{}", id),
+            ..Default::default()
+        }),
+        children: task
+            .subtasks
+            .iter()
+            .map(|t| IssueRef::from(&map_task(t)))
+            .collect(),
+        links: map_task_links(task),
+        attachments: task.attachments.iter().map(map_attachment).collect(),
+        estimate_seconds: lenient_number(task.time_estimate.as_ref()).map(|ms| ms / 1000),
+        time_spent_seconds: lenient_number(task.time_spent.as_ref()).map(|ms| ms / 1000),
+        milestone: None,
+        checklists: task.checklists.iter().map(map_checklist).collect(),
+    }
+}
+
+fn map_checklist(checklist: &ClickUpChecklist) -> Checklist {
+    Checklist {
+        id: checklist.id.clone(),
+        name: checklist.name.clone(),
+        items: checklist
+            .items
+            .iter()
+            .map(|item| ChecklistItem {
+                id: item.id.clone(),
+                name: item.name.clone(),
+                resolved: item.resolved,
+                assignee: map_user(item.assignee.as_ref()),
+            })
+            .collect(),
+    }
+}
+
+/// Map dependencies and linked tasks. Only raw IDs are returned for the
+/// other task, so targets are `CU-{id}` keys without titles.
+fn map_task_links(task: &ClickUpTask) -> Vec<IssueLink> {
+    let link = |kind: &str, id: &str| IssueLink {
+        kind: kind.to_string(),
+        target: IssueRef {
+            key: format!("CU-{}", id),
+            ..Default::default()
+        },
+        id: None,
+    };
+
+    let dependencies = task.dependencies.iter().filter_map(|dep| {
+        if dep.task_id == task.id {
+            Some(link("blocked_by", &dep.depends_on))
+        } else if dep.depends_on == task.id {
+            Some(link("blocks", &dep.task_id))
+        } else {
+            None
+        }
+    });
+    let linked_tasks = task.linked_tasks.iter().map(|linked| {
+        let other = if linked.task_id == task.id {
+            &linked.link_id
+        } else {
+            &linked.task_id
+        };
+        link("relates_to", other)
+    });
+    dependencies.chain(linked_tasks).collect()
+}
+
+/// Check that a link kind is one ClickUp supports, accepting ClickUp's own
+/// dependency names ("waiting_on", "blocking").
+fn link_kind(kind: &str) -> Result<&'static str> {
+    match kind {
+        "blocks" | "blocking" => Ok("blocks"),
+        "blocked_by" | "waiting_on" => Ok("blocked_by"),
+        "relates_to" => Ok("relates_to"),
+        other => Err(Error::InvalidData(format!(
+            "Unsupported ClickUp link kind '{}'. Available: blocks (blocking), blocked_by (waiting_on), relates_to",
+            other
+        ))),
+    }
+}
+
+/// Read a number sent either as a JSON number or as a numeric string.
+fn lenient_number(value: Option<&Value>) -> Option<u64> {
+    match value? {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn map_attachment(attachment: &ClickUpAttachment) -> Attachment {
+    let name = attachment
+        .title
+        .clone()
+        .unwrap_or_else(|| attachment.id.clone());
+    Attachment {
+        id: attachment.id.clone(),
+        mime_type: attachment
+            .mimetype
+            .clone()
+            .or_else(|| mime_type_for(&name).map(str::to_string)),
+        name,
+        size: lenient_number(attachment.size.as_ref()),
+        url: attachment.url.clone(),
+        created_at: lenient_number(attachment.date.as_ref())
+            .and_then(|ms| epoch_ms_to_iso8601(&ms.to_string())),
+    }
+}
+
+/// Check whether a URL is served by ClickUp (API or attachment storage).
+fn is_clickup_url(url: &str) -> bool {
+    let Some(origin) = url_origin(url) else {
+        return false;
+    };
+    let host = origin.split("://").nth(1).unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default().to_lowercase();
+    ["clickup.com", "clickup-attachments.com"]
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{}", domain)))
+}
+
+fn map_task_type(task: &ClickUpTask) -> &'static str {
+    if task.parent.is_some() {
+        "subtask"
+    } else {
+        "task"
+    }
+}
+
+/// Attach subtasks to their parents when both are in the same list response
+/// (`subtasks=true` returns them flattened). `issues` must match `tasks`.
+fn link_subtasks(tasks: &[ClickUpTask], issues: &mut [Issue]) {
+    for (index, task) in tasks.iter().enumerate() {
+        let Some(parent_id) = &task.parent else {
+            continue;
+        };
+        if let Some(parent_index) = tasks.iter().position(|t| &t.id == parent_id) {
+            let child = IssueRef::from(&issues[index]);
+            issues[index].parent = Some(IssueRef::from(&issues[parent_index]));
+            issues[parent_index].children.push(child);
+        }
+    }
+}
+
+fn field_options(field: &ClickUpCustomField) -> &[ClickUpFieldOption] {
+    field
+        .type_config
+        .as_ref()
+        .map(|c| c.options.as_slice())
+        .unwrap_or_default()
+}
+
+/// Display name of a drop-down (`name`) or labels (`label`) option.
+fn option_name(option: &ClickUpFieldOption) -> &str {
+    option
+        .name
+        .as_deref()
+        .or(option.label.as_deref())
+        .unwrap_or_default()
+}
+
+/// Unified value type of a ClickUp custom field type.
+fn custom_field_type(field_type: &str) -> &'static str {
+    match field_type {
+        "drop_down" => "option",
+        "labels" => "multi_option",
+        "users" => "multi_user",
+        "date" => "date",
+        "number" | "currency" | "emoji" => "number",
+        "checkbox" => "checkbox",
+        "text" | "short_text" | "email" | "url" | "phone" => "text",
+        _ => "other",
+    }
+}
+
+fn map_custom_field(field: &ClickUpCustomField) -> CustomField {
+    CustomField {
+        id: field.id.clone(),
+        name: field.name.clone(),
+        field_type: custom_field_type(&field.field_type).to_string(),
+        options: field_options(field)
+            .iter()
+            .map(|o| option_name(o).to_string())
+            .collect(),
+        required: field.required.unwrap_or(false),
+    }
+}
+
+/// Map custom field values of a task to plain JSON keyed by field name.
+fn map_custom_fields(fields: &[ClickUpCustomField]) -> serde_json::Map<String, Value> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let value = field.value.as_ref().filter(|v| !v.is_null())?;
+            read_custom_field_value(field, value).map(|v| (field.name.clone(), v))
+        })
+        .collect()
+}
+
+/// Simplify a custom field value: options become names, dates become
+/// "YYYY-MM-DD", users become usernames. Empty values are `None`.
+fn read_custom_field_value(field: &ClickUpCustomField, value: &Value) -> Option<Value> {
+    let options = field_options(field);
+    let value = match field.field_type.as_str() {
+        // Tasks store the option's orderindex; accept the option ID as well
+        "drop_down" => options
+            .iter()
+            .find(|o| {
+                o.orderindex.as_ref().and_then(as_text) == as_text(value)
+                    || value.as_str() == Some(o.id.as_str())
+            })
+            .map(|o| Value::String(option_name(o).to_string()))?,
+        "labels" => Value::Array(
+            value
+                .as_array()?
+                .iter()
+                .filter_map(|id| options.iter().find(|o| id.as_str() == Some(o.id.as_str())))
+                .map(|o| Value::String(option_name(o).to_string()))
+                .collect(),
+        ),
+        "date" => {
+            let millis = as_number(value)?.as_i64()?;
+            Value::String(unix_ms_to_date(millis))
+        }
+        "number" | "currency" | "emoji" => as_number(value)?,
+        "checkbox" => Value::Bool(as_bool(value)?),
+        "users" => Value::Array(
+            value
+                .as_array()?
+                .iter()
+                .filter_map(|u| u.get("username").cloned())
+                .collect(),
+        ),
+        _ => value.clone(),
+    };
+
+    match &value {
+        Value::String(s) if s.is_empty() => None,
+        Value::Array(items) if items.is_empty() => None,
+        _ => Some(value),
+    }
+}
+
+/// Find the ID of a drop-down or labels option by name (case-insensitive) or ID.
+fn option_id(field: &ClickUpCustomField, name: &str) -> Result<String> {
+    let options = field_options(field);
+    options
+        .iter()
+        .find(|o| o.id == name || option_name(o).eq_ignore_ascii_case(name))
+        .map(|o| o.id.clone())
+        .ok_or_else(|| {
+            let names: Vec<&str> = options.iter().map(option_name).collect();
+            Error::InvalidData(format!(
+                "Custom field '{}' has no option '{}'. Available: {}",
+                field.name,
+                name,
+                names.join(", ")
+            ))
+        })
+}
+
+/// Convert a custom field value to the shape ClickUp expects for the field type.
+/// Updates of user fields add the given users instead of replacing them.
+fn custom_field_to_clickup(
+    field: &ClickUpCustomField,
+    value: &Value,
+    for_update: bool,
+) -> Result<Value> {
+    if value.is_null() {
+        return Ok(Value::Null);
+    }
+
+    let unified = map_custom_field(field);
+    match field.field_type.as_str() {
+        "drop_down" => {
+            let name = as_text(value).ok_or_else(|| invalid_value(&unified, "an option", value))?;
+            option_id(field, &name).map(Value::String)
+        }
+        "labels" => as_list(value)
+            .ok_or_else(|| invalid_value(&unified, "a list of options", value))?
+            .iter()
+            .map(|name| option_id(field, name).map(Value::String))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Array),
+        "date" => as_text(value)
+            .and_then(|d| date_to_unix_ms(&d))
+            .map(Value::from)
+            .ok_or_else(|| invalid_value(&unified, "a date (YYYY-MM-DD)", value)),
+        "number" | "currency" | "emoji" => {
+            as_number(value).ok_or_else(|| invalid_value(&unified, "a number", value))
+        }
+        "checkbox" => as_bool(value)
+            .map(Value::Bool)
+            .ok_or_else(|| invalid_value(&unified, "true or false", value)),
+        "users" => {
+            let ids: Vec<Value> = as_list(value)
+                .and_then(|users| {
+                    users
+                        .iter()
+                        .map(|u| u.parse::<u64>().ok().map(Value::from))
+                        .collect()
+                })
+                .ok_or_else(|| invalid_value(&unified, "a list of user IDs", value))?;
+            if for_update {
+                Ok(serde_json::json!({ "add": ids }))
+            } else {
+                Ok(Value::Array(ids))
+            }
+        }
+        "text" | "short_text" | "email" | "url" | "phone" => as_text(value)
+            .map(Value::String)
+            .ok_or_else(|| invalid_value(&unified, "text", value)),
+        _ => Ok(value.clone()),
     }
 }
 
@@ -335,6 +951,12 @@ fn map_comment(cu_comment: &ClickUpComment) -> Comment {
         created_at: map_timestamp(&cu_comment.date),
         updated_at: None,
         position: None,
+        attachments: cu_comment
+            .comment
+            .iter()
+            .filter_map(|part| part.attachment.as_ref())
+            .map(map_attachment)
+            .collect(),
     }
 }
 
@@ -356,6 +978,13 @@ fn priority_to_clickup(priority: &str) -> Option<u8> {
 #[async_trait]
 impl IssueProvider for ClickUpClient {
     async fn get_issues(&self, filter: IssueFilter) -> Result<Vec<Issue>> {
+        if filter.query.is_some() {
+            return Err(Error::ProviderUnsupported {
+                provider: "clickup".to_string(),
+                operation: "raw issue query".to_string(),
+            });
+        }
+
         let limit = filter.limit.unwrap_or(20) as usize;
         if limit == 0 {
             return Ok(vec![]);
@@ -378,14 +1007,9 @@ impl IssueProvider for ClickUpClient {
         base_params.push(("subtasks", "true".to_string()));
 
         if let Some(assignee) = &filter.assignee {
-            // ClickUp API expects numeric user IDs for assignee filtering,
-            // but IssueFilter.assignee is documented as a username.
-            // Pass through as-is — it will work if the caller provides a user ID.
-            warn!(
-                assignee = assignee.as_str(),
-                "ClickUp assignee filter expects numeric user IDs, not usernames"
-            );
-            base_params.push(("assignees[]", assignee.clone()));
+            // ClickUp filters by numeric user IDs
+            let id = self.resolve_user_id(assignee).await?;
+            base_params.push(("assignees[]", id.to_string()));
         }
 
         if let Some(tags) = &filter.labels {
@@ -409,8 +1033,36 @@ impl IssueProvider for ClickUpClient {
             }
         }
 
+        // A milestone is another list of the folder. Several lists and the
+        // whole workspace are searched through the workspace-level endpoint.
+        let base_url = match (&filter.milestone, filter.container.as_deref()) {
+            (Some(milestone), _) => {
+                let list = self
+                    .milestone_lists("all")
+                    .await?
+                    .into_iter()
+                    .find(|l| l.name.eq_ignore_ascii_case(milestone))
+                    .ok_or_else(|| Error::NotFound(format!("List '{}' not found", milestone)))?;
+                format!("{}/list/{}/task", self.base_url, list.id)
+            }
+            (None, Some("workspace")) => {
+                let team_id = self.require_team_id("search the workspace")?;
+                format!("{}/team/{}/task", self.base_url, team_id)
+            }
+            (None, Some(list_id)) => format!("{}/list/{}/task", self.base_url, list_id),
+            (None, None) if self.lists.is_empty() => {
+                format!("{}/list/{}/task", self.base_url, self.list_id)
+            }
+            (None, None) => {
+                let team_id = self.require_team_id("search several lists")?;
+                for list_id in std::iter::once(&self.list_id).chain(&self.lists) {
+                    base_params.push(("list_ids[]", list_id.clone()));
+                }
+                format!("{}/team/{}/task", self.base_url, team_id)
+            }
+        };
+
         // Fetch all needed pages
-        let base_url = format!("{}/list/{}/task", self.base_url, self.list_id);
         let mut all_tasks: Vec<ClickUpTask> = Vec::new();
 
         for page in start_page..=end_page {
@@ -430,6 +1082,7 @@ impl IssueProvider for ClickUpClient {
         }
 
         let mut issues: Vec<Issue> = all_tasks.iter().map(map_task).collect();
+        link_subtasks(&all_tasks, &mut issues);
 
         // Filter by state client-side if needed
         if let Some(state) = &filter.state {
@@ -459,12 +1112,15 @@ impl IssueProvider for ClickUpClient {
 
     async fn get_issue(&self, key: &str) -> Result<Issue> {
         let url = self.task_url(key)?;
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let url = format!("{}{}include_subtasks=true", url, separator);
         let task: ClickUpTask = self.get(&url).await?;
         Ok(map_task(&task))
     }
 
     async fn create_issue(&self, input: CreateIssueInput) -> Result<Issue> {
-        let url = format!("{}/list/{}/task", self.base_url, self.list_id);
+        let list_id = input.container.as_deref().unwrap_or(&self.list_id);
+        let url = format!("{}/list/{}/task", self.base_url, list_id);
 
         let priority = input.priority.as_deref().and_then(priority_to_clickup);
 
@@ -474,13 +1130,33 @@ impl IssueProvider for ClickUpClient {
             Some(input.labels)
         };
 
+        let custom_fields = self
+            .resolve_custom_fields(&input.custom_fields, false)
+            .await?
+            .into_iter()
+            .filter(|f| !f.value.is_null())
+            .collect();
+
+        let parent = match &input.parent {
+            Some(key) => Some(self.resolve_task_id(key).await?),
+            None => None,
+        };
+
+        let assignees = if input.assignees.is_empty() {
+            None
+        } else {
+            Some(self.resolve_user_ids(&input.assignees).await?)
+        };
+
         let request = CreateTaskRequest {
             name: input.title,
             description: input.description,
             status: None,
             priority,
             tags,
-            assignees: None, // ClickUp expects user IDs, not usernames
+            assignees,
+            parent,
+            custom_fields,
         };
 
         let task: ClickUpTask = self.post(&url, &request).await?;
@@ -523,14 +1199,59 @@ impl IssueProvider for ClickUpClient {
 
         let priority = input.priority.as_deref().and_then(priority_to_clickup);
 
+        // Assignees are changed by adding and removing user IDs
+        let assignees = match &input.assignees {
+            Some(assignees) => {
+                let wanted = self.resolve_user_ids(assignees).await?;
+                let task: ClickUpTask = self.get(&url).await?;
+                let current: Vec<u64> = task.assignees.iter().map(|u| u.id).collect();
+                Some(UpdateAssignees {
+                    add: wanted
+                        .iter()
+                        .filter(|id| !current.contains(id))
+                        .copied()
+                        .collect(),
+                    rem: current
+                        .iter()
+                        .filter(|id| !wanted.contains(id))
+                        .copied()
+                        .collect(),
+                })
+            }
+            None => None,
+        };
+
         let request = UpdateTaskRequest {
             name: input.title,
             description: input.description,
             status,
             priority,
+            time_estimate: None,
+            assignees,
         };
 
+        let custom_fields = self
+            .resolve_custom_fields(&input.custom_fields, true)
+            .await?;
+
         let task: ClickUpTask = self.put(&url, &request).await?;
+        if custom_fields.is_empty() {
+            return Ok(map_task(&task));
+        }
+
+        // Custom fields have their own endpoint, keyed by the raw task ID
+        for field in custom_fields {
+            let field_url = format!("{}/task/{}/field/{}", self.base_url, task.id, field.id);
+            if field.value.is_null() {
+                self.delete(&field_url).await?;
+            } else {
+                let request = SetCustomFieldRequest { value: field.value };
+                let _: Value = self.post(&field_url, &request).await?;
+            }
+        }
+
+        let task_url = format!("{}/task/{}", self.base_url, task.id);
+        let task: ClickUpTask = self.get(&task_url).await?;
         Ok(map_task(&task))
     }
 
@@ -568,31 +1289,323 @@ impl IssueProvider for ClickUpClient {
             created_at: map_timestamp(&response.date),
             updated_at: None,
             position: None,
+            attachments: vec![],
         })
     }
 
-    fn provider_name(&self) -> &'static str {
-        "clickup"
+    async fn get_custom_fields(&self) -> Result<Vec<CustomField>> {
+        let fields = self.get_list_fields().await?;
+        Ok(fields.iter().map(map_custom_field).collect())
     }
-}
 
-#[async_trait]
-impl MergeRequestProvider for ClickUpClient {
-    async fn get_merge_requests(&self, _filter: MrFilter) -> Result<Vec<MergeRequest>> {
-        Err(Error::ProviderUnsupported {
-            provider: "clickup".to_string(),
-            operation: "get_merge_requests".to_string(),
-        })
+    /// Lists the tags of the configured list's space.
+    async fn list_labels(&self) -> Result<Vec<Label>> {
+        let url = format!("{}/list/{}", self.base_url, self.list_id);
+        let list_info: ClickUpListInfo = self.get(&url).await?;
+        let space = list_info
+            .space
+            .ok_or_else(|| Error::InvalidData(format!("List {} has no space", self.list_id)))?;
+
+        let url = format!("{}/space/{}/tag", self.base_url, space.id);
+        let response: ClickUpSpaceTags = self.get(&url).await?;
+        Ok(response
+            .tags
+            .into_iter()
+            .map(|t| Label {
+                name: t.name,
+                color: t.tag_bg,
+                description: None,
+            })
+            .collect())
     }
 
-    async fn get_merge_request(&self, _key: &str) -> Result<MergeRequest> {
-        Err(Error::ProviderUnsupported {
-            provider: "clickup".to_string(),
-            operation: "get_merge_request".to_string(),
-        })
+    /// Lists the statuses of the configured list.
+    async fn list_statuses(&self) -> Result<Vec<IssueStatus>> {
+        let url = format!("{}/list/{}", self.base_url, self.list_id);
+        let list_info: ClickUpListInfo = self.get(&url).await?;
+        Ok(list_info
+            .statuses
+            .into_iter()
+            .map(|s| IssueStatus {
+                category: match s.status_type.as_deref() {
+                    Some("open") => "todo",
+                    Some("done") | Some("closed") => "done",
+                    _ => "in_progress",
+                }
+                .to_string(),
+                name: s.status,
+            })
+            .collect())
     }
 
-    async fn get_discussions(&self, _mr_key: &str) -> Result<Vec<Discussion>> {
+    /// Lists the other lists of the configured list's folder, e.g. sprint lists.
+    async fn get_milestones(&self, state: Option<&str>) -> Result<Vec<Milestone>> {
+        let lists = self.milestone_lists(state.unwrap_or("open")).await?;
+
+        // Counts are best-effort: one failed count leaves them unknown
+        // instead of failing the whole listing
+        let milestones = stream::iter(lists.iter().map(map_list).collect::<Vec<_>>())
+            .map(|mut milestone| async move {
+                match self.count_tasks(&milestone.id).await {
+                    Ok((open, closed)) => {
+                        milestone.open_issues = Some(open);
+                        milestone.closed_issues = Some(closed);
+                    }
+                    Err(e) => {
+                        debug!(error = %e, milestone = %milestone.title, "ClickUp task counts unavailable")
+                    }
+                }
+                milestone
+            })
+            .buffered(COUNT_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(milestones)
+    }
+
+    /// Walks workspace → space → folder → list; a space also holds folderless lists.
+    /// Archived spaces, folders and lists are left out.
+    async fn get_containers(&self, parent: Option<(&str, &str)>) -> Result<Vec<Container>> {
+        let container = |id: &str, name: &str, kind: &str, archived: bool| Container {
+            id: id.to_string(),
+            name: name.to_string(),
+            kind: kind.to_string(),
+            parent_id: parent.map(|(_, id)| id.to_string()),
+            archived,
+        };
+
+        match parent {
+            None => {
+                let response: ClickUpTeams = self.get(&format!("{}/team", self.base_url)).await?;
+                Ok(response
+                    .teams
+                    .iter()
+                    .map(|t| container(&t.id, &t.name, "workspace", false))
+                    .collect())
+            }
+            Some(("workspace", id)) => {
+                let url = format!("{}/team/{}/space?archived=false", self.base_url, id);
+                let response: ClickUpSpaces = self.get(&url).await?;
+                Ok(response
+                    .spaces
+                    .iter()
+                    .map(|s| container(&s.id, &s.name, "space", s.archived))
+                    .collect())
+            }
+            Some(("space", id)) => {
+                let url = format!("{}/space/{}/folder?archived=false", self.base_url, id);
+                let folders: ClickUpFolders = self.get(&url).await?;
+                let url = format!("{}/space/{}/list?archived=false", self.base_url, id);
+                let lists: ClickUpLists = self.get(&url).await?;
+                Ok(folders
+                    .folders
+                    .iter()
+                    .map(|f| container(&f.id, &f.name, "folder", f.archived))
+                    .chain(
+                        lists
+                            .lists
+                            .iter()
+                            .map(|l| container(&l.id, &l.name, "list", l.archived)),
+                    )
+                    .collect())
+            }
+            Some(("folder", id)) => {
+                let url = format!("{}/folder/{}/list?archived=false", self.base_url, id);
+                let response: ClickUpLists = self.get(&url).await?;
+                Ok(response
+                    .lists
+                    .iter()
+                    .map(|l| container(&l.id, &l.name, "list", l.archived))
+                    .collect())
+            }
+            Some((kind, _)) => Err(Error::InvalidData(format!(
+                "Unknown ClickUp container kind '{}', expected workspace, space or folder",
+                kind
+            ))),
+        }
+    }
+
+    async fn download_attachment(&self, url: &str) -> Result<Vec<u8>> {
+        if !is_clickup_url(url) && !is_same_origin(url, &self.base_url) {
+            return Err(Error::InvalidData(format!(
+                "{} is not a ClickUp attachment URL",
+                url
+            )));
+        }
+        self.get_bytes(url).await
+    }
+
+    async fn upload_attachment(
+        &self,
+        issue_key: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<Attachment> {
+        let task_id = self.resolve_task_id(issue_key).await?;
+        let url = format!("{}/task/{}/attachment", self.base_url, task_id);
+        let attachment: ClickUpAttachment = self
+            .post_file(&url, "attachment", file_name, content)
+            .await?;
+        let mut mapped = map_attachment(&attachment);
+        mapped.size = mapped.size.or(Some(content.len() as u64));
+        Ok(mapped)
+    }
+
+    async fn add_checklist_item(
+        &self,
+        issue_key: &str,
+        checklist: Option<&str>,
+        item: &str,
+    ) -> Result<Checklist> {
+        let task: ClickUpTask = self.get(&self.task_url(issue_key)?).await?;
+        let existing = match checklist {
+            Some(name) => task
+                .checklists
+                .iter()
+                .find(|c| c.name.eq_ignore_ascii_case(name)),
+            None => task.checklists.first(),
+        };
+        let checklist_id = match existing {
+            Some(existing) => existing.id.clone(),
+            None => {
+                let url = format!("{}/task/{}/checklist", self.base_url, task.id);
+                let request = CreateChecklistRequest {
+                    name: checklist.unwrap_or("Checklist").to_string(),
+                };
+                let response: ClickUpChecklistResponse = self.post(&url, &request).await?;
+                response.checklist.id
+            }
+        };
+
+        let url = format!(
+            "{}/checklist/{}/checklist_item",
+            self.base_url, checklist_id
+        );
+        let request = CreateChecklistRequest {
+            name: item.to_string(),
+        };
+        let response: ClickUpChecklistResponse = self.post(&url, &request).await?;
+        Ok(map_checklist(&response.checklist))
+    }
+
+    async fn set_checklist_item(
+        &self,
+        issue_key: &str,
+        item: &str,
+        resolved: bool,
+    ) -> Result<Checklist> {
+        let task: ClickUpTask = self.get(&self.task_url(issue_key)?).await?;
+        let (checklist_id, item_id) = task
+            .checklists
+            .iter()
+            .find_map(|c| {
+                c.items
+                    .iter()
+                    .find(|i| i.id == item || i.name.eq_ignore_ascii_case(item))
+                    .map(|i| (c.id.clone(), i.id.clone()))
+            })
+            .ok_or_else(|| {
+                Error::NotFound(format!(
+                    "Checklist item '{}' not found in {}",
+                    item, issue_key
+                ))
+            })?;
+
+        let url = format!(
+            "{}/checklist/{}/checklist_item/{}",
+            self.base_url, checklist_id, item_id
+        );
+        let response: ClickUpChecklistResponse = self
+            .put(&url, &EditChecklistItemRequest { resolved })
+            .await?;
+        Ok(map_checklist(&response.checklist))
+    }
+
+    async fn link_issues(&self, key: &str, target_key: &str, kind: &str) -> Result<()> {
+        let kind = link_kind(kind)?;
+        let task_id = self.resolve_task_id(key).await?;
+        let target_id = self.resolve_task_id(target_key).await?;
+
+        if kind == "relates_to" {
+            let url = format!("{}/task/{}/link/{}", self.base_url, task_id, target_id);
+            let _: Value = self.post(&url, &serde_json::json!({})).await?;
+        } else {
+            let request = if kind == "blocked_by" {
+                AddDependencyRequest {
+                    depends_on: Some(target_id),
+                    ..Default::default()
+                }
+            } else {
+                AddDependencyRequest {
+                    dependency_of: Some(target_id),
+                    ..Default::default()
+                }
+            };
+            let url = format!("{}/task/{}/dependency", self.base_url, task_id);
+            let _: Value = self.post(&url, &request).await?;
+        }
+        Ok(())
+    }
+
+    async fn unlink_issues(&self, key: &str, target_key: &str, kind: Option<&str>) -> Result<()> {
+        let kind = kind.map(link_kind).transpose()?;
+        let task: ClickUpTask = self.get(&self.task_url(key)?).await?;
+        let target_id = self.resolve_task_id(target_key).await?;
+        let target = format!("CU-{}", target_id);
+
+        let links: Vec<IssueLink> = map_task_links(&task)
+            .into_iter()
+            .filter(|link| link.target.key == target)
+            .filter(|link| kind.is_none_or(|k| link.kind == k))
+            .collect();
+        if links.is_empty() {
+            return Err(Error::NotFound(format!(
+                "No link between {} and {}",
+                key, target_key
+            )));
+        }
+
+        for link in links {
+            let url = match link.kind.as_str() {
+                "relates_to" => format!("{}/task/{}/link/{}", self.base_url, task.id, target_id),
+                // The waiting task is always `dependency_of`
+                "blocked_by" => format!(
+                    "{}/task/{}/dependency?depends_on={}&dependency_of={}",
+                    self.base_url, task.id, target_id, task.id
+                ),
+                _ => format!(
+                    "{}/task/{}/dependency?depends_on={}&dependency_of={}",
+                    self.base_url, task.id, task.id, target_id
+                ),
+            };
+            self.delete(&url).await?;
+        }
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "clickup"
+    }
+}
+
+#[async_trait]
+impl MergeRequestProvider for ClickUpClient {
+    async fn get_merge_requests(&self, _filter: MrFilter) -> Result<Vec<MergeRequest>> {
+        Err(Error::ProviderUnsupported {
+            provider: "clickup".to_string(),
+            operation: "get_merge_requests".to_string(),
+        })
+    }
+
+    async fn get_merge_request(&self, _key: &str) -> Result<MergeRequest> {
+        Err(Error::ProviderUnsupported {
+            provider: "clickup".to_string(),
+            operation: "get_merge_request".to_string(),
+        })
+    }
+
+    async fn get_discussions(&self, _mr_key: &str) -> Result<Vec<Discussion>> {
         Err(Error::ProviderUnsupported {
             provider: "clickup".to_string(),
             operation: "get_discussions".to_string(),
@@ -618,24 +1631,123 @@ impl MergeRequestProvider for ClickUpClient {
     }
 }
 
+/// ClickUp has no CI, all pipeline operations are unsupported.
+impl PipelineProvider for ClickUpClient {
+    fn provider_name(&self) -> &'static str {
+        "clickup"
+    }
+}
+
+/// ClickUp has no repositories, all repository operations are unsupported.
+impl RepositoryProvider for ClickUpClient {
+    fn provider_name(&self) -> &'static str {
+        "clickup"
+    }
+}
+
+/// ClickUp sprints are plain lists, all agile operations are unsupported.
+impl AgileProvider for ClickUpClient {
+    fn provider_name(&self) -> &'static str {
+        "clickup"
+    }
+}
+
+#[async_trait]
+impl TimeTrackingProvider for ClickUpClient {
+    async fn log_time(
+        &self,
+        issue_key: &str,
+        duration_seconds: u64,
+        comment: Option<&str>,
+        started_at: Option<&str>,
+    ) -> Result<()> {
+        // Time entries are created per workspace
+        let team_id = self.require_team_id("log time")?;
+        let start = match started_at {
+            Some(started_at) => parse_iso8601(started_at).ok_or_else(|| {
+                Error::InvalidData(format!(
+                    "Invalid start time '{}', expected ISO 8601 such as 2024-01-15T10:00:00Z",
+                    started_at
+                ))
+            })?,
+            None => now_unix() - duration_seconds as i64,
+        };
+
+        let request = CreateTimeEntryRequest {
+            tid: self.resolve_task_id(issue_key).await?,
+            start: start * 1000,
+            duration: duration_seconds * 1000,
+            description: comment.map(str::to_string),
+        };
+        let url = format!("{}/team/{}/time_entries", self.base_url, team_id);
+        let _: Value = self.post(&url, &request).await?;
+        Ok(())
+    }
+
+    /// Uses the task's tracked time, which covers all users but has no
+    /// descriptions; the workspace time entry list only covers the caller.
+    async fn get_time_entries(&self, issue_key: &str) -> Result<Vec<TimeEntry>> {
+        let task_id = self.resolve_task_id(issue_key).await?;
+        let url = format!("{}/task/{}/time", self.base_url, task_id);
+        let response: ClickUpTrackedTimeList = self.get(&url).await?;
+
+        Ok(response
+            .data
+            .iter()
+            .flat_map(|tracked| {
+                tracked.intervals.iter().map(|interval| TimeEntry {
+                    id: interval.id.clone(),
+                    author: map_user(tracked.user.as_ref()),
+                    duration_seconds: lenient_number(interval.time.as_ref()).unwrap_or(0) / 1000,
+                    started_at: lenient_number(interval.start.as_ref())
+                        .and_then(|ms| epoch_ms_to_iso8601(&ms.to_string())),
+                    comment: None,
+                })
+            })
+            .collect())
+    }
+
+    async fn set_time_estimate(&self, issue_key: &str, estimate_seconds: u64) -> Result<()> {
+        let request = UpdateTaskRequest {
+            name: None,
+            description: None,
+            status: None,
+            priority: None,
+            time_estimate: Some(estimate_seconds * 1000),
+            assignees: None,
+        };
+        let _: ClickUpTask = self.put(&self.task_url(issue_key)?, &request).await?;
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "clickup"
+    }
+}
+
 #[async_trait]
 impl Provider for ClickUpClient {
     async fn get_current_user(&self) -> Result<User> {
-        // ClickUp v2 API does not have a /user/me endpoint.
-        // Verify the token by fetching the first page of tasks with a minimal request.
-        let url = format!(
-            "{}/list/{}/task?page=0&subtasks=false",
-            self.base_url, self.list_id
-        );
-        let _: ClickUpTaskList = self.get(&url).await?;
+        let url = format!("{}/user", self.base_url);
+        let response: ClickUpAuthorizedUser = self.get(&url).await?;
+        Ok(map_member(&response.user))
+    }
 
-        // Token is valid — return a synthetic user
-        Ok(User {
-            id: "clickup".to_string(),
-            username: "clickup-user".to_string(),
-            name: Some("ClickUp User".to_string()),
-            ..Default::default()
-        })
+    /// `CU-{id}` keys, plus custom task IDs (e.g. `DEV-42`) when the workspace is known.
+    fn key_patterns(&self) -> Vec<KeyPattern> {
+        let mut patterns = vec![
+            KeyPattern::Prefix("CU-".to_string()),
+            KeyPattern::Url(TASK_WEB_URL.to_string()),
+        ];
+        if self.team_id.is_some() {
+            patterns.push(KeyPattern::Fallback);
+        }
+        patterns
+    }
+
+    /// Search the workspace members.
+    async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        Ok(search_members(&self.members().await?, query))
     }
 }
 
@@ -672,6 +1784,23 @@ mod tests {
         assert_eq!(epoch_ms_to_iso8601("not_a_number"), None);
     }
 
+    #[test]
+    fn test_key_patterns() {
+        let client =
+            ClickUpClient::with_base_url("https://api.clickup.com/api/v2", "12345", "token");
+        assert_eq!(
+            client.key_patterns(),
+            vec![
+                KeyPattern::Prefix("CU-".to_string()),
+                KeyPattern::Url(TASK_WEB_URL.to_string()),
+            ]
+        );
+
+        // Custom task IDs need the workspace
+        let client = client.with_team_id("9876");
+        assert!(client.key_patterns().contains(&KeyPattern::Fallback));
+    }
+
     #[test]
     fn test_task_url_cu_prefix() {
         let client =
@@ -692,6 +1821,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_task_url_from_link() {
+        let client =
+            ClickUpClient::with_base_url("https://api.clickup.com/api/v2", "12345", "token")
+                .with_team_id("9876");
+        assert_eq!(
+            client.task_url("https://app.clickup.com/t/abc123").unwrap(),
+            "https://api.clickup.com/api/v2/task/abc123"
+        );
+        assert_eq!(
+            client
+                .task_url("https://app.clickup.com/t/9876/DEV-42?comment=1")
+                .unwrap(),
+            "https://api.clickup.com/api/v2/task/DEV-42?custom_task_ids=true&team_id=9876"
+        );
+    }
+
     #[test]
     fn test_task_url_custom_id_without_team() {
         let client =
@@ -735,6 +1881,15 @@ mod tests {
             url: "https://app.clickup.com/t/abc123".to_string(),
             date_created: Some("1704067200000".to_string()),
             date_updated: Some("1704153600000".to_string()),
+            custom_fields: vec![],
+            parent: None,
+            subtasks: vec![],
+            dependencies: vec![],
+            linked_tasks: vec![],
+            attachments: vec![],
+            time_estimate: None,
+            time_spent: None,
+            checklists: vec![],
         };
 
         let issue = map_task(&task);
@@ -777,6 +1932,15 @@ mod tests {
             url: "https://app.clickup.com/t/abc123".to_string(),
             date_created: None,
             date_updated: None,
+            custom_fields: vec![],
+            parent: None,
+            subtasks: vec![],
+            dependencies: vec![],
+            linked_tasks: vec![],
+            attachments: vec![],
+            time_estimate: None,
+            time_spent: None,
+            checklists: vec![],
         };
 
         let issue = map_task(&task);
@@ -802,6 +1966,15 @@ mod tests {
             url: "https://app.clickup.com/t/abc123".to_string(),
             date_created: None,
             date_updated: None,
+            custom_fields: vec![],
+            parent: None,
+            subtasks: vec![],
+            dependencies: vec![],
+            linked_tasks: vec![],
+            attachments: vec![],
+            time_estimate: None,
+            time_spent: None,
+            checklists: vec![],
         };
 
         let issue = map_task(&task);
@@ -891,6 +2064,7 @@ mod tests {
                 profile_picture: None,
             }),
             date: Some("1705312800000".to_string()),
+            comment: vec![],
         };
 
         let comment = map_comment(&cu_comment);
@@ -979,6 +2153,15 @@ mod tests {
             url: "https://app.clickup.com/t/abc".to_string(),
             date_created: None,
             date_updated: None,
+            custom_fields: vec![],
+            parent: None,
+            subtasks: vec![],
+            dependencies: vec![],
+            linked_tasks: vec![],
+            attachments: vec![],
+            time_estimate: None,
+            time_spent: None,
+            checklists: vec![],
         };
 
         let issue = map_task(&task);
@@ -1004,12 +2187,315 @@ mod tests {
             url: "https://app.clickup.com/t/abc".to_string(),
             date_created: None,
             date_updated: None,
+            custom_fields: vec![],
+            parent: None,
+            subtasks: vec![],
+            dependencies: vec![],
+            linked_tasks: vec![],
+            attachments: vec![],
+            time_estimate: None,
+            time_spent: None,
+            checklists: vec![],
         };
 
         let issue = map_task(&task);
         assert_eq!(issue.state, "open");
     }
 
+    #[test]
+    fn test_map_task_links() {
+        let task: ClickUpTask = serde_json::from_value(serde_json::json!({
+            "id": "abc123",
+            "name": "Task",
+            "status": {"status": "open", "type": "open"},
+            "url": "https://app.clickup.com/t/abc123",
+            "dependencies": [
+                {"task_id": "abc123", "depends_on": "api1", "type": 1},
+                {"task_id": "ui1", "depends_on": "abc123", "type": 0}
+            ],
+            "linked_tasks": [
+                {"task_id": "abc123", "link_id": "doc1"},
+                {"task_id": "spec1", "link_id": "abc123"}
+            ]
+        }))
+        .unwrap();
+
+        let links: Vec<(String, String)> = map_task(&task)
+            .links
+            .into_iter()
+            .map(|l| (l.kind, l.target.key))
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                ("blocked_by".to_string(), "CU-api1".to_string()),
+                ("blocks".to_string(), "CU-ui1".to_string()),
+                ("relates_to".to_string(), "CU-doc1".to_string()),
+                ("relates_to".to_string(), "CU-spec1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_task_checklists() {
+        let task: ClickUpTask = serde_json::from_value(serde_json::json!({
+            "id": "abc123",
+            "name": "Task",
+            "status": {"status": "open", "type": "open"},
+            "url": "https://app.clickup.com/t/abc123",
+            "checklists": [{
+                "id": "cl1",
+                "task_id": "abc123",
+                "name": "Acceptance criteria",
+                "resolved": 1,
+                "unresolved": 1,
+                "items": [
+                    {"id": "it1", "name": "API returns 201", "resolved": true, "assignee": null},
+                    {"id": "it2", "name": "Docs updated", "resolved": false,
+                     "assignee": {"id": 7, "username": "jdoe"}}
+                ]
+            }]
+        }))
+        .unwrap();
+
+        let checklists = map_task(&task).checklists;
+        assert_eq!(checklists.len(), 1);
+        assert_eq!(checklists[0].name, "Acceptance criteria");
+        assert_eq!(checklists[0].items[0].name, "API returns 201");
+        assert!(checklists[0].items[0].resolved);
+        assert!(!checklists[0].items[1].resolved);
+        assert_eq!(
+            checklists[0].items[1].assignee.as_ref().unwrap().username,
+            "jdoe"
+        );
+    }
+
+    #[test]
+    fn test_map_task_time_tracking() {
+        let task: ClickUpTask = serde_json::from_value(serde_json::json!({
+            "id": "abc123",
+            "name": "Task",
+            "status": {"status": "open", "type": "open"},
+            "url": "https://app.clickup.com/t/abc123",
+            "time_estimate": 7200000,
+            "time_spent": "5400000"
+        }))
+        .unwrap();
+
+        let issue = map_task(&task);
+        assert_eq!(issue.estimate_seconds, Some(7200));
+        assert_eq!(issue.time_spent_seconds, Some(5400));
+    }
+
+    #[test]
+    fn test_map_task_attachments() {
+        let task: ClickUpTask = serde_json::from_value(serde_json::json!({
+            "id": "abc123",
+            "name": "Task",
+            "status": {"status": "open", "type": "open"},
+            "url": "https://app.clickup.com/t/abc123",
+            "attachments": [
+            {
+                "id": "att1",
+                "title": "screenshot.png",
+                "url": "https://t12345.p.clickup-attachments.com/t12345/att1/screenshot.png",
+                "size": "2048",
+                "date": 1705312800000_i64
+            },
+            {
+                "id": "att2",
+                "title": "report",
+                "url": "https://t12345.p.clickup-attachments.com/t12345/att2/report",
+                "size": 10,
+                "mimetype": "application/pdf"
+            }
+            ]
+        }))
+        .unwrap();
+
+        let attachments = map_task(&task).attachments;
+        assert_eq!(attachments.len(), 2);
+        assert_eq!(attachments[0].name, "screenshot.png");
+        assert_eq!(attachments[0].size, Some(2048));
+        assert_eq!(attachments[0].mime_type.as_deref(), Some("image/png"));
+        assert_eq!(
+            attachments[0].created_at.as_deref(),
+            Some("2024-01-15T10:00:00Z")
+        );
+        assert_eq!(attachments[1].size, Some(10));
+        assert_eq!(attachments[1].mime_type.as_deref(), Some("application/pdf"));
+    }
+
+    #[test]
+    fn test_is_clickup_url() {
+        assert!(is_clickup_url(
+            "https://t12345.p.clickup-attachments.com/t12345/a/b.png"
+        ));
+        assert!(is_clickup_url("https://api.clickup.com/api/v2/x"));
+        assert!(!is_clickup_url("https://clickup.com.evil.io/x"));
+        assert!(!is_clickup_url("/relative/path"));
+    }
+
+    #[test]
+    fn test_link_subtasks() {
+        let tasks: Vec<ClickUpTask> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "parent1",
+                "custom_id": "DEV-1",
+                "name": "Parent",
+                "status": {"status": "open", "type": "open"},
+                "url": "https://app.clickup.com/t/parent1"
+            },
+            {
+                "id": "child1",
+                "name": "Child",
+                "status": {"status": "done", "type": "closed"},
+                "url": "https://app.clickup.com/t/child1",
+                "parent": "parent1"
+            },
+            {
+                "id": "orphan1",
+                "name": "Orphan",
+                "status": {"status": "open", "type": "open"},
+                "url": "https://app.clickup.com/t/orphan1",
+                "parent": "elsewhere"
+            }
+        ]))
+        .unwrap();
+        let mut issues: Vec<Issue> = tasks.iter().map(map_task).collect();
+
+        link_subtasks(&tasks, &mut issues);
+
+        assert_eq!(issues[0].issue_type.as_deref(), Some("task"));
+        assert_eq!(
+            issues[0].children,
+            vec![IssueRef {
+                key: "CU-child1".to_string(),
+                title: Some("Child".to_string()),
+                state: Some("closed".to_string()),
+                issue_type: Some("subtask".to_string()),
+            }]
+        );
+        assert_eq!(issues[1].parent.as_ref().unwrap().key, "DEV-1");
+        assert_eq!(
+            issues[1].parent.as_ref().unwrap().title.as_deref(),
+            Some("Parent")
+        );
+        assert_eq!(issues[2].parent.as_ref().unwrap().key, "CU-elsewhere");
+        assert_eq!(issues[2].parent.as_ref().unwrap().title, None);
+    }
+
+    fn sample_custom_fields() -> Vec<ClickUpCustomField> {
+        serde_json::from_value(serde_json::json!([
+            {
+                "id": "f-priority",
+                "name": "Severity",
+                "type": "drop_down",
+                "type_config": {"options": [
+                    {"id": "opt-low", "name": "Low", "orderindex": 0},
+                    {"id": "opt-high", "name": "High", "orderindex": 1}
+                ]},
+                "required": true
+            },
+            {
+                "id": "f-areas",
+                "name": "Areas",
+                "type": "labels",
+                "type_config": {"options": [
+                    {"id": "lbl-api", "label": "API"},
+                    {"id": "lbl-ui", "label": "UI"}
+                ]}
+            },
+            {"id": "f-points", "name": "Points", "type": "number"},
+            {"id": "f-due", "name": "Release date", "type": "date"},
+            {"id": "f-reviewers", "name": "Reviewers", "type": "users"},
+            {"id": "f-done", "name": "Verified", "type": "checkbox"}
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_map_custom_field() {
+        let fields = sample_custom_fields();
+
+        let severity = map_custom_field(&fields[0]);
+        assert_eq!(severity.field_type, "option");
+        assert_eq!(severity.options, vec!["Low", "High"]);
+        assert!(severity.required);
+
+        let areas = map_custom_field(&fields[1]);
+        assert_eq!(areas.field_type, "multi_option");
+        assert_eq!(areas.options, vec!["API", "UI"]);
+        assert!(!areas.required);
+
+        assert_eq!(map_custom_field(&fields[4]).field_type, "multi_user");
+    }
+
+    #[test]
+    fn test_map_custom_field_values() {
+        let mut fields = sample_custom_fields();
+        fields[0].value = Some(serde_json::json!(1));
+        fields[1].value = Some(serde_json::json!(["lbl-ui"]));
+        fields[2].value = Some(serde_json::json!("8"));
+        fields[3].value = Some(serde_json::json!("1705276800000"));
+        fields[4].value = Some(serde_json::json!([{"id": 1, "username": "dev1"}]));
+        fields[5].value = None;
+
+        let values = map_custom_fields(&fields);
+
+        assert_eq!(values.len(), 5);
+        assert_eq!(values["Severity"], serde_json::json!("High"));
+        assert_eq!(values["Areas"], serde_json::json!(["UI"]));
+        assert_eq!(values["Points"], serde_json::json!(8));
+        assert_eq!(values["Release date"], serde_json::json!("2024-01-15"));
+        assert_eq!(values["Reviewers"], serde_json::json!(["dev1"]));
+    }
+
+    #[test]
+    fn test_custom_field_to_clickup() {
+        let fields = sample_custom_fields();
+
+        assert_eq!(
+            custom_field_to_clickup(&fields[0], &serde_json::json!("high"), false).unwrap(),
+            serde_json::json!("opt-high")
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[1], &serde_json::json!(["API", "UI"]), false).unwrap(),
+            serde_json::json!(["lbl-api", "lbl-ui"])
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[2], &serde_json::json!("3"), false).unwrap(),
+            serde_json::json!(3)
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[3], &serde_json::json!("2024-01-15"), false).unwrap(),
+            serde_json::json!(1_705_276_800_000i64)
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[4], &serde_json::json!(["42"]), false).unwrap(),
+            serde_json::json!([42])
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[4], &serde_json::json!("42"), true).unwrap(),
+            serde_json::json!({"add": [42]})
+        );
+        assert_eq!(
+            custom_field_to_clickup(&fields[5], &serde_json::json!("yes"), false).unwrap(),
+            serde_json::json!(true)
+        );
+
+        let err = custom_field_to_clickup(&fields[0], &serde_json::json!("Blocker"), false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("no option 'Blocker'"), "{}", err);
+        assert!(err.contains("Low, High"), "{}", err);
+
+        let err = custom_field_to_clickup(&fields[4], &serde_json::json!("dev1"), false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("expects a list of user IDs"), "{}", err);
+    }
+
     // =========================================================================
     // Integration tests with httpmock
     // =========================================================================
@@ -1084,6 +2570,21 @@ mod tests {
             })
         }
 
+        #[tokio::test]
+        async fn test_get_issues_with_query_unsupported() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client
+                .get_issues(IssueFilter {
+                    query: Some("status=open".to_string()),
+                    ..Default::default()
+                })
+                .await;
+
+            assert!(matches!(result, Err(Error::ProviderUnsupported { .. })));
+        }
+
         #[tokio::test]
         async fn test_get_issues() {
             let server = MockServer::start();
@@ -1356,27 +2857,847 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn test_get_issue_custom_id_without_team_fails() {
-            let client = ClickUpClient::new("12345", "token");
-            let result = client.get_issue("DEV-42").await;
-            assert!(result.is_err());
-        }
-
-        #[tokio::test]
-        async fn test_create_issue_with_custom_id_retry() {
+        async fn test_get_issue_with_subtasks() {
             let server = MockServer::start();
 
-            // POST returns task without custom_id
             server.mock(|when, then| {
-                when.method(POST)
-                    .path("/list/12345/task")
-                    .body_includes("\"name\":\"New Task\"");
-                then.status(200).json_body(sample_task_json());
+                when.method(GET)
+                    .path("/task/abc123")
+                    .query_param("include_subtasks", "true");
+                let mut task = sample_task_json();
+                task["subtasks"] = serde_json::json!([{
+                    "id": "sub1",
+                    "name": "Subtask",
+                    "status": {"status": "open", "type": "open"},
+                    "url": "https://app.clickup.com/t/sub1",
+                    "parent": "abc123"
+                }]);
+                then.status(200).json_body(task);
             });
 
-            // GET retry returns task with custom_id
-            let mut task_with_custom_id = sample_task_json();
-            task_with_custom_id["custom_id"] = serde_json::json!("DEV-100");
+            let client = create_test_client(&server);
+            let issue = client.get_issue("CU-abc123").await.unwrap();
+
+            assert_eq!(issue.children.len(), 1);
+            assert_eq!(issue.children[0].key, "CU-sub1");
+            assert_eq!(issue.children[0].issue_type.as_deref(), Some("subtask"));
+        }
+
+        #[tokio::test]
+        async fn test_create_subtask_of_custom_id_parent() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/task/DEV-42")
+                    .query_param("custom_task_ids", "true");
+                then.status(200)
+                    .json_body(sample_task_with_custom_id_json());
+            });
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/list/12345/task")
+                    .body_includes("\"parent\":\"abc123\"");
+                let mut task = sample_task_with_custom_id_json();
+                task["id"] = serde_json::json!("sub1");
+                task["parent"] = serde_json::json!("abc123");
+                then.status(200).json_body(task);
+            });
+
+            let client = create_test_client_with_team(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "Subtask".to_string(),
+                    parent: Some("DEV-42".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+            assert_eq!(issue.parent.unwrap().key, "CU-abc123");
+        }
+
+        #[tokio::test]
+        async fn test_link_issues() {
+            let server = MockServer::start();
+
+            let dependency_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/task/abc123/dependency")
+                    .json_body(serde_json::json!({"depends_on": "def456"}));
+                then.status(200).json_body(serde_json::json!({}));
+            });
+
+            let link_mock = server.mock(|when, then| {
+                when.method(POST).path("/task/abc123/link/def456");
+                then.status(200)
+                    .json_body(serde_json::json!({"task": sample_task_json()}));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .link_issues("CU-abc123", "CU-def456", "blocked_by")
+                .await
+                .unwrap();
+            client
+                .link_issues("CU-abc123", "CU-def456", "relates_to")
+                .await
+                .unwrap();
+
+            dependency_mock.assert();
+            link_mock.assert();
+
+            // ClickUp's own dependency names
+            let blocking_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/task/abc123/dependency")
+                    .json_body(serde_json::json!({"dependency_of": "def456"}));
+                then.status(200).json_body(serde_json::json!({}));
+            });
+            client
+                .link_issues("CU-abc123", "CU-def456", "blocking")
+                .await
+                .unwrap();
+            client
+                .link_issues("CU-abc123", "CU-def456", "waiting_on")
+                .await
+                .unwrap();
+            blocking_mock.assert();
+            dependency_mock.assert_calls(2);
+
+            let err = client
+                .link_issues("CU-abc123", "CU-def456", "duplicates")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("Unsupported ClickUp link kind"));
+        }
+
+        #[tokio::test]
+        async fn test_unlink_issues() {
+            let server = MockServer::start();
+
+            let mut task = sample_task_json();
+            task["dependencies"] =
+                serde_json::json!([{"task_id": "ui1", "depends_on": "abc123", "type": 0}]);
+            task["linked_tasks"] = serde_json::json!([{"task_id": "abc123", "link_id": "doc1"}]);
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123");
+                then.status(200).json_body(task);
+            });
+
+            let delete_mock = server.mock(|when, then| {
+                when.method(DELETE)
+                    .path("/task/abc123/dependency")
+                    .query_param("depends_on", "abc123")
+                    .query_param("dependency_of", "ui1");
+                then.status(200).json_body(serde_json::json!({}));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .unlink_issues("CU-abc123", "CU-ui1", None)
+                .await
+                .unwrap();
+            delete_mock.assert();
+
+            let err = client
+                .unlink_issues("CU-abc123", "CU-doc1", Some("blocks"))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        fn sample_checklist_json(resolved: bool) -> serde_json::Value {
+            serde_json::json!({
+                "id": "cl1",
+                "name": "Acceptance criteria",
+                "items": [
+                    {"id": "it1", "name": "API returns 201", "resolved": resolved}
+                ]
+            })
+        }
+
+        #[tokio::test]
+        async fn test_add_checklist_item() {
+            let server = MockServer::start();
+
+            let mut task = sample_task_json();
+            task["checklists"] = serde_json::json!([sample_checklist_json(false)]);
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123");
+                then.status(200).json_body(task);
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/task/def456");
+                then.status(200).json_body(sample_task_json());
+            });
+
+            let item_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/checklist/cl1/checklist_item")
+                    .json_body(serde_json::json!({"name": "API returns 201"}));
+                then.status(200)
+                    .json_body(serde_json::json!({"checklist": sample_checklist_json(false)}));
+            });
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/task/abc123/checklist")
+                    .json_body(serde_json::json!({"name": "Checklist"}));
+                then.status(200).json_body(serde_json::json!({
+                    "checklist": {"id": "cl1", "name": "Checklist", "items": []}
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let checklist = client
+                .add_checklist_item("CU-abc123", Some("acceptance criteria"), "API returns 201")
+                .await
+                .unwrap();
+            assert_eq!(checklist.items.len(), 1);
+            create_mock.assert_calls(0);
+
+            // A task without checklists gets a default one (the mocked task is abc123)
+            client
+                .add_checklist_item("CU-def456", None, "API returns 201")
+                .await
+                .unwrap();
+            create_mock.assert();
+            item_mock.assert_calls(2);
+        }
+
+        #[tokio::test]
+        async fn test_set_checklist_item() {
+            let server = MockServer::start();
+
+            let mut task = sample_task_json();
+            task["checklists"] = serde_json::json!([sample_checklist_json(false)]);
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123");
+                then.status(200).json_body(task);
+            });
+
+            let check_mock = server.mock(|when, then| {
+                when.method(PUT)
+                    .path("/checklist/cl1/checklist_item/it1")
+                    .json_body(serde_json::json!({"resolved": true}));
+                then.status(200)
+                    .json_body(serde_json::json!({"checklist": sample_checklist_json(true)}));
+            });
+
+            let client = create_test_client(&server);
+            let checklist = client
+                .set_checklist_item("CU-abc123", "api returns 201", true)
+                .await
+                .unwrap();
+            check_mock.assert();
+            assert!(checklist.items[0].resolved);
+
+            let err = client
+                .set_checklist_item("CU-abc123", "Missing", true)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        #[tokio::test]
+        async fn test_download_attachment() {
+            let server = MockServer::start();
+
+            let download_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/files/att1/log.txt")
+                    .header("Authorization", "pk_test_token");
+                then.status(200).body("line 1\nline 2");
+            });
+
+            let client = create_test_client(&server);
+            let content = client
+                .download_attachment(&format!("{}/files/att1/log.txt", server.base_url()))
+                .await
+                .unwrap();
+
+            download_mock.assert();
+            assert_eq!(content, b"line 1\nline 2");
+
+            let err = client
+                .download_attachment("https://example.com/files/att1/log.txt")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("not a ClickUp attachment URL"));
+        }
+
+        #[tokio::test]
+        async fn test_upload_attachment() {
+            let server = MockServer::start();
+
+            let upload_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/task/abc123/attachment")
+                    .header(
+                        "Content-Type",
+                        "multipart/form-data; boundary=devboy-tools-attachment-7d3b0c4f",
+                    )
+                    .body_includes("name=\"attachment\"; filename=\"trace.log\"");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "att9",
+                    "title": "trace.log",
+                    "url": "https://t12345.p.clickup-attachments.com/t12345/att9/trace.log",
+                    "date": 1705312800000_i64
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let attachment = client
+                .upload_attachment("CU-abc123", "trace.log", b"panic at line 3")
+                .await
+                .unwrap();
+
+            upload_mock.assert();
+            assert_eq!(attachment.id, "att9");
+            assert_eq!(attachment.name, "trace.log");
+            assert_eq!(attachment.size, Some(15));
+            assert_eq!(attachment.mime_type.as_deref(), Some("text/plain"));
+        }
+
+        fn mock_sprint_folder(server: &MockServer) {
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "12345",
+                    "name": "Backlog",
+                    "statuses": [],
+                    "folder": {"id": "456", "name": "Sprints", "hidden": false},
+                    "space": {"id": "789"}
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/folder/456/list")
+                    .query_param("archived", "false");
+                then.status(200).json_body(serde_json::json!({
+                    "lists": [
+                        {"id": "12345", "name": "Backlog", "archived": false},
+                        {
+                            "id": "12346",
+                            "name": "Sprint 5",
+                            "content": "Ship 2.4",
+                            "start_date": "1706745600000",
+                            "due_date": "1709251200000",
+                            "archived": false
+                        }
+                    ]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/folder/456/list")
+                    .query_param("archived", "true");
+                then.status(200).json_body(serde_json::json!({"lists": []}));
+            });
+        }
+
+        #[tokio::test]
+        async fn test_get_milestones() {
+            let server = MockServer::start();
+            mock_sprint_folder(&server);
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/list/12345/task")
+                    .query_param("include_closed", "true");
+                then.status(200).json_body(serde_json::json!({"tasks": []}));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/list/12346/task")
+                    .query_param("include_closed", "true");
+                then.status(200).json_body(serde_json::json!({"tasks": [
+                    {
+                        "id": "t1",
+                        "name": "Open task",
+                        "status": {"status": "to do", "type": "open"},
+                        "url": "https://app.clickup.com/t/t1"
+                    },
+                    {
+                        "id": "t2",
+                        "name": "Done task",
+                        "status": {"status": "complete", "type": "closed"},
+                        "url": "https://app.clickup.com/t/t2"
+                    }
+                ]}));
+            });
+
+            let client = create_test_client(&server);
+            let milestones = client.get_milestones(None).await.unwrap();
+
+            assert_eq!(milestones.len(), 2);
+            let sprint = &milestones[1];
+            assert_eq!(sprint.id, "12346");
+            assert_eq!(sprint.title, "Sprint 5");
+            assert_eq!(sprint.kind, "list");
+            assert_eq!(sprint.description.as_deref(), Some("Ship 2.4"));
+            assert_eq!(sprint.start_date.as_deref(), Some("2024-02-01"));
+            assert_eq!(sprint.due_date.as_deref(), Some("2024-03-01"));
+            assert_eq!(sprint.open_issues, Some(1));
+            assert_eq!(sprint.closed_issues, Some(1));
+            assert_eq!(milestones[0].open_issues, Some(0));
+        }
+
+        #[tokio::test]
+        async fn test_get_milestones_with_failed_count() {
+            let server = MockServer::start();
+            mock_sprint_folder(&server);
+
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345/task");
+                then.status(500).body("Internal Server Error");
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12346/task");
+                then.status(200).json_body(serde_json::json!({"tasks": []}));
+            });
+
+            let client = create_test_client(&server);
+            let milestones = client.get_milestones(None).await.unwrap();
+
+            assert_eq!(milestones.len(), 2);
+            assert_eq!(milestones[0].open_issues, None);
+            assert_eq!(milestones[0].closed_issues, None);
+            assert_eq!(milestones[1].open_issues, Some(0));
+        }
+
+        #[tokio::test]
+        async fn test_list_labels_and_statuses() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345");
+                then.status(200).json_body(serde_json::json!({
+                    "statuses": [
+                        {"status": "to do", "type": "open"},
+                        {"status": "in review", "type": "custom"},
+                        {"status": "complete", "type": "closed"}
+                    ],
+                    "space": {"id": "789"}
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/space/789/tag");
+                then.status(200).json_body(serde_json::json!({
+                    "tags": [{"name": "backend", "tag_fg": "#fff", "tag_bg": "#7C4DFF"}]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let labels = client.list_labels().await.unwrap();
+            assert_eq!(labels.len(), 1);
+            assert_eq!(labels[0].name, "backend");
+            assert_eq!(labels[0].color.as_deref(), Some("#7C4DFF"));
+
+            let statuses = client.list_statuses().await.unwrap();
+            let categories: Vec<&str> = statuses.iter().map(|s| s.category.as_str()).collect();
+            assert_eq!(categories, vec!["todo", "in_progress", "done"]);
+            assert_eq!(statuses[1].name, "in review");
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_with_milestone() {
+            let server = MockServer::start();
+            mock_sprint_folder(&server);
+
+            let tasks_mock = server.mock(|when, then| {
+                when.method(GET).path("/list/12346/task");
+                then.status(200)
+                    .json_body(serde_json::json!({"tasks": [sample_task_json()]}));
+            });
+
+            let client = create_test_client(&server);
+            let issues = client
+                .get_issues(IssueFilter {
+                    milestone: Some("sprint 5".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            tasks_mock.assert();
+            assert_eq!(issues.len(), 1);
+
+            let result = client
+                .get_issues(IssueFilter {
+                    milestone: Some("Sprint 9".to_string()),
+                    ..Default::default()
+                })
+                .await;
+            assert!(matches!(result, Err(Error::NotFound(_))));
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_from_several_lists() {
+            let server = MockServer::start();
+
+            let tasks_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/team/9876/task")
+                    .query_param("list_ids[]", "12345")
+                    .query_param("list_ids[]", "777")
+                    .query_param("page", "0");
+                then.status(200)
+                    .json_body(serde_json::json!({"tasks": [sample_task_json()]}));
+            });
+
+            let client = create_test_client_with_team(&server).with_lists(vec!["777".to_string()]);
+            let issues = client.get_issues(IssueFilter::default()).await.unwrap();
+
+            tasks_mock.assert();
+            assert_eq!(issues.len(), 1);
+
+            // The workspace-level endpoint needs the team
+            let client = create_test_client(&server).with_lists(vec!["777".to_string()]);
+            let result = client.get_issues(IssueFilter::default()).await;
+            assert!(matches!(result, Err(Error::Config(_))));
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_with_container() {
+            let server = MockServer::start();
+
+            let workspace_mock = server.mock(|when, then| {
+                when.method(GET).path("/team/9876/task");
+                then.status(200)
+                    .json_body(serde_json::json!({"tasks": [sample_task_json()]}));
+            });
+            let list_mock = server.mock(|when, then| {
+                when.method(GET).path("/list/555/task");
+                then.status(200).json_body(serde_json::json!({"tasks": []}));
+            });
+
+            let client = create_test_client_with_team(&server);
+            let issues = client
+                .get_issues(IssueFilter {
+                    container: Some("workspace".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            assert_eq!(issues.len(), 1);
+
+            let issues = client
+                .get_issues(IssueFilter {
+                    container: Some("555".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            assert!(issues.is_empty());
+
+            workspace_mock.assert();
+            list_mock.assert();
+        }
+
+        fn mock_team_members(server: &MockServer) -> httpmock::Mock<'_> {
+            server.mock(|when, then| {
+                when.method(GET).path("/team");
+                then.status(200).json_body(serde_json::json!({
+                    "teams": [{
+                        "id": "9876",
+                        "name": "Acme",
+                        "members": [
+                            {"user": {"id": 1, "username": "John Doe", "email": "john@example.com"}},
+                            {"user": {"id": 2, "username": "Jane Doe", "email": "jane@example.com"}},
+                            {"user": {"id": 3, "username": null, "email": "invited@example.com"}}
+                        ]
+                    }]
+                }));
+            })
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_resolves_assignee() {
+            let server = MockServer::start();
+            let team_mock = mock_team_members(&server);
+
+            let tasks_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/list/12345/task")
+                    .query_param("assignees[]", "2");
+                then.status(200).json_body(serde_json::json!({"tasks": []}));
+            });
+
+            let client = create_test_client(&server);
+            for assignee in ["jane@example.com", "@jane doe"] {
+                client
+                    .get_issues(IssueFilter {
+                        assignee: Some(assignee.to_string()),
+                        ..Default::default()
+                    })
+                    .await
+                    .unwrap();
+            }
+
+            tasks_mock.assert_calls(2);
+            // Members are loaded once
+            team_mock.assert();
+
+            let err = client
+                .get_issues(IssueFilter {
+                    assignee: Some("doe".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("matches several users"));
+            assert!(err.contains("John Doe (john@example.com, id 1)"));
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_assigned_to_me() {
+            let server = MockServer::start();
+
+            let user_mock = server.mock(|when, then| {
+                when.method(GET).path("/user");
+                then.status(200)
+                    .json_body(serde_json::json!({"user": {"id": 2, "username": "Jane Doe"}}));
+            });
+            let tasks_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/list/12345/task")
+                    .query_param("assignees[]", "2");
+                then.status(200).json_body(serde_json::json!({"tasks": []}));
+            });
+
+            let client = create_test_client(&server);
+            for _ in 0..2 {
+                client
+                    .get_issues(IssueFilter {
+                        assignee: Some("@me".to_string()),
+                        ..Default::default()
+                    })
+                    .await
+                    .unwrap();
+            }
+
+            tasks_mock.assert_calls(2);
+            user_mock.assert_calls(1);
+        }
+
+        #[tokio::test]
+        async fn test_update_issue_assignees() {
+            let server = MockServer::start();
+            mock_team_members(&server);
+
+            let mut task = sample_task_json();
+            task["assignees"] = serde_json::json!([{"id": 1, "username": "John Doe"}]);
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123");
+                then.status(200).json_body(task);
+            });
+            let update_mock = server.mock(|when, then| {
+                when.method(PUT)
+                    .path("/task/abc123")
+                    .json_body(serde_json::json!({"assignees": {"add": [2], "rem": [1]}}));
+                then.status(200).json_body(sample_task_json());
+            });
+
+            let client = create_test_client(&server);
+            client
+                .update_issue(
+                    "CU-abc123",
+                    UpdateIssueInput {
+                        assignees: Some(vec!["Jane Doe".to_string()]),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            update_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_containers() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/team");
+                then.status(200).json_body(serde_json::json!({
+                    "teams": [{"id": "9876", "name": "Acme"}]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/team/9876/space")
+                    .query_param("archived", "false");
+                then.status(200).json_body(serde_json::json!({
+                    "spaces": [{"id": "7", "name": "Engineering", "archived": false}]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/space/7/folder");
+                then.status(200).json_body(serde_json::json!({
+                    "folders": [{"id": "55", "name": "Sprints", "hidden": false}]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/space/7/list");
+                then.status(200).json_body(serde_json::json!({
+                    "lists": [{"id": "901", "name": "Inbox"}]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/folder/55/list");
+                then.status(200).json_body(serde_json::json!({
+                    "lists": [{"id": "12345", "name": "Sprint 4"}]
+                }));
+            });
+
+            let client = create_test_client(&server);
+
+            let workspaces = client.get_containers(None).await.unwrap();
+            assert_eq!(
+                workspaces,
+                vec![Container {
+                    id: "9876".to_string(),
+                    name: "Acme".to_string(),
+                    kind: "workspace".to_string(),
+                    ..Default::default()
+                }]
+            );
+
+            let spaces = client
+                .get_containers(Some(("workspace", "9876")))
+                .await
+                .unwrap();
+            assert_eq!(spaces[0].name, "Engineering");
+            assert_eq!(spaces[0].parent_id.as_deref(), Some("9876"));
+
+            let children = client.get_containers(Some(("space", "7"))).await.unwrap();
+            let kinds: Vec<_> = children
+                .iter()
+                .map(|c| (c.id.as_str(), c.kind.as_str()))
+                .collect();
+            assert_eq!(kinds, vec![("55", "folder"), ("901", "list")]);
+
+            let lists = client.get_containers(Some(("folder", "55"))).await.unwrap();
+            assert_eq!(lists[0].name, "Sprint 4");
+            assert_eq!(lists[0].kind, "list");
+
+            let result = client.get_containers(Some(("list", "12345"))).await;
+            assert!(matches!(result, Err(Error::InvalidData(_))));
+        }
+
+        #[tokio::test]
+        async fn test_log_time() {
+            let server = MockServer::start();
+
+            let log_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/team/9876/time_entries")
+                    .body_includes("\"tid\":\"abc123\"")
+                    .body_includes("\"start\":1705312800000")
+                    .body_includes("\"duration\":5400000")
+                    .body_includes("\"description\":\"Code review\"");
+                then.status(200)
+                    .json_body(serde_json::json!({"data": {"id": "te1"}}));
+            });
+
+            let client = create_test_client_with_team(&server);
+            client
+                .log_time(
+                    "CU-abc123",
+                    5400,
+                    Some("Code review"),
+                    Some("2024-01-15T10:00:00Z"),
+                )
+                .await
+                .unwrap();
+
+            log_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_log_time_without_team_fails() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client.log_time("CU-abc123", 600, None, None).await;
+            assert!(matches!(result, Err(Error::Config(_))));
+        }
+
+        #[tokio::test]
+        async fn test_get_time_entries() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123/time");
+                then.status(200).json_body(serde_json::json!({
+                    "data": [{
+                        "user": {"id": 1, "username": "alice", "email": "alice@example.com"},
+                        "time": 5400000,
+                        "intervals": [
+                            {"id": "int1", "start": "1705312800000", "end": "1705316400000", "time": "3600000"},
+                            {"id": "int2", "start": "1705399200000", "end": "1705401000000", "time": "1800000"}
+                        ]
+                    }]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let entries = client.get_time_entries("CU-abc123").await.unwrap();
+
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].id, "int1");
+            assert_eq!(entries[0].duration_seconds, 3600);
+            assert_eq!(
+                entries[0].started_at.as_deref(),
+                Some("2024-01-15T10:00:00Z")
+            );
+            assert_eq!(entries[0].author.as_ref().unwrap().username, "alice");
+            assert_eq!(entries[1].duration_seconds, 1800);
+        }
+
+        #[tokio::test]
+        async fn test_set_time_estimate() {
+            let server = MockServer::start();
+
+            let update_mock = server.mock(|when, then| {
+                when.method(PUT)
+                    .path("/task/abc123")
+                    .body_includes("\"time_estimate\":7200000");
+                then.status(200).json_body(sample_task_json());
+            });
+
+            let client = create_test_client(&server);
+            client.set_time_estimate("CU-abc123", 7200).await.unwrap();
+
+            update_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_issue_custom_id_without_team_fails() {
+            let client = ClickUpClient::new("12345", "token");
+            let result = client.get_issue("DEV-42").await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_create_issue_with_custom_id_retry() {
+            let server = MockServer::start();
+
+            // POST returns task without custom_id
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/list/12345/task")
+                    .body_includes("\"name\":\"New Task\"");
+                then.status(200).json_body(sample_task_json());
+            });
+
+            // GET retry returns task with custom_id
+            let mut task_with_custom_id = sample_task_json();
+            task_with_custom_id["custom_id"] = serde_json::json!("DEV-100");
 
             server.mock(|when, then| {
                 when.method(GET).path("/task/abc123");
@@ -1391,6 +3712,10 @@ mod tests {
                     labels: vec!["bug".to_string()],
                     assignees: vec![],
                     priority: None,
+                    custom_fields: Default::default(),
+                    issue_type: None,
+                    parent: None,
+                    container: None,
                 })
                 .await
                 .unwrap();
@@ -1458,6 +3783,60 @@ mod tests {
             assert_eq!(result.unwrap().key, "DEV-101");
         }
 
+        #[tokio::test]
+        async fn test_create_issue_with_assignees() {
+            let server = MockServer::start();
+            mock_team_members(&server);
+
+            let mut task = sample_task_json();
+            task["custom_id"] = serde_json::json!("DEV-103");
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/list/12345/task")
+                    .body_includes("\"assignees\":[2,7]");
+                then.status(200).json_body(task);
+            });
+
+            let client = create_test_client(&server);
+            client
+                .create_issue(CreateIssueInput {
+                    title: "Assigned Task".to_string(),
+                    assignees: vec!["jane@example.com".to_string(), "7".to_string()],
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_create_issue_in_container() {
+            let server = MockServer::start();
+
+            let mut task = sample_task_json();
+            task["custom_id"] = serde_json::json!("DEV-102");
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST).path("/list/901/task");
+                then.status(200).json_body(task);
+            });
+
+            let client = create_test_client(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "Inbox Task".to_string(),
+                    container: Some("901".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+            assert_eq!(issue.key, "DEV-102");
+        }
+
         #[tokio::test]
         async fn test_update_issue() {
             let server = MockServer::start();
@@ -1484,6 +3863,138 @@ mod tests {
             assert_eq!(issue.key, "CU-abc123");
         }
 
+        fn sample_fields_json() -> serde_json::Value {
+            serde_json::json!({
+                "fields": [
+                    {
+                        "id": "f-severity",
+                        "name": "Severity",
+                        "type": "drop_down",
+                        "type_config": {"options": [
+                            {"id": "opt-low", "name": "Low", "orderindex": 0},
+                            {"id": "opt-high", "name": "High", "orderindex": 1}
+                        ]}
+                    },
+                    {"id": "f-points", "name": "Points", "type": "number"}
+                ]
+            })
+        }
+
+        #[tokio::test]
+        async fn test_get_custom_fields() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            let client = create_test_client(&server);
+            let fields = client.get_custom_fields().await.unwrap();
+
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name, "Severity");
+            assert_eq!(fields[0].field_type, "option");
+            assert_eq!(fields[0].options, vec!["Low", "High"]);
+            assert_eq!(fields[1].field_type, "number");
+        }
+
+        #[tokio::test]
+        async fn test_create_issue_with_custom_fields() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/list/12345/task")
+                    .body_includes("{\"id\":\"f-severity\",\"value\":\"opt-high\"}")
+                    .body_includes("{\"id\":\"f-points\",\"value\":5}");
+                then.status(200)
+                    .json_body(sample_task_with_custom_id_json());
+            });
+
+            let client = create_test_client(&server);
+            let mut custom_fields = serde_json::Map::new();
+            custom_fields.insert("severity".to_string(), serde_json::json!("High"));
+            custom_fields.insert("f-points".to_string(), serde_json::json!(5));
+            client
+                .create_issue(CreateIssueInput {
+                    title: "Task with fields".to_string(),
+                    custom_fields,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_update_issue_custom_fields() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/list/12345/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            server.mock(|when, then| {
+                when.method(PUT).path("/task/DEV-42");
+                then.status(200)
+                    .json_body(sample_task_with_custom_id_json());
+            });
+
+            let set_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/task/abc123/field/f-severity")
+                    .json_body(serde_json::json!({"value": "opt-low"}));
+                then.status(200).json_body(serde_json::json!({}));
+            });
+
+            let clear_mock = server.mock(|when, then| {
+                when.method(DELETE).path("/task/abc123/field/f-points");
+                then.status(200).json_body(serde_json::json!({}));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/task/abc123");
+                let mut task = sample_task_with_custom_id_json();
+                task["custom_fields"] = serde_json::json!([{
+                    "id": "f-severity",
+                    "name": "Severity",
+                    "type": "drop_down",
+                    "type_config": {"options": [
+                        {"id": "opt-low", "name": "Low", "orderindex": 0}
+                    ]},
+                    "value": 0
+                }]);
+                then.status(200).json_body(task);
+            });
+
+            let client = create_test_client_with_team(&server);
+            let mut custom_fields = serde_json::Map::new();
+            custom_fields.insert("Severity".to_string(), serde_json::json!("low"));
+            custom_fields.insert("Points".to_string(), serde_json::Value::Null);
+            let issue = client
+                .update_issue(
+                    "DEV-42",
+                    UpdateIssueInput {
+                        custom_fields,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+
+            set_mock.assert();
+            clear_mock.assert();
+            assert_eq!(issue.custom_fields["Severity"], serde_json::json!("Low"));
+        }
+
         #[tokio::test]
         async fn test_update_issue_by_custom_id() {
             let server = MockServer::start();
@@ -1620,6 +4131,18 @@ mod tests {
                     "comments": [{
                         "id": "1",
                         "comment_text": "Looks good!",
+                        "comment": [
+                            {"text": "Looks good!"},
+                            {
+                                "type": "attachment",
+                                "text": "diff.png",
+                                "attachment": {
+                                    "id": "att3",
+                                    "title": "diff.png",
+                                    "url": "https://t12345.p.clickup-attachments.com/t12345/att3/diff.png"
+                                }
+                            }
+                        ],
                         "user": {"id": 1, "username": "reviewer"},
                         "date": "1705312800000"
                     }]
@@ -1637,6 +4160,8 @@ mod tests {
                 comments[0].created_at,
                 Some("2024-01-15T10:00:00Z".to_string())
             );
+            assert_eq!(comments[0].attachments.len(), 1);
+            assert_eq!(comments[0].attachments[0].name, "diff.png");
         }
 
         #[tokio::test]
@@ -1765,14 +4290,35 @@ mod tests {
             let server = MockServer::start();
 
             server.mock(|when, then| {
-                when.method(GET).path("/list/12345/task");
-                then.status(200).json_body(serde_json::json!({"tasks": []}));
+                when.method(GET).path("/user");
+                then.status(200).json_body(serde_json::json!({
+                    "user": {
+                        "id": 1,
+                        "username": "John Doe",
+                        "email": "john@example.com",
+                        "profilePicture": null
+                    }
+                }));
             });
 
             let client = create_test_client(&server);
             let user = client.get_current_user().await.unwrap();
 
-            assert_eq!(user.username, "clickup-user");
+            assert_eq!(user.id, "1");
+            assert_eq!(user.username, "John Doe");
+            assert_eq!(user.email.as_deref(), Some("john@example.com"));
+        }
+
+        #[tokio::test]
+        async fn test_search_users() {
+            let server = MockServer::start();
+            mock_team_members(&server);
+
+            let client = create_test_client_with_team(&server);
+            let users = client.search_users("doe").await.unwrap();
+
+            assert_eq!(users.len(), 2);
+            assert_eq!(users[0].username, "John Doe");
         }
 
         #[tokio::test]
@@ -1780,7 +4326,7 @@ mod tests {
             let server = MockServer::start();
 
             server.mock(|when, then| {
-                when.method(GET).path("/list/12345/task");
+                when.method(GET).path("/user");
                 then.status(401).body("Unauthorized");
             });
 
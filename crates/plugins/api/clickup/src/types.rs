@@ -49,6 +49,89 @@ pub struct ClickUpTask {
     pub date_created: Option<String>,
     #[serde(default)]
     pub date_updated: Option<String>,
+    #[serde(default)]
+    pub custom_fields: Vec<ClickUpCustomField>,
+    /// Raw ID of the parent task (set for subtasks)
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Subtasks (only returned with `include_subtasks=true`)
+    #[serde(default)]
+    pub subtasks: Vec<ClickUpTask>,
+    /// Dependencies in both directions
+    #[serde(default)]
+    pub dependencies: Vec<ClickUpDependency>,
+    /// Linked tasks
+    #[serde(default)]
+    pub linked_tasks: Vec<ClickUpLinkedTask>,
+    /// Attached files (only returned for a single task)
+    #[serde(default)]
+    pub attachments: Vec<ClickUpAttachment>,
+    /// Time estimate in milliseconds
+    #[serde(default)]
+    pub time_estimate: Option<serde_json::Value>,
+    /// Time tracked in milliseconds
+    #[serde(default)]
+    pub time_spent: Option<serde_json::Value>,
+    /// Checklists
+    #[serde(default)]
+    pub checklists: Vec<ClickUpChecklist>,
+}
+
+/// File attached to a task or comment.
+///
+/// `size` and `date` are numbers or numeric strings depending on the endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpAttachment {
+    pub id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    pub url: String,
+    #[serde(default)]
+    pub size: Option<serde_json::Value>,
+    #[serde(default)]
+    pub mimetype: Option<String>,
+    #[serde(default)]
+    pub date: Option<serde_json::Value>,
+}
+
+/// Dependency between two tasks: `task_id` is waiting on `depends_on`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpDependency {
+    pub task_id: String,
+    pub depends_on: String,
+}
+
+/// Checklist of a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpChecklist {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub items: Vec<ClickUpChecklistItem>,
+}
+
+/// Item of a checklist.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpChecklistItem {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub resolved: bool,
+    #[serde(default)]
+    pub assignee: Option<ClickUpUser>,
+}
+
+/// Response from the checklist endpoints, which return the whole checklist.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpChecklistResponse {
+    pub checklist: ClickUpChecklist,
+}
+
+/// Link between two tasks (either side may be the task it was read from).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpLinkedTask {
+    pub task_id: String,
+    pub link_id: String,
 }
 
 /// ClickUp task status.
@@ -74,6 +157,21 @@ pub struct ClickUpTag {
     pub name: String,
 }
 
+/// Tag defined in a space.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpSpaceTag {
+    pub name: String,
+    #[serde(default)]
+    pub tag_bg: Option<String>,
+}
+
+/// Response from GET /space/{space_id}/tag.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpSpaceTags {
+    #[serde(default)]
+    pub tags: Vec<ClickUpSpaceTag>,
+}
+
 // =============================================================================
 // Task List Response
 // =============================================================================
@@ -97,6 +195,16 @@ pub struct ClickUpComment {
     pub user: Option<ClickUpUser>,
     #[serde(default)]
     pub date: Option<String>,
+    /// Rich text parts of the comment, including attachments
+    #[serde(default)]
+    pub comment: Vec<ClickUpCommentPart>,
+}
+
+/// Part of a comment's rich text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpCommentPart {
+    #[serde(default)]
+    pub attachment: Option<ClickUpAttachment>,
 }
 
 /// Response from GET /task/{task_id}/comment.
@@ -117,10 +225,179 @@ pub struct ClickUpListStatus {
     pub status_type: Option<String>,
 }
 
-/// Partial response from GET /list/{list_id} (only statuses needed).
+/// Partial response from GET /list/{list_id} (statuses and location).
 #[derive(Debug, Clone, Deserialize)]
 pub struct ClickUpListInfo {
     pub statuses: Vec<ClickUpListStatus>,
+    #[serde(default)]
+    pub folder: Option<ClickUpFolderRef>,
+    #[serde(default)]
+    pub space: Option<ClickUpSpaceRef>,
+}
+
+/// Folder a list belongs to; folderless lists have a hidden folder.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpFolderRef {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// Space a list belongs to.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpSpaceRef {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+}
+
+/// List of a folder or space, used as sprint or milestone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpList {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    pub name: String,
+    /// Description
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Epoch milliseconds as string
+    #[serde(default)]
+    pub start_date: Option<serde_json::Value>,
+    /// Epoch milliseconds as string
+    #[serde(default)]
+    pub due_date: Option<serde_json::Value>,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Response from GET /folder/{folder_id}/list and GET /space/{space_id}/list.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpLists {
+    #[serde(default)]
+    pub lists: Vec<ClickUpList>,
+}
+
+// =============================================================================
+// Hierarchy
+// =============================================================================
+
+/// ClickUp team, called workspace in the UI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTeam {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub members: Vec<ClickUpTeamMember>,
+}
+
+/// Member of a team.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTeamMember {
+    pub user: ClickUpMember,
+}
+
+/// User of a team member; invited users have no username yet.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpMember {
+    pub id: u64,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default, rename = "profilePicture")]
+    pub profile_picture: Option<String>,
+}
+
+/// Response from GET /user.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpAuthorizedUser {
+    pub user: ClickUpMember,
+}
+
+/// Response from GET /team.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTeams {
+    #[serde(default)]
+    pub teams: Vec<ClickUpTeam>,
+}
+
+/// ClickUp space.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpSpace {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Response from GET /team/{team_id}/space.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpSpaces {
+    #[serde(default)]
+    pub spaces: Vec<ClickUpSpace>,
+}
+
+/// ClickUp folder.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpFolder {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+/// Response from GET /space/{space_id}/folder.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpFolders {
+    #[serde(default)]
+    pub folders: Vec<ClickUpFolder>,
+}
+
+// =============================================================================
+// Custom fields
+// =============================================================================
+
+/// ClickUp custom field definition. Tasks embed the same shape with a `value`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpCustomField {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+    #[serde(default)]
+    pub type_config: Option<ClickUpFieldTypeConfig>,
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    #[serde(default)]
+    pub required: Option<bool>,
+}
+
+/// Type-specific configuration of a custom field (only options are needed).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClickUpFieldTypeConfig {
+    #[serde(default)]
+    pub options: Vec<ClickUpFieldOption>,
+}
+
+/// Option of a drop-down (`name`) or labels (`label`) custom field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClickUpFieldOption {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub orderindex: Option<serde_json::Value>,
+}
+
+/// Response from GET /list/{list_id}/field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpFieldList {
+    pub fields: Vec<ClickUpCustomField>,
 }
 
 // =============================================================================
@@ -141,6 +418,47 @@ pub struct CreateTaskRequest {
     pub tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignees: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom_fields: Vec<CustomFieldValue>,
+}
+
+/// Custom field value in a create task request.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomFieldValue {
+    pub id: String,
+    pub value: serde_json::Value,
+}
+
+/// Request body for POST /task/{task_id}/field/{field_id}.
+#[derive(Debug, Clone, Serialize)]
+pub struct SetCustomFieldRequest {
+    pub value: serde_json::Value,
+}
+
+/// Request body for POST /task/{task_id}/dependency.
+///
+/// `depends_on` makes the task wait on another task, `dependency_of` makes
+/// another task wait on it.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct AddDependencyRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depends_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependency_of: Option<String>,
+}
+
+/// Request body for creating a checklist or a checklist item.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateChecklistRequest {
+    pub name: String,
+}
+
+/// Request body for checking or unchecking a checklist item.
+#[derive(Debug, Clone, Serialize)]
+pub struct EditChecklistItemRequest {
+    pub resolved: bool,
 }
 
 /// Request body for updating a task.
@@ -154,6 +472,58 @@ pub struct UpdateTaskRequest {
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<u8>,
+    /// Time estimate in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_estimate: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignees: Option<UpdateAssignees>,
+}
+
+/// Assignee changes of a task update, as user IDs.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct UpdateAssignees {
+    pub add: Vec<u64>,
+    pub rem: Vec<u64>,
+}
+
+/// Time tracked on a task by one user, from GET /task/{task_id}/time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTrackedTime {
+    #[serde(default)]
+    pub user: Option<ClickUpUser>,
+    #[serde(default)]
+    pub intervals: Vec<ClickUpTimeInterval>,
+}
+
+/// Single time entry of a user; times are milliseconds as strings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTimeInterval {
+    #[serde(deserialize_with = "value_to_string")]
+    pub id: String,
+    #[serde(default)]
+    pub start: Option<serde_json::Value>,
+    #[serde(default)]
+    pub time: Option<serde_json::Value>,
+}
+
+/// Response from GET /task/{task_id}/time.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClickUpTrackedTimeList {
+    #[serde(default)]
+    pub data: Vec<ClickUpTrackedTime>,
+}
+
+/// Request body for POST /team/{team_id}/time_entries.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateTimeEntryRequest {
+    /// Task ID
+    pub tid: String,
+    /// Start time in epoch milliseconds
+    pub start: i64,
+    /// Duration in milliseconds
+    pub duration: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// Request body for creating a comment.
@@ -1,20 +1,44 @@
 //! GitHub API client implementation.
 
 use async_trait::async_trait;
+use devboy_core::attachments::{markdown_attachments, url_origin};
+use devboy_core::encoding::{decode_base64, encode_uri_component, is_binary};
+use devboy_core::keys::{parse_key_number, KeyPattern};
+use devboy_core::members::{is_me, search_members, MemberDirectory};
+use devboy_core::time::{now_unix, parse_iso8601, unix_to_iso8601};
 use devboy_core::{
-    CodePosition, Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff,
-    Issue, IssueFilter, IssueProvider, MergeRequest, MergeRequestProvider, MrFilter, Provider,
-    Result, UpdateIssueInput, User,
+    AgileProvider, Branch, CiJob, CiPipeline, CodePosition, Comment, Commit, CreateCommentInput,
+    CreateIssueInput, Discussion, Error, FileDiff, Issue, IssueFilter, IssueLink, IssueProvider,
+    IssueRef, IssueStatus, Label, MergeRequest, MergeRequestProvider, Milestone, MrFilter,
+    PipelineProvider, Provider, RepositoryFile, RepositoryProvider, Result, RunPipelineInput,
+    TimeTrackingProvider, TreeEntry, UpdateIssueInput, User,
 };
+use futures_util::stream::{self, StreamExt};
 use tracing::{debug, warn};
 
 use crate::types::{
-    CreateCommentRequest, CreateIssueRequest, CreateReviewCommentRequest, GitHubComment,
-    GitHubFile, GitHubIssue, GitHubLabel, GitHubPullRequest, GitHubReview, GitHubReviewComment,
-    GitHubUser, UpdateIssueRequest,
+    AddDependencyRequest, AddSubIssueRequest, CreateCommentRequest, CreateIssueRequest,
+    CreateRefRequest, CreateReviewCommentRequest, GitHubCheckRun, GitHubCheckRunList,
+    GitHubComment, GitHubCommit, GitHubContent, GitHubContentResponse, GitHubFile, GitHubGitRef,
+    GitHubIssue, GitHubJob, GitHubJobList, GitHubLabel, GitHubMilestone, GitHubPullRequest,
+    GitHubRepository, GitHubReview, GitHubReviewComment, GitHubSearchResponse, GitHubTimelineEvent,
+    GitHubUser, GitHubWorkflowRun, GitHubWorkflowRunList, UpdateIssueRequest,
+    WorkflowDispatchRequest,
 };
 use crate::DEFAULT_GITHUB_URL;
 
+/// How many times to look for the run created by a workflow dispatch.
+const DISPATCH_POLL_ATTEMPTS: usize = 5;
+
+/// Delay between lookups of the run created by a workflow dispatch.
+const DISPATCH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Tolerated clock difference between this machine and GitHub.
+const DISPATCH_CLOCK_SKEW_SECS: i64 = 30;
+
+/// How many pull requests found by a search are fetched at once.
+const PR_FETCH_CONCURRENCY: usize = 4;
+
 /// GitHub API client.
 pub struct GitHubClient {
     base_url: String,
@@ -22,6 +46,7 @@ pub struct GitHubClient {
     repo: String,
     token: String,
     client: reqwest::Client,
+    members: MemberDirectory,
 }
 
 impl GitHubClient {
@@ -50,6 +75,7 @@ impl GitHubClient {
                 .user_agent("devboy-tools")
                 .build()
                 .expect("Failed to create HTTP client"),
+            members: MemberDirectory::new(),
         }
     }
 
@@ -93,6 +119,58 @@ impl GitHubClient {
         self.handle_response(response).await
     }
 
+    /// Make an authenticated POST request for endpoints that respond without a body
+    /// (e.g., 201/202/204 for Actions re-runs and dispatches).
+    async fn post_no_content<B: serde::Serialize>(&self, url: &str, body: &B) -> Result<()> {
+        debug!(url = url, "GitHub POST request");
+
+        let response = self
+            .request(reqwest::Method::POST, url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitHub API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        Ok(())
+    }
+
+    /// Make an authenticated DELETE request, ignoring the response body.
+    async fn delete(&self, url: &str) -> Result<()> {
+        debug!(url = url, "GitHub DELETE request");
+
+        let response = self
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitHub API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        Ok(())
+    }
+
     /// Make an authenticated PATCH request.
     async fn patch<T: serde::de::DeserializeOwned, B: serde::Serialize>(
         &self,
@@ -111,6 +189,67 @@ impl GitHubClient {
         self.handle_response(response).await
     }
 
+    /// Make an authenticated GET request returning the raw response body as text.
+    async fn get_text(&self, url: &str) -> Result<String> {
+        debug!(url = url, "GitHub GET text request");
+
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitHub API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
+    /// Make an authenticated GET request returning the raw response body.
+    ///
+    /// Uses a generic `Accept` header: attachment URLs serve files, not JSON.
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        debug!(url = url, "GitHub GET bytes request");
+
+        let response = self
+            .client
+            .get(url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitHub API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
     /// Handle response and map errors.
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
@@ -135,6 +274,85 @@ impl GitHubClient {
             .map_err(|e| Error::InvalidData(format!("Failed to parse response: {}", e)))
     }
 
+    /// Search issues of the repository with the search API, combining the
+    /// filter with a raw search query (e.g. `milestone:v2.3 comments:>5`).
+    async fn search_issues(&self, filter: &IssueFilter, query: &str) -> Result<Vec<Issue>> {
+        let q = build_search_query(&self.owner, &self.repo, filter, query)?;
+        let per_page = filter.limit.unwrap_or(30).clamp(1, 100);
+        let page = filter.offset.unwrap_or(0) / per_page + 1;
+
+        let mut url = format!(
+            "{}/search/issues?q={}&per_page={}&page={}",
+            self.base_url,
+            encode_uri_component(&q),
+            per_page,
+            page
+        );
+        if let Some(sort_by) = &filter.sort_by {
+            let gh_sort = match sort_by.as_str() {
+                "created_at" | "created" => "created",
+                _ => "updated",
+            };
+            url.push_str(&format!("&sort={}", gh_sort));
+        }
+        if let Some(order) = &filter.sort_order {
+            url.push_str(&format!("&order={}", order));
+        }
+
+        let response: GitHubSearchResponse<GitHubIssue> = self.get(&url).await?;
+        Ok(response.items.iter().map(map_issue).collect())
+    }
+
+    /// Search pull requests of the repository with the search API. Search
+    /// results carry no branches, so each match is then fetched in full.
+    async fn search_pull_requests(&self, filter: &MrFilter) -> Result<Vec<MergeRequest>> {
+        let q = build_pr_search_query(&self.owner, &self.repo, filter);
+        let url = format!(
+            "{}/search/issues?q={}&per_page={}&sort=updated&order=desc",
+            self.base_url,
+            encode_uri_component(&q),
+            filter.limit.unwrap_or(30).clamp(1, 100)
+        );
+        let response: GitHubSearchResponse<GitHubIssue> = self.get(&url).await?;
+
+        let urls: Vec<String> = response
+            .items
+            .iter()
+            .map(|item| self.repo_url(&format!("/pulls/{}", item.number)))
+            .collect();
+        let prs: Vec<Result<GitHubPullRequest>> = stream::iter(urls)
+            .map(|url| async move { self.get(&url).await })
+            .buffered(PR_FETCH_CONCURRENCY)
+            .collect()
+            .await;
+        prs.into_iter()
+            .map(|pr| pr.map(|pr| map_pull_request(&pr)))
+            .collect()
+    }
+
+    /// Resolve the `@me` alias to the login of the authenticated user.
+    async fn resolve_login(&self, user: &str) -> Result<String> {
+        if !is_me(user) {
+            return Ok(user.to_string());
+        }
+        let me = self
+            .members
+            .current_user(|| self.get_current_user())
+            .await?;
+        Ok(me.username)
+    }
+
+    /// Find the number of a milestone by title, which the issues endpoint filters by.
+    async fn milestone_number(&self, title: &str) -> Result<u64> {
+        let url = self.repo_url("/milestones?state=all&per_page=100");
+        let milestones: Vec<GitHubMilestone> = self.get(&url).await?;
+        milestones
+            .iter()
+            .find(|m| m.title.eq_ignore_ascii_case(title))
+            .map(|m| m.number)
+            .ok_or_else(|| Error::NotFound(format!("Milestone '{}' not found", title)))
+    }
+
     /// Build repo API URL.
     fn repo_url(&self, endpoint: &str) -> String {
         format!(
@@ -142,6 +360,87 @@ impl GitHubClient {
             self.base_url, self.owner, self.repo, endpoint
         )
     }
+
+    /// Get the issues an issue is blocked by (`blocked_by`) or blocks (`blocks`).
+    async fn get_dependencies(&self, number: u64, kind: &str) -> Result<Vec<GitHubIssue>> {
+        let url = self.repo_url(&format!(
+            "/issues/{}/dependencies/{}?per_page=100",
+            number,
+            dependency_endpoint(kind)?
+        ));
+        self.get(&url).await
+    }
+
+    /// Map the cross-references of an issue timeline to `mentioned_in` links.
+    ///
+    /// References from other repositories are skipped: their numbers would be
+    /// read as issues of this repository.
+    fn cross_reference_links(&self, events: &[GitHubTimelineEvent]) -> Vec<IssueLink> {
+        let repo_path = format!("/repos/{}/{}", self.owner, self.repo);
+        let mut links: Vec<IssueLink> = Vec::new();
+        let sources = events
+            .iter()
+            .filter(|e| e.event.as_deref() == Some("cross-referenced"))
+            .filter_map(|e| e.source.as_ref()?.issue.as_ref())
+            .filter(|i| {
+                i.repository_url
+                    .as_deref()
+                    .is_none_or(|url| url.ends_with(&repo_path))
+            });
+        for source in sources {
+            let link = map_cross_reference(source);
+            if !links.iter().any(|l| l.target.key == link.target.key) {
+                links.push(link);
+            }
+        }
+        links
+    }
+
+    /// Origin of the web UI serving uploaded files: github.com for the public
+    /// API, the API host itself for GitHub Enterprise Server.
+    fn web_origin(&self) -> String {
+        match url_origin(&self.base_url) {
+            Some("https://api.github.com") | None => "https://github.com".to_string(),
+            Some(origin) => origin.to_string(),
+        }
+    }
+
+    /// Web URL of the repository, e.g. `https://github.com/org/repo/`.
+    fn web_repo_url(&self) -> String {
+        format!("{}/{}/{}/", self.web_origin(), self.owner, self.repo)
+    }
+
+    /// Number of an issue key or issue URL.
+    fn issue_number(&self, key: &str) -> Result<u64> {
+        parse_issue_key(key, &self.web_repo_url())
+    }
+
+    /// Number of a PR key or pull request URL.
+    fn pr_number(&self, key: &str) -> Result<u64> {
+        parse_pr_key(key, &self.web_repo_url())
+    }
+
+    /// Check whether a URL points to a file uploaded to an issue or comment.
+    ///
+    /// Uploads live under `/user-attachments/` or, for older ones, under the
+    /// repository's `assets/` and `files/` paths.
+    fn is_attachment_url(&self, url: &str) -> bool {
+        let Some(path) = url.strip_prefix(&self.web_origin()) else {
+            return false;
+        };
+        let repo_path = format!("/{}/{}/", self.owner, self.repo);
+        path.starts_with("/user-attachments/")
+            || path
+                .strip_prefix(&repo_path)
+                .is_some_and(|rest| rest.starts_with("assets/") || rest.starts_with("files/"))
+    }
+
+    /// Get a workflow run (without jobs).
+    async fn get_workflow_run(&self, run_id: u64) -> Result<CiPipeline> {
+        let url = self.repo_url(&format!("/actions/runs/{}", run_id));
+        let run: GitHubWorkflowRun = self.get(&url).await?;
+        Ok(map_workflow_run(&run))
+    }
 }
 
 // =============================================================================
@@ -171,6 +470,63 @@ fn map_labels(labels: &[GitHubLabel]) -> Vec<String> {
     labels.iter().map(|l| l.name.clone()).collect()
 }
 
+/// Map an [`IssueLink::kind`] to its issue dependencies endpoint.
+fn dependency_endpoint(kind: &str) -> Result<&'static str> {
+    match kind {
+        "blocked_by" => Ok("blocked_by"),
+        "blocks" => Ok("blocking"),
+        other => Err(Error::InvalidData(format!(
+            "Unsupported GitHub link kind '{}'. Issue dependencies support: blocks, blocked_by",
+            other
+        ))),
+    }
+}
+
+/// Map an issue or pull request referencing an issue to a `mentioned_in` link.
+fn map_cross_reference(source: &GitHubIssue) -> IssueLink {
+    let target = if source.pull_request.is_some() {
+        IssueRef {
+            key: format!("pr#{}", source.number),
+            title: Some(source.title.clone()),
+            state: Some(source.state.clone()),
+            issue_type: Some("pull_request".to_string()),
+        }
+    } else {
+        IssueRef::from(&map_issue(source))
+    };
+    IssueLink {
+        kind: "mentioned_in".to_string(),
+        target,
+        id: None,
+    }
+}
+
+fn map_dependency(kind: &str, gh_issue: &GitHubIssue) -> IssueLink {
+    IssueLink {
+        kind: kind.to_string(),
+        target: IssueRef::from(&map_issue(gh_issue)),
+        id: None,
+    }
+}
+
+fn map_milestone(gh_milestone: &GitHubMilestone) -> Milestone {
+    Milestone {
+        id: gh_milestone.number.to_string(),
+        title: gh_milestone.title.clone(),
+        kind: "milestone".to_string(),
+        state: gh_milestone.state.clone(),
+        description: gh_milestone.description.clone().filter(|d| !d.is_empty()),
+        start_date: None,
+        due_date: gh_milestone
+            .due_on
+            .as_ref()
+            .map(|due| due.chars().take(10).collect()),
+        open_issues: Some(gh_milestone.open_issues),
+        closed_issues: Some(gh_milestone.closed_issues),
+        url: Some(gh_milestone.html_url.clone()),
+    }
+}
+
 fn map_issue(gh_issue: &GitHubIssue) -> Issue {
     Issue {
         key: format!("gh#{}", gh_issue.number),
@@ -189,6 +545,16 @@ fn map_issue(gh_issue: &GitHubIssue) -> Issue {
         url: Some(gh_issue.html_url.clone()),
         created_at: Some(gh_issue.created_at.clone()),
         updated_at: Some(gh_issue.updated_at.clone()),
+        custom_fields: Default::default(),
+        issue_type: gh_issue.issue_type.as_ref().map(|t| t.name.clone()),
+        parent: None,
+        children: vec![],
+        links: vec![],
+        attachments: vec![],
+        estimate_seconds: None,
+        time_spent_seconds: None,
+        milestone: gh_issue.milestone.as_ref().map(map_milestone),
+        checklists: vec![],
     }
 }
 
@@ -228,6 +594,8 @@ fn map_pull_request(gh_pr: &GitHubPullRequest) -> MergeRequest {
         url: Some(gh_pr.html_url.clone()),
         created_at: Some(gh_pr.created_at.clone()),
         updated_at: Some(gh_pr.updated_at.clone()),
+        pipelines: vec![],
+        milestone: gh_pr.milestone.as_ref().map(map_milestone),
     }
 }
 
@@ -239,6 +607,7 @@ fn map_comment(gh_comment: &GitHubComment) -> Comment {
         created_at: Some(gh_comment.created_at.clone()),
         updated_at: gh_comment.updated_at.clone(),
         position: None,
+        attachments: vec![],
     }
 }
 
@@ -268,6 +637,7 @@ fn map_review_comment(gh_comment: &GitHubReviewComment) -> Comment {
         created_at: Some(gh_comment.created_at.clone()),
         updated_at: gh_comment.updated_at.clone(),
         position,
+        attachments: vec![],
     }
 }
 
@@ -284,13 +654,190 @@ fn map_file(gh_file: &GitHubFile) -> FileDiff {
     }
 }
 
+fn map_commit(gh_commit: &GitHubCommit) -> Commit {
+    let git = &gh_commit.commit;
+    let git_author = git.author.as_ref();
+
+    // Prefer the linked GitHub account, fall back to the git author name
+    let author = match map_user(gh_commit.author.as_ref()) {
+        Some(user) => Some(User {
+            name: user
+                .name
+                .or_else(|| git_author.and_then(|a| a.name.clone())),
+            email: user
+                .email
+                .or_else(|| git_author.and_then(|a| a.email.clone())),
+            ..user
+        }),
+        None => git_author.and_then(|a| {
+            a.name.as_ref().map(|name| User {
+                id: String::new(),
+                username: name.clone(),
+                name: Some(name.clone()),
+                email: a.email.clone(),
+                avatar_url: None,
+            })
+        }),
+    };
+
+    let message = git.message.trim_end().to_string();
+    Commit {
+        sha: gh_commit.sha.clone(),
+        title: message.lines().next().unwrap_or_default().to_string(),
+        message,
+        author,
+        authored_at: git_author.and_then(|a| a.date.clone()),
+        committed_at: git.committer.as_ref().and_then(|c| c.date.clone()),
+        url: gh_commit.html_url.clone(),
+    }
+}
+
+fn map_content_file(content: &GitHubContent) -> Result<RepositoryFile> {
+    if content.kind != "file" {
+        return Err(Error::InvalidData(format!(
+            "{} is a {}, not a file",
+            content.path, content.kind
+        )));
+    }
+    if content.encoding.as_deref() != Some("base64") {
+        // The contents API omits the content of files larger than 1 MB
+        return Err(Error::InvalidData(format!(
+            "{} is too large to read through the API ({} bytes)",
+            content.path, content.size
+        )));
+    }
+
+    let bytes = decode_base64(content.content.as_deref().unwrap_or_default())?;
+    let binary = is_binary(&bytes);
+
+    Ok(RepositoryFile {
+        path: content.path.clone(),
+        ref_name: None,
+        content: if binary {
+            String::new()
+        } else {
+            String::from_utf8_lossy(&bytes).into_owned()
+        },
+        size: content.size,
+        binary,
+        sha: content.sha.clone(),
+        url: content.html_url.clone(),
+    })
+}
+
+fn map_tree_entry(content: &GitHubContent) -> TreeEntry {
+    TreeEntry {
+        path: content.path.clone(),
+        name: content.name.clone(),
+        kind: content.kind.clone(),
+        size: (content.kind == "file").then_some(content.size),
+    }
+}
+
+/// Build the `/contents/:path` endpoint, keeping `/` between path segments.
+fn contents_endpoint(path: &str, ref_name: Option<&str>) -> String {
+    let encoded_path = path
+        .trim_matches('/')
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(encode_uri_component)
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let mut endpoint = format!("/contents/{}", encoded_path);
+    if let Some(ref_name) = ref_name {
+        endpoint.push_str(&format!("?ref={}", encode_uri_component(ref_name)));
+    }
+    endpoint
+}
+
+/// Map GitHub status + conclusion to the unified CI status.
+fn map_ci_status(status: &str, conclusion: Option<&str>) -> String {
+    if status != "completed" {
+        return match status {
+            "in_progress" => "running".to_string(),
+            _ => "pending".to_string(), // queued, waiting, requested, pending
+        };
+    }
+
+    match conclusion {
+        Some("success") | Some("neutral") => "success".to_string(),
+        Some("cancelled") | Some("stale") => "canceled".to_string(),
+        Some("skipped") => "skipped".to_string(),
+        Some("action_required") => "manual".to_string(),
+        _ => "failed".to_string(), // failure, timed_out, startup_failure
+    }
+}
+
+fn map_workflow_run(run: &GitHubWorkflowRun) -> CiPipeline {
+    let duration = if run.status == "completed" {
+        run.run_started_at
+            .as_deref()
+            .and_then(|start| seconds_between(start, &run.updated_at))
+    } else {
+        None
+    };
+
+    CiPipeline {
+        id: run.id.to_string(),
+        name: run.name.clone(),
+        status: map_ci_status(&run.status, run.conclusion.as_deref()),
+        ref_name: run.head_branch.clone(),
+        sha: Some(run.head_sha.clone()),
+        duration,
+        url: Some(run.html_url.clone()),
+        jobs: vec![],
+        created_at: Some(run.created_at.clone()),
+        updated_at: Some(run.updated_at.clone()),
+    }
+}
+
+fn map_job(gh_job: &GitHubJob) -> CiJob {
+    CiJob {
+        id: gh_job.id.to_string(),
+        name: gh_job.name.clone(),
+        stage: None,
+        status: map_ci_status(&gh_job.status, gh_job.conclusion.as_deref()),
+        allow_failure: false,
+        duration: match (&gh_job.started_at, &gh_job.completed_at) {
+            (Some(start), Some(end)) => seconds_between(start, end),
+            _ => None,
+        },
+        url: gh_job.html_url.clone(),
+    }
+}
+
+fn map_check_run(check_run: &GitHubCheckRun) -> CiJob {
+    CiJob {
+        id: check_run.id.to_string(),
+        name: check_run.name.clone(),
+        stage: None,
+        status: map_ci_status(&check_run.status, check_run.conclusion.as_deref()),
+        allow_failure: false,
+        duration: match (&check_run.started_at, &check_run.completed_at) {
+            (Some(start), Some(end)) => seconds_between(start, end),
+            _ => None,
+        },
+        url: check_run.html_url.clone(),
+    }
+}
+
 // =============================================================================
 // Trait implementations
 // =============================================================================
 
 #[async_trait]
 impl IssueProvider for GitHubClient {
-    async fn get_issues(&self, filter: IssueFilter) -> Result<Vec<Issue>> {
+    async fn get_issues(&self, mut filter: IssueFilter) -> Result<Vec<Issue>> {
+        if let Some(assignee) = &filter.assignee {
+            filter.assignee = Some(self.resolve_login(assignee).await?);
+        }
+
+        // The issues endpoint has no free-form query, raw queries go to the search API
+        if let Some(query) = &filter.query {
+            return self.search_issues(&filter, query).await;
+        }
+
         let mut url = self.repo_url("/issues");
         let mut params = vec![];
 
@@ -315,6 +862,13 @@ impl IssueProvider for GitHubClient {
             params.push(format!("assignee={}", assignee));
         }
 
+        if let Some(milestone) = &filter.milestone {
+            params.push(format!(
+                "milestone={}",
+                self.milestone_number(milestone).await?
+            ));
+        }
+
         if let Some(limit) = filter.limit {
             params.push(format!("per_page={}", limit.min(100)));
         }
@@ -356,7 +910,7 @@ impl IssueProvider for GitHubClient {
     }
 
     async fn get_issue(&self, key: &str) -> Result<Issue> {
-        let number = parse_issue_key(key)?;
+        let number = self.issue_number(key)?;
         let url = self.repo_url(&format!("/issues/{}", number));
         let gh_issue: GitHubIssue = self.get(&url).await?;
 
@@ -368,24 +922,102 @@ impl IssueProvider for GitHubClient {
             )));
         }
 
-        Ok(map_issue(&gh_issue))
+        let mut issue = map_issue(&gh_issue);
+        if let Some(body) = &gh_issue.body {
+            issue.attachments = markdown_attachments(body, |url| self.is_attachment_url(url));
+        }
+
+        // A missing parent is a 404, so failures are not errors here
+        let parent_url = self.repo_url(&format!("/issues/{}/parent", number));
+        match self.get::<GitHubIssue>(&parent_url).await {
+            Ok(parent) => issue.parent = Some(IssueRef::from(&map_issue(&parent))),
+            Err(e) => debug!(error = %e, key = key, "No parent issue"),
+        }
+
+        let has_sub_issues = gh_issue
+            .sub_issues_summary
+            .as_ref()
+            .is_some_and(|s| s.total > 0);
+        if has_sub_issues {
+            let url = self.repo_url(&format!("/issues/{}/sub_issues?per_page=100", number));
+            match self.get::<Vec<GitHubIssue>>(&url).await {
+                Ok(sub_issues) => {
+                    issue.children = sub_issues
+                        .iter()
+                        .map(|i| IssueRef::from(&map_issue(i)))
+                        .collect();
+                }
+                Err(e) => warn!(error = %e, key = key, "Failed to fetch sub-issues"),
+            }
+        }
+
+        if let Some(summary) = &gh_issue.issue_dependencies_summary {
+            for (kind, total) in [
+                ("blocked_by", summary.total_blocked_by),
+                ("blocks", summary.total_blocking),
+            ] {
+                if total == 0 {
+                    continue;
+                }
+                match self.get_dependencies(number, kind).await {
+                    Ok(deps) => issue
+                        .links
+                        .extend(deps.iter().map(|d| map_dependency(kind, d))),
+                    Err(e) => warn!(error = %e, key = key, "Failed to fetch issue dependencies"),
+                }
+            }
+        }
+
+        let url = self.repo_url(&format!("/issues/{}/timeline?per_page=100", number));
+        match self.get::<Vec<GitHubTimelineEvent>>(&url).await {
+            Ok(events) => issue.links.extend(self.cross_reference_links(&events)),
+            Err(e) => warn!(error = %e, key = key, "Failed to fetch issue timeline"),
+        }
+
+        Ok(issue)
     }
 
     async fn create_issue(&self, input: CreateIssueInput) -> Result<Issue> {
+        let parent_number = input
+            .parent
+            .as_deref()
+            .map(|parent| self.issue_number(parent))
+            .transpose()?;
+
         let url = self.repo_url("/issues");
         let request = CreateIssueRequest {
             title: input.title,
             body: input.description,
             labels: input.labels,
             assignees: input.assignees,
+            issue_type: input.issue_type,
         };
 
         let gh_issue: GitHubIssue = self.post(&url, &request).await?;
-        Ok(map_issue(&gh_issue))
+        let mut issue = map_issue(&gh_issue);
+
+        if let Some(parent_number) = parent_number {
+            let url = self.repo_url(&format!("/issues/{}/sub_issues", parent_number));
+            let request = AddSubIssueRequest {
+                sub_issue_id: gh_issue.id,
+            };
+            self.post_no_content(&url, &request).await.map_err(|e| {
+                Error::InvalidData(format!(
+                    "Created {} but failed to add it as a sub-issue of gh#{}: {}",
+                    issue.key, parent_number, e
+                ))
+            })?;
+            issue.parent = Some(IssueRef {
+                key: format!("gh#{}", parent_number),
+                ..Default::default()
+            });
+        }
+
+        Ok(issue)
     }
 
     async fn update_issue(&self, key: &str, input: UpdateIssueInput) -> Result<Issue> {
-        let number = parse_issue_key(key)?;
+        let number = self.issue_number(key)?;
         let url = self.repo_url(&format!("/issues/{}", number));
 
         // Map state
@@ -408,14 +1040,22 @@ impl IssueProvider for GitHubClient {
     }
 
     async fn get_comments(&self, issue_key: &str) -> Result<Vec<Comment>> {
-        let number = parse_issue_key(issue_key)?;
+        let number = self.issue_number(issue_key)?;
         let url = self.repo_url(&format!("/issues/{}/comments", number));
         let gh_comments: Vec<GitHubComment> = self.get(&url).await?;
-        Ok(gh_comments.iter().map(map_comment).collect())
+        Ok(gh_comments
+            .iter()
+            .map(|c| {
+                let mut comment = map_comment(c);
+                comment.attachments =
+                    markdown_attachments(&c.body, |url| self.is_attachment_url(url));
+                comment
+            })
+            .collect())
     }
 
     async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment> {
-        let number = parse_issue_key(issue_key)?;
+        let number = self.issue_number(issue_key)?;
         let url = self.repo_url(&format!("/issues/{}/comments", number));
         let request = CreateCommentRequest {
             body: body.to_string(),
@@ -425,6 +1065,122 @@ impl IssueProvider for GitHubClient {
         Ok(map_comment(&gh_comment))
     }
 
+    async fn get_milestones(&self, state: Option<&str>) -> Result<Vec<Milestone>> {
+        let gh_state = match state.unwrap_or("open") {
+            "closed" => "closed",
+            "all" => "all",
+            _ => "open",
+        };
+        let url = self.repo_url(&format!("/milestones?state={}&per_page=100", gh_state));
+        let milestones: Vec<GitHubMilestone> = self.get(&url).await?;
+        Ok(milestones.iter().map(map_milestone).collect())
+    }
+
+    async fn list_labels(&self) -> Result<Vec<Label>> {
+        let url = self.repo_url("/labels?per_page=100");
+        let labels: Vec<GitHubLabel> = self.get(&url).await?;
+        Ok(labels
+            .into_iter()
+            .map(|l| Label {
+                name: l.name,
+                color: l.color.map(|c| format!("#{}", c)),
+                description: l.description.filter(|d| !d.is_empty()),
+            })
+            .collect())
+    }
+
+    /// GitHub issues are either open or closed.
+    async fn list_statuses(&self) -> Result<Vec<IssueStatus>> {
+        Ok(vec![
+            IssueStatus {
+                name: "open".to_string(),
+                category: "todo".to_string(),
+            },
+            IssueStatus {
+                name: "closed".to_string(),
+                category: "done".to_string(),
+            },
+        ])
+    }
+
+    async fn download_attachment(&self, url: &str) -> Result<Vec<u8>> {
+        if !self.is_attachment_url(url) {
+            return Err(Error::InvalidData(format!(
+                "{} is not an attachment URL of {}/{}",
+                url, self.owner, self.repo
+            )));
+        }
+        self.get_bytes(url).await
+    }
+
+    async fn link_issues(&self, key: &str, target_key: &str, kind: &str) -> Result<()> {
+        let number = self.issue_number(key)?;
+        let target = self.issue_number(target_key)?;
+        dependency_endpoint(kind)?;
+
+        // Dependencies are always stored on the blocked issue
+        let (blocked, blocking) = if kind == "blocked_by" {
+            (number, target)
+        } else {
+            (target, number)
+        };
+        let blocking_issue: GitHubIssue = self
+            .get(&self.repo_url(&format!("/issues/{}", blocking)))
+            .await?;
+        let url = self.repo_url(&format!("/issues/{}/dependencies/blocked_by", blocked));
+        let request = AddDependencyRequest {
+            issue_id: blocking_issue.id,
+        };
+        self.post_no_content(&url, &request).await
+    }
+
+    async fn unlink_issues(&self, key: &str, target_key: &str, kind: Option<&str>) -> Result<()> {
+        let number = self.issue_number(key)?;
+        let target = self.issue_number(target_key)?;
+        let kinds = match kind {
+            Some(kind) => {
+                dependency_endpoint(kind)?;
+                vec![kind]
+            }
+            None => vec!["blocked_by", "blocks"],
+        };
+
+        let mut removed = false;
+        for kind in kinds {
+            let deps = self.get_dependencies(number, kind).await?;
+            let Some(dep) = deps.iter().find(|d| d.number == target) else {
+                continue;
+            };
+            let url = if kind == "blocked_by" {
+                self.repo_url(&format!(
+                    "/issues/{}/dependencies/blocked_by/{}",
+                    number, dep.id
+                ))
+            } else {
+                let issue: GitHubIssue = self
+                    .get(&self.repo_url(&format!("/issues/{}", number)))
+                    .await?;
+                self.repo_url(&format!(
+                    "/issues/{}/dependencies/blocked_by/{}",
+                    target, issue.id
+                ))
+            };
+            self.delete(&url).await?;
+            removed = true;
+        }
+
+        if !removed {
+            return Err(Error::NotFound(format!(
+                "No link between {} and {}",
+                key, target_key
+            )));
+        }
+        Ok(())
+    }
+
+    // There is no public API for uploading files, so `upload_attachment`
+    // keeps the unsupported default.
+
     fn provider_name(&self) -> &'static str {
         "github"
     }
@@ -433,6 +1189,11 @@ impl IssueProvider for GitHubClient {
 #[async_trait]
 impl MergeRequestProvider for GitHubClient {
     async fn get_merge_requests(&self, filter: MrFilter) -> Result<Vec<MergeRequest>> {
+        // The pulls endpoint has no milestone, author or reviewer filter
+        if filter.milestone.is_some() || filter.author.is_some() || filter.reviewer.is_some() {
+            return self.search_pull_requests(&filter).await;
+        }
+
         let mut url = self.repo_url("/pulls");
         let mut params = vec![];
 
@@ -480,14 +1241,14 @@ impl MergeRequestProvider for GitHubClient {
     }
 
     async fn get_merge_request(&self, key: &str) -> Result<MergeRequest> {
-        let number = parse_pr_key(key)?;
+        let number = self.pr_number(key)?;
         let url = self.repo_url(&format!("/pulls/{}", number));
         let gh_pr: GitHubPullRequest = self.get(&url).await?;
         Ok(map_pull_request(&gh_pr))
     }
 
     async fn get_discussions(&self, mr_key: &str) -> Result<Vec<Discussion>> {
-        let number = parse_pr_key(mr_key)?;
+        let number = self.pr_number(mr_key)?;
 
         // Fetch reviews, review comments, and general comments
         let reviews_url = self.repo_url(&format!("/pulls/{}/reviews", number));
@@ -536,6 +1297,7 @@ impl MergeRequestProvider for GitHubClient {
                         created_at: review.submitted_at.clone(),
                         updated_at: None,
                         position: None,
+                        attachments: vec![],
                     });
                 }
             }
@@ -566,14 +1328,27 @@ impl MergeRequestProvider for GitHubClient {
     }
 
     async fn get_diffs(&self, mr_key: &str) -> Result<Vec<FileDiff>> {
-        let number = parse_pr_key(mr_key)?;
+        let number = self.pr_number(mr_key)?;
         let url = self.repo_url(&format!("/pulls/{}/files", number));
         let gh_files: Vec<GitHubFile> = self.get(&url).await?;
         Ok(gh_files.iter().map(map_file).collect())
     }
 
+    async fn get_commits(&self, mr_key: &str) -> Result<Vec<Commit>> {
+        let number = self.pr_number(mr_key)?;
+        let url = self.repo_url(&format!("/pulls/{}/commits?per_page=100", number));
+        let gh_commits: Vec<GitHubCommit> = self.get(&url).await?;
+        Ok(gh_commits.iter().map(map_commit).collect())
+    }
+
+    async fn get_commit_diff(&self, sha: &str) -> Result<Vec<FileDiff>> {
+        let url = self.repo_url(&format!("/commits/{}", sha));
+        let gh_commit: GitHubCommit = self.get(&url).await?;
+        Ok(gh_commit.files.iter().map(map_file).collect())
+    }
+
     async fn add_comment(&self, mr_key: &str, input: CreateCommentInput) -> Result<Comment> {
-        let number = parse_pr_key(mr_key)?;
+        let number = self.pr_number(mr_key)?;
 
         // First verify that this is actually a PR, not an issue
         let pr_url = self.repo_url(&format!("/pulls/{}", number));
@@ -633,6 +1408,260 @@ impl MergeRequestProvider for GitHubClient {
     }
 }
 
+#[async_trait]
+impl PipelineProvider for GitHubClient {
+    async fn get_pipelines(&self, mr_key: &str) -> Result<Vec<CiPipeline>> {
+        let number = self.pr_number(mr_key)?;
+        let pr_url = self.repo_url(&format!("/pulls/{}", number));
+        let pr: GitHubPullRequest = self.get(&pr_url).await?;
+
+        let url = self.repo_url(&format!(
+            "/actions/runs?head_sha={}&per_page=100",
+            pr.head.sha
+        ));
+        let runs: GitHubWorkflowRunList = self.get(&url).await?;
+        Ok(runs.workflow_runs.iter().map(map_workflow_run).collect())
+    }
+
+    async fn get_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
+
+        let url = self.repo_url(&format!("/actions/runs/{}", id));
+        let run: GitHubWorkflowRun = self.get(&url).await?;
+
+        let jobs_url = self.repo_url(&format!("/actions/runs/{}/jobs?per_page=100", id));
+        let jobs: GitHubJobList = self.get(&jobs_url).await?;
+
+        let mut pipeline = map_workflow_run(&run);
+        pipeline.jobs = jobs.jobs.iter().map(map_job).collect();
+        Ok(pipeline)
+    }
+
+    async fn get_job_log(&self, job_id: &str) -> Result<String> {
+        let id = parse_ci_id(job_id)?;
+        // Responds with a redirect to a short-lived download URL, followed by reqwest
+        let url = self.repo_url(&format!("/actions/jobs/{}/logs", id));
+        self.get_text(&url).await
+    }
+
+    async fn retry_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
+        let url = self.repo_url(&format!("/actions/runs/{}/rerun-failed-jobs", id));
+        self.post_no_content(&url, &serde_json::json!({})).await?;
+        self.get_workflow_run(id).await
+    }
+
+    async fn retry_job(&self, job_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(job_id)?;
+        let job_url = self.repo_url(&format!("/actions/jobs/{}", id));
+        let job: GitHubJob = self.get(&job_url).await?;
+        let run_id = job.run_id.ok_or_else(|| {
+            Error::InvalidData(format!("Job {} has no workflow run information", id))
+        })?;
+
+        let url = self.repo_url(&format!("/actions/jobs/{}/rerun", id));
+        self.post_no_content(&url, &serde_json::json!({})).await?;
+        self.get_workflow_run(run_id).await
+    }
+
+    async fn cancel_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
+        let url = self.repo_url(&format!("/actions/runs/{}/cancel", id));
+        self.post_no_content(&url, &serde_json::json!({})).await?;
+        self.get_workflow_run(id).await
+    }
+
+    async fn run_pipeline(&self, input: RunPipelineInput) -> Result<CiPipeline> {
+        let workflow = input.workflow.ok_or_else(|| {
+            Error::InvalidData(
+                "GitHub requires a workflow (file name like 'ci.yml' or workflow ID)".to_string(),
+            )
+        })?;
+
+        // The dispatch endpoint returns no run ID, so look for runs created after it
+        let dispatched_at = unix_to_iso8601(now_unix() - DISPATCH_CLOCK_SKEW_SECS);
+
+        let workflow_path = encode_uri_component(&workflow);
+        let url = self.repo_url(&format!("/actions/workflows/{}/dispatches", workflow_path));
+        let request = WorkflowDispatchRequest {
+            ref_name: input.ref_name.clone(),
+            inputs: input.variables,
+        };
+        self.post_no_content(&url, &request).await?;
+
+        let runs_url = self.repo_url(&format!(
+            "/actions/workflows/{}/runs?event=workflow_dispatch&branch={}&created=%3E%3D{}&per_page=1",
+            workflow_path,
+            encode_uri_component(&input.ref_name),
+            dispatched_at
+        ));
+        for attempt in 0..DISPATCH_POLL_ATTEMPTS {
+            if attempt > 0 {
+                tokio::time::sleep(DISPATCH_POLL_INTERVAL).await;
+            }
+            let runs: GitHubWorkflowRunList = self.get(&runs_url).await?;
+            if let Some(run) = runs.workflow_runs.first() {
+                return Ok(map_workflow_run(run));
+            }
+        }
+
+        Err(Error::NotFound(format!(
+            "Workflow {} was dispatched for {}, but the new run is not visible yet",
+            workflow, input.ref_name
+        )))
+    }
+
+    async fn get_latest_pipelines(&self, mr_key: &str) -> Result<Vec<CiPipeline>> {
+        let number = self.pr_number(mr_key)?;
+        let pr_url = self.repo_url(&format!("/pulls/{}", number));
+        let pr: GitHubPullRequest = self.get(&pr_url).await?;
+        let sha = pr.head.sha;
+
+        let url = self.repo_url(&format!("/actions/runs?head_sha={}&per_page=100", sha));
+        let runs: GitHubWorkflowRunList = self.get(&url).await?;
+
+        // Runs come newest first; re-runs and repeated triggers of a workflow
+        // on the same commit only matter through their latest attempt.
+        let mut seen = std::collections::HashSet::new();
+        let mut pipelines = Vec::new();
+        for run in &runs.workflow_runs {
+            let workflow = match run.workflow_id {
+                Some(id) => id.to_string(),
+                None => run.name.clone().unwrap_or_else(|| run.id.to_string()),
+            };
+            if !seen.insert(workflow) {
+                continue;
+            }
+
+            let mut pipeline = map_workflow_run(run);
+            // Jobs are only needed to point at what broke
+            if pipeline.status == "failed" {
+                let jobs_url =
+                    self.repo_url(&format!("/actions/runs/{}/jobs?per_page=100", run.id));
+                let jobs: GitHubJobList = self.get(&jobs_url).await?;
+                pipeline.jobs = jobs.jobs.iter().map(map_job).collect();
+            }
+            pipelines.push(pipeline);
+        }
+
+        // Check runs reported by external CI apps are not part of any workflow run
+        let checks_url = self.repo_url(&format!("/commits/{}/check-runs?per_page=100", sha));
+        let checks: GitHubCheckRunList = self.get(&checks_url).await?;
+        let external: Vec<CiJob> = checks
+            .check_runs
+            .iter()
+            .filter(|c| {
+                c.app
+                    .as_ref()
+                    .and_then(|a| a.slug.as_deref())
+                    .is_some_and(|slug| slug != "github-actions")
+            })
+            .map(map_check_run)
+            .collect();
+
+        if !external.is_empty() {
+            pipelines.push(CiPipeline {
+                id: sha.clone(),
+                name: Some("checks".to_string()),
+                status: aggregate_ci_status(&external),
+                ref_name: Some(pr.head.ref_name.clone()),
+                sha: Some(sha),
+                duration: None,
+                url: None,
+                jobs: external,
+                created_at: None,
+                updated_at: None,
+            });
+        }
+
+        Ok(pipelines)
+    }
+
+    fn has_ci(&self) -> bool {
+        true
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for GitHubClient {
+    async fn get_file(&self, path: &str, ref_name: Option<&str>) -> Result<RepositoryFile> {
+        let url = self.repo_url(&contents_endpoint(path, ref_name));
+        match self.get(&url).await? {
+            GitHubContentResponse::Item(content) => {
+                let mut file = map_content_file(&content)?;
+                file.ref_name = ref_name.map(String::from);
+                Ok(file)
+            }
+            GitHubContentResponse::List(_) => Err(Error::InvalidData(format!(
+                "{} is a directory, use list_tree instead",
+                path
+            ))),
+        }
+    }
+
+    async fn list_tree(&self, path: &str, ref_name: Option<&str>) -> Result<Vec<TreeEntry>> {
+        let url = self.repo_url(&contents_endpoint(path, ref_name));
+        match self.get(&url).await? {
+            GitHubContentResponse::List(contents) => {
+                Ok(contents.iter().map(map_tree_entry).collect())
+            }
+            GitHubContentResponse::Item(content) => Err(Error::InvalidData(format!(
+                "{} is a {}, not a directory",
+                content.path, content.kind
+            ))),
+        }
+    }
+
+    async fn create_branch(&self, name: &str, from_ref: Option<&str>) -> Result<Branch> {
+        let repository: GitHubRepository = self.get(&self.repo_url("")).await?;
+        let from_ref = from_ref.unwrap_or(&repository.default_branch);
+
+        // The refs API needs a SHA, resolve the branch / tag / SHA first
+        let commit_url = self.repo_url(&format!("/commits/{}", encode_uri_component(from_ref)));
+        let commit: GitHubCommit = self.get(&commit_url).await?;
+
+        let request = CreateRefRequest {
+            ref_name: format!("refs/heads/{}", name),
+            sha: commit.sha,
+        };
+        let gh_ref: GitHubGitRef = self.post(&self.repo_url("/git/refs"), &request).await?;
+
+        Ok(Branch {
+            name: name.to_string(),
+            sha: Some(gh_ref.object.sha),
+            url: repository
+                .html_url
+                .map(|url| format!("{}/tree/{}", url, name)),
+        })
+    }
+
+    fn has_repository(&self) -> bool {
+        true
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+/// GitHub has no sprints, all agile operations are unsupported.
+impl AgileProvider for GitHubClient {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
+/// GitHub has no time tracking, all operations are unsupported.
+impl TimeTrackingProvider for GitHubClient {
+    fn provider_name(&self) -> &'static str {
+        "github"
+    }
+}
+
 #[async_trait]
 impl Provider for GitHubClient {
     async fn get_current_user(&self) -> Result<User> {
@@ -640,24 +1669,159 @@ impl Provider for GitHubClient {
         let gh_user: GitHubUser = self.get(&url).await?;
         Ok(map_user_required(Some(&gh_user)))
     }
+
+    fn key_patterns(&self) -> Vec<KeyPattern> {
+        vec![
+            KeyPattern::Prefix("gh#".to_string()),
+            KeyPattern::Prefix("pr#".to_string()),
+            KeyPattern::Url(self.web_repo_url()),
+        ]
+    }
+
+    /// Search the users that can be assigned in the repository.
+    async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        let url = self.repo_url("/assignees?per_page=100");
+        let gh_users: Vec<GitHubUser> = self.get(&url).await?;
+        let users: Vec<User> = gh_users
+            .iter()
+            .map(|u| map_user_required(Some(u)))
+            .collect();
+        Ok(search_members(&users, query))
+    }
 }
 
 // =============================================================================
 // Helper functions
 // =============================================================================
 
-/// Parse issue key like "gh#123" to get issue number.
-fn parse_issue_key(key: &str) -> Result<u64> {
-    key.strip_prefix("gh#")
-        .and_then(|s| s.parse::<u64>().ok())
-        .ok_or_else(|| Error::InvalidData(format!("Invalid issue key: {}", key)))
+/// Build a search API query for issues of a repository from a filter and a
+/// raw query. Qualifiers that would widen the scope beyond the repository
+/// are rejected.
+fn build_search_query(
+    owner: &str,
+    repo: &str,
+    filter: &IssueFilter,
+    query: &str,
+) -> Result<String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(Error::InvalidData("Search query is empty".to_string()));
+    }
+
+    for term in query.split_whitespace() {
+        let qualifier = term.trim_start_matches('-').to_ascii_lowercase();
+        if ["repo:", "org:", "user:"]
+            .iter()
+            .any(|q| qualifier.starts_with(q))
+        {
+            return Err(Error::InvalidData(format!(
+                "Search query must not contain '{}', the search is limited to {}/{}",
+                term, owner, repo
+            )));
+        }
+    }
+
+    let mut parts = vec![format!("repo:{}/{}", owner, repo), "is:issue".to_string()];
+
+    match filter.state.as_deref() {
+        Some("closed") => parts.push("is:closed".to_string()),
+        Some("all") => {}
+        Some(_) => parts.push("is:open".to_string()),
+        None => {}
+    }
+
+    if let Some(labels) = &filter.labels {
+        for label in labels {
+            parts.push(format!("label:\"{}\"", label));
+        }
+    }
+
+    if let Some(assignee) = &filter.assignee {
+        parts.push(format!("assignee:{}", assignee));
+    }
+
+    if let Some(milestone) = &filter.milestone {
+        parts.push(format!("milestone:\"{}\"", milestone));
+    }
+
+    if let Some(search) = &filter.search {
+        parts.push(search.clone());
+    }
+
+    parts.push(query.to_string());
+    Ok(parts.join(" "))
+}
+
+/// Build a search API query for pull requests of a repository. The search
+/// API resolves `@me` in `author:` and `review-requested:` itself.
+fn build_pr_search_query(owner: &str, repo: &str, filter: &MrFilter) -> String {
+    let mut parts = vec![format!("repo:{}/{}", owner, repo), "is:pr".to_string()];
+
+    match filter.state.as_deref() {
+        Some("all") => {}
+        Some("closed") => parts.push("is:closed".to_string()),
+        Some("merged") => parts.push("is:merged".to_string()),
+        _ => parts.push("is:open".to_string()),
+    }
+
+    if let Some(source_branch) = &filter.source_branch {
+        parts.push(format!("head:{}", source_branch));
+    }
+    if let Some(target_branch) = &filter.target_branch {
+        parts.push(format!("base:{}", target_branch));
+    }
+    let login = |user: &str| {
+        if is_me(user) {
+            "@me".to_string()
+        } else {
+            user.trim().to_string()
+        }
+    };
+    if let Some(author) = &filter.author {
+        parts.push(format!("author:{}", login(author)));
+    }
+    if let Some(reviewer) = &filter.reviewer {
+        parts.push(format!("review-requested:{}", login(reviewer)));
+    }
+    if let Some(milestone) = &filter.milestone {
+        parts.push(format!("milestone:\"{}\"", milestone));
+    }
+
+    parts.join(" ")
+}
+
+/// Parse issue key like "gh#123", or an issue URL of the repository at
+/// `repo_url` (e.g. `https://github.com/org/repo/`), to get issue number.
+fn parse_issue_key(key: &str, repo_url: &str) -> Result<u64> {
+    parse_key_number(key, "gh#", &format!("{}issues/", repo_url), "issue")
+}
+
+/// Parse PR key like "pr#123", or a pull request URL of the repository at
+/// `repo_url`, to get PR number.
+fn parse_pr_key(key: &str, repo_url: &str) -> Result<u64> {
+    parse_key_number(key, "pr#", &format!("{}pull/", repo_url), "PR")
+}
+
+/// Parse a numeric workflow run or job ID.
+fn parse_ci_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| Error::InvalidData(format!("Invalid CI ID: {}", id)))
+}
+
+/// Overall status of a set of check runs: the worst status wins.
+fn aggregate_ci_status(jobs: &[CiJob]) -> String {
+    const PRIORITY: [&str; 5] = ["failed", "running", "pending", "canceled", "manual"];
+    PRIORITY
+        .iter()
+        .find(|status| jobs.iter().any(|j| j.status == **status))
+        .map(|status| status.to_string())
+        .unwrap_or_else(|| "success".to_string())
 }
 
-/// Parse PR key like "pr#123" to get PR number.
-fn parse_pr_key(key: &str) -> Result<u64> {
-    key.strip_prefix("pr#")
-        .and_then(|s| s.parse::<u64>().ok())
-        .ok_or_else(|| Error::InvalidData(format!("Invalid PR key: {}", key)))
+/// Seconds elapsed between two ISO 8601 timestamps.
+fn seconds_between(start: &str, end: &str) -> Option<u64> {
+    let elapsed = parse_iso8601(end)? - parse_iso8601(start)?;
+    u64::try_from(elapsed).ok()
 }
 
 #[cfg(test)]
@@ -665,21 +1829,102 @@ mod tests {
     use super::*;
     use crate::types::GitHubBranchRef;
 
+    const REPO_URL: &str = "https://github.com/owner/repo/";
+
+    #[test]
+    fn test_build_search_query() {
+        let filter = IssueFilter {
+            state: Some("open".to_string()),
+            labels: Some(vec!["good first issue".to_string()]),
+            ..Default::default()
+        };
+
+        let q = build_search_query("owner", "repo", &filter, " milestone:v2.3 ").unwrap();
+        assert_eq!(
+            q,
+            "repo:owner/repo is:issue is:open label:\"good first issue\" milestone:v2.3"
+        );
+    }
+
+    #[test]
+    fn test_build_search_query_with_milestone() {
+        let filter = IssueFilter {
+            milestone: Some("Release 2.4".to_string()),
+            ..Default::default()
+        };
+
+        let q = build_search_query("owner", "repo", &filter, "crash").unwrap();
+        assert_eq!(
+            q,
+            "repo:owner/repo is:issue milestone:\"Release 2.4\" crash"
+        );
+    }
+
+    #[test]
+    fn test_build_search_query_rejects_scope_qualifiers() {
+        let filter = IssueFilter::default();
+        assert!(build_search_query("owner", "repo", &filter, "repo:other/repo bug").is_err());
+        assert!(build_search_query("owner", "repo", &filter, "ORG:acme").is_err());
+        assert!(build_search_query("owner", "repo", &filter, "-user:bot").is_err());
+        assert!(build_search_query("owner", "repo", &filter, "   ").is_err());
+    }
+
+    #[test]
+    fn test_is_attachment_url() {
+        let client = GitHubClient::new("owner", "repo", "token");
+        assert!(client.is_attachment_url("https://github.com/user-attachments/assets/1234-abcd"));
+        assert!(client.is_attachment_url("https://github.com/owner/repo/assets/1/abcd"));
+        assert!(client.is_attachment_url("https://github.com/owner/repo/files/9/trace.log"));
+        assert!(!client.is_attachment_url("https://github.com/other/repo/files/9/trace.log"));
+        assert!(!client.is_attachment_url("https://github.com/owner/repo/issues/1"));
+        assert!(!client.is_attachment_url("https://api.github.com/user-attachments/assets/1"));
+
+        let enterprise =
+            GitHubClient::with_base_url("https://ghe.example.com/api/v3", "owner", "repo", "token");
+        assert!(enterprise.is_attachment_url("https://ghe.example.com/user-attachments/assets/1"));
+        assert!(!enterprise.is_attachment_url("https://github.com/user-attachments/assets/1"));
+    }
+
     #[test]
     fn test_parse_issue_key() {
-        assert_eq!(parse_issue_key("gh#123").unwrap(), 123);
-        assert_eq!(parse_issue_key("gh#1").unwrap(), 1);
-        assert!(parse_issue_key("pr#123").is_err());
-        assert!(parse_issue_key("123").is_err());
-        assert!(parse_issue_key("gh#").is_err());
+        assert_eq!(parse_issue_key("gh#123", REPO_URL).unwrap(), 123);
+        assert_eq!(parse_issue_key("gh#1", REPO_URL).unwrap(), 1);
+        assert!(parse_issue_key("pr#123", REPO_URL).is_err());
+        assert!(parse_issue_key("123", REPO_URL).is_err());
+        assert!(parse_issue_key("gh#", REPO_URL).is_err());
+        assert_eq!(
+            parse_issue_key("https://github.com/owner/repo/issues/45", REPO_URL).unwrap(),
+            45
+        );
+        assert!(parse_issue_key("https://github.com/owner/repo/pull/45", REPO_URL).is_err());
+        assert!(parse_issue_key("https://github.com/owner/other/issues/45", REPO_URL).is_err());
     }
 
     #[test]
     fn test_parse_pr_key() {
-        assert_eq!(parse_pr_key("pr#456").unwrap(), 456);
-        assert_eq!(parse_pr_key("pr#1").unwrap(), 1);
-        assert!(parse_pr_key("gh#123").is_err());
-        assert!(parse_pr_key("456").is_err());
+        assert_eq!(parse_pr_key("pr#456", REPO_URL).unwrap(), 456);
+        assert_eq!(parse_pr_key("pr#1", REPO_URL).unwrap(), 1);
+        assert!(parse_pr_key("gh#123", REPO_URL).is_err());
+        assert!(parse_pr_key("456", REPO_URL).is_err());
+        assert_eq!(
+            parse_pr_key("https://github.com/owner/repo/pull/45/files", REPO_URL).unwrap(),
+            45
+        );
+    }
+
+    #[test]
+    fn test_web_repo_url() {
+        let client = GitHubClient::new("owner", "repo", "token");
+        assert_eq!(client.web_repo_url(), REPO_URL);
+        let client =
+            GitHubClient::with_base_url("https://ghe.example.com/api/v3", "owner", "repo", "token");
+        assert_eq!(client.web_repo_url(), "https://ghe.example.com/owner/repo/");
+        assert_eq!(
+            client
+                .issue_number("https://ghe.example.com/owner/repo/issues/7")
+                .unwrap(),
+            7
+        );
     }
 
     #[test]
@@ -745,6 +1990,85 @@ mod tests {
         assert_eq!(result, vec!["bug", "feature"]);
     }
 
+    #[test]
+    fn test_map_ci_status() {
+        assert_eq!(map_ci_status("queued", None), "pending");
+        assert_eq!(map_ci_status("in_progress", None), "running");
+        assert_eq!(map_ci_status("completed", Some("success")), "success");
+        assert_eq!(map_ci_status("completed", Some("failure")), "failed");
+        assert_eq!(map_ci_status("completed", Some("timed_out")), "failed");
+        assert_eq!(map_ci_status("completed", Some("cancelled")), "canceled");
+        assert_eq!(map_ci_status("completed", Some("skipped")), "skipped");
+    }
+
+    #[test]
+    fn test_aggregate_ci_status() {
+        let job = |status: &str| CiJob {
+            status: status.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            aggregate_ci_status(&[job("success"), job("failed")]),
+            "failed"
+        );
+        assert_eq!(
+            aggregate_ci_status(&[job("success"), job("running")]),
+            "running"
+        );
+        assert_eq!(
+            aggregate_ci_status(&[job("success"), job("skipped")]),
+            "success"
+        );
+    }
+
+    #[test]
+    fn test_seconds_between() {
+        assert_eq!(
+            seconds_between("2024-01-15T10:30:00Z", "2024-01-15T10:34:12Z"),
+            Some(252)
+        );
+        assert_eq!(
+            seconds_between("2023-12-31T23:59:00Z", "2024-01-01T00:01:00Z"),
+            Some(120)
+        );
+        assert_eq!(
+            seconds_between("2024-01-15T10:30:00.000+00:00", "2024-01-15T10:30:05Z"),
+            Some(5)
+        );
+        assert_eq!(
+            seconds_between("2024-01-15T12:30:00+02:00", "2024-01-15T10:31:00Z"),
+            Some(60)
+        );
+        assert_eq!(seconds_between("invalid", "2024-01-15T10:30:00Z"), None);
+        assert_eq!(
+            seconds_between("2024-01-15T10:30:05Z", "2024-01-15T10:30:00Z"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_contents_endpoint() {
+        assert_eq!(
+            contents_endpoint("src/main.rs", None),
+            "/contents/src/main.rs"
+        );
+        assert_eq!(
+            contents_endpoint("/docs/my file.md", Some("feature/x")),
+            "/contents/docs/my%20file.md?ref=feature%2Fx"
+        );
+        assert_eq!(contents_endpoint("", None), "/contents/");
+    }
+
+    #[test]
+    fn test_unix_to_iso8601() {
+        assert_eq!(unix_to_iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(unix_to_iso8601(1705314600), "2024-01-15T10:30:00Z");
+        assert_eq!(
+            parse_iso8601(&unix_to_iso8601(1709164800)),
+            Some(1709164800)
+        );
+    }
+
     #[test]
     fn test_map_labels_empty() {
         let result = map_labels(&[]);
@@ -1012,6 +2336,7 @@ mod tests {
             },
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-02T00:00:00Z".to_string(),
+            milestone: None,
         };
 
         let mr = map_pull_request(&pr);
@@ -1057,6 +2382,7 @@ mod tests {
             },
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-02T00:00:00Z".to_string(),
+            milestone: None,
         };
 
         let mr = map_pull_request(&pr);
@@ -1090,6 +2416,11 @@ mod tests {
             updated_at: "2024-01-02T00:00:00Z".to_string(),
             closed_at: None,
             pull_request: None,
+            issue_type: None,
+            sub_issues_summary: None,
+            issue_dependencies_summary: None,
+            repository_url: None,
+            milestone: None,
         };
 
         let issue = map_issue(&gh_issue);
@@ -1131,6 +2462,11 @@ mod tests {
             updated_at: "2024-01-02T00:00:00Z".to_string(),
             closed_at: None,
             pull_request: None,
+            issue_type: None,
+            sub_issues_summary: None,
+            issue_dependencies_summary: None,
+            repository_url: None,
+            milestone: None,
         };
 
         let issue = map_issue(&gh_issue);
@@ -1165,6 +2501,7 @@ mod tests {
             },
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-02T00:00:00Z".to_string(),
+            milestone: None,
         };
 
         // Open PR
@@ -1296,6 +2633,38 @@ mod tests {
             assert_eq!(issues[0].title, "Test Issue");
         }
 
+        #[tokio::test]
+        async fn test_get_issues_with_query_uses_search() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/issues")
+                    .query_param("q", "repo:owner/repo is:issue is:open comments:>5")
+                    .query_param("per_page", "10")
+                    .query_param("page", "1");
+                then.status(200).json_body(serde_json::json!({
+                    "total_count": 1,
+                    "items": [sample_issue_json()]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let issues = client
+                .get_issues(IssueFilter {
+                    state: Some("open".to_string()),
+                    limit: Some(10),
+                    query: Some("comments:>5".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            mock.assert();
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].key, "gh#42");
+        }
+
         #[tokio::test]
         async fn test_get_issues_filters_pull_requests() {
             let server = MockServer::start();
@@ -1354,31 +2723,288 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn test_get_issue() {
+        async fn test_get_issues_with_milestone() {
             let server = MockServer::start();
 
             server.mock(|when, then| {
-                when.method(GET).path("/repos/owner/repo/issues/42");
-                then.status(200).json_body(sample_issue_json());
+                when.method(GET)
+                    .path("/repos/owner/repo/milestones")
+                    .query_param("state", "all");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "number": 3,
+                        "title": "v2.4",
+                        "state": "open",
+                        "open_issues": 2,
+                        "closed_issues": 5,
+                        "html_url": "https://github.com/owner/repo/milestone/3"
+                    }
+                ]));
+            });
+            let issues_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues")
+                    .query_param("milestone", "3");
+                then.status(200).json_body(serde_json::json!([]));
             });
 
             let client = create_test_client(&server);
-            let issue = client.get_issue("gh#42").await.unwrap();
+            client
+                .get_issues(IssueFilter {
+                    milestone: Some("V2.4".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            issues_mock.assert();
 
-            assert_eq!(issue.key, "gh#42");
-            assert_eq!(issue.title, "Test Issue");
+            let result = client
+                .get_issues(IssueFilter {
+                    milestone: Some("v9".to_string()),
+                    ..Default::default()
+                })
+                .await;
+            assert!(matches!(result, Err(Error::NotFound(_))));
         }
 
         #[tokio::test]
-        async fn test_get_issue_rejects_pr() {
+        async fn test_get_milestones() {
             let server = MockServer::start();
 
-            let mut issue_json = sample_issue_json();
-            issue_json["pull_request"] = serde_json::json!({"url": "..."});
-
             server.mock(|when, then| {
-                when.method(GET).path("/repos/owner/repo/issues/42");
-                then.status(200).json_body(issue_json);
+                when.method(GET)
+                    .path("/repos/owner/repo/milestones")
+                    .query_param("state", "open");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "number": 3,
+                        "title": "v2.4",
+                        "description": "Spring release",
+                        "state": "open",
+                        "due_on": "2024-03-01T08:00:00Z",
+                        "open_issues": 2,
+                        "closed_issues": 5,
+                        "html_url": "https://github.com/owner/repo/milestone/3"
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let milestones = client.get_milestones(None).await.unwrap();
+
+            assert_eq!(milestones.len(), 1);
+            assert_eq!(milestones[0].id, "3");
+            assert_eq!(milestones[0].title, "v2.4");
+            assert_eq!(milestones[0].state, "open");
+            assert_eq!(milestones[0].due_date.as_deref(), Some("2024-03-01"));
+            assert_eq!(milestones[0].open_issues, Some(2));
+            assert_eq!(milestones[0].closed_issues, Some(5));
+        }
+
+        #[tokio::test]
+        async fn test_get_issue() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42");
+                then.status(200).json_body(sample_issue_json());
+            });
+
+            let client = create_test_client(&server);
+            let issue = client.get_issue("gh#42").await.unwrap();
+
+            assert_eq!(issue.key, "gh#42");
+            assert_eq!(issue.title, "Test Issue");
+        }
+
+        #[tokio::test]
+        async fn test_get_issue_with_parent_and_sub_issues() {
+            let server = MockServer::start();
+
+            let mut issue_json = sample_issue_json();
+            issue_json["type"] = serde_json::json!({"id": 3, "name": "Feature"});
+            issue_json["sub_issues_summary"] =
+                serde_json::json!({"total": 1, "completed": 0, "percent_completed": 0});
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42");
+                then.status(200).json_body(issue_json);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42/parent");
+                let mut parent = sample_issue_json();
+                parent["number"] = serde_json::json!(40);
+                parent["title"] = serde_json::json!("Roadmap");
+                then.status(200).json_body(parent);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues/42/sub_issues");
+                let mut child = sample_issue_json();
+                child["number"] = serde_json::json!(43);
+                child["title"] = serde_json::json!("Part one");
+                child["state"] = serde_json::json!("closed");
+                then.status(200).json_body(serde_json::json!([child]));
+            });
+
+            let client = create_test_client(&server);
+            let issue = client.get_issue("gh#42").await.unwrap();
+
+            assert_eq!(issue.issue_type.as_deref(), Some("Feature"));
+            let parent = issue.parent.unwrap();
+            assert_eq!(parent.key, "gh#40");
+            assert_eq!(parent.title.as_deref(), Some("Roadmap"));
+            assert_eq!(
+                issue.children,
+                vec![IssueRef {
+                    key: "gh#43".to_string(),
+                    title: Some("Part one".to_string()),
+                    state: Some("closed".to_string()),
+                    issue_type: None,
+                }]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_get_issue_with_links() {
+            let server = MockServer::start();
+
+            let mut issue_json = sample_issue_json();
+            issue_json["issue_dependencies_summary"] = serde_json::json!({
+                "blocked_by": 1, "blocking": 0, "total_blocked_by": 1, "total_blocking": 0
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42");
+                then.status(200).json_body(issue_json);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues/42/dependencies/blocked_by");
+                let mut blocker = sample_issue_json();
+                blocker["number"] = serde_json::json!(41);
+                blocker["title"] = serde_json::json!("Schema migration");
+                then.status(200).json_body(serde_json::json!([blocker]));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues/42/timeline");
+                let mut pr = sample_issue_json();
+                pr["number"] = serde_json::json!(50);
+                pr["title"] = serde_json::json!("Fix #42");
+                pr["pull_request"] = serde_json::json!({"url": "..."});
+                pr["repository_url"] = serde_json::json!("https://api.github.com/repos/owner/repo");
+                let mut foreign = sample_issue_json();
+                foreign["number"] = serde_json::json!(7);
+                foreign["repository_url"] =
+                    serde_json::json!("https://api.github.com/repos/other/repo");
+                then.status(200).json_body(serde_json::json!([
+                    {"event": "labeled", "label": {"name": "bug"}},
+                    {"event": "cross-referenced", "source": {"type": "issue", "issue": pr}},
+                    {"event": "cross-referenced", "source": {"type": "issue", "issue": pr}},
+                    {"event": "cross-referenced", "source": {"type": "issue", "issue": foreign}}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let issue = client.get_issue("gh#42").await.unwrap();
+
+            assert_eq!(issue.links.len(), 2);
+            assert_eq!(issue.links[0].kind, "blocked_by");
+            assert_eq!(issue.links[0].target.key, "gh#41");
+            assert_eq!(issue.links[1].kind, "mentioned_in");
+            assert_eq!(
+                issue.links[1].target,
+                IssueRef {
+                    key: "pr#50".to_string(),
+                    title: Some("Fix #42".to_string()),
+                    state: Some("open".to_string()),
+                    issue_type: Some("pull_request".to_string()),
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn test_link_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42");
+                let mut blocking = sample_issue_json();
+                blocking["id"] = serde_json::json!(4200);
+                then.status(200).json_body(blocking);
+            });
+
+            // "gh#42 blocks gh#43" is stored on gh#43
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/issues/43/dependencies/blocked_by")
+                    .json_body(serde_json::json!({"issue_id": 4200}));
+                then.status(201).json_body(sample_issue_json());
+            });
+
+            let client = create_test_client(&server);
+            client
+                .link_issues("gh#42", "gh#43", "blocks")
+                .await
+                .unwrap();
+            mock.assert();
+
+            let err = client
+                .link_issues("gh#42", "gh#43", "relates_to")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("Unsupported GitHub link kind"));
+        }
+
+        #[tokio::test]
+        async fn test_unlink_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues/42/dependencies/blocked_by");
+                let mut blocker = sample_issue_json();
+                blocker["id"] = serde_json::json!(4100);
+                blocker["number"] = serde_json::json!(41);
+                then.status(200).json_body(serde_json::json!([blocker]));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues/42/dependencies/blocking");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let delete_mock = server.mock(|when, then| {
+                when.method(DELETE)
+                    .path("/repos/owner/repo/issues/42/dependencies/blocked_by/4100");
+                then.status(200).json_body(sample_issue_json());
+            });
+
+            let client = create_test_client(&server);
+            client.unlink_issues("gh#42", "gh#41", None).await.unwrap();
+            delete_mock.assert();
+
+            let err = client
+                .unlink_issues("gh#42", "gh#44", None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        #[tokio::test]
+        async fn test_get_issue_rejects_pr() {
+            let server = MockServer::start();
+
+            let mut issue_json = sample_issue_json();
+            issue_json["pull_request"] = serde_json::json!({"url": "..."});
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/issues/42");
+                then.status(200).json_body(issue_json);
             });
 
             let client = create_test_client(&server);
@@ -1405,6 +3031,10 @@ mod tests {
                     labels: vec!["bug".to_string()],
                     assignees: vec![],
                     priority: None,
+                    custom_fields: Default::default(),
+                    issue_type: None,
+                    parent: None,
+                    container: None,
                 })
                 .await
                 .unwrap();
@@ -1412,6 +3042,42 @@ mod tests {
             assert_eq!(issue.key, "gh#42");
         }
 
+        #[tokio::test]
+        async fn test_create_sub_issue() {
+            let server = MockServer::start();
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/issues")
+                    .body_includes("\"type\":\"Task\"");
+                let mut issue = sample_issue_json();
+                issue["id"] = serde_json::json!(9001);
+                then.status(201).json_body(issue);
+            });
+
+            let link_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/issues/40/sub_issues")
+                    .json_body(serde_json::json!({"sub_issue_id": 9001}));
+                then.status(201).json_body(sample_issue_json());
+            });
+
+            let client = create_test_client(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "New Issue".to_string(),
+                    issue_type: Some("Task".to_string()),
+                    parent: Some("gh#40".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+            link_mock.assert();
+            assert_eq!(issue.parent.unwrap().key, "gh#40");
+        }
+
         #[tokio::test]
         async fn test_update_issue() {
             let server = MockServer::start();
@@ -1475,14 +3141,65 @@ mod tests {
                     "body": "Comment text",
                     "user": {"id": 1, "login": "commenter"},
                     "created_at": "2024-01-15T10:00:00Z"
+                }, {
+                    "id": 2,
+                    "body": format!(
+                        "Logs: [build.log]({}/user-attachments/files/77/build.log), see [wiki](https://example.com/x.log)",
+                        server.base_url()
+                    ),
+                    "user": {"id": 1, "login": "commenter"},
+                    "created_at": "2024-01-15T11:00:00Z"
                 }]));
             });
 
             let client = create_test_client(&server);
             let comments = client.get_comments("gh#42").await.unwrap();
 
-            assert_eq!(comments.len(), 1);
+            assert_eq!(comments.len(), 2);
             assert_eq!(comments[0].body, "Comment text");
+            assert!(comments[0].attachments.is_empty());
+            assert_eq!(comments[1].attachments.len(), 1);
+            assert_eq!(comments[1].attachments[0].name, "build.log");
+            assert_eq!(
+                comments[1].attachments[0].mime_type.as_deref(),
+                Some("text/plain")
+            );
+        }
+
+        #[tokio::test]
+        async fn test_download_attachment() {
+            let server = MockServer::start();
+
+            let download_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user-attachments/assets/1234-abcd")
+                    .header("Authorization", "Bearer test-token");
+                then.status(200).body("file content");
+            });
+
+            let client = create_test_client(&server);
+            let content = client
+                .download_attachment(&format!(
+                    "{}/user-attachments/assets/1234-abcd",
+                    server.base_url()
+                ))
+                .await
+                .unwrap();
+
+            download_mock.assert();
+            assert_eq!(content, b"file content");
+
+            let err = client
+                .download_attachment("https://example.com/user-attachments/assets/1234-abcd")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("is not an attachment URL"));
+
+            let err = client
+                .upload_attachment("gh#42", "a.txt", b"a")
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::ProviderUnsupported { .. }));
         }
 
         #[tokio::test]
@@ -1682,6 +3399,216 @@ mod tests {
             assert_eq!(diffs[0].additions, Some(10));
         }
 
+        #[tokio::test]
+        async fn test_get_commits() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls/10/commits");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "sha": "aaa111",
+                        "commit": {
+                            "message": "Add feature\n\nLonger explanation",
+                            "author": {
+                                "name": "Test User",
+                                "email": "test@example.com",
+                                "date": "2024-01-01T00:00:00Z"
+                            },
+                            "committer": {"date": "2024-01-01T01:00:00Z"}
+                        },
+                        "author": {"id": 1, "login": "test"},
+                        "html_url": "https://github.com/owner/repo/commit/aaa111"
+                    },
+                    {
+                        "sha": "bbb222",
+                        "commit": {
+                            "message": "Fix typo",
+                            "author": {"name": "Someone Else"}
+                        },
+                        "author": null
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let commits = client.get_commits("pr#10").await.unwrap();
+
+            assert_eq!(commits.len(), 2);
+            assert_eq!(commits[0].title, "Add feature");
+            assert_eq!(commits[0].message, "Add feature\n\nLonger explanation");
+            let author = commits[0].author.as_ref().unwrap();
+            assert_eq!(author.username, "test");
+            assert_eq!(author.name, Some("Test User".to_string()));
+            assert_eq!(
+                commits[0].committed_at,
+                Some("2024-01-01T01:00:00Z".to_string())
+            );
+            assert_eq!(commits[1].author.as_ref().unwrap().username, "Someone Else");
+        }
+
+        #[tokio::test]
+        async fn test_get_commit_diff() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/commits/aaa111");
+                then.status(200).json_body(serde_json::json!({
+                    "sha": "aaa111",
+                    "commit": {"message": "Add feature"},
+                    "files": [{
+                        "sha": "f1",
+                        "filename": "src/lib.rs",
+                        "status": "added",
+                        "additions": 5,
+                        "deletions": 0,
+                        "changes": 5,
+                        "patch": "@@ +pub fn feature() {}"
+                    }]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let diffs = client.get_commit_diff("aaa111").await.unwrap();
+
+            assert_eq!(diffs.len(), 1);
+            assert_eq!(diffs[0].file_path, "src/lib.rs");
+            assert!(diffs[0].new_file);
+        }
+
+        #[tokio::test]
+        async fn test_get_file() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/contents/src/main.rs")
+                    .query_param("ref", "abc123");
+                then.status(200).json_body(serde_json::json!({
+                    "type": "file",
+                    "name": "main.rs",
+                    "path": "src/main.rs",
+                    "size": 12,
+                    "sha": "blob1",
+                    "encoding": "base64",
+                    "content": "Zm4gbWFp\nbigpIHt9\n",
+                    "html_url": "https://github.com/owner/repo/blob/abc123/src/main.rs"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let file = client
+                .get_file("src/main.rs", Some("abc123"))
+                .await
+                .unwrap();
+
+            assert_eq!(file.content, "fn main() {}");
+            assert_eq!(file.ref_name, Some("abc123".to_string()));
+            assert!(file.url.is_some());
+        }
+
+        #[tokio::test]
+        async fn test_get_file_too_large() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/contents/big.json");
+                then.status(200).json_body(serde_json::json!({
+                    "type": "file",
+                    "name": "big.json",
+                    "path": "big.json",
+                    "size": 5000000,
+                    "encoding": "none",
+                    "content": ""
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let result = client.get_file("big.json", None).await;
+
+            assert!(matches!(result.unwrap_err(), Error::InvalidData(_)));
+        }
+
+        #[tokio::test]
+        async fn test_get_file_on_directory() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/contents/src");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_test_client(&server);
+            let result = client.get_file("src", None).await;
+
+            assert!(matches!(result.unwrap_err(), Error::InvalidData(_)));
+        }
+
+        #[tokio::test]
+        async fn test_list_tree() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/contents/src");
+                then.status(200).json_body(serde_json::json!([
+                    {"type": "dir", "name": "bin", "path": "src/bin", "size": 0},
+                    {"type": "file", "name": "lib.rs", "path": "src/lib.rs", "size": 120}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let entries = client.list_tree("src", None).await.unwrap();
+
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].kind, "dir");
+            assert!(entries[0].size.is_none());
+            assert_eq!(entries[1].size, Some(120));
+        }
+
+        #[tokio::test]
+        async fn test_create_branch() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo");
+                then.status(200).json_body(serde_json::json!({
+                    "default_branch": "main",
+                    "html_url": "https://github.com/owner/repo"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/commits/main");
+                then.status(200).json_body(serde_json::json!({
+                    "sha": "abc123",
+                    "commit": {"message": "Latest"}
+                }));
+            });
+
+            let create = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/git/refs")
+                    .json_body(serde_json::json!({
+                        "ref": "refs/heads/7-fix-login",
+                        "sha": "abc123"
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "ref": "refs/heads/7-fix-login",
+                    "object": {"sha": "abc123"}
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let branch = client.create_branch("7-fix-login", None).await.unwrap();
+
+            create.assert();
+            assert_eq!(branch.name, "7-fix-login");
+            assert_eq!(
+                branch.url,
+                Some("https://github.com/owner/repo/tree/7-fix-login".to_string())
+            );
+        }
+
         #[tokio::test]
         async fn test_add_mr_comment_general() {
             let server = MockServer::start();
@@ -1819,6 +3746,526 @@ mod tests {
             assert!(matches!(err, Error::ServerError { .. }));
         }
 
+        #[tokio::test]
+        async fn test_get_pipelines() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls/10");
+                then.status(200).json_body(sample_pr_json());
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/runs")
+                    .query_param("head_sha", "abc123");
+                then.status(200).json_body(serde_json::json!({
+                    "total_count": 1,
+                    "workflow_runs": [{
+                        "id": 555,
+                        "name": "CI",
+                        "status": "completed",
+                        "conclusion": "failure",
+                        "head_branch": "feature",
+                        "head_sha": "abc123",
+                        "html_url": "https://github.com/owner/repo/actions/runs/555",
+                        "run_started_at": "2024-01-01T00:00:00Z",
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "updated_at": "2024-01-01T00:03:30Z"
+                    }]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipelines = client.get_pipelines("pr#10").await.unwrap();
+
+            assert_eq!(pipelines.len(), 1);
+            assert_eq!(pipelines[0].id, "555");
+            assert_eq!(pipelines[0].name, Some("CI".to_string()));
+            assert_eq!(pipelines[0].status, "failed");
+            assert_eq!(pipelines[0].duration, Some(210));
+        }
+
+        #[tokio::test]
+        async fn test_get_latest_pipelines_with_external_checks() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls/10");
+                then.status(200).json_body(sample_pr_json());
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/actions/runs");
+                then.status(200).json_body(serde_json::json!({
+                    "workflow_runs": [
+                        {
+                            "id": 556,
+                            "workflow_id": 1,
+                            "name": "CI",
+                            "status": "in_progress",
+                            "head_sha": "abc123",
+                            "html_url": "https://github.com/owner/repo/actions/runs/556",
+                            "created_at": "2024-01-01T00:05:00Z",
+                            "updated_at": "2024-01-01T00:06:00Z"
+                        },
+                        {
+                            "id": 555,
+                            "workflow_id": 1,
+                            "name": "CI",
+                            "status": "completed",
+                            "conclusion": "failure",
+                            "head_sha": "abc123",
+                            "html_url": "https://github.com/owner/repo/actions/runs/555",
+                            "created_at": "2024-01-01T00:00:00Z",
+                            "updated_at": "2024-01-01T00:01:00Z"
+                        },
+                        {
+                            "id": 557,
+                            "workflow_id": 2,
+                            "name": "Release",
+                            "status": "completed",
+                            "conclusion": "failure",
+                            "head_sha": "abc123",
+                            "html_url": "https://github.com/owner/repo/actions/runs/557",
+                            "run_started_at": "2024-01-01T00:00:00Z",
+                            "created_at": "2024-01-01T00:00:00Z",
+                            "updated_at": "2024-01-01T00:02:00Z"
+                        }
+                    ]
+                }));
+            });
+
+            let running_jobs = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/runs/556/jobs");
+                then.status(200).json_body(serde_json::json!({"jobs": []}));
+            });
+
+            let stale_jobs = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/runs/555/jobs");
+                then.status(200).json_body(serde_json::json!({"jobs": []}));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/runs/557/jobs");
+                then.status(200).json_body(serde_json::json!({
+                    "jobs": [
+                        {
+                            "id": 1,
+                            "name": "build",
+                            "status": "completed",
+                            "conclusion": "success",
+                            "started_at": "2024-01-01T00:00:00Z",
+                            "completed_at": "2024-01-01T00:00:45Z"
+                        },
+                        {
+                            "id": 2,
+                            "name": "publish",
+                            "status": "completed",
+                            "conclusion": "failure",
+                            "started_at": "2024-01-01T00:00:45Z",
+                            "completed_at": "2024-01-01T00:01:00Z"
+                        }
+                    ]
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/commits/abc123/check-runs");
+                then.status(200).json_body(serde_json::json!({
+                    "check_runs": [
+                        {
+                            "id": 10,
+                            "name": "lint",
+                            "status": "completed",
+                            "conclusion": "success",
+                            "app": {"slug": "github-actions"}
+                        },
+                        {
+                            "id": 11,
+                            "name": "ci/circleci: build",
+                            "status": "completed",
+                            "conclusion": "failure",
+                            "app": {"slug": "circleci-checks"}
+                        }
+                    ]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipelines = client.get_latest_pipelines("pr#10").await.unwrap();
+
+            assert_eq!(pipelines.len(), 3);
+            assert_eq!(pipelines[0].id, "556");
+            assert_eq!(pipelines[0].status, "running");
+            assert!(pipelines[0].jobs.is_empty());
+            assert_eq!(pipelines[1].id, "557");
+            assert_eq!(pipelines[1].status, "failed");
+            assert_eq!(pipelines[1].jobs.len(), 2);
+            assert_eq!(pipelines[1].jobs[0].duration, Some(45));
+            assert_eq!(pipelines[1].jobs[1].status, "failed");
+            assert_eq!(pipelines[2].name, Some("checks".to_string()));
+            assert_eq!(pipelines[2].status, "failed");
+            assert_eq!(pipelines[2].jobs.len(), 1);
+            assert_eq!(pipelines[2].jobs[0].name, "ci/circleci: build");
+            running_jobs.assert_calls(0);
+            stale_jobs.assert_calls(0);
+        }
+
+        #[tokio::test]
+        async fn test_get_job_log_follows_redirect() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/jobs/2/logs");
+                then.status(302)
+                    .header("Location", server.url("/download/job-2.txt"));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/download/job-2.txt");
+                then.status(200).body(
+                    "2024-01-01T00:00:00.0000000Z ##[error]Process completed with exit code 1.",
+                );
+            });
+
+            let client = create_test_client(&server);
+            let log = client.get_job_log("2").await.unwrap();
+
+            assert!(log.contains("##[error]Process completed with exit code 1."));
+        }
+
+        fn sample_run_json(status: &str) -> serde_json::Value {
+            serde_json::json!({
+                "id": 555,
+                "name": "CI",
+                "status": status,
+                "head_branch": "feature",
+                "head_sha": "abc123",
+                "html_url": "https://github.com/owner/repo/actions/runs/555",
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-01T00:01:00Z"
+            })
+        }
+
+        #[tokio::test]
+        async fn test_retry_pipeline() {
+            let server = MockServer::start();
+
+            let rerun = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/actions/runs/555/rerun-failed-jobs");
+                then.status(201);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/actions/runs/555");
+                then.status(200).json_body(sample_run_json("queued"));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.retry_pipeline("555").await.unwrap();
+
+            rerun.assert();
+            assert_eq!(pipeline.id, "555");
+            assert_eq!(pipeline.status, "pending");
+        }
+
+        #[tokio::test]
+        async fn test_retry_job() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/actions/jobs/2");
+                then.status(200).json_body(serde_json::json!({
+                    "id": 2,
+                    "run_id": 555,
+                    "name": "test",
+                    "status": "completed",
+                    "conclusion": "failure"
+                }));
+            });
+
+            let rerun = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/actions/jobs/2/rerun");
+                then.status(201);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/actions/runs/555");
+                then.status(200).json_body(sample_run_json("queued"));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.retry_job("2").await.unwrap();
+
+            rerun.assert();
+            assert_eq!(pipeline.id, "555");
+        }
+
+        #[tokio::test]
+        async fn test_cancel_pipeline() {
+            let server = MockServer::start();
+
+            let cancel = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/actions/runs/555/cancel");
+                then.status(202);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/actions/runs/555");
+                then.status(200).json_body(sample_run_json("in_progress"));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.cancel_pipeline("555").await.unwrap();
+
+            cancel.assert();
+            assert_eq!(pipeline.status, "running");
+        }
+
+        #[tokio::test]
+        async fn test_run_pipeline() {
+            let server = MockServer::start();
+
+            let dispatch = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/repos/owner/repo/actions/workflows/ci.yml/dispatches")
+                    .json_body(serde_json::json!({
+                        "ref": "feature/a+b",
+                        "inputs": {"debug": "true"}
+                    }));
+                then.status(204);
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/actions/workflows/ci.yml/runs")
+                    .query_param("event", "workflow_dispatch")
+                    .query_param("branch", "feature/a+b");
+                then.status(200).json_body(serde_json::json!({
+                    "workflow_runs": [sample_run_json("queued")]
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client
+                .run_pipeline(RunPipelineInput {
+                    ref_name: "feature/a+b".to_string(),
+                    variables: [("debug".to_string(), "true".to_string())].into(),
+                    workflow: Some("ci.yml".to_string()),
+                })
+                .await
+                .unwrap();
+
+            dispatch.assert();
+            assert_eq!(pipeline.id, "555");
+        }
+
+        #[tokio::test]
+        async fn test_run_pipeline_requires_workflow() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client
+                .run_pipeline(RunPipelineInput {
+                    ref_name: "main".to_string(),
+                    ..Default::default()
+                })
+                .await;
+
+            assert!(matches!(result.unwrap_err(), Error::InvalidData(_)));
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_assigned_to_me() {
+            let server = MockServer::start();
+
+            let user_mock = server.mock(|when, then| {
+                when.method(GET).path("/user");
+                then.status(200)
+                    .json_body(serde_json::json!({"id": 7, "login": "octocat"}));
+            });
+            let issues_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/repos/owner/repo/issues")
+                    .query_param("assignee", "octocat");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_test_client(&server);
+            for _ in 0..2 {
+                client
+                    .get_issues(IssueFilter {
+                        assignee: Some("@me".to_string()),
+                        ..Default::default()
+                    })
+                    .await
+                    .unwrap();
+            }
+
+            // The current user is looked up once
+            user_mock.assert_calls(1);
+            issues_mock.assert_calls(2);
+        }
+
+        #[tokio::test]
+        async fn test_get_merge_requests_by_author_and_reviewer() {
+            let server = MockServer::start();
+
+            // The newest page of the pulls endpoint holds no match
+            let pulls = server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls");
+                then.status(200)
+                    .json_body(serde_json::json!([sample_pr_json()]));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/issues")
+                    .query_param(
+                        "q",
+                        "repo:owner/repo is:pr is:open author:@me review-requested:hubot",
+                    )
+                    .query_param("per_page", "30");
+                then.status(200).json_body(serde_json::json!({
+                    "total_count": 1,
+                    "items": [{
+                        "id": 42,
+                        "number": 42,
+                        "title": "Old PR",
+                        "state": "open",
+                        "html_url": "https://github.com/owner/repo/pull/42",
+                        "user": {"id": 7, "login": "octocat"},
+                        "labels": [],
+                        "assignees": [],
+                        "created_at": "2023-01-01T00:00:00Z",
+                        "updated_at": "2023-01-02T00:00:00Z"
+                    }]
+                }));
+            });
+            let mut old = sample_pr_json();
+            old["number"] = serde_json::json!(42);
+            old["user"] = serde_json::json!({"id": 7, "login": "octocat"});
+            old["requested_reviewers"] = serde_json::json!([{"id": 8, "login": "hubot"}]);
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls/42");
+                then.status(200).json_body(old);
+            });
+
+            let client = create_test_client(&server);
+            let prs = client
+                .get_merge_requests(MrFilter {
+                    author: Some("@me".to_string()),
+                    reviewer: Some("hubot".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(prs.len(), 1);
+            assert_eq!(prs[0].key, "pr#42");
+            assert_eq!(prs[0].author.as_ref().unwrap().username, "octocat");
+            assert_eq!(prs[0].source_branch, "feature");
+            pulls.assert_calls(0);
+        }
+
+        #[tokio::test]
+        async fn test_get_merge_requests_by_milestone() {
+            let server = MockServer::start();
+
+            let pulls = server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/issues")
+                    .query_param("q", "repo:owner/repo is:pr is:open milestone:\"2.4\"");
+                then.status(200).json_body(serde_json::json!({
+                    "total_count": 1,
+                    "items": [sample_issue_json()]
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/pulls/42");
+                then.status(200).json_body(sample_pr_json());
+            });
+
+            let client = create_test_client(&server);
+            let prs = client
+                .get_merge_requests(MrFilter {
+                    milestone: Some("2.4".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(prs.len(), 1);
+            pulls.assert_calls(0);
+        }
+
+        #[test]
+        fn test_build_pr_search_query() {
+            let filter = MrFilter {
+                state: Some("merged".to_string()),
+                target_branch: Some("main".to_string()),
+                reviewer: Some(" @ME ".to_string()),
+                milestone: Some("Release 2.4".to_string()),
+                ..Default::default()
+            };
+            assert_eq!(
+                build_pr_search_query("owner", "repo", &filter),
+                "repo:owner/repo is:pr is:merged base:main review-requested:@me milestone:\"Release 2.4\""
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_labels() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/labels");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": 1, "name": "bug", "color": "d73a4a", "description": "Something isn't working"},
+                    {"id": 2, "name": "wontfix", "color": "ffffff", "description": ""}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let labels = client.list_labels().await.unwrap();
+
+            assert_eq!(labels.len(), 2);
+            assert_eq!(labels[0].color.as_deref(), Some("#d73a4a"));
+            assert_eq!(labels[1].description, None);
+            assert_eq!(client.list_statuses().await.unwrap().len(), 2);
+        }
+
+        #[tokio::test]
+        async fn test_search_users() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/repos/owner/repo/assignees");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": 7, "login": "octocat"},
+                    {"id": 8, "login": "hubot"}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let users = client.search_users("OCTO").await.unwrap();
+
+            assert_eq!(users.len(), 1);
+            assert_eq!(users[0].username, "octocat");
+        }
+
         #[tokio::test]
         async fn test_get_current_user() {
             let server = MockServer::start();
@@ -3,6 +3,8 @@
 //! These types represent the raw JSON responses from GitHub API.
 //! They are deserialized and then mapped to unified types.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -49,6 +51,86 @@ pub struct GitHubIssue {
     /// PRs are also returned by /issues endpoint, this field distinguishes them
     #[serde(default)]
     pub pull_request: Option<serde_json::Value>,
+    /// Organization issue type
+    #[serde(default, rename = "type")]
+    pub issue_type: Option<GitHubIssueType>,
+    #[serde(default)]
+    pub sub_issues_summary: Option<GitHubSubIssuesSummary>,
+    #[serde(default)]
+    pub issue_dependencies_summary: Option<GitHubDependenciesSummary>,
+    /// API URL of the repository (e.g., "https://api.github.com/repos/owner/repo")
+    #[serde(default)]
+    pub repository_url: Option<String>,
+    #[serde(default)]
+    pub milestone: Option<GitHubMilestone>,
+}
+
+/// GitHub milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubMilestone {
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// "open" or "closed"
+    pub state: String,
+    /// Due date as a timestamp (e.g., "2024-03-01T08:00:00Z")
+    #[serde(default)]
+    pub due_on: Option<String>,
+    /// Open issues and pull requests
+    #[serde(default)]
+    pub open_issues: u32,
+    /// Closed issues and pull requests
+    #[serde(default)]
+    pub closed_issues: u32,
+    pub html_url: String,
+}
+
+/// Organization issue type (e.g., "Bug", "Feature", "Task").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubIssueType {
+    pub name: String,
+}
+
+/// Sub-issue counts of an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubSubIssuesSummary {
+    pub total: u64,
+    #[serde(default)]
+    pub completed: u64,
+}
+
+/// Issue dependency counts of an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubDependenciesSummary {
+    #[serde(default)]
+    pub total_blocked_by: u64,
+    #[serde(default)]
+    pub total_blocking: u64,
+}
+
+/// Issue timeline event; only cross-references (`source`) are used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubTimelineEvent {
+    #[serde(default)]
+    pub event: Option<String>,
+    #[serde(default)]
+    pub source: Option<GitHubTimelineSource>,
+}
+
+/// Issue or pull request that referenced the issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubTimelineSource {
+    #[serde(default)]
+    pub issue: Option<GitHubIssue>,
+}
+
+/// Response of the issue search API (`/search/issues`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubSearchResponse<T> {
+    pub total_count: u64,
+    #[serde(default = "Vec::new")]
+    pub items: Vec<T>,
 }
 
 /// GitHub label representation.
@@ -94,6 +176,8 @@ pub struct GitHubPullRequest {
     pub base: GitHubBranchRef,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub milestone: Option<GitHubMilestone>,
 }
 
 /// GitHub branch reference.
@@ -176,6 +260,46 @@ pub struct GitHubReview {
     pub submitted_at: Option<String>,
 }
 
+// =============================================================================
+// Commits
+// =============================================================================
+
+/// GitHub commit (pull request commits list and single commit endpoints).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCommit {
+    pub sha: String,
+    pub commit: GitHubGitCommit,
+    /// Linked GitHub account of the git author (None when not linked)
+    #[serde(default)]
+    pub author: Option<GitHubUser>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    /// Changed files (only returned by the single commit endpoint)
+    #[serde(default)]
+    pub files: Vec<GitHubFile>,
+}
+
+/// Git data of a GitHub commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubGitCommit {
+    pub message: String,
+    #[serde(default)]
+    pub author: Option<GitHubGitActor>,
+    #[serde(default)]
+    pub committer: Option<GitHubGitActor>,
+}
+
+/// Git author / committer of a commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubGitActor {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub date: Option<String>,
+}
+
 // =============================================================================
 // Files (Diffs)
 // =============================================================================
@@ -196,6 +320,161 @@ pub struct GitHubFile {
     pub previous_filename: Option<String>,
 }
 
+// =============================================================================
+// Repository contents
+// =============================================================================
+
+/// GitHub repository (only the fields we need).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubRepository {
+    pub default_branch: String,
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+/// Request to create a git reference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRefRequest {
+    /// Fully qualified ref name, e.g. "refs/heads/feature"
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub sha: String,
+}
+
+/// GitHub git reference.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubGitRef {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    pub object: GitHubGitObject,
+}
+
+/// Object a git reference points to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubGitObject {
+    pub sha: String,
+}
+
+/// GitHub repository content item (`/contents/:path`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubContent {
+    pub name: String,
+    pub path: String,
+    /// file, dir, symlink, submodule
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub sha: Option<String>,
+    /// "base64", or "none" for files larger than 1 MB
+    #[serde(default)]
+    pub encoding: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+/// Response of `/contents/:path`: a single item for files, a list for directories.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GitHubContentResponse {
+    List(Vec<GitHubContent>),
+    Item(GitHubContent),
+}
+
+// =============================================================================
+// Actions (Workflow runs, Jobs, Check runs)
+// =============================================================================
+
+/// GitHub Actions workflow run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubWorkflowRun {
+    pub id: u64,
+    #[serde(default)]
+    pub workflow_id: Option<u64>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// queued, in_progress, completed, waiting, requested, pending
+    pub status: String,
+    /// success, failure, cancelled, skipped, neutral, timed_out, action_required, ...
+    #[serde(default)]
+    pub conclusion: Option<String>,
+    #[serde(default)]
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub run_started_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Response of the workflow runs list endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubWorkflowRunList {
+    #[serde(default)]
+    pub workflow_runs: Vec<GitHubWorkflowRun>,
+}
+
+/// GitHub Actions job of a workflow run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubJob {
+    pub id: u64,
+    #[serde(default)]
+    pub run_id: Option<u64>,
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub conclusion: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+/// Response of the workflow run jobs endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubJobList {
+    #[serde(default)]
+    pub jobs: Vec<GitHubJob>,
+}
+
+/// GitHub check run (reported by Actions or by external CI apps).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCheckRun {
+    pub id: u64,
+    pub name: String,
+    pub status: String,
+    #[serde(default)]
+    pub conclusion: Option<String>,
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    #[serde(default)]
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub app: Option<GitHubApp>,
+}
+
+/// GitHub App that reported a check run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubApp {
+    #[serde(default)]
+    pub slug: Option<String>,
+}
+
+/// Response of the check runs endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCheckRunList {
+    #[serde(default)]
+    pub check_runs: Vec<GitHubCheckRun>,
+}
+
 // =============================================================================
 // Create/Update types
 // =============================================================================
@@ -210,6 +489,21 @@ pub struct CreateIssueRequest {
     pub labels: Vec<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub assignees: Vec<String>,
+    /// Organization issue type name
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+}
+
+/// Request body for adding a sub-issue (`sub_issue_id` is the issue ID, not its number).
+#[derive(Debug, Clone, Serialize)]
+pub struct AddSubIssueRequest {
+    pub sub_issue_id: u64,
+}
+
+/// Request body for adding an issue dependency (`issue_id` is the issue ID, not its number).
+#[derive(Debug, Clone, Serialize)]
+pub struct AddDependencyRequest {
+    pub issue_id: u64,
 }
 
 /// Request body for updating an issue.
@@ -246,3 +540,12 @@ pub struct CreateReviewCommentRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub in_reply_to: Option<u64>,
 }
+
+/// Request body for triggering a workflow_dispatch event.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowDispatchRequest {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub inputs: HashMap<String, String>,
+}
@@ -1,26 +1,42 @@
 //! GitLab API client implementation.
 
 use async_trait::async_trait;
+use devboy_core::attachments::{
+    is_same_origin, markdown_attachments, mime_type_for, multipart_file,
+};
+use devboy_core::encoding::{decode_base64, encode_uri_component, is_binary};
+use devboy_core::keys::{is_url, parse_key_number, KeyPattern};
+use devboy_core::members::{is_me, MemberDirectory};
 use devboy_core::{
-    CodePosition, Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff,
-    Issue, IssueFilter, IssueProvider, MergeRequest, MergeRequestProvider, MrFilter, Provider,
-    Result, UpdateIssueInput, User,
+    AgileProvider, Attachment, Branch, CiJob, CiPipeline, CodePosition, Comment, Commit,
+    CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff, Issue, IssueFilter,
+    IssueLink, IssueProvider, IssueRef, IssueStatus, Label, MergeRequest, MergeRequestProvider,
+    Milestone, MrFilter, PipelineProvider, Provider, RepositoryFile, RepositoryProvider, Result,
+    RunPipelineInput, TimeTrackingProvider, TreeEntry, UpdateIssueInput, User,
 };
+use futures_util::stream::{self, StreamExt};
 use tracing::{debug, warn};
 
 use crate::types::{
-    CreateDiscussionRequest, CreateIssueRequest, CreateNoteRequest, DiscussionPosition, GitLabDiff,
-    GitLabDiscussion, GitLabIssue, GitLabMergeRequest, GitLabMergeRequestChanges, GitLabNote,
-    GitLabNotePosition, GitLabUser, UpdateIssueRequest,
+    CreateDiscussionRequest, CreateIssueLinkRequest, CreateIssueRequest, CreateNoteRequest,
+    CreatePipelineRequest, DiscussionPosition, GitLabBranch, GitLabCommit, GitLabDiff,
+    GitLabDiscussion, GitLabFile, GitLabIssue, GitLabIssuesStatistics, GitLabIteration, GitLabJob,
+    GitLabLabel, GitLabLinkedIssue, GitLabMergeRequest, GitLabMergeRequestChanges, GitLabMilestone,
+    GitLabNote, GitLabNotePosition, GitLabPipeline, GitLabProject, GitLabTreeEntry, GitLabUpload,
+    GitLabUser, PipelineVariable, TimeTrackingRequest, UpdateIssueRequest,
 };
 use crate::DEFAULT_GITLAB_URL;
 
+/// Number of milestones whose issues are counted at the same time.
+const COUNT_CONCURRENCY: usize = 4;
+
 /// GitLab API client.
 pub struct GitLabClient {
     base_url: String,
     project_id: String,
     token: String,
     client: reqwest::Client,
+    members: MemberDirectory,
 }
 
 impl GitLabClient {
@@ -40,6 +56,7 @@ impl GitLabClient {
             project_id: project_id.into(),
             token: token.into(),
             client: reqwest::Client::new(),
+            members: MemberDirectory::new(),
         }
     }
 
@@ -58,6 +75,24 @@ impl GitLabClient {
         )
     }
 
+    /// Web URL of the project, e.g. `https://gitlab.com/group/proj/`.
+    /// `None` when the project is configured by numeric ID.
+    fn web_project_url(&self) -> Option<String> {
+        let path = self.project_id.replace("%2F", "/").replace("%2f", "/");
+        path.contains('/')
+            .then(|| format!("{}/{}/", self.base_url, path.trim_matches('/')))
+    }
+
+    /// Iid of an issue key or issue URL.
+    fn issue_iid(&self, key: &str) -> Result<u64> {
+        parse_issue_key(key, self.web_project_url().as_deref())
+    }
+
+    /// Iid of an MR key or merge request URL.
+    fn mr_iid(&self, key: &str) -> Result<u64> {
+        parse_mr_key(key, self.web_project_url().as_deref())
+    }
+
     /// Get the API URL for a given endpoint (non-project-scoped).
     fn api_url(&self, endpoint: &str) -> String {
         format!("{}/api/v4{}", self.base_url, endpoint)
@@ -112,6 +147,110 @@ impl GitLabClient {
         self.handle_response(response).await
     }
 
+    /// Make an authenticated DELETE request, ignoring the response body.
+    async fn delete(&self, url: &str) -> Result<()> {
+        debug!(url = url, "GitLab DELETE request");
+
+        let response = self
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitLab API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        Ok(())
+    }
+
+    /// Make an authenticated GET request returning the raw response body as text.
+    async fn get_text(&self, url: &str) -> Result<String> {
+        debug!(url = url, "GitLab GET text request");
+
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitLab API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
+    /// Make an authenticated GET request returning the raw response body.
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        debug!(url = url, "GitLab GET bytes request");
+
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "GitLab API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
+    /// Make an authenticated `multipart/form-data` POST request uploading one file.
+    async fn post_file<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        field: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<T> {
+        debug!(url = url, file_name = file_name, "GitLab POST file request");
+
+        let (content_type, body) = multipart_file(field, file_name, content);
+        let response = self
+            .request(reqwest::Method::POST, url)
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        self.handle_response(response).await
+    }
+
     /// Handle response and map errors.
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
@@ -135,12 +274,82 @@ impl GitLabClient {
             .await
             .map_err(|e| Error::InvalidData(format!("Failed to parse response: {}", e)))
     }
+
+    /// Get the issues linked to an issue.
+    async fn get_issue_links(&self, iid: u64) -> Result<Vec<GitLabLinkedIssue>> {
+        let url = self.project_url(&format!("/issues/{}/links", iid));
+        self.get(&url).await
+    }
+
+    /// Resolve the `@me` alias to the username of the authenticated user.
+    async fn resolve_username(&self, user: &str) -> Result<String> {
+        if !is_me(user) {
+            return Ok(user.to_string());
+        }
+        let me = self
+            .members
+            .current_user(|| self.get_current_user())
+            .await?;
+        Ok(me.username)
+    }
+
+    /// Count the open and closed issues matching a filter such as `milestone=2.4`.
+    async fn count_issues(&self, filter: &str) -> Result<(u32, u32)> {
+        let url = self.project_url(&format!("/issues_statistics?{}", filter));
+        let stats: GitLabIssuesStatistics = self.get(&url).await?;
+        Ok((
+            stats.statistics.counts.opened,
+            stats.statistics.counts.closed,
+        ))
+    }
 }
 
 // =============================================================================
 // Mapping functions: GitLab types -> Unified types
 // =============================================================================
 
+fn map_milestone(gl_milestone: &GitLabMilestone) -> Milestone {
+    Milestone {
+        id: gl_milestone.id.to_string(),
+        title: gl_milestone.title.clone(),
+        kind: "milestone".to_string(),
+        state: if gl_milestone.state == "closed" {
+            "closed".to_string()
+        } else {
+            "open".to_string()
+        },
+        description: gl_milestone.description.clone().filter(|d| !d.is_empty()),
+        start_date: gl_milestone.start_date.clone(),
+        due_date: gl_milestone.due_date.clone(),
+        open_issues: None,
+        closed_issues: None,
+        url: gl_milestone.web_url.clone(),
+    }
+}
+
+fn map_iteration(gl_iteration: &GitLabIteration) -> Milestone {
+    Milestone {
+        id: gl_iteration.id.to_string(),
+        title: gl_iteration
+            .title
+            .clone()
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| format!("Iteration {}", gl_iteration.iid)),
+        kind: "iteration".to_string(),
+        state: if gl_iteration.state == 3 {
+            "closed".to_string()
+        } else {
+            "open".to_string()
+        },
+        description: gl_iteration.description.clone().filter(|d| !d.is_empty()),
+        start_date: gl_iteration.start_date.clone(),
+        due_date: gl_iteration.due_date.clone(),
+        open_issues: None,
+        closed_issues: None,
+        url: gl_iteration.web_url.clone(),
+    }
+}
+
 fn map_user(gl_user: Option<&GitLabUser>) -> Option<User> {
     gl_user.map(|u| User {
         id: u.id.to_string(),
@@ -178,6 +387,139 @@ fn map_issue(gl_issue: &GitLabIssue) -> Issue {
         url: Some(gl_issue.web_url.clone()),
         created_at: Some(gl_issue.created_at.clone()),
         updated_at: Some(gl_issue.updated_at.clone()),
+        custom_fields: Default::default(),
+        issue_type: gl_issue.issue_type.clone(),
+        parent: gl_issue.epic.as_ref().map(|epic| IssueRef {
+            key: format!("gitlab&{}", epic.iid),
+            title: Some(epic.title.clone()),
+            state: None,
+            issue_type: Some("epic".to_string()),
+        }),
+        // Tasks of an issue live in the work item hierarchy, which only the
+        // GraphQL API exposes
+        children: vec![],
+        links: vec![],
+        attachments: issue_attachments(gl_issue),
+        estimate_seconds: gl_issue
+            .time_stats
+            .as_ref()
+            .map(|stats| stats.time_estimate)
+            .filter(|secs| *secs > 0),
+        time_spent_seconds: gl_issue
+            .time_stats
+            .as_ref()
+            .map(|stats| stats.total_time_spent)
+            .filter(|secs| *secs > 0),
+        milestone: gl_issue
+            .milestone
+            .as_ref()
+            .map(map_milestone)
+            .or_else(|| gl_issue.iteration.as_ref().map(map_iteration)),
+        checklists: vec![],
+    }
+}
+
+/// Format seconds as a GitLab duration ("1h30m", "45m", "20s").
+///
+/// Days are avoided since GitLab's day length is configurable per instance.
+fn gitlab_duration(seconds: u64) -> String {
+    let (hours, minutes, secs) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    let mut duration = String::new();
+    if hours > 0 {
+        duration.push_str(&format!("{}h", hours));
+    }
+    if minutes > 0 {
+        duration.push_str(&format!("{}m", minutes));
+    }
+    if secs > 0 || duration.is_empty() {
+        duration.push_str(&format!("{}s", secs));
+    }
+    duration
+}
+
+/// Check whether a Markdown link points to a project upload.
+fn is_upload_url(url: &str) -> bool {
+    url.contains("/uploads/")
+}
+
+/// Uploads linked from an issue description, with absolute URLs.
+///
+/// Upload links are relative to the project's web URL, which is the issue's
+/// web URL up to "/-/".
+fn issue_attachments(gl_issue: &GitLabIssue) -> Vec<Attachment> {
+    let Some(description) = gl_issue.description.as_deref() else {
+        return vec![];
+    };
+    let project_web_url = gl_issue
+        .web_url
+        .split("/-/")
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches('/');
+
+    let mut attachments = markdown_attachments(description, is_upload_url);
+    for attachment in &mut attachments {
+        if attachment.url.starts_with('/') {
+            attachment.url = format!("{}{}", project_web_url, attachment.url);
+        }
+    }
+    attachments
+}
+
+/// Extract "<secret>/<filename>" from a project upload URL.
+fn upload_path(url: &str) -> Option<&str> {
+    let start = url.rfind("/uploads/")? + "/uploads/".len();
+    let path = url[start..].split(['?', '#']).next()?;
+    let (secret, file_name) = path.split_once('/')?;
+    (!secret.is_empty() && !file_name.is_empty() && !file_name.contains('/')).then_some(path)
+}
+
+/// Map a GitLab link type to an [`IssueLink::kind`].
+fn link_kind(link_type: &str) -> String {
+    match link_type {
+        "is_blocked_by" => "blocked_by".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Map an [`IssueLink::kind`] to a GitLab link type.
+fn link_type_for_kind(kind: &str) -> Result<&'static str> {
+    match kind {
+        "relates_to" => Ok("relates_to"),
+        "blocks" => Ok("blocks"),
+        "blocked_by" | "is_blocked_by" => Ok("is_blocked_by"),
+        other => Err(Error::InvalidData(format!(
+            "Unsupported GitLab link kind '{}'. Available: relates_to, blocks, blocked_by",
+            other
+        ))),
+    }
+}
+
+fn map_linked_issue(linked: &GitLabLinkedIssue) -> IssueLink {
+    IssueLink {
+        kind: link_kind(&linked.link_type),
+        target: IssueRef {
+            key: format!("gitlab#{}", linked.iid),
+            title: Some(linked.title.clone()),
+            state: Some(linked.state.clone()),
+            issue_type: linked.issue_type.clone(),
+        },
+        id: Some(linked.issue_link_id.to_string()),
+    }
+}
+
+/// Map a merge request that closes an issue to a `closed_by` link.
+fn map_closing_merge_request(gl_mr: &GitLabMergeRequest) -> IssueLink {
+    let mr = map_merge_request(gl_mr);
+    IssueLink {
+        kind: "closed_by".to_string(),
+        target: IssueRef {
+            key: mr.key,
+            title: Some(mr.title),
+            state: Some(mr.state),
+            issue_type: Some("merge_request".to_string()),
+        },
+        id: None,
     }
 }
 
@@ -217,6 +559,8 @@ fn map_merge_request(gl_mr: &GitLabMergeRequest) -> MergeRequest {
         url: Some(gl_mr.web_url.clone()),
         created_at: Some(gl_mr.created_at.clone()),
         updated_at: Some(gl_mr.updated_at.clone()),
+        pipelines: vec![],
+        milestone: gl_mr.milestone.as_ref().map(map_milestone),
     }
 }
 
@@ -230,6 +574,7 @@ fn map_note(gl_note: &GitLabNote) -> Comment {
         created_at: Some(gl_note.created_at.clone()),
         updated_at: gl_note.updated_at.clone(),
         position,
+        attachments: markdown_attachments(&gl_note.body, is_upload_url),
     }
 }
 
@@ -307,22 +652,192 @@ fn map_diff(gl_diff: &GitLabDiff) -> FileDiff {
     }
 }
 
+fn map_commit(gl_commit: &GitLabCommit) -> Commit {
+    // GitLab only exposes the git author, not the linked account
+    let author = gl_commit.author_name.as_ref().map(|name| User {
+        id: String::new(),
+        username: name.clone(),
+        name: Some(name.clone()),
+        email: gl_commit.author_email.clone(),
+        avatar_url: None,
+    });
+
+    Commit {
+        sha: gl_commit.id.clone(),
+        title: gl_commit.title.clone(),
+        message: gl_commit.message.trim_end().to_string(),
+        author,
+        authored_at: gl_commit.authored_date.clone(),
+        committed_at: gl_commit.committed_date.clone(),
+        url: gl_commit.web_url.clone(),
+    }
+}
+
+fn map_file(gl_file: &GitLabFile) -> Result<RepositoryFile> {
+    let bytes = match gl_file.encoding.as_str() {
+        "base64" => decode_base64(&gl_file.content)?,
+        _ => gl_file.content.clone().into_bytes(),
+    };
+    let binary = is_binary(&bytes);
+
+    Ok(RepositoryFile {
+        path: gl_file.file_path.clone(),
+        ref_name: gl_file.ref_name.clone(),
+        content: if binary {
+            String::new()
+        } else {
+            String::from_utf8_lossy(&bytes).into_owned()
+        },
+        size: gl_file.size,
+        binary,
+        sha: gl_file.blob_id.clone(),
+        url: None,
+    })
+}
+
+fn map_tree_entry(gl_entry: &GitLabTreeEntry) -> TreeEntry {
+    let kind = match gl_entry.kind.as_str() {
+        "tree" => "dir",
+        "commit" => "submodule",
+        // Symlinks are blobs with mode 120000
+        _ if gl_entry.mode.as_deref() == Some("120000") => "symlink",
+        _ => "file",
+    };
+
+    TreeEntry {
+        path: gl_entry.path.clone(),
+        name: gl_entry.name.clone(),
+        kind: kind.to_string(),
+        size: None,
+    }
+}
+
+/// Map GitLab pipeline/job status to the unified CI status.
+fn map_ci_status(status: &str) -> String {
+    match status {
+        "created" | "waiting_for_resource" | "preparing" | "pending" | "scheduled" => {
+            "pending".to_string()
+        }
+        "canceling" => "canceled".to_string(),
+        other => other.to_string(), // running, success, failed, canceled, skipped, manual
+    }
+}
+
+fn map_pipeline(gl_pipeline: &GitLabPipeline) -> CiPipeline {
+    CiPipeline {
+        id: gl_pipeline.id.to_string(),
+        name: gl_pipeline.name.clone(),
+        status: map_ci_status(&gl_pipeline.status),
+        ref_name: gl_pipeline.ref_name.clone(),
+        sha: gl_pipeline.sha.clone(),
+        duration: gl_pipeline.duration.map(|d| d.round() as u64),
+        url: gl_pipeline.web_url.clone(),
+        jobs: vec![],
+        created_at: gl_pipeline.created_at.clone(),
+        updated_at: gl_pipeline.updated_at.clone(),
+    }
+}
+
+fn map_job(gl_job: &GitLabJob) -> CiJob {
+    CiJob {
+        id: gl_job.id.to_string(),
+        name: gl_job.name.clone(),
+        stage: gl_job.stage.clone(),
+        status: map_ci_status(&gl_job.status),
+        allow_failure: gl_job.allow_failure,
+        duration: gl_job.duration.map(|d| d.round() as u64),
+        url: gl_job.web_url.clone(),
+    }
+}
+
 // =============================================================================
 // Helper functions
 // =============================================================================
 
-/// Parse issue key like "gitlab#123" to get issue iid.
-fn parse_issue_key(key: &str) -> Result<u64> {
-    key.strip_prefix("gitlab#")
-        .and_then(|s| s.parse::<u64>().ok())
-        .ok_or_else(|| Error::InvalidData(format!("Invalid issue key: {}", key)))
+/// Parse issue key like "gitlab#123", or an issue URL of the project at
+/// `project_url` (e.g. `https://gitlab.com/group/proj/`), to get issue iid.
+fn parse_issue_key(key: &str, project_url: Option<&str>) -> Result<u64> {
+    parse_project_key(key, "gitlab#", project_url, "issues", "issue")
 }
 
-/// Parse MR key like "mr#123" to get MR iid.
-fn parse_mr_key(key: &str) -> Result<u64> {
-    key.strip_prefix("mr#")
+/// Parse an epic key like "gitlab&12" to get the epic iid.
+/// Issue keys are rejected: the REST API cannot create tasks under an issue.
+fn parse_epic_key(key: &str) -> Result<u64> {
+    key.strip_prefix("gitlab&")
         .and_then(|s| s.parse::<u64>().ok())
-        .ok_or_else(|| Error::InvalidData(format!("Invalid MR key: {}", key)))
+        .ok_or_else(|| {
+            Error::InvalidData(format!(
+                "Invalid parent '{}': GitLab issues can only be created under an epic (gitlab&<iid>)",
+                key
+            ))
+        })
+}
+
+/// Parse MR key like "mr#123", or a merge request URL of the project at
+/// `project_url`, to get MR iid.
+fn parse_mr_key(key: &str, project_url: Option<&str>) -> Result<u64> {
+    parse_project_key(key, "mr#", project_url, "merge_requests", "MR")
+}
+
+/// Parse a short key with `prefix` or a URL of the project's `section`
+/// (`https://gitlab.com/group/proj/-/issues/7`). URLs need the project path;
+/// it is unknown when the project is configured by numeric ID.
+fn parse_project_key(
+    key: &str,
+    prefix: &str,
+    project_url: Option<&str>,
+    section: &str,
+    kind: &str,
+) -> Result<u64> {
+    match project_url {
+        Some(url) => parse_key_number(key, prefix, &format!("{}-/{}/", url, section), kind),
+        None if is_url(key) => Err(Error::InvalidData(format!(
+            "Cannot resolve '{}': set gitlab.project_id to the project path \
+             (group/project) instead of its numeric ID to use URLs",
+            key.trim()
+        ))),
+        None => parse_key_number(key, prefix, "", kind),
+    }
+}
+
+/// Parse a raw query of GitLab list parameters (`milestone=v2.3&weight=3`)
+/// into `key=value` pairs with URL-encoded values.
+fn parse_query_params(query: &str) -> Result<Vec<(String, String)>> {
+    let query = query.trim().trim_start_matches('?');
+    if query.is_empty() {
+        return Err(Error::InvalidData("Query is empty".to_string()));
+    }
+
+    query
+        .split('&')
+        .filter(|pair| !pair.trim().is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').ok_or_else(|| {
+                Error::InvalidData(format!(
+                    "Invalid query parameter '{}', expected key=value",
+                    pair
+                ))
+            })?;
+            let key = key.trim();
+            let valid_key = !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "_[]".contains(c));
+            if !valid_key {
+                return Err(Error::InvalidData(format!(
+                    "Invalid query parameter name '{}'",
+                    key
+                )));
+            }
+            Ok((key.to_string(), encode_uri_component(value.trim())))
+        })
+        .collect()
+}
+
+/// Parse a numeric pipeline or job ID.
+fn parse_ci_id(id: &str) -> Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| Error::InvalidData(format!("Invalid CI ID: {}", id)))
 }
 
 // =============================================================================
@@ -356,7 +871,10 @@ impl IssueProvider for GitLabClient {
         }
 
         if let Some(assignee) = &filter.assignee {
-            params.push(format!("assignee_username={}", assignee));
+            params.push(format!(
+                "assignee_username={}",
+                self.resolve_username(assignee).await?
+            ));
         }
 
         if let Some(limit) = filter.limit {
@@ -382,6 +900,23 @@ impl IssueProvider for GitLabClient {
             params.push(format!("sort={}", order));
         }
 
+        if let Some(milestone) = &filter.milestone {
+            params.push(format!("milestone={}", encode_uri_component(milestone)));
+        }
+
+        if let Some(query) = &filter.query {
+            for (key, value) in parse_query_params(query)? {
+                let prefix = format!("{}=", key);
+                if params.iter().any(|p| p.starts_with(&prefix)) {
+                    return Err(Error::InvalidData(format!(
+                        "Query parameter '{}' is already set by another filter",
+                        key
+                    )));
+                }
+                params.push(format!("{}={}", key, value));
+            }
+        }
+
         if !params.is_empty() {
             url.push_str(&format!("?{}", params.join("&")));
         }
@@ -391,10 +926,25 @@ impl IssueProvider for GitLabClient {
     }
 
     async fn get_issue(&self, key: &str) -> Result<Issue> {
-        let iid = parse_issue_key(key)?;
+        let iid = self.issue_iid(key)?;
         let url = self.project_url(&format!("/issues/{}", iid));
         let gl_issue: GitLabIssue = self.get(&url).await?;
-        Ok(map_issue(&gl_issue))
+        let mut issue = map_issue(&gl_issue);
+
+        match self.get_issue_links(iid).await {
+            Ok(links) => issue.links.extend(links.iter().map(map_linked_issue)),
+            Err(e) => warn!(error = %e, key = key, "Failed to fetch issue links"),
+        }
+
+        let url = self.project_url(&format!("/issues/{}/closed_by", iid));
+        match self.get::<Vec<GitLabMergeRequest>>(&url).await {
+            Ok(mrs) => issue
+                .links
+                .extend(mrs.iter().map(map_closing_merge_request)),
+            Err(e) => warn!(error = %e, key = key, "Failed to fetch closing merge requests"),
+        }
+
+        Ok(issue)
     }
 
     async fn create_issue(&self, input: CreateIssueInput) -> Result<Issue> {
@@ -405,11 +955,15 @@ impl IssueProvider for GitLabClient {
             Some(input.labels.join(","))
         };
 
+        let epic_iid = input.parent.as_deref().map(parse_epic_key).transpose()?;
+
         let request = CreateIssueRequest {
             title: input.title,
             description: input.description,
             labels,
             assignee_ids: None, // GitLab needs user IDs, not usernames; skip for now
+            issue_type: input.issue_type.map(|t| t.to_lowercase()),
+            epic_iid,
         };
 
         let gl_issue: GitLabIssue = self.post(&url, &request).await?;
@@ -417,7 +971,7 @@ impl IssueProvider for GitLabClient {
     }
 
     async fn update_issue(&self, key: &str, input: UpdateIssueInput) -> Result<Issue> {
-        let iid = parse_issue_key(key)?;
+        let iid = self.issue_iid(key)?;
         let url = self.project_url(&format!("/issues/{}", iid));
 
         // Map state to state_event
@@ -442,7 +996,7 @@ impl IssueProvider for GitLabClient {
     }
 
     async fn get_comments(&self, issue_key: &str) -> Result<Vec<Comment>> {
-        let iid = parse_issue_key(issue_key)?;
+        let iid = self.issue_iid(issue_key)?;
         let url = self.project_url(&format!("/issues/{}/notes", iid));
         let gl_notes: Vec<GitLabNote> = self.get(&url).await?;
 
@@ -455,7 +1009,7 @@ impl IssueProvider for GitLabClient {
     }
 
     async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment> {
-        let iid = parse_issue_key(issue_key)?;
+        let iid = self.issue_iid(issue_key)?;
         let url = self.project_url(&format!("/issues/{}/notes", iid));
         let request = CreateNoteRequest {
             body: body.to_string(),
@@ -465,6 +1019,172 @@ impl IssueProvider for GitLabClient {
         Ok(map_note(&gl_note))
     }
 
+    /// Lists project and group milestones, plus iterations on Premium.
+    async fn get_milestones(&self, state: Option<&str>) -> Result<Vec<Milestone>> {
+        let (milestone_state, iteration_state) = match state.unwrap_or("open") {
+            "open" | "opened" | "active" => ("&state=active", "&state=opened"),
+            "closed" => ("&state=closed", "&state=closed"),
+            _ => ("", "&state=all"),
+        };
+
+        let url = self.project_url(&format!(
+            "/milestones?include_ancestors=true&per_page=100{}",
+            milestone_state
+        ));
+        let gl_milestones: Vec<GitLabMilestone> = self.get(&url).await?;
+
+        // Iterations need GitLab Premium, without it the project has none
+        let url = self.project_url(&format!("/iterations?per_page=100{}", iteration_state));
+        let gl_iterations: Vec<GitLabIteration> = match self.get(&url).await {
+            Ok(iterations) => iterations,
+            Err(e) => {
+                debug!(error = %e, "GitLab iterations unavailable");
+                vec![]
+            }
+        };
+
+        let mut counted: Vec<(Milestone, String)> = Vec::new();
+        for gl_milestone in &gl_milestones {
+            let filter = format!("milestone={}", encode_uri_component(&gl_milestone.title));
+            counted.push((map_milestone(gl_milestone), filter));
+        }
+        for gl_iteration in &gl_iterations {
+            let filter = format!("iteration_id={}", gl_iteration.id);
+            counted.push((map_iteration(gl_iteration), filter));
+        }
+
+        // Counts are best-effort: one failed count leaves them unknown
+        // instead of failing the whole listing
+        let milestones = stream::iter(counted)
+            .map(|(mut milestone, filter)| async move {
+                match self.count_issues(&filter).await {
+                    Ok((open, closed)) => {
+                        milestone.open_issues = Some(open);
+                        milestone.closed_issues = Some(closed);
+                    }
+                    Err(e) => {
+                        debug!(error = %e, milestone = %milestone.title, "GitLab issue counts unavailable")
+                    }
+                }
+                milestone
+            })
+            .buffered(COUNT_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(milestones)
+    }
+
+    /// Lists project labels and the labels of its ancestor groups.
+    async fn list_labels(&self) -> Result<Vec<Label>> {
+        let url = self.project_url("/labels?include_ancestor_groups=true&per_page=100");
+        let gl_labels: Vec<GitLabLabel> = self.get(&url).await?;
+        Ok(gl_labels
+            .into_iter()
+            .map(|l| Label {
+                name: l.name,
+                color: l.color,
+                description: l.description.filter(|d| !d.is_empty()),
+            })
+            .collect())
+    }
+
+    /// GitLab issues are either open or closed.
+    async fn list_statuses(&self) -> Result<Vec<IssueStatus>> {
+        Ok(vec![
+            IssueStatus {
+                name: "open".to_string(),
+                category: "todo".to_string(),
+            },
+            IssueStatus {
+                name: "closed".to_string(),
+                category: "done".to_string(),
+            },
+        ])
+    }
+
+    async fn download_attachment(&self, url: &str) -> Result<Vec<u8>> {
+        let foreign = url.contains("://") && !is_same_origin(url, &self.base_url);
+        let path = upload_path(url).filter(|_| !foreign).ok_or_else(|| {
+            Error::InvalidData(format!("{} is not an upload URL of {}", url, self.base_url))
+        })?;
+        self.get_bytes(&self.project_url(&format!("/uploads/{}", path)))
+            .await
+    }
+
+    /// Upload a file to the project and link it from a new comment on the issue,
+    /// since GitLab issues have no attachment list of their own.
+    async fn upload_attachment(
+        &self,
+        issue_key: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<Attachment> {
+        let iid = self.issue_iid(issue_key)?;
+        let upload: GitLabUpload = self
+            .post_file(&self.project_url("/uploads"), "file", file_name, content)
+            .await?;
+
+        let url = self.project_url(&format!("/issues/{}/notes", iid));
+        let request = CreateNoteRequest {
+            body: upload.markdown.clone(),
+        };
+        let gl_note: GitLabNote = self.post(&url, &request).await?;
+
+        Ok(Attachment {
+            id: upload
+                .id
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| upload.url.clone()),
+            name: file_name.to_string(),
+            size: Some(content.len() as u64),
+            mime_type: mime_type_for(file_name).map(str::to_string),
+            url: match &upload.full_path {
+                Some(full_path) => format!("{}{}", self.base_url, full_path),
+                None => upload.url.clone(),
+            },
+            created_at: Some(gl_note.created_at),
+        })
+    }
+
+    async fn link_issues(&self, key: &str, target_key: &str, kind: &str) -> Result<()> {
+        let iid = self.issue_iid(key)?;
+        let request = CreateIssueLinkRequest {
+            target_project_id: self.project_id.clone(),
+            target_issue_iid: self.issue_iid(target_key)?,
+            link_type: link_type_for_kind(kind)?.to_string(),
+        };
+        let url = self.project_url(&format!("/issues/{}/links", iid));
+        let _: serde_json::Value = self.post(&url, &request).await?;
+        Ok(())
+    }
+
+    async fn unlink_issues(&self, key: &str, target_key: &str, kind: Option<&str>) -> Result<()> {
+        let iid = self.issue_iid(key)?;
+        let target_iid = self.issue_iid(target_key)?;
+        let link_type = kind.map(link_type_for_kind).transpose()?;
+
+        let links: Vec<GitLabLinkedIssue> = self
+            .get_issue_links(iid)
+            .await?
+            .into_iter()
+            .filter(|link| link.iid == target_iid)
+            .filter(|link| link_type.is_none_or(|t| link.link_type == t))
+            .collect();
+        if links.is_empty() {
+            return Err(Error::NotFound(format!(
+                "No link between {} and {}",
+                key, target_key
+            )));
+        }
+
+        for link in links {
+            let url = self.project_url(&format!("/issues/{}/links/{}", iid, link.issue_link_id));
+            self.delete(&url).await?;
+        }
+        Ok(())
+    }
+
     fn provider_name(&self) -> &'static str {
         "gitlab"
     }
@@ -496,7 +1216,17 @@ impl MergeRequestProvider for GitLabClient {
         }
 
         if let Some(author) = &filter.author {
-            params.push(format!("author_username={}", author));
+            params.push(format!(
+                "author_username={}",
+                self.resolve_username(author).await?
+            ));
+        }
+
+        if let Some(reviewer) = &filter.reviewer {
+            params.push(format!(
+                "reviewer_username={}",
+                self.resolve_username(reviewer).await?
+            ));
         }
 
         if let Some(labels) = &filter.labels {
@@ -505,6 +1235,10 @@ impl MergeRequestProvider for GitLabClient {
             }
         }
 
+        if let Some(milestone) = &filter.milestone {
+            params.push(format!("milestone={}", encode_uri_component(milestone)));
+        }
+
         if let Some(limit) = filter.limit {
             params.push(format!("per_page={}", limit.min(100)));
         }
@@ -521,14 +1255,14 @@ impl MergeRequestProvider for GitLabClient {
     }
 
     async fn get_merge_request(&self, key: &str) -> Result<MergeRequest> {
-        let iid = parse_mr_key(key)?;
+        let iid = self.mr_iid(key)?;
         let url = self.project_url(&format!("/merge_requests/{}", iid));
         let gl_mr: GitLabMergeRequest = self.get(&url).await?;
         Ok(map_merge_request(&gl_mr))
     }
 
     async fn get_discussions(&self, mr_key: &str) -> Result<Vec<Discussion>> {
-        let iid = parse_mr_key(mr_key)?;
+        let iid = self.mr_iid(mr_key)?;
         let url = self.project_url(&format!("/merge_requests/{}/discussions", iid));
         let gl_discussions: Vec<GitLabDiscussion> = self.get(&url).await?;
 
@@ -541,15 +1275,29 @@ impl MergeRequestProvider for GitLabClient {
     }
 
     async fn get_diffs(&self, mr_key: &str) -> Result<Vec<FileDiff>> {
-        let iid = parse_mr_key(mr_key)?;
+        let iid = self.mr_iid(mr_key)?;
         // Use the changes endpoint which returns diffs with content
         let url = self.project_url(&format!("/merge_requests/{}/changes", iid));
         let gl_changes: GitLabMergeRequestChanges = self.get(&url).await?;
         Ok(gl_changes.changes.iter().map(map_diff).collect())
     }
 
+    async fn get_commits(&self, mr_key: &str) -> Result<Vec<Commit>> {
+        let iid = self.mr_iid(mr_key)?;
+        let url = self.project_url(&format!("/merge_requests/{}/commits?per_page=100", iid));
+        let gl_commits: Vec<GitLabCommit> = self.get(&url).await?;
+        // GitLab lists the newest commit first
+        Ok(gl_commits.iter().rev().map(map_commit).collect())
+    }
+
+    async fn get_commit_diff(&self, sha: &str) -> Result<Vec<FileDiff>> {
+        let url = self.project_url(&format!("/repository/commits/{}/diff?per_page=100", sha));
+        let gl_diffs: Vec<GitLabDiff> = self.get(&url).await?;
+        Ok(gl_diffs.iter().map(map_diff).collect())
+    }
+
     async fn add_comment(&self, mr_key: &str, input: CreateCommentInput) -> Result<Comment> {
-        let iid = parse_mr_key(mr_key)?;
+        let iid = self.mr_iid(mr_key)?;
 
         // If discussion_id is provided, reply to existing discussion
         if let Some(discussion_id) = &input.discussion_id {
@@ -624,46 +1372,308 @@ impl MergeRequestProvider for GitLabClient {
 }
 
 #[async_trait]
-impl Provider for GitLabClient {
-    async fn get_current_user(&self) -> Result<User> {
-        let url = self.api_url("/user");
-        let gl_user: GitLabUser = self.get(&url).await?;
-        Ok(map_user_required(Some(&gl_user)))
+impl PipelineProvider for GitLabClient {
+    async fn get_pipelines(&self, mr_key: &str) -> Result<Vec<CiPipeline>> {
+        let iid = self.mr_iid(mr_key)?;
+        let url = self.project_url(&format!("/merge_requests/{}/pipelines", iid));
+        let gl_pipelines: Vec<GitLabPipeline> = self.get(&url).await?;
+        Ok(gl_pipelines.iter().map(map_pipeline).collect())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::{GitLabDiffRefs, GitLabNotePosition};
+    async fn get_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
 
-    #[test]
-    fn test_parse_issue_key() {
-        assert_eq!(parse_issue_key("gitlab#123").unwrap(), 123);
-        assert_eq!(parse_issue_key("gitlab#1").unwrap(), 1);
-        assert!(parse_issue_key("mr#123").is_err());
-        assert!(parse_issue_key("gh#123").is_err());
-        assert!(parse_issue_key("123").is_err());
-        assert!(parse_issue_key("gitlab#").is_err());
+        let url = self.project_url(&format!("/pipelines/{}", id));
+        let gl_pipeline: GitLabPipeline = self.get(&url).await?;
+
+        let jobs_url = self.project_url(&format!("/pipelines/{}/jobs?per_page=100", id));
+        let mut gl_jobs: Vec<GitLabJob> = self.get(&jobs_url).await?;
+        // GitLab returns the newest job first; show them in execution order
+        gl_jobs.sort_by_key(|j| j.id);
+
+        let mut pipeline = map_pipeline(&gl_pipeline);
+        pipeline.jobs = gl_jobs.iter().map(map_job).collect();
+        Ok(pipeline)
     }
 
-    #[test]
-    fn test_parse_mr_key() {
-        assert_eq!(parse_mr_key("mr#456").unwrap(), 456);
-        assert_eq!(parse_mr_key("mr#1").unwrap(), 1);
-        assert!(parse_mr_key("gitlab#123").is_err());
-        assert!(parse_mr_key("pr#123").is_err());
-        assert!(parse_mr_key("456").is_err());
+    async fn get_job_log(&self, job_id: &str) -> Result<String> {
+        let id = parse_ci_id(job_id)?;
+        let url = self.project_url(&format!("/jobs/{}/trace", id));
+        self.get_text(&url).await
     }
 
-    #[test]
-    fn test_map_user() {
-        let gl_user = GitLabUser {
-            id: 42,
-            username: "testuser".to_string(),
-            name: Some("Test User".to_string()),
-            avatar_url: Some("https://gitlab.com/avatar.png".to_string()),
-            web_url: Some("https://gitlab.com/testuser".to_string()),
+    async fn retry_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
+        let url = self.project_url(&format!("/pipelines/{}/retry", id));
+        let gl_pipeline: GitLabPipeline = self.post(&url, &serde_json::json!({})).await?;
+        Ok(map_pipeline(&gl_pipeline))
+    }
+
+    async fn retry_job(&self, job_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(job_id)?;
+        let url = self.project_url(&format!("/jobs/{}/retry", id));
+        let gl_job: GitLabJob = self.post(&url, &serde_json::json!({})).await?;
+
+        // The retried job is a new job in the same pipeline
+        let gl_pipeline = gl_job.pipeline.as_ref().ok_or_else(|| {
+            Error::InvalidData("Retried job has no pipeline information".to_string())
+        })?;
+        let mut pipeline = map_pipeline(gl_pipeline);
+        pipeline.jobs = vec![map_job(&gl_job)];
+        Ok(pipeline)
+    }
+
+    async fn cancel_pipeline(&self, pipeline_id: &str) -> Result<CiPipeline> {
+        let id = parse_ci_id(pipeline_id)?;
+        let url = self.project_url(&format!("/pipelines/{}/cancel", id));
+        let gl_pipeline: GitLabPipeline = self.post(&url, &serde_json::json!({})).await?;
+        Ok(map_pipeline(&gl_pipeline))
+    }
+
+    async fn run_pipeline(&self, input: RunPipelineInput) -> Result<CiPipeline> {
+        let url = self.project_url("/pipeline");
+        let mut variables: Vec<PipelineVariable> = input
+            .variables
+            .into_iter()
+            .map(|(key, value)| PipelineVariable { key, value })
+            .collect();
+        variables.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let request = CreatePipelineRequest {
+            ref_name: input.ref_name,
+            variables,
+        };
+        let gl_pipeline: GitLabPipeline = self.post(&url, &request).await?;
+        Ok(map_pipeline(&gl_pipeline))
+    }
+
+    fn has_ci(&self) -> bool {
+        true
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "gitlab"
+    }
+}
+
+#[async_trait]
+impl RepositoryProvider for GitLabClient {
+    async fn get_file(&self, path: &str, ref_name: Option<&str>) -> Result<RepositoryFile> {
+        // GitLab requires a ref, HEAD resolves to the default branch
+        let url = self.project_url(&format!(
+            "/repository/files/{}?ref={}",
+            encode_uri_component(path.trim_matches('/')),
+            encode_uri_component(ref_name.unwrap_or("HEAD"))
+        ));
+        let gl_file: GitLabFile = self.get(&url).await?;
+        let mut file = map_file(&gl_file)?;
+        file.ref_name = ref_name.map(String::from);
+        Ok(file)
+    }
+
+    async fn list_tree(&self, path: &str, ref_name: Option<&str>) -> Result<Vec<TreeEntry>> {
+        let mut url = self.project_url(&format!(
+            "/repository/tree?path={}&per_page=100",
+            encode_uri_component(path.trim_matches('/'))
+        ));
+        if let Some(ref_name) = ref_name {
+            url.push_str(&format!("&ref={}", encode_uri_component(ref_name)));
+        }
+        let gl_entries: Vec<GitLabTreeEntry> = self.get(&url).await?;
+        Ok(gl_entries.iter().map(map_tree_entry).collect())
+    }
+
+    async fn create_branch(&self, name: &str, from_ref: Option<&str>) -> Result<Branch> {
+        let from_ref = match from_ref {
+            Some(r) => r.to_string(),
+            None => {
+                let project: GitLabProject = self.get(&self.project_url("")).await?;
+                project.default_branch.ok_or_else(|| {
+                    Error::InvalidData("Project has no default branch".to_string())
+                })?
+            }
+        };
+
+        let url = self.project_url(&format!(
+            "/repository/branches?branch={}&ref={}",
+            encode_uri_component(name),
+            encode_uri_component(&from_ref)
+        ));
+        let gl_branch: GitLabBranch = self.post(&url, &serde_json::json!({})).await?;
+        Ok(Branch {
+            name: gl_branch.name,
+            sha: gl_branch.commit.map(|c| c.id),
+            url: gl_branch.web_url,
+        })
+    }
+
+    fn has_repository(&self) -> bool {
+        true
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "gitlab"
+    }
+}
+
+/// GitLab has no sprints, all agile operations are unsupported.
+impl AgileProvider for GitLabClient {
+    fn provider_name(&self) -> &'static str {
+        "gitlab"
+    }
+}
+
+/// GitLab only exposes time totals over REST, so listing entries is unsupported.
+#[async_trait]
+impl TimeTrackingProvider for GitLabClient {
+    async fn log_time(
+        &self,
+        issue_key: &str,
+        duration_seconds: u64,
+        comment: Option<&str>,
+        started_at: Option<&str>,
+    ) -> Result<()> {
+        if started_at.is_some() {
+            return Err(Error::InvalidData(
+                "GitLab records spent time at the current date, a start time cannot be set"
+                    .to_string(),
+            ));
+        }
+        let iid = self.issue_iid(issue_key)?;
+        let request = TimeTrackingRequest {
+            duration: gitlab_duration(duration_seconds),
+            summary: comment.map(str::to_string),
+        };
+        let url = self.project_url(&format!("/issues/{}/add_spent_time", iid));
+        let _: serde_json::Value = self.post(&url, &request).await?;
+        Ok(())
+    }
+
+    async fn set_time_estimate(&self, issue_key: &str, estimate_seconds: u64) -> Result<()> {
+        let iid = self.issue_iid(issue_key)?;
+        let request = TimeTrackingRequest {
+            duration: gitlab_duration(estimate_seconds),
+            summary: None,
+        };
+        let url = self.project_url(&format!("/issues/{}/time_estimate", iid));
+        let _: serde_json::Value = self.post(&url, &request).await?;
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "gitlab"
+    }
+}
+
+#[async_trait]
+impl Provider for GitLabClient {
+    async fn get_current_user(&self) -> Result<User> {
+        let url = self.api_url("/user");
+        let gl_user: GitLabUser = self.get(&url).await?;
+        Ok(map_user_required(Some(&gl_user)))
+    }
+
+    fn key_patterns(&self) -> Vec<KeyPattern> {
+        let mut patterns = vec![
+            KeyPattern::Prefix("gitlab#".to_string()),
+            KeyPattern::Prefix("gitlab&".to_string()),
+            KeyPattern::Prefix("mr#".to_string()),
+        ];
+        patterns.extend(self.web_project_url().map(KeyPattern::Url));
+        patterns
+    }
+
+    /// Search the project members, including inherited ones.
+    async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        let url = self.project_url(&format!(
+            "/members/all?query={}&per_page=100",
+            encode_uri_component(query.trim().trim_start_matches('@'))
+        ));
+        let gl_users: Vec<GitLabUser> = self.get(&url).await?;
+        Ok(gl_users
+            .iter()
+            .map(|u| map_user_required(Some(u)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{GitLabDiffRefs, GitLabNotePosition};
+
+    #[test]
+    fn test_parse_issue_key() {
+        assert_eq!(parse_issue_key("gitlab#123", None).unwrap(), 123);
+        assert_eq!(parse_issue_key("gitlab#1", None).unwrap(), 1);
+        assert!(parse_issue_key("mr#123", None).is_err());
+        assert!(parse_issue_key("gh#123", None).is_err());
+        assert!(parse_issue_key("123", None).is_err());
+        assert!(parse_issue_key("gitlab#", None).is_err());
+        assert_eq!(
+            parse_issue_key(
+                "https://gitlab.example.com/group/proj/-/issues/7",
+                Some("https://gitlab.example.com/group/proj/")
+            )
+            .unwrap(),
+            7
+        );
+        // Numeric project IDs have no web path
+        let err = parse_issue_key("https://gitlab.example.com/group/proj/-/issues/7", None)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("set gitlab.project_id to the project path"),
+            "got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_mr_key() {
+        assert_eq!(parse_mr_key("mr#456", None).unwrap(), 456);
+        assert_eq!(parse_mr_key("mr#1", None).unwrap(), 1);
+        assert!(parse_mr_key("gitlab#123", None).is_err());
+        assert!(parse_mr_key("pr#123", None).is_err());
+        assert!(parse_mr_key("456", None).is_err());
+        let project = Some("https://gitlab.example.com/group/proj/");
+        assert_eq!(
+            parse_mr_key(
+                "https://gitlab.example.com/group/proj/-/merge_requests/7/diffs",
+                project
+            )
+            .unwrap(),
+            7
+        );
+        assert!(parse_mr_key(
+            "https://gitlab.example.com/group/other/-/merge_requests/7",
+            project
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_web_project_url() {
+        let client = GitLabClient::with_base_url("https://gitlab.example.com", "group%2Fproj", "t");
+        assert_eq!(
+            client.web_project_url().as_deref(),
+            Some("https://gitlab.example.com/group/proj/")
+        );
+        assert!(client.key_patterns().contains(&KeyPattern::Url(
+            "https://gitlab.example.com/group/proj/".to_string()
+        )));
+        let client = GitLabClient::with_base_url("https://gitlab.example.com", "123", "t");
+        assert_eq!(client.web_project_url(), None);
+    }
+
+    #[test]
+    fn test_map_user() {
+        let gl_user = GitLabUser {
+            id: 42,
+            username: "testuser".to_string(),
+            name: Some("Test User".to_string()),
+            avatar_url: Some("https://gitlab.com/avatar.png".to_string()),
+            web_url: Some("https://gitlab.com/testuser".to_string()),
         };
 
         let user = map_user(Some(&gl_user)).unwrap();
@@ -689,6 +1699,22 @@ mod tests {
         assert_eq!(user.username, "unknown");
     }
 
+    #[test]
+    fn test_parse_query_params() {
+        let params = parse_query_params("milestone=v2.3 beta&not[labels]=wontfix&").unwrap();
+        assert_eq!(
+            params,
+            vec![
+                ("milestone".to_string(), "v2.3%20beta".to_string()),
+                ("not[labels]".to_string(), "wontfix".to_string()),
+            ]
+        );
+
+        assert!(parse_query_params("").is_err());
+        assert!(parse_query_params("milestone").is_err());
+        assert!(parse_query_params("bad key=1").is_err());
+    }
+
     #[test]
     fn test_map_issue() {
         let gl_issue = GitLabIssue {
@@ -709,6 +1735,11 @@ mod tests {
             web_url: "https://gitlab.com/group/project/-/issues/42".to_string(),
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-02T00:00:00Z".to_string(),
+            issue_type: None,
+            epic: None,
+            time_stats: None,
+            milestone: None,
+            iteration: None,
         };
 
         let issue = map_issue(&gl_issue);
@@ -723,6 +1754,113 @@ mod tests {
             issue.url,
             Some("https://gitlab.com/group/project/-/issues/42".to_string())
         );
+        assert!(issue.attachments.is_empty());
+    }
+
+    #[test]
+    fn test_map_issue_time_stats() {
+        let gl_issue: GitLabIssue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 42,
+            "title": "Tracked",
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/project/-/issues/42",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "time_stats": {
+                "time_estimate": 7200,
+                "total_time_spent": 0,
+                "human_time_estimate": "2h",
+                "human_total_time_spent": null
+            }
+        }))
+        .unwrap();
+
+        let issue = map_issue(&gl_issue);
+        assert_eq!(issue.estimate_seconds, Some(7200));
+        assert_eq!(issue.time_spent_seconds, None);
+    }
+
+    #[test]
+    fn test_map_issue_milestone() {
+        let gl_issue: GitLabIssue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 42,
+            "title": "Planned",
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/project/-/issues/42",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z",
+            "milestone": {
+                "id": 7,
+                "iid": 2,
+                "title": "2.4",
+                "description": "",
+                "state": "active",
+                "due_date": "2024-03-01",
+                "web_url": "https://gitlab.com/group/project/-/milestones/2"
+            },
+            "iteration": {"id": 9, "iid": 3, "title": null, "state": 2}
+        }))
+        .unwrap();
+
+        let milestone = map_issue(&gl_issue).milestone.unwrap();
+        assert_eq!(milestone.title, "2.4");
+        assert_eq!(milestone.kind, "milestone");
+        assert_eq!(milestone.state, "open");
+        assert_eq!(milestone.description, None);
+        assert_eq!(milestone.due_date.as_deref(), Some("2024-03-01"));
+
+        let iteration = map_iteration(gl_issue.iteration.as_ref().unwrap());
+        assert_eq!(iteration.title, "Iteration 3");
+        assert_eq!(iteration.kind, "iteration");
+    }
+
+    #[test]
+    fn test_gitlab_duration() {
+        assert_eq!(gitlab_duration(5400), "1h30m");
+        assert_eq!(gitlab_duration(36000), "10h");
+        assert_eq!(gitlab_duration(2700), "45m");
+        assert_eq!(gitlab_duration(3630), "1h30s");
+        assert_eq!(gitlab_duration(0), "0s");
+    }
+
+    #[test]
+    fn test_map_issue_attachments() {
+        let gl_issue: GitLabIssue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "iid": 42,
+            "title": "Crash on start",
+            "description": "![screen.png](/uploads/abc123/screen.png)\nSee [the docs](https://docs.gitlab.com).",
+            "state": "opened",
+            "web_url": "https://gitlab.com/group/project/-/issues/42",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z"
+        }))
+        .unwrap();
+
+        let attachments = map_issue(&gl_issue).attachments;
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].name, "screen.png");
+        assert_eq!(
+            attachments[0].url,
+            "https://gitlab.com/group/project/uploads/abc123/screen.png"
+        );
+        assert_eq!(attachments[0].mime_type.as_deref(), Some("image/png"));
+    }
+
+    #[test]
+    fn test_upload_path() {
+        assert_eq!(
+            upload_path("https://gitlab.com/group/project/uploads/abc123/screen.png"),
+            Some("abc123/screen.png")
+        );
+        assert_eq!(
+            upload_path("/uploads/abc123/my%20log.txt?inline=false"),
+            Some("abc123/my%20log.txt")
+        );
+        assert_eq!(upload_path("/uploads/abc123"), None);
+        assert_eq!(upload_path("https://gitlab.com/group/project"), None);
     }
 
     #[test]
@@ -751,6 +1889,7 @@ mod tests {
             }),
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-02T00:00:00Z".to_string(),
+            milestone: None,
         };
 
         // Open MR
@@ -912,6 +2051,81 @@ mod tests {
         assert!(diff.new_file);
     }
 
+    #[test]
+    fn test_map_commit() {
+        let gl_commit = GitLabCommit {
+            id: "abc123def456".to_string(),
+            title: "Fix login".to_string(),
+            message: "Fix login\n\nHandle expired tokens.\n".to_string(),
+            author_name: Some("Test User".to_string()),
+            author_email: Some("test@example.com".to_string()),
+            authored_date: Some("2024-01-01T00:00:00Z".to_string()),
+            committed_date: Some("2024-01-02T00:00:00Z".to_string()),
+            web_url: Some("https://gitlab.com/test/-/commit/abc123def456".to_string()),
+        };
+
+        let commit = map_commit(&gl_commit);
+        assert_eq!(commit.sha, "abc123def456");
+        assert_eq!(commit.title, "Fix login");
+        assert_eq!(commit.message, "Fix login\n\nHandle expired tokens.");
+        let author = commit.author.unwrap();
+        assert_eq!(author.username, "Test User");
+        assert_eq!(author.email, Some("test@example.com".to_string()));
+        assert_eq!(
+            commit.committed_at,
+            Some("2024-01-02T00:00:00Z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_map_file() {
+        let gl_file = GitLabFile {
+            file_path: "src/main.rs".to_string(),
+            size: 12,
+            encoding: "base64".to_string(),
+            content: "Zm4gbWFpbigpIHt9".to_string(),
+            ref_name: Some("main".to_string()),
+            blob_id: Some("blob1".to_string()),
+        };
+
+        let file = map_file(&gl_file).unwrap();
+        assert_eq!(file.path, "src/main.rs");
+        assert_eq!(file.content, "fn main() {}");
+        assert!(!file.binary);
+        assert_eq!(file.sha, Some("blob1".to_string()));
+    }
+
+    #[test]
+    fn test_map_file_binary() {
+        let gl_file = GitLabFile {
+            file_path: "logo.png".to_string(),
+            size: 4,
+            encoding: "base64".to_string(),
+            content: "iVBORwA=".to_string(),
+            ref_name: None,
+            blob_id: None,
+        };
+
+        let file = map_file(&gl_file).unwrap();
+        assert!(file.binary);
+        assert!(file.content.is_empty());
+    }
+
+    #[test]
+    fn test_map_tree_entry() {
+        let entry = |kind: &str, mode: &str| GitLabTreeEntry {
+            name: "x".to_string(),
+            path: "a/x".to_string(),
+            kind: kind.to_string(),
+            mode: Some(mode.to_string()),
+        };
+
+        assert_eq!(map_tree_entry(&entry("tree", "040000")).kind, "dir");
+        assert_eq!(map_tree_entry(&entry("blob", "100644")).kind, "file");
+        assert_eq!(map_tree_entry(&entry("blob", "120000")).kind, "symlink");
+        assert_eq!(map_tree_entry(&entry("commit", "160000")).kind, "submodule");
+    }
+
     #[test]
     fn test_map_discussion() {
         let gl_discussion = GitLabDiscussion {
@@ -965,6 +2179,37 @@ mod tests {
         assert_eq!(discussion.position.unwrap().file_path, "src/lib.rs");
     }
 
+    #[test]
+    fn test_map_ci_status() {
+        assert_eq!(map_ci_status("success"), "success");
+        assert_eq!(map_ci_status("failed"), "failed");
+        assert_eq!(map_ci_status("running"), "running");
+        assert_eq!(map_ci_status("created"), "pending");
+        assert_eq!(map_ci_status("waiting_for_resource"), "pending");
+        assert_eq!(map_ci_status("canceling"), "canceled");
+        assert_eq!(map_ci_status("manual"), "manual");
+    }
+
+    #[test]
+    fn test_map_job() {
+        let gl_job = GitLabJob {
+            id: 7,
+            name: "test".to_string(),
+            stage: Some("test".to_string()),
+            status: "failed".to_string(),
+            allow_failure: true,
+            duration: Some(61.6),
+            web_url: Some("https://gitlab.com/group/project/-/jobs/7".to_string()),
+            pipeline: None,
+        };
+
+        let job = map_job(&gl_job);
+        assert_eq!(job.id, "7");
+        assert_eq!(job.status, "failed");
+        assert!(job.allow_failure);
+        assert_eq!(job.duration, Some(62));
+    }
+
     #[test]
     fn test_map_discussion_filters_system_notes() {
         let gl_discussion = GitLabDiscussion {
@@ -1062,6 +2307,127 @@ mod tests {
             assert_eq!(issues[0].labels, vec!["bug"]);
         }
 
+        #[tokio::test]
+        async fn test_get_issues_with_query() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues")
+                    .query_param("state", "opened")
+                    .query_param("milestone", "v2.3")
+                    .query_param("weight", "3");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .get_issues(IssueFilter {
+                    state: Some("opened".to_string()),
+                    query: Some("milestone=v2.3&weight=3".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_with_milestone() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues")
+                    .query_param("milestone", "Release 2.4");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .get_issues(IssueFilter {
+                    milestone: Some("Release 2.4".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_milestones() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/milestones")
+                    .query_param("state", "active")
+                    .query_param("include_ancestors", "true");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "id": 7,
+                        "iid": 2,
+                        "title": "2.4",
+                        "description": "Spring release",
+                        "state": "active",
+                        "start_date": "2024-02-01",
+                        "due_date": "2024-03-01",
+                        "web_url": "https://gitlab.com/group/project/-/milestones/2"
+                    },
+                    {"id": 8, "iid": 3, "title": "2.5", "state": "active"}
+                ]));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/api/v4/projects/123/iterations");
+                then.status(403)
+                    .json_body(serde_json::json!({"message": "403 Forbidden"}));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues_statistics")
+                    .query_param("milestone", "2.4");
+                then.status(200).json_body(serde_json::json!({
+                    "statistics": {"counts": {"all": 12, "closed": 9, "opened": 3}}
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues_statistics")
+                    .query_param("milestone", "2.5");
+                then.status(500);
+            });
+
+            let client = create_test_client(&server);
+            let milestones = client.get_milestones(None).await.unwrap();
+
+            // A failed count leaves the counts unknown
+            assert_eq!(milestones.len(), 2);
+            assert_eq!(milestones[1].title, "2.5");
+            assert_eq!(milestones[1].open_issues, None);
+            assert_eq!(milestones[0].title, "2.4");
+            assert_eq!(milestones[0].description.as_deref(), Some("Spring release"));
+            assert_eq!(milestones[0].open_issues, Some(3));
+            assert_eq!(milestones[0].closed_issues, Some(9));
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_query_conflicts_with_filter() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client
+                .get_issues(IssueFilter {
+                    state: Some("opened".to_string()),
+                    query: Some("state=closed".to_string()),
+                    ..Default::default()
+                })
+                .await;
+
+            assert!(matches!(result, Err(Error::InvalidData(_))));
+        }
+
         #[tokio::test]
         async fn test_get_issue() {
             let server = MockServer::start();
@@ -1096,29 +2462,303 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn test_create_issue() {
+        async fn test_get_issue_with_links() {
             let server = MockServer::start();
 
             server.mock(|when, then| {
-                when.method(POST)
-                    .path("/api/v4/projects/123/issues")
-                    .header("PRIVATE-TOKEN", "test-token")
-                    .body_includes("\"title\":\"New Issue\"")
-                    .body_includes("\"labels\":\"bug,feature\"");
-                then.status(201).json_body(serde_json::json!({
-                    "id": 10,
-                    "iid": 99,
-                    "title": "New Issue",
-                    "description": "Description",
+                when.method(GET).path("/api/v4/projects/123/issues/42");
+                then.status(200).json_body(serde_json::json!({
+                    "id": 1,
+                    "iid": 42,
+                    "title": "Single Issue",
                     "state": "opened",
-                    "labels": ["bug", "feature"],
-                    "author": {"id": 1, "username": "creator"},
-                    "assignees": [],
-                    "web_url": "https://gitlab.com/group/project/-/issues/99",
-                    "created_at": "2024-02-01T00:00:00Z",
-                    "updated_at": "2024-02-01T00:00:00Z"
-                }));
-            });
+                    "web_url": "https://gitlab.com/group/project/-/issues/42",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-03T00:00:00Z"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues/42/links");
+                then.status(200).json_body(serde_json::json!([{
+                    "id": 2,
+                    "iid": 41,
+                    "title": "Schema migration",
+                    "state": "opened",
+                    "web_url": "https://gitlab.com/group/project/-/issues/41",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z",
+                    "issue_link_id": 7,
+                    "link_type": "is_blocked_by"
+                }]));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues/42/closed_by");
+                then.status(200).json_body(serde_json::json!([{
+                    "id": 3,
+                    "iid": 50,
+                    "title": "Fix the issue",
+                    "state": "merged",
+                    "source_branch": "fix",
+                    "target_branch": "main",
+                    "web_url": "https://gitlab.com/group/project/-/merge_requests/50",
+                    "created_at": "2024-01-02T00:00:00Z",
+                    "updated_at": "2024-01-03T00:00:00Z",
+                    "merged_at": "2024-01-03T00:00:00Z"
+                }]));
+            });
+
+            let client = create_test_client(&server);
+            let issue = client.get_issue("gitlab#42").await.unwrap();
+
+            assert_eq!(issue.links.len(), 2);
+            assert_eq!(issue.links[0].kind, "blocked_by");
+            assert_eq!(issue.links[0].target.key, "gitlab#41");
+            assert_eq!(issue.links[0].id.as_deref(), Some("7"));
+            assert_eq!(issue.links[1].kind, "closed_by");
+            assert_eq!(issue.links[1].target.key, "mr#50");
+            assert_eq!(issue.links[1].target.state.as_deref(), Some("merged"));
+        }
+
+        #[tokio::test]
+        async fn test_link_issues() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues/42/links")
+                    .json_body(serde_json::json!({
+                        "target_project_id": "123",
+                        "target_issue_iid": 41,
+                        "link_type": "is_blocked_by"
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "source_issue": {"iid": 42},
+                    "target_issue": {"iid": 41},
+                    "link_type": "is_blocked_by"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .link_issues("gitlab#42", "gitlab#41", "blocked_by")
+                .await
+                .unwrap();
+            mock.assert();
+
+            let err = client
+                .link_issues("gitlab#42", "gitlab#41", "duplicates")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("Unsupported GitLab link kind"));
+        }
+
+        #[tokio::test]
+        async fn test_unlink_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues/42/links");
+                then.status(200).json_body(serde_json::json!([
+                    {"iid": 41, "title": "A", "state": "opened", "issue_link_id": 7, "link_type": "relates_to"},
+                    {"iid": 43, "title": "B", "state": "opened", "issue_link_id": 8, "link_type": "blocks"}
+                ]));
+            });
+
+            let delete_mock = server.mock(|when, then| {
+                when.method(DELETE)
+                    .path("/api/v4/projects/123/issues/42/links/7");
+                then.status(200).json_body(serde_json::json!({}));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .unlink_issues("gitlab#42", "gitlab#41", None)
+                .await
+                .unwrap();
+            delete_mock.assert();
+
+            let err = client
+                .unlink_issues("gitlab#42", "gitlab#43", Some("relates_to"))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        #[tokio::test]
+        async fn test_download_attachment() {
+            let server = MockServer::start();
+
+            let download_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/uploads/abc123/screen.png")
+                    .header("PRIVATE-TOKEN", "test-token");
+                then.status(200).body([0x89, b'P', b'N', b'G']);
+            });
+
+            let client = create_test_client(&server);
+            let content = client
+                .download_attachment(&format!(
+                    "{}/group/project/uploads/abc123/screen.png",
+                    server.base_url()
+                ))
+                .await
+                .unwrap();
+            assert_eq!(content, vec![0x89, b'P', b'N', b'G']);
+
+            // Relative links from comments resolve against the project too
+            client
+                .download_attachment("/uploads/abc123/screen.png")
+                .await
+                .unwrap();
+            download_mock.assert_calls(2);
+
+            let err = client
+                .download_attachment("https://example.com/group/project/uploads/abc123/screen.png")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("is not an upload URL"));
+        }
+
+        #[tokio::test]
+        async fn test_log_time() {
+            let server = MockServer::start();
+
+            let spent_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues/42/add_spent_time")
+                    .json_body(serde_json::json!({
+                        "duration": "1h30m",
+                        "summary": "Code review"
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "time_estimate": 0,
+                    "total_time_spent": 5400
+                }));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .log_time("gitlab#42", 5400, Some("Code review"), None)
+                .await
+                .unwrap();
+
+            spent_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_log_time_with_start_fails() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client
+                .log_time("gitlab#42", 600, None, Some("2024-01-15T10:00:00Z"))
+                .await;
+            assert!(matches!(result, Err(Error::InvalidData(_))));
+        }
+
+        #[tokio::test]
+        async fn test_set_time_estimate() {
+            let server = MockServer::start();
+
+            let estimate_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues/42/time_estimate")
+                    .json_body(serde_json::json!({"duration": "2h"}));
+                then.status(200).json_body(serde_json::json!({
+                    "time_estimate": 7200,
+                    "total_time_spent": 0
+                }));
+            });
+
+            let client = create_test_client(&server);
+            client.set_time_estimate("gitlab#42", 7200).await.unwrap();
+
+            estimate_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_upload_attachment() {
+            let server = MockServer::start();
+
+            let upload_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/uploads")
+                    .header(
+                        "Content-Type",
+                        "multipart/form-data; boundary=devboy-tools-attachment-7d3b0c4f",
+                    )
+                    .body_includes("name=\"file\"; filename=\"trace.log\"");
+                then.status(201).json_body(serde_json::json!({
+                    "id": 5,
+                    "alt": "trace.log",
+                    "url": "/uploads/def456/trace.log",
+                    "full_path": "/group/project/uploads/def456/trace.log",
+                    "markdown": "[trace.log](/uploads/def456/trace.log)"
+                }));
+            });
+
+            let note_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues/42/notes")
+                    .json_body(serde_json::json!({
+                        "body": "[trace.log](/uploads/def456/trace.log)"
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "id": 900,
+                    "body": "[trace.log](/uploads/def456/trace.log)",
+                    "created_at": "2024-01-03T00:00:00Z"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let attachment = client
+                .upload_attachment("gitlab#42", "trace.log", b"panic at line 3")
+                .await
+                .unwrap();
+
+            upload_mock.assert();
+            note_mock.assert();
+            assert_eq!(attachment.id, "5");
+            assert_eq!(attachment.size, Some(15));
+            assert_eq!(attachment.mime_type.as_deref(), Some("text/plain"));
+            assert_eq!(
+                attachment.url,
+                format!(
+                    "{}/group/project/uploads/def456/trace.log",
+                    server.base_url()
+                )
+            );
+        }
+
+        #[tokio::test]
+        async fn test_create_issue() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues")
+                    .header("PRIVATE-TOKEN", "test-token")
+                    .body_includes("\"title\":\"New Issue\"")
+                    .body_includes("\"labels\":\"bug,feature\"");
+                then.status(201).json_body(serde_json::json!({
+                    "id": 10,
+                    "iid": 99,
+                    "title": "New Issue",
+                    "description": "Description",
+                    "state": "opened",
+                    "labels": ["bug", "feature"],
+                    "author": {"id": 1, "username": "creator"},
+                    "assignees": [],
+                    "web_url": "https://gitlab.com/group/project/-/issues/99",
+                    "created_at": "2024-02-01T00:00:00Z",
+                    "updated_at": "2024-02-01T00:00:00Z"
+                }));
+            });
 
             let client = create_test_client(&server);
             let issue = client
@@ -1128,6 +2768,10 @@ mod tests {
                     labels: vec!["bug".to_string(), "feature".to_string()],
                     assignees: vec![],
                     priority: None,
+                    custom_fields: Default::default(),
+                    issue_type: None,
+                    parent: None,
+                    container: None,
                 })
                 .await
                 .unwrap();
@@ -1136,6 +2780,64 @@ mod tests {
             assert_eq!(issue.title, "New Issue");
         }
 
+        #[tokio::test]
+        async fn test_create_task_in_epic() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/issues")
+                    .body_includes("\"issue_type\":\"task\"")
+                    .body_includes("\"epic_iid\":7");
+                then.status(201).json_body(serde_json::json!({
+                    "id": 10,
+                    "iid": 99,
+                    "title": "New Task",
+                    "state": "opened",
+                    "web_url": "https://gitlab.com/group/project/-/issues/99",
+                    "created_at": "2024-02-01T00:00:00Z",
+                    "updated_at": "2024-02-01T00:00:00Z",
+                    "issue_type": "task",
+                    "epic": {"id": 70, "iid": 7, "title": "Q1 goals", "group_id": 5}
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "New Task".to_string(),
+                    issue_type: Some("Task".to_string()),
+                    parent: Some("gitlab&7".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            mock.assert();
+            assert_eq!(issue.issue_type.as_deref(), Some("task"));
+            let parent = issue.parent.unwrap();
+            assert_eq!(parent.key, "gitlab&7");
+            assert_eq!(parent.title.as_deref(), Some("Q1 goals"));
+            assert_eq!(parent.issue_type.as_deref(), Some("epic"));
+        }
+
+        #[tokio::test]
+        async fn test_create_issue_under_issue_rejected() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let err = client
+                .create_issue(CreateIssueInput {
+                    title: "Child".to_string(),
+                    parent: Some("gitlab#1".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap_err();
+
+            assert!(err.to_string().contains("only be created under an epic"));
+        }
+
         #[tokio::test]
         async fn test_update_issue() {
             let server = MockServer::start();
@@ -1331,6 +3033,194 @@ mod tests {
             assert!(diffs[1].new_file);
         }
 
+        #[tokio::test]
+        async fn test_get_commits() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/merge_requests/50/commits")
+                    .header("PRIVATE-TOKEN", "test-token");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "id": "bbb222",
+                        "title": "Second commit",
+                        "message": "Second commit",
+                        "author_name": "Test User"
+                    },
+                    {
+                        "id": "aaa111",
+                        "title": "First commit",
+                        "message": "First commit\n\nDetails",
+                        "author_name": "Test User"
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let commits = client.get_commits("mr#50").await.unwrap();
+
+            assert_eq!(commits.len(), 2);
+            assert_eq!(commits[0].sha, "aaa111");
+            assert_eq!(commits[1].title, "Second commit");
+        }
+
+        #[tokio::test]
+        async fn test_get_commit_diff() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/repository/commits/aaa111/diff")
+                    .header("PRIVATE-TOKEN", "test-token");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "old_path": "src/lib.rs",
+                        "new_path": "src/lib.rs",
+                        "diff": "@@ -1 +1 @@\n-old\n+new\n"
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let diffs = client.get_commit_diff("aaa111").await.unwrap();
+
+            assert_eq!(diffs.len(), 1);
+            assert_eq!(diffs[0].file_path, "src/lib.rs");
+            assert!(diffs[0].diff.contains("+new"));
+        }
+
+        #[tokio::test]
+        async fn test_get_file() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/repository/files/src%2Fmain.rs")
+                    .query_param("ref", "feature")
+                    .header("PRIVATE-TOKEN", "test-token");
+                then.status(200).json_body(serde_json::json!({
+                    "file_name": "main.rs",
+                    "file_path": "src/main.rs",
+                    "size": 12,
+                    "encoding": "base64",
+                    "content": "Zm4gbWFpbigpIHt9",
+                    "ref": "feature",
+                    "blob_id": "blob1"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let file = client
+                .get_file("src/main.rs", Some("feature"))
+                .await
+                .unwrap();
+
+            assert_eq!(file.content, "fn main() {}");
+            assert_eq!(file.ref_name, Some("feature".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_get_file_default_ref() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/repository/files/README.md")
+                    .query_param("ref", "HEAD");
+                then.status(200).json_body(serde_json::json!({
+                    "file_path": "README.md",
+                    "size": 5,
+                    "encoding": "base64",
+                    "content": "aGVsbG8="
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let file = client.get_file("README.md", None).await.unwrap();
+
+            mock.assert();
+            assert_eq!(file.content, "hello");
+            assert!(file.ref_name.is_none());
+        }
+
+        #[tokio::test]
+        async fn test_list_tree() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/repository/tree")
+                    .query_param("path", "src")
+                    .query_param("ref", "main");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": "a", "name": "lib", "type": "tree", "path": "src/lib", "mode": "040000"},
+                    {"id": "b", "name": "main.rs", "type": "blob", "path": "src/main.rs", "mode": "100644"}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let entries = client.list_tree("src", Some("main")).await.unwrap();
+
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].kind, "dir");
+            assert_eq!(entries[1].path, "src/main.rs");
+        }
+
+        #[tokio::test]
+        async fn test_create_branch() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/repository/branches")
+                    .query_param("branch", "42-fix-login")
+                    .query_param("ref", "develop");
+                then.status(201).json_body(serde_json::json!({
+                    "name": "42-fix-login",
+                    "commit": {"id": "abc123"},
+                    "web_url": "https://gitlab.com/test/-/tree/42-fix-login"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let branch = client
+                .create_branch("42-fix-login", Some("develop"))
+                .await
+                .unwrap();
+
+            mock.assert();
+            assert_eq!(branch.name, "42-fix-login");
+            assert_eq!(branch.sha, Some("abc123".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_create_branch_from_default_branch() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/api/v4/projects/123");
+                then.status(200).json_body(serde_json::json!({
+                    "id": 123,
+                    "default_branch": "main"
+                }));
+            });
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/repository/branches")
+                    .query_param("ref", "main");
+                then.status(201)
+                    .json_body(serde_json::json!({"name": "42-fix-login"}));
+            });
+
+            let client = create_test_client(&server);
+            let branch = client.create_branch("42-fix-login", None).await.unwrap();
+
+            mock.assert();
+            assert_eq!(branch.name, "42-fix-login");
+        }
+
         #[tokio::test]
         async fn test_add_mr_comment_general() {
             let server = MockServer::start();
@@ -1443,6 +3333,95 @@ mod tests {
             assert!(comment.position.is_some());
         }
 
+        #[tokio::test]
+        async fn test_me_filters_resolve_current_user() {
+            let server = MockServer::start();
+
+            let user_mock = server.mock(|when, then| {
+                when.method(GET).path("/api/v4/user");
+                then.status(200)
+                    .json_body(serde_json::json!({"id": 42, "username": "current_user"}));
+            });
+            let issues_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/issues")
+                    .query_param("assignee_username", "current_user");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+            let mrs_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/merge_requests")
+                    .query_param("author_username", "current_user")
+                    .query_param("reviewer_username", "jdoe");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_test_client(&server);
+            client
+                .get_issues(IssueFilter {
+                    assignee: Some("@me".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            client
+                .get_merge_requests(MrFilter {
+                    author: Some("@me".to_string()),
+                    reviewer: Some("jdoe".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            user_mock.assert_calls(1);
+            issues_mock.assert();
+            mrs_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_list_labels() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/labels")
+                    .query_param("include_ancestor_groups", "true");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": 1, "name": "bug", "color": "#d9534f", "description": null},
+                    {"id": 2, "name": "backend", "color": "#428bca", "description": "Server side"}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let labels = client.list_labels().await.unwrap();
+
+            mock.assert();
+            assert_eq!(labels.len(), 2);
+            assert_eq!(labels[1].name, "backend");
+            assert_eq!(labels[1].description.as_deref(), Some("Server side"));
+        }
+
+        #[tokio::test]
+        async fn test_search_users() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/members/all")
+                    .query_param("query", "john");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": 5, "username": "jdoe", "name": "John Doe"}
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let users = client.search_users("@john").await.unwrap();
+
+            assert_eq!(users.len(), 1);
+            assert_eq!(users[0].username, "jdoe");
+            assert_eq!(users[0].name.as_deref(), Some("John Doe"));
+        }
+
         #[tokio::test]
         async fn test_get_current_user() {
             let server = MockServer::start();
@@ -1468,6 +3447,230 @@ mod tests {
             assert_eq!(user.name, Some("Current User".to_string()));
         }
 
+        #[tokio::test]
+        async fn test_get_pipelines() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/merge_requests/5/pipelines");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "id": 101,
+                        "sha": "abc123",
+                        "ref": "feature",
+                        "status": "running",
+                        "web_url": "https://gitlab.com/group/project/-/pipelines/101"
+                    },
+                    {
+                        "id": 100,
+                        "sha": "def456",
+                        "ref": "feature",
+                        "status": "failed",
+                        "web_url": "https://gitlab.com/group/project/-/pipelines/100"
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let pipelines = client.get_pipelines("mr#5").await.unwrap();
+
+            assert_eq!(pipelines.len(), 2);
+            assert_eq!(pipelines[0].id, "101");
+            assert_eq!(pipelines[0].status, "running");
+            assert_eq!(pipelines[0].ref_name, Some("feature".to_string()));
+            assert!(pipelines[0].jobs.is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_get_pipeline_with_jobs() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/api/v4/projects/123/pipelines/101");
+                then.status(200).json_body(serde_json::json!({
+                    "id": 101,
+                    "sha": "abc123",
+                    "ref": "feature",
+                    "status": "failed",
+                    "duration": 245,
+                    "web_url": "https://gitlab.com/group/project/-/pipelines/101"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/pipelines/101/jobs");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "id": 12,
+                        "name": "test",
+                        "stage": "test",
+                        "status": "failed",
+                        "allow_failure": false,
+                        "duration": 120.4
+                    },
+                    {
+                        "id": 11,
+                        "name": "build",
+                        "stage": "build",
+                        "status": "success",
+                        "allow_failure": false,
+                        "duration": 80.0
+                    }
+                ]));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.get_pipeline("101").await.unwrap();
+
+            assert_eq!(pipeline.status, "failed");
+            assert_eq!(pipeline.duration, Some(245));
+            assert_eq!(pipeline.jobs.len(), 2);
+            assert_eq!(pipeline.jobs[0].name, "build");
+            assert_eq!(pipeline.jobs[1].name, "test");
+            assert_eq!(pipeline.jobs[1].status, "failed");
+        }
+
+        #[tokio::test]
+        async fn test_get_job_log() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/api/v4/projects/123/jobs/12/trace")
+                    .header("PRIVATE-TOKEN", "test-token");
+                then.status(200)
+                    .header("content-type", "text/plain")
+                    .body("$ cargo test\nerror: test failed\n");
+            });
+
+            let client = create_test_client(&server);
+            let log = client.get_job_log("12").await.unwrap();
+
+            assert_eq!(log, "$ cargo test\nerror: test failed\n");
+        }
+
+        #[tokio::test]
+        async fn test_get_job_log_not_found() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/api/v4/projects/123/jobs/99/trace");
+                then.status(404).body("{\"message\":\"404 Not Found\"}");
+            });
+
+            let client = create_test_client(&server);
+            let result = client.get_job_log("99").await;
+
+            assert!(matches!(result.unwrap_err(), Error::NotFound(_)));
+        }
+
+        #[tokio::test]
+        async fn test_retry_pipeline() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/pipelines/101/retry");
+                then.status(201).json_body(serde_json::json!({
+                    "id": 101,
+                    "status": "pending",
+                    "ref": "feature"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.retry_pipeline("101").await.unwrap();
+
+            assert_eq!(pipeline.id, "101");
+            assert_eq!(pipeline.status, "pending");
+        }
+
+        #[tokio::test]
+        async fn test_retry_job() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(POST).path("/api/v4/projects/123/jobs/12/retry");
+                then.status(201).json_body(serde_json::json!({
+                    "id": 13,
+                    "name": "test",
+                    "stage": "test",
+                    "status": "pending",
+                    "pipeline": {"id": 101, "status": "running", "ref": "feature"}
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.retry_job("12").await.unwrap();
+
+            assert_eq!(pipeline.id, "101");
+            assert_eq!(pipeline.status, "running");
+            assert_eq!(pipeline.jobs[0].id, "13");
+        }
+
+        #[tokio::test]
+        async fn test_cancel_pipeline() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/pipelines/101/cancel");
+                then.status(200).json_body(serde_json::json!({
+                    "id": 101,
+                    "status": "canceled"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client.cancel_pipeline("101").await.unwrap();
+
+            assert_eq!(pipeline.status, "canceled");
+        }
+
+        #[tokio::test]
+        async fn test_run_pipeline() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/api/v4/projects/123/pipeline")
+                    .json_body(serde_json::json!({
+                        "ref": "main",
+                        "variables": [{"key": "DEPLOY", "value": "false"}]
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "id": 202,
+                    "status": "created",
+                    "ref": "main",
+                    "web_url": "https://gitlab.com/group/project/-/pipelines/202"
+                }));
+            });
+
+            let client = create_test_client(&server);
+            let pipeline = client
+                .run_pipeline(RunPipelineInput {
+                    ref_name: "main".to_string(),
+                    variables: [("DEPLOY".to_string(), "false".to_string())].into(),
+                    workflow: None,
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(pipeline.id, "202");
+            assert_eq!(pipeline.status, "pending");
+        }
+
+        #[tokio::test]
+        async fn test_get_pipeline_invalid_id() {
+            let server = MockServer::start();
+            let client = create_test_client(&server);
+
+            let result = client.get_pipeline("abc").await;
+            assert!(matches!(result.unwrap_err(), Error::InvalidData(_)));
+        }
+
         #[tokio::test]
         async fn test_api_error_handling() {
             let server = MockServer::start();
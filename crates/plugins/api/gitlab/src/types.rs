@@ -44,6 +44,121 @@ pub struct GitLabIssue {
     pub web_url: String,
     pub created_at: String,
     pub updated_at: String,
+    /// "issue", "incident", "test_case" or "task"
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// Epic the issue belongs to (Premium)
+    #[serde(default)]
+    pub epic: Option<GitLabEpicRef>,
+    #[serde(default)]
+    pub time_stats: Option<GitLabTimeStats>,
+    #[serde(default)]
+    pub milestone: Option<GitLabMilestone>,
+    /// Iteration (Premium)
+    #[serde(default)]
+    pub iteration: Option<GitLabIteration>,
+}
+
+/// Time tracking totals of an issue, in seconds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitLabTimeStats {
+    #[serde(default)]
+    pub time_estimate: u64,
+    #[serde(default)]
+    pub total_time_spent: u64,
+}
+
+/// Epic reference embedded in an issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabEpicRef {
+    pub iid: u64,
+    pub title: String,
+}
+
+/// Issue linked to another issue, as returned by `/issues/:iid/links`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabLinkedIssue {
+    pub iid: u64,
+    pub title: String,
+    pub state: String,
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    /// ID of the link, used to delete it
+    pub issue_link_id: u64,
+    /// "relates_to", "blocks" or "is_blocked_by"
+    pub link_type: String,
+}
+
+// =============================================================================
+// Milestones
+// =============================================================================
+
+/// GitLab project or group label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabLabel {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// GitLab project or group milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabMilestone {
+    pub id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// "active" or "closed"
+    pub state: String,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+}
+
+/// GitLab iteration (Premium).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabIteration {
+    pub id: u64,
+    pub iid: u64,
+    /// Empty for iterations created by a cadence
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 1 = upcoming, 2 = current, 3 = closed
+    #[serde(default)]
+    pub state: u8,
+    #[serde(default)]
+    pub start_date: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+}
+
+/// Response from `/issues_statistics`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabIssuesStatistics {
+    pub statistics: GitLabIssueStatistics,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabIssueStatistics {
+    pub counts: GitLabIssueCounts,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabIssueCounts {
+    #[serde(default)]
+    pub opened: u32,
+    #[serde(default)]
+    pub closed: u32,
 }
 
 // =============================================================================
@@ -82,6 +197,8 @@ pub struct GitLabMergeRequest {
     pub diff_refs: Option<GitLabDiffRefs>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub milestone: Option<GitLabMilestone>,
 }
 
 /// GitLab diff refs (SHA references for code positions).
@@ -140,6 +257,46 @@ pub struct GitLabNotePosition {
     pub old_line: Option<u32>,
 }
 
+// =============================================================================
+// Uploads
+// =============================================================================
+
+/// File uploaded to a project, linked from Markdown by `markdown`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabUpload {
+    #[serde(default)]
+    pub id: Option<u64>,
+    /// Project-relative URL, e.g. "/uploads/<secret>/file.png"
+    pub url: String,
+    /// Instance-relative URL, e.g. "/group/project/uploads/<secret>/file.png"
+    #[serde(default)]
+    pub full_path: Option<String>,
+    pub markdown: String,
+}
+
+// =============================================================================
+// Commits
+// =============================================================================
+
+/// GitLab commit representation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabCommit {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub author_name: Option<String>,
+    #[serde(default)]
+    pub author_email: Option<String>,
+    #[serde(default)]
+    pub authored_date: Option<String>,
+    #[serde(default)]
+    pub committed_date: Option<String>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+}
+
 // =============================================================================
 // Diffs
 // =============================================================================
@@ -166,6 +323,106 @@ pub struct GitLabMergeRequestChanges {
     pub changes: Vec<GitLabDiff>,
 }
 
+// =============================================================================
+// Repository
+// =============================================================================
+
+/// GitLab repository file (`/repository/files/:path`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabFile {
+    pub file_path: String,
+    pub size: u64,
+    /// Always "base64"
+    pub encoding: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default, rename = "ref")]
+    pub ref_name: Option<String>,
+    #[serde(default)]
+    pub blob_id: Option<String>,
+}
+
+/// GitLab project (only the fields we need).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabProject {
+    pub id: u64,
+    #[serde(default)]
+    pub default_branch: Option<String>,
+}
+
+/// GitLab branch representation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabBranch {
+    pub name: String,
+    #[serde(default)]
+    pub commit: Option<GitLabBranchCommit>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+}
+
+/// Commit a GitLab branch points to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabBranchCommit {
+    pub id: String,
+}
+
+/// GitLab repository tree entry (`/repository/tree`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabTreeEntry {
+    pub name: String,
+    pub path: String,
+    /// "blob", "tree" or "commit" (submodule)
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub mode: Option<String>,
+}
+
+// =============================================================================
+// Pipelines
+// =============================================================================
+
+/// GitLab pipeline representation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabPipeline {
+    pub id: u64,
+    pub status: String,
+    #[serde(rename = "ref", default)]
+    pub ref_name: Option<String>,
+    #[serde(default)]
+    pub sha: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Only present on the single pipeline endpoint
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// GitLab CI job representation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabJob {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub stage: Option<String>,
+    pub status: String,
+    #[serde(default)]
+    pub allow_failure: bool,
+    #[serde(default)]
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub web_url: Option<String>,
+    /// Pipeline the job belongs to
+    #[serde(default)]
+    pub pipeline: Option<GitLabPipeline>,
+}
+
 // =============================================================================
 // Request types
 // =============================================================================
@@ -181,6 +438,20 @@ pub struct CreateIssueRequest {
     pub labels: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epic_iid: Option<u64>,
+}
+
+/// Request body for linking two issues.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateIssueLinkRequest {
+    /// ID or path of the target issue's project
+    pub target_project_id: String,
+    pub target_issue_iid: u64,
+    /// "relates_to", "blocks" or "is_blocked_by"
+    pub link_type: String,
 }
 
 /// Request body for updating an issue.
@@ -206,6 +477,15 @@ pub struct CreateNoteRequest {
     pub body: String,
 }
 
+/// Request body for `/issues/:iid/add_spent_time` and `/issues/:iid/time_estimate`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeTrackingRequest {
+    /// Duration in GitLab format, e.g. "1h30m"
+    pub duration: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
 /// Request body for creating a discussion on a merge request.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateDiscussionRequest {
@@ -230,3 +510,19 @@ pub struct DiscussionPosition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub old_line: Option<u32>,
 }
+
+/// Request body for creating a pipeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatePipelineRequest {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<PipelineVariable>,
+}
+
+/// Pipeline variable for creating a pipeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineVariable {
+    pub key: String,
+    pub value: String,
+}
@@ -0,0 +1,1695 @@
+//! ADF (Atlassian Document Format) ⇄ Markdown conversion for Jira Cloud.
+//!
+//! Jira Cloud (API v3) stores descriptions and comments as ADF documents,
+//! while agents read and write Markdown.
+//!
+//! | ADF                                  | Markdown                         |
+//! |--------------------------------------|----------------------------------|
+//! | heading, paragraph, hardBreak        | `# Title`, text, line break      |
+//! | bulletList, orderedList, taskList    | `- item`, `1. item`, `- [x] done` |
+//! | codeBlock, blockquote, rule          | fenced code, `> quote`, `---`    |
+//! | panel                                | `> [!WARNING]` alert             |
+//! | table                                | GFM table                        |
+//! | strong, em, strike, code, link marks | `**b**`, `*i*`, `~~s~~`, `` `c` ``, `[t](url)` |
+//!
+//! Read-only nodes (mentions, emojis, dates, statuses, cards, media, expands)
+//! are rendered as readable text. A single newline inside a paragraph maps
+//! to a `hardBreak`, since agents use it as a line break.
+
+use devboy_core::custom_fields::unix_ms_to_date;
+use serde_json::{json, Value};
+
+// =============================================================================
+// ADF -> Markdown
+// =============================================================================
+
+/// Convert an ADF document (or a single ADF node) to Markdown.
+///
+/// Strings are returned as-is, so plain-text values pass through unchanged.
+pub fn adf_to_markdown(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        Value::Object(_) if node_type(value) == "doc" => blocks_to_markdown(children(value)),
+        Value::Object(_) => block_to_markdown(value),
+        other => other.to_string(),
+    }
+}
+
+pub(crate) fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or("")
+}
+
+pub(crate) fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+pub(crate) fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs")?.get(name)
+}
+
+pub(crate) fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name)?.as_str()
+}
+
+pub(crate) fn is_inline(node: &Value) -> bool {
+    matches!(
+        node_type(node),
+        "text"
+            | "hardBreak"
+            | "mention"
+            | "emoji"
+            | "date"
+            | "status"
+            | "inlineCard"
+            | "mediaInline"
+            | "placeholder"
+    )
+}
+
+/// Render block nodes separated by blank lines.
+fn blocks_to_markdown(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(block_to_markdown)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn block_to_markdown(node: &Value) -> String {
+    match node_type(node) {
+        "paragraph" => inlines_to_markdown(children(node)),
+        "heading" => {
+            let level = attr(node, "level")
+                .and_then(Value::as_u64)
+                .unwrap_or(1)
+                .clamp(1, 6) as usize;
+            format!(
+                "{} {}",
+                "#".repeat(level),
+                inlines_to_markdown(children(node))
+            )
+        }
+        "bulletList" => list_to_markdown(node, None),
+        "orderedList" => {
+            let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1);
+            list_to_markdown(node, Some(start))
+        }
+        "taskList" => task_list_to_markdown(node),
+        "codeBlock" => {
+            let language = attr_str(node, "language").unwrap_or_default();
+            let code = plain_text(children(node));
+            let fence = code_fence(&code);
+            format!("{}{}\n{}\n{}", fence, language, code, fence)
+        }
+        "blockquote" => quote(&blocks_to_markdown(children(node))),
+        "panel" => {
+            let kind = attr_str(node, "panelType").unwrap_or("info");
+            let body = blocks_to_markdown(children(node));
+            quote(&format!("[!{}]\n{}", kind.to_ascii_uppercase(), body))
+        }
+        "rule" => "---".to_string(),
+        "table" => table_to_markdown(node),
+        "expand" | "nestedExpand" => {
+            let body = blocks_to_markdown(children(node));
+            match attr_str(node, "title").filter(|t| !t.is_empty()) {
+                Some(title) => blocks_join(&[format!("**{}**", title), body]),
+                None => body,
+            }
+        }
+        "decisionList" => children(node)
+            .iter()
+            .map(|item| format!("- ✔ {}", inlines_to_markdown(children(item))))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "mediaSingle" | "mediaGroup" => children(node)
+            .iter()
+            .map(media_to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "media" => media_to_markdown(node),
+        "blockCard" | "embedCard" => attr_str(node, "url").unwrap_or_default().to_string(),
+        _ if is_inline(node) => inlines_to_markdown(std::slice::from_ref(node)),
+        _ => blocks_to_markdown(children(node)),
+    }
+}
+
+fn blocks_join(parts: &[String]) -> String {
+    parts
+        .iter()
+        .filter(|p| !p.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn list_to_markdown(node: &Value, start: Option<u64>) -> String {
+    children(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = match start {
+                Some(start) => format!("{}. ", start + i as u64),
+                None => "- ".to_string(),
+            };
+            let body = children(item)
+                .iter()
+                .map(block_to_markdown)
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}{}", marker, indent_continuation(&body, marker.len()))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn task_list_to_markdown(node: &Value) -> String {
+    children(node)
+        .iter()
+        .map(|child| match node_type(child) {
+            "taskItem" => {
+                let check = if attr_str(child, "state") == Some("DONE") {
+                    "x"
+                } else {
+                    " "
+                };
+                let text = inlines_to_markdown(children(child));
+                format!("- [{}] {}", check, indent_continuation(&text, 2))
+            }
+            "taskList" => indent(&task_list_to_markdown(child), 2),
+            _ => block_to_markdown(child),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn table_to_markdown(node: &Value) -> String {
+    let rows: Vec<Vec<String>> = children(node)
+        .iter()
+        .filter(|row| node_type(row) == "tableRow")
+        .map(|row| children(row).iter().map(cell_to_markdown).collect())
+        .collect();
+    if rows.is_empty() {
+        return String::new();
+    }
+
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0).max(1);
+    let format_row = |cells: &[String]| {
+        let mut cells = cells.to_vec();
+        cells.resize(columns, String::new());
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![
+        format_row(&rows[0]),
+        format!("|{}", " --- |".repeat(columns)),
+    ];
+    lines.extend(rows[1..].iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
+
+fn cell_to_markdown(cell: &Value) -> String {
+    blocks_to_markdown(children(cell))
+        .replace("\n\n", "<br>")
+        .replace('\n', "<br>")
+        .replace('|', "\\|")
+}
+
+fn media_to_markdown(node: &Value) -> String {
+    let name = attr_str(node, "alt")
+        .or_else(|| attr_str(node, "id"))
+        .unwrap_or("media");
+    format!("[attachment: {}]", name)
+}
+
+fn inlines_to_markdown(nodes: &[Value]) -> String {
+    nodes.iter().map(inline_to_markdown).collect()
+}
+
+fn inline_to_markdown(node: &Value) -> String {
+    match node_type(node) {
+        "text" => {
+            let text = node.get("text").and_then(Value::as_str).unwrap_or_default();
+            let marks = node
+                .get("marks")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            apply_marks(text, marks)
+        }
+        "hardBreak" => "\n".to_string(),
+        "mention" => {
+            let text = attr_str(node, "text")
+                .or_else(|| attr_str(node, "id"))
+                .unwrap_or_default();
+            if text.starts_with('@') {
+                text.to_string()
+            } else {
+                format!("@{}", text)
+            }
+        }
+        "emoji" => attr_str(node, "text")
+            .or_else(|| attr_str(node, "shortName"))
+            .unwrap_or_default()
+            .to_string(),
+        "date" => {
+            let timestamp = attr(node, "timestamp").and_then(|t| match t {
+                Value::String(s) => s.parse::<i64>().ok(),
+                other => other.as_i64(),
+            });
+            timestamp.map(unix_ms_to_date).unwrap_or_default()
+        }
+        "status" => format!("[{}]", attr_str(node, "text").unwrap_or_default()),
+        "inlineCard" => attr_str(node, "url").unwrap_or_default().to_string(),
+        "mediaInline" => media_to_markdown(node),
+        _ => inlines_to_markdown(children(node)),
+    }
+}
+
+/// Wrap text in the Markdown syntax of its ADF marks.
+fn apply_marks(text: &str, marks: &[Value]) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let (mut strong, mut em, mut strike, mut code) = (false, false, false, false);
+    let mut href = None;
+    for mark in marks {
+        match node_type(mark) {
+            "strong" => strong = true,
+            "em" => em = true,
+            "strike" => strike = true,
+            "code" => code = true,
+            "link" => href = attr_str(mark, "href"),
+            _ => {}
+        }
+    }
+
+    if code {
+        let span = code_span(text);
+        return match href {
+            Some(href) => format!("[{}]({})", span, href),
+            None => span,
+        };
+    }
+
+    // Emphasis markers must hug the text: "**bold** " rather than "**bold **"
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let lead = &text[..text.len() - text.trim_start().len()];
+    let trail = &text[text.trim_end().len()..];
+
+    let mut out = core.to_string();
+    if strike {
+        out = format!("~~{}~~", out);
+    }
+    if em {
+        out = format!("*{}*", out);
+    }
+    if strong {
+        out = format!("**{}**", out);
+    }
+    if let Some(href) = href {
+        // A bare URL links to itself
+        if out != href {
+            out = format!("[{}]({})", out, href);
+        }
+    }
+
+    format!("{}{}{}", lead, out, trail)
+}
+
+fn code_span(text: &str) -> String {
+    if text.contains('`') {
+        format!("`` {} ``", text)
+    } else {
+        format!("`{}`", text)
+    }
+}
+
+/// A backtick fence longer than any backtick run inside the code.
+fn code_fence(code: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in code.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    "`".repeat((longest + 1).max(3))
+}
+
+fn plain_text(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node_type(node) {
+            "text" => node
+                .get("text")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            "hardBreak" => "\n".to_string(),
+            _ => plain_text(children(node)),
+        })
+        .collect()
+}
+
+fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn indent(text: &str, width: usize) -> String {
+    let pad = " ".repeat(width);
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{}{}", pad, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Indent every line but the first (which follows a list marker).
+fn indent_continuation(text: &str, width: usize) -> String {
+    match text.split_once('\n') {
+        Some((first, rest)) => format!("{}\n{}", first, indent(rest, width)),
+        None => text.to_string(),
+    }
+}
+
+// =============================================================================
+// Markdown -> ADF
+// =============================================================================
+
+/// Convert Markdown to an ADF document.
+pub fn markdown_to_adf(markdown: &str) -> Value {
+    let lines: Vec<String> = markdown.lines().map(expand_leading_tabs).collect();
+    let mut content = MarkdownParser::default().parse_blocks(&lines);
+    if content.is_empty() {
+        content.push(paragraph(Vec::new()));
+    }
+
+    json!({
+        "version": 1,
+        "type": "doc",
+        "content": content
+    })
+}
+
+fn expand_leading_tabs(line: &str) -> String {
+    let trimmed = line.trim_start_matches([' ', '\t']);
+    let leading = &line[..line.len() - trimmed.len()];
+    format!("{}{}", leading.replace('\t', "    "), trimmed)
+}
+
+pub(crate) fn paragraph(content: Vec<Value>) -> Value {
+    json!({ "type": "paragraph", "content": content })
+}
+
+fn leading_spaces(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Block-level Markdown parser. Holds the counter for task list IDs.
+#[derive(Default)]
+struct MarkdownParser {
+    local_ids: usize,
+}
+
+impl MarkdownParser {
+    fn local_id(&mut self) -> String {
+        self.local_ids += 1;
+        format!("task-{}", self.local_ids)
+    }
+
+    fn parse_blocks(&mut self, lines: &[String]) -> Vec<Value> {
+        let mut blocks = Vec::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i].as_str();
+            let trimmed = line.trim_start();
+
+            if trimmed.is_empty() {
+                i += 1;
+            } else if let Some(fence) = parse_fence(trimmed) {
+                let (block, next) = parse_code_block(lines, i, fence);
+                blocks.push(block);
+                i = next;
+            } else if let Some((level, text)) = parse_heading(trimmed) {
+                blocks.push(json!({
+                    "type": "heading",
+                    "attrs": { "level": level },
+                    "content": parse_inline(text)
+                }));
+                i += 1;
+            } else if is_rule(trimmed) {
+                blocks.push(json!({ "type": "rule" }));
+                i += 1;
+            } else if trimmed.starts_with('>') {
+                let (block, next) = self.parse_quote(lines, i);
+                blocks.push(block);
+                i = next;
+            } else if parse_list_marker(line).is_some() {
+                let (block, next) = self.parse_list(lines, i);
+                blocks.push(block);
+                i = next;
+            } else if is_table_start(lines, i) {
+                let (block, next) = parse_table(lines, i);
+                blocks.push(block);
+                i = next;
+            } else {
+                let start = i;
+                i += 1;
+                while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines, i) {
+                    i += 1;
+                }
+                blocks.push(paragraph(parse_inline_lines(&lines[start..i])));
+            }
+        }
+
+        blocks
+    }
+
+    fn parse_quote(&mut self, lines: &[String], start: usize) -> (Value, usize) {
+        let mut inner = Vec::new();
+        let mut i = start;
+        while i < lines.len() {
+            let Some(rest) = lines[i].trim_start().strip_prefix('>') else {
+                break;
+            };
+            inner.push(rest.strip_prefix(' ').unwrap_or(rest).to_string());
+            i += 1;
+        }
+
+        // "> [!WARNING]" alerts map to panels
+        let panel_type = inner.first().and_then(|first| {
+            let kind = first.trim().strip_prefix("[!")?.strip_suffix(']')?;
+            panel_type_from_alert(kind)
+        });
+
+        let block = match panel_type {
+            Some(panel_type) => {
+                let mut content = self.parse_blocks(&inner[1..]);
+                if content.is_empty() {
+                    content.push(paragraph(Vec::new()));
+                }
+                json!({
+                    "type": "panel",
+                    "attrs": { "panelType": panel_type },
+                    "content": content
+                })
+            }
+            None => {
+                let mut content = self.parse_blocks(&inner);
+                if content.is_empty() {
+                    content.push(paragraph(Vec::new()));
+                }
+                json!({ "type": "blockquote", "content": content })
+            }
+        };
+
+        (block, i)
+    }
+
+    fn parse_list(&mut self, lines: &[String], start: usize) -> (Value, usize) {
+        let first = parse_list_marker(&lines[start]).expect("list starts with a marker");
+        let mut items: Vec<(ListMarker, Vec<String>)> = Vec::new();
+        let mut i = start;
+
+        while i < lines.len() {
+            let line = lines[i].as_str();
+            let offset = items
+                .last()
+                .map(|(m, _)| m.content_offset)
+                .unwrap_or(first.content_offset);
+
+            if let Some(marker) = parse_list_marker(line) {
+                if marker.indent < offset {
+                    if marker.indent < first.indent || marker.ordered != first.ordered {
+                        break;
+                    }
+                    let text = line.get(marker.content_offset..).unwrap_or_default();
+                    items.push((marker, vec![text.to_string()]));
+                    i += 1;
+                    continue;
+                }
+            }
+
+            let Some((_, item_lines)) = items.last_mut() else {
+                break;
+            };
+
+            if line.trim().is_empty() {
+                // A blank line continues the list only if the list goes on after it
+                let next = lines[i..].iter().position(|l| !l.trim().is_empty());
+                let continues = next.is_some_and(|n| {
+                    let next_line = &lines[i + n];
+                    leading_spaces(next_line) >= offset
+                        || parse_list_marker(next_line).is_some_and(|m| {
+                            m.indent >= first.indent
+                                && m.indent < offset
+                                && m.ordered == first.ordered
+                        })
+                });
+                if !continues {
+                    break;
+                }
+                item_lines.push(String::new());
+            } else if leading_spaces(line) >= offset {
+                item_lines.push(line[offset..].to_string());
+            } else if item_lines.last().is_some_and(|l| !l.trim().is_empty())
+                && !starts_block(lines, i)
+            {
+                // Lazy continuation of the item's paragraph
+                item_lines.push(line.trim_start().to_string());
+            } else {
+                break;
+            }
+            i += 1;
+        }
+
+        let block = if items
+            .iter()
+            .all(|(_, lines)| task_state(&lines[0]).is_some())
+        {
+            self.build_task_list(&items)
+                .unwrap_or_else(|| self.build_list(&first, &items))
+        } else {
+            self.build_list(&first, &items)
+        };
+
+        (block, i)
+    }
+
+    fn build_list(&mut self, first: &ListMarker, items: &[(ListMarker, Vec<String>)]) -> Value {
+        let list_items: Vec<Value> = items
+            .iter()
+            .map(|(_, lines)| {
+                let mut content = self.parse_blocks(lines);
+                if content.is_empty() {
+                    content.push(paragraph(Vec::new()));
+                }
+                json!({ "type": "listItem", "content": content })
+            })
+            .collect();
+
+        if first.ordered {
+            let mut list = json!({ "type": "orderedList", "content": list_items });
+            if first.start != 1 {
+                list["attrs"] = json!({ "order": first.start });
+            }
+            list
+        } else {
+            json!({ "type": "bulletList", "content": list_items })
+        }
+    }
+
+    /// Build a task list, or `None` if an item has content a task item cannot hold.
+    fn build_task_list(&mut self, items: &[(ListMarker, Vec<String>)]) -> Option<Value> {
+        let mut content = Vec::new();
+
+        for (_, lines) in items {
+            let (state, text) = task_state(&lines[0])?;
+            let mut item_lines = lines.clone();
+            item_lines[0] = text.to_string();
+
+            let mut blocks = self.parse_blocks(&item_lines).into_iter();
+            let inline = match blocks.next() {
+                Some(block) if node_type(&block) == "paragraph" => children(&block).to_vec(),
+                None => Vec::new(),
+                Some(_) => return None,
+            };
+            content.push(json!({
+                "type": "taskItem",
+                "attrs": { "localId": self.local_id(), "state": state },
+                "content": inline
+            }));
+
+            for block in blocks {
+                if node_type(&block) != "taskList" {
+                    return None;
+                }
+                content.push(block);
+            }
+        }
+
+        Some(json!({
+            "type": "taskList",
+            "attrs": { "localId": self.local_id() },
+            "content": content
+        }))
+    }
+}
+
+/// Whether line `i` starts a block that interrupts a paragraph.
+fn starts_block(lines: &[String], i: usize) -> bool {
+    let line = lines[i].as_str();
+    let trimmed = line.trim_start();
+    parse_fence(trimmed).is_some()
+        || parse_heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || trimmed.starts_with('>')
+        || parse_list_marker(line).is_some()
+        || is_table_start(lines, i)
+}
+
+struct Fence {
+    marker: char,
+    len: usize,
+    indent: usize,
+    language: String,
+}
+
+fn parse_fence(trimmed: &str) -> Option<Fence> {
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = trimmed.chars().take_while(|c| *c == marker).count();
+    if len < 3 {
+        return None;
+    }
+    let info = trimmed[len..].trim();
+    if marker == '`' && info.contains('`') {
+        return None;
+    }
+    Some(Fence {
+        marker,
+        len,
+        indent: 0,
+        language: info
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+fn parse_code_block(lines: &[String], start: usize, mut fence: Fence) -> (Value, usize) {
+    fence.indent = leading_spaces(&lines[start]);
+    let mut code_lines = Vec::new();
+    let mut i = start + 1;
+
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+        let is_closing = trimmed.len() >= fence.len && trimmed.chars().all(|c| c == fence.marker);
+        if is_closing {
+            i += 1;
+            break;
+        }
+        let strip = leading_spaces(&lines[i]).min(fence.indent);
+        code_lines.push(&lines[i][strip..]);
+        i += 1;
+    }
+
+    let code = code_lines.join("\n");
+    let mut block = json!({ "type": "codeBlock", "content": [] });
+    if !fence.language.is_empty() {
+        block["attrs"] = json!({ "language": fence.language });
+    }
+    if !code.is_empty() {
+        block["content"] = json!([{ "type": "text", "text": code }]);
+    }
+
+    (block, i)
+}
+
+fn parse_heading(trimmed: &str) -> Option<(usize, &str)> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level, rest.trim()))
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    let Some(marker) = trimmed.chars().next().filter(|c| "-*_".contains(*c)) else {
+        return false;
+    };
+    trimmed.chars().all(|c| c == marker || c == ' ')
+        && trimmed.chars().filter(|c| *c == marker).count() >= 3
+}
+
+pub(crate) fn panel_type_from_alert(kind: &str) -> Option<&'static str> {
+    match kind.to_ascii_lowercase().as_str() {
+        "info" | "important" => Some("info"),
+        "note" => Some("note"),
+        "tip" => Some("tip"),
+        "success" => Some("success"),
+        "warning" => Some("warning"),
+        "error" | "caution" => Some("error"),
+        _ => None,
+    }
+}
+
+struct ListMarker {
+    indent: usize,
+    ordered: bool,
+    start: u64,
+    content_offset: usize,
+}
+
+fn parse_list_marker(line: &str) -> Option<ListMarker> {
+    let indent = leading_spaces(line);
+    let rest = &line[indent..];
+
+    let (ordered, start, marker_len) = if rest.starts_with(['-', '*', '+']) {
+        (false, 1, 1)
+    } else {
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || digits > 9 || !rest[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        (true, rest[..digits].parse().ok()?, digits + 1)
+    };
+
+    let after = &rest[marker_len..];
+    if after.is_empty() {
+        return Some(ListMarker {
+            indent,
+            ordered,
+            start,
+            content_offset: indent + marker_len + 1,
+        });
+    }
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    if spaces == 0 {
+        return None;
+    }
+    // Five or more spaces start indented content, not a wider marker
+    let spaces = if spaces > 4 { 1 } else { spaces };
+
+    Some(ListMarker {
+        indent,
+        ordered,
+        start,
+        content_offset: indent + marker_len + spaces,
+    })
+}
+
+/// Task state and text of a task list item ("[x] Done" -> ("DONE", "Done")).
+fn task_state(text: &str) -> Option<(&'static str, &str)> {
+    let (state, rest) = if let Some(rest) = text.strip_prefix("[ ]") {
+        ("TODO", rest)
+    } else if let Some(rest) = text
+        .strip_prefix("[x]")
+        .or_else(|| text.strip_prefix("[X]"))
+    {
+        ("DONE", rest)
+    } else {
+        return None;
+    };
+
+    if rest.is_empty() {
+        Some((state, rest))
+    } else {
+        rest.strip_prefix(' ').map(|rest| (state, rest))
+    }
+}
+
+fn is_table_start(lines: &[String], i: usize) -> bool {
+    lines[i].contains('|')
+        && lines
+            .get(i + 1)
+            .is_some_and(|next| is_table_delimiter(next))
+}
+
+fn is_table_delimiter(line: &str) -> bool {
+    let cells = split_table_row(line);
+    !cells.is_empty()
+        && cells.iter().all(|cell| {
+            let cell = cell.trim_start_matches(':').trim_end_matches(':');
+            !cell.is_empty() && cell.chars().all(|c| c == '-')
+        })
+}
+
+fn split_table_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = match trimmed.strip_suffix('|') {
+        Some(rest) if !rest.ends_with('\\') => rest,
+        _ => trimmed,
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_code = false;
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '`' => {
+                in_code = !in_code;
+                cell.push(c);
+            }
+            '|' if !in_code => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn parse_table(lines: &[String], start: usize) -> (Value, usize) {
+    let header = split_table_row(&lines[start]);
+    let columns = header.len();
+
+    let mut rows = vec![table_row(&header, "tableHeader", columns)];
+    let mut i = start + 2;
+    while i < lines.len() && !lines[i].trim().is_empty() && lines[i].contains('|') {
+        rows.push(table_row(&split_table_row(&lines[i]), "tableCell", columns));
+        i += 1;
+    }
+
+    let table = json!({
+        "type": "table",
+        "attrs": { "isNumberColumnEnabled": false, "layout": "default" },
+        "content": rows
+    });
+    (table, i)
+}
+
+fn table_row(cells: &[String], cell_type: &str, columns: usize) -> Value {
+    let content: Vec<Value> = (0..columns)
+        .map(|i| {
+            let text = cells.get(i).map(String::as_str).unwrap_or_default();
+            let lines: Vec<String> = text
+                .replace("<br />", "<br>")
+                .replace("<br/>", "<br>")
+                .split("<br>")
+                .map(str::to_string)
+                .collect();
+            json!({
+                "type": cell_type,
+                "attrs": {},
+                "content": [paragraph(parse_inline_lines(&lines))]
+            })
+        })
+        .collect();
+    json!({ "type": "tableRow", "content": content })
+}
+
+// =============================================================================
+// Inline Markdown -> ADF
+// =============================================================================
+
+/// Active inline marks while parsing.
+#[derive(Clone, Default)]
+pub(crate) struct Marks {
+    pub(crate) strong: bool,
+    pub(crate) em: bool,
+    pub(crate) strike: bool,
+    pub(crate) underline: bool,
+    pub(crate) code: bool,
+    pub(crate) link: Option<String>,
+}
+
+impl Marks {
+    fn to_json(&self) -> Vec<Value> {
+        let mut marks = Vec::new();
+        if self.code {
+            marks.push(json!({ "type": "code" }));
+        }
+        if self.strong {
+            marks.push(json!({ "type": "strong" }));
+        }
+        if self.em {
+            marks.push(json!({ "type": "em" }));
+        }
+        if self.strike {
+            marks.push(json!({ "type": "strike" }));
+        }
+        if self.underline {
+            marks.push(json!({ "type": "underline" }));
+        }
+        if let Some(href) = &self.link {
+            marks.push(json!({ "type": "link", "attrs": { "href": href } }));
+        }
+        marks
+    }
+
+    pub(crate) fn with_link(&self, href: &str) -> Marks {
+        Marks {
+            link: Some(href.to_string()),
+            ..self.clone()
+        }
+    }
+}
+
+/// Parse paragraph lines, joining them with hard breaks.
+fn parse_inline_lines(lines: &[String]) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            nodes.push(json!({ "type": "hardBreak" }));
+        }
+        // Trailing "\" is an explicit hard break in Markdown, the newline already is one
+        let line = line.trim();
+        let line = line.strip_suffix('\\').unwrap_or(line);
+        nodes.extend(parse_inline(line));
+    }
+    nodes
+}
+
+fn parse_inline(text: &str) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    parse_inline_into(text, &Marks::default(), &mut nodes);
+    nodes
+}
+
+pub(crate) fn push_text(nodes: &mut Vec<Value>, text: &str, marks: &Marks) {
+    if text.is_empty() {
+        return;
+    }
+    let marks = marks.to_json();
+
+    // Merge with the previous text node when the marks are the same
+    if let Some(last) = nodes.last_mut() {
+        let last_marks = last
+            .get("marks")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        if node_type(last) == "text" && last_marks == marks {
+            let merged = format!("{}{}", last["text"].as_str().unwrap_or_default(), text);
+            last["text"] = Value::String(merged);
+            return;
+        }
+    }
+
+    let mut node = json!({ "type": "text", "text": text });
+    if !marks.is_empty() {
+        node["marks"] = Value::Array(marks);
+    }
+    nodes.push(node);
+}
+
+fn parse_inline_into(text: &str, marks: &Marks, nodes: &mut Vec<Value>) {
+    let mut plain = String::new();
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().expect("index is a char boundary");
+
+        match c {
+            '\\' => {
+                if let Some(next) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                    plain.push(next);
+                    i += 1 + next.len_utf8();
+                    continue;
+                }
+            }
+            '`' => {
+                let ticks = rest.chars().take_while(|c| *c == '`').count();
+                let body = &rest[ticks..];
+                if let Some(end) = find_code_end(body, ticks) {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+
+                    let code = &body[..end];
+                    let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                        Some(inner) if !inner.trim().is_empty() => inner,
+                        _ => code,
+                    };
+                    let code_marks = Marks {
+                        code: true,
+                        link: marks.link.clone(),
+                        ..Marks::default()
+                    };
+                    push_text(nodes, code, &code_marks);
+                    i += ticks * 2 + end;
+                } else {
+                    plain.push_str(&rest[..ticks]);
+                    i += ticks;
+                }
+                continue;
+            }
+            '!' | '[' => {
+                let link_start = if c == '!' { 1 } else { 0 };
+                if let Some((label, href, len)) = parse_link(&rest[link_start..]) {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    let label = if label.is_empty() { href } else { label };
+                    parse_inline_into(label, &marks.with_link(href), nodes);
+                    i += link_start + len;
+                    continue;
+                }
+            }
+            '<' => {
+                if let Some(end) = rest.find('>') {
+                    let url = &rest[1..end];
+                    if is_url(url) && !url.contains(char::is_whitespace) {
+                        push_text(nodes, &plain, marks);
+                        plain.clear();
+                        push_text(nodes, url, &marks.with_link(url));
+                        i += end + 1;
+                        continue;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                let prev = text[..i].chars().last();
+                if let Some((delim_len, inner)) = parse_emphasis(rest, prev) {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    let mut inner_marks = marks.clone();
+                    match (c, delim_len) {
+                        ('~', _) => inner_marks.strike = true,
+                        (_, 2) => inner_marks.strong = true,
+                        _ => inner_marks.em = true,
+                    }
+                    parse_inline_into(inner, &inner_marks, nodes);
+                    i += inner.len() + delim_len * 2;
+                    continue;
+                }
+            }
+            'h' if marks.link.is_none() && is_url(rest) => {
+                let at_word_start = text[..i]
+                    .chars()
+                    .last()
+                    .is_none_or(|p| p.is_whitespace() || p == '(');
+                let end = url_end(rest);
+                if at_word_start && end > "https://".len() {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    let url = &rest[..end];
+                    push_text(nodes, url, &marks.with_link(url));
+                    i += end;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        plain.push(c);
+        i += c.len_utf8();
+    }
+
+    push_text(nodes, &plain, marks);
+}
+
+/// Find the closing backtick run of exactly `ticks` backticks.
+fn find_code_end(body: &str, ticks: usize) -> Option<usize> {
+    let mut i = 0;
+    while i < body.len() {
+        if body[i..].starts_with('`') {
+            let run = body[i..].chars().take_while(|c| *c == '`').count();
+            if run == ticks {
+                return Some(i);
+            }
+            i += run;
+        } else {
+            i += body[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    None
+}
+
+/// Parse `[label](url)` at the start of `text`. Returns label, URL and length.
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    if !text.starts_with('[') {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut label_end = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    label_end = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let label_end = label_end?;
+    let after = text[label_end + 1..].strip_prefix('(')?;
+
+    let mut depth = 1;
+    let mut url_end = None;
+    for (i, c) in after.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    url_end = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let url_end = url_end?;
+
+    // Drop an optional title: [label](url "title")
+    let target = after[..url_end].trim();
+    let href = target.split_whitespace().next().unwrap_or_default();
+    let href = href
+        .strip_prefix('<')
+        .and_then(|h| h.strip_suffix('>'))
+        .unwrap_or(href);
+    if href.is_empty() {
+        return None;
+    }
+
+    let len = label_end + 1 + 1 + url_end + 1;
+    Some((&text[1..label_end], href, len))
+}
+
+/// Parse an emphasis span at the start of `text`. Returns the delimiter
+/// length and the inner text.
+fn parse_emphasis(text: &str, prev: Option<char>) -> Option<(usize, &str)> {
+    let c = text.chars().next()?;
+    let double: String = [c, c].iter().collect();
+    let delim_len = if text.starts_with(&double) { 2 } else { 1 };
+    if c == '~' && delim_len == 1 {
+        return None;
+    }
+    // Intraword underscores are literal (snake_case)
+    if c == '_' && prev.is_some_and(char::is_alphanumeric) {
+        return None;
+    }
+
+    let delim = &text[..delim_len];
+    let after = &text[delim_len..];
+    if after.is_empty() || after.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let mut from = 0;
+    while let Some(pos) = after[from..].find(delim) {
+        let k = from + pos;
+        let before = after[..k].chars().last();
+        let next = after[k + delim_len..].chars().next();
+
+        let skip = k == 0
+            || before.is_none_or(char::is_whitespace)
+            || before == Some('\\')
+            // Inside a single-delimiter span, skip over double delimiters ("*a **b** c*")
+            || (delim_len == 1 && next == Some(c))
+            || (c == '_' && next.is_some_and(char::is_alphanumeric));
+        if skip {
+            from = k + if delim_len == 1 && next == Some(c) {
+                2
+            } else {
+                1
+            };
+            continue;
+        }
+
+        return Some((delim_len, &after[..k]));
+    }
+
+    None
+}
+
+pub(crate) fn is_url(text: &str) -> bool {
+    text.starts_with("https://") || text.starts_with("http://")
+}
+
+/// Length of a bare URL, without trailing punctuation.
+pub(crate) fn url_end(text: &str) -> usize {
+    let end = text
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .unwrap_or(text.len());
+    let mut url = &text[..end];
+
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '"', '\'']);
+        let trimmed = if trimmed.ends_with(')')
+            && trimmed.matches('(').count() < trimmed.matches(')').count()
+        {
+            &trimmed[..trimmed.len() - 1]
+        } else {
+            trimmed
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+
+    url.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(markdown: &str) -> String {
+        adf_to_markdown(&markdown_to_adf(markdown))
+    }
+
+    // =========================================================================
+    // Markdown -> ADF
+    // =========================================================================
+
+    #[test]
+    fn test_markdown_to_adf_simple() {
+        let adf = markdown_to_adf("Hello world");
+        assert_eq!(adf["type"], "doc");
+        assert_eq!(adf["version"], 1);
+        let content = adf["content"].as_array().unwrap();
+        assert_eq!(content.len(), 1);
+        assert_eq!(content[0]["type"], "paragraph");
+        let inline = content[0]["content"].as_array().unwrap();
+        assert_eq!(inline.len(), 1);
+        assert_eq!(inline[0]["text"], "Hello world");
+    }
+
+    #[test]
+    fn test_markdown_to_adf_multi_paragraph() {
+        let adf = markdown_to_adf("First paragraph\n\nSecond paragraph");
+        let content = adf["content"].as_array().unwrap();
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[0]["content"][0]["text"], "First paragraph");
+        assert_eq!(content[1]["content"][0]["text"], "Second paragraph");
+    }
+
+    #[test]
+    fn test_markdown_to_adf_with_line_breaks() {
+        let adf = markdown_to_adf("Line 1\nLine 2\nLine 3");
+        let content = adf["content"].as_array().unwrap();
+        assert_eq!(content.len(), 1);
+        let inline = content[0]["content"].as_array().unwrap();
+        // text, hardBreak, text, hardBreak, text = 5 nodes
+        assert_eq!(inline.len(), 5);
+        assert_eq!(inline[0]["text"], "Line 1");
+        assert_eq!(inline[1]["type"], "hardBreak");
+        assert_eq!(inline[2]["text"], "Line 2");
+        assert_eq!(inline[3]["type"], "hardBreak");
+        assert_eq!(inline[4]["text"], "Line 3");
+    }
+
+    #[test]
+    fn test_markdown_to_adf_empty() {
+        let adf = markdown_to_adf("");
+        assert_eq!(adf["type"], "doc");
+        let content = adf["content"].as_array().unwrap();
+        assert_eq!(content.len(), 1);
+        assert_eq!(content[0]["type"], "paragraph");
+        assert!(content[0]["content"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_markdown_to_adf_inline_marks() {
+        let adf =
+            markdown_to_adf("Use **bold**, *em*, ~~old~~ and `cargo test` in [docs](https://x.io)");
+        let inline = adf["content"][0]["content"].as_array().unwrap();
+
+        assert_eq!(inline[1]["text"], "bold");
+        assert_eq!(inline[1]["marks"], json!([{"type": "strong"}]));
+        assert_eq!(inline[3]["marks"], json!([{"type": "em"}]));
+        assert_eq!(inline[5]["marks"], json!([{"type": "strike"}]));
+        assert_eq!(inline[7]["text"], "cargo test");
+        assert_eq!(inline[7]["marks"], json!([{"type": "code"}]));
+        assert_eq!(inline[9]["text"], "docs");
+        assert_eq!(
+            inline[9]["marks"],
+            json!([{"type": "link", "attrs": {"href": "https://x.io"}}])
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_literal_delimiters() {
+        let adf = markdown_to_adf("snake_case_name and 2 * 3 * 4 and \\*not em\\*");
+        let inline = adf["content"][0]["content"].as_array().unwrap();
+        assert_eq!(inline.len(), 1);
+        assert_eq!(
+            inline[0]["text"],
+            "snake_case_name and 2 * 3 * 4 and *not em*"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_bare_url() {
+        let adf = markdown_to_adf("See https://example.com/a_(b).");
+        let inline = adf["content"][0]["content"].as_array().unwrap();
+        assert_eq!(inline[1]["text"], "https://example.com/a_(b)");
+        assert_eq!(
+            inline[1]["marks"][0]["attrs"]["href"],
+            "https://example.com/a_(b)"
+        );
+        assert_eq!(inline[2]["text"], ".");
+    }
+
+    #[test]
+    fn test_markdown_to_adf_code_block() {
+        let adf = markdown_to_adf("```rust\nfn main() {\n    println!(\"hi\");\n}\n```");
+        let block = &adf["content"][0];
+        assert_eq!(block["type"], "codeBlock");
+        assert_eq!(block["attrs"]["language"], "rust");
+        assert_eq!(
+            block["content"][0]["text"],
+            "fn main() {\n    println!(\"hi\");\n}"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_nested_lists() {
+        let adf = markdown_to_adf("- one\n- two\n  1. first\n  2. second\n- three");
+        let list = &adf["content"][0];
+        assert_eq!(list["type"], "bulletList");
+        let items = list["content"].as_array().unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[1]["content"][1]["type"], "orderedList");
+        assert_eq!(
+            items[1]["content"][1]["content"][1]["content"][0]["content"][0]["text"],
+            "second"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_paragraph_followed_by_list() {
+        let adf = markdown_to_adf("Steps:\n3. build\n4. test");
+        let content = adf["content"].as_array().unwrap();
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[1]["type"], "orderedList");
+        assert_eq!(content[1]["attrs"]["order"], 3);
+    }
+
+    #[test]
+    fn test_markdown_to_adf_task_list() {
+        let adf = markdown_to_adf("- [x] write tests\n- [ ] ship");
+        let list = &adf["content"][0];
+        assert_eq!(list["type"], "taskList");
+        assert_eq!(list["content"][0]["type"], "taskItem");
+        assert_eq!(list["content"][0]["attrs"]["state"], "DONE");
+        assert_eq!(list["content"][1]["attrs"]["state"], "TODO");
+        assert_eq!(list["content"][1]["content"][0]["text"], "ship");
+    }
+
+    #[test]
+    fn test_markdown_to_adf_table() {
+        let adf = markdown_to_adf("| Name | Value |\n|:-----|------:|\n| a \\| b | `x|y` |");
+        let table = &adf["content"][0];
+        assert_eq!(table["type"], "table");
+        let rows = table["content"].as_array().unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["content"][0]["type"], "tableHeader");
+        assert_eq!(rows[1]["content"][0]["type"], "tableCell");
+        assert_eq!(
+            rows[1]["content"][0]["content"][0]["content"][0]["text"],
+            "a | b"
+        );
+        assert_eq!(
+            rows[1]["content"][1]["content"][0]["content"][0]["text"],
+            "x|y"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_adf_panel() {
+        let adf = markdown_to_adf("> [!WARNING]\n> Breaking change");
+        let panel = &adf["content"][0];
+        assert_eq!(panel["type"], "panel");
+        assert_eq!(panel["attrs"]["panelType"], "warning");
+        assert_eq!(panel["content"][0]["content"][0]["text"], "Breaking change");
+    }
+
+    // =========================================================================
+    // ADF -> Markdown
+    // =========================================================================
+
+    #[test]
+    fn test_adf_to_markdown_simple() {
+        let adf = json!({
+            "version": 1,
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [{
+                    "type": "text",
+                    "text": "Hello world"
+                }]
+            }]
+        });
+        assert_eq!(adf_to_markdown(&adf), "Hello world");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_multi() {
+        let adf = json!({
+            "version": 1,
+            "type": "doc",
+            "content": [
+                {
+                    "type": "paragraph",
+                    "content": [{
+                        "type": "text",
+                        "text": "First"
+                    }]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [{
+                        "type": "text",
+                        "text": "Second"
+                    }]
+                }
+            ]
+        });
+        assert_eq!(adf_to_markdown(&adf), "First\n\nSecond");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_with_hardbreak() {
+        let adf = json!({
+            "version": 1,
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    {"type": "text", "text": "Line 1"},
+                    {"type": "hardBreak"},
+                    {"type": "text", "text": "Line 2"}
+                ]
+            }]
+        });
+        assert_eq!(adf_to_markdown(&adf), "Line 1\nLine 2");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_empty() {
+        let adf = json!({
+            "version": 1,
+            "type": "doc",
+            "content": []
+        });
+        assert_eq!(adf_to_markdown(&adf), "");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_non_adf_string() {
+        let value = Value::String("plain text".to_string());
+        assert_eq!(adf_to_markdown(&value), "plain text");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_null() {
+        assert_eq!(adf_to_markdown(&Value::Null), "");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_marks_keep_spaces_outside() {
+        let adf = json!({
+            "type": "paragraph",
+            "content": [
+                {"type": "text", "text": "Fixed "},
+                {"type": "text", "text": "in v2 ", "marks": [{"type": "strong"}, {"type": "em"}]},
+                {"type": "text", "text": "a|b", "marks": [{"type": "code"}]}
+            ]
+        });
+        assert_eq!(adf_to_markdown(&adf), "Fixed ***in v2*** `a|b`");
+    }
+
+    #[test]
+    fn test_adf_to_markdown_jira_inline_nodes() {
+        let adf = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    {"type": "mention", "attrs": {"id": "5b10ac", "text": "@John Doe"}},
+                    {"type": "text", "text": " due "},
+                    {"type": "date", "attrs": {"timestamp": "1705276800000"}},
+                    {"type": "text", "text": " "},
+                    {"type": "status", "attrs": {"text": "IN REVIEW", "color": "blue"}},
+                    {"type": "text", "text": " "},
+                    {"type": "emoji", "attrs": {"shortName": ":rocket:", "text": "🚀"}},
+                    {"type": "text", "text": " "},
+                    {"type": "inlineCard", "attrs": {"url": "https://jira.example.com/browse/PROJ-2"}}
+                ]
+            }]
+        });
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "@John Doe due 2024-01-15 [IN REVIEW] 🚀 https://jira.example.com/browse/PROJ-2"
+        );
+    }
+
+    #[test]
+    fn test_adf_to_markdown_blocks() {
+        let adf = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {"type": "heading", "attrs": {"level": 2}, "content": [{"type": "text", "text": "Steps"}]},
+                {"type": "orderedList", "attrs": {"order": 1}, "content": [
+                    {"type": "listItem", "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "Open the app"}]},
+                        {"type": "bulletList", "content": [
+                            {"type": "listItem", "content": [
+                                {"type": "paragraph", "content": [{"type": "text", "text": "on mobile"}]}
+                            ]}
+                        ]}
+                    ]},
+                    {"type": "listItem", "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "Log in"}]}
+                    ]}
+                ]},
+                {"type": "panel", "attrs": {"panelType": "error"}, "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "Crashes"}]}
+                ]},
+                {"type": "mediaSingle", "content": [
+                    {"type": "media", "attrs": {"id": "abc", "type": "file", "alt": "screenshot.png"}}
+                ]},
+                {"type": "table", "content": [
+                    {"type": "tableRow", "content": [
+                        {"type": "tableHeader", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "OS"}]}]},
+                        {"type": "tableHeader", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Result"}]}]}
+                    ]},
+                    {"type": "tableRow", "content": [
+                        {"type": "tableCell", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "iOS"}]}]},
+                        {"type": "tableCell", "content": [{"type": "paragraph", "content": [
+                            {"type": "text", "text": "fails"},
+                            {"type": "hardBreak"},
+                            {"type": "text", "text": "always"}
+                        ]}]}
+                    ]}
+                ]}
+            ]
+        });
+
+        assert_eq!(
+            adf_to_markdown(&adf),
+            "## Steps\n\n\
+             1. Open the app\n   - on mobile\n2. Log in\n\n\
+             > [!ERROR]\n> Crashes\n\n\
+             [attachment: screenshot.png]\n\n\
+             | OS | Result |\n| --- | --- |\n| iOS | fails<br>always |"
+        );
+    }
+
+    #[test]
+    fn test_adf_to_markdown_code_block_with_fence_inside() {
+        let adf = json!({
+            "type": "codeBlock",
+            "attrs": {"language": "markdown"},
+            "content": [{"type": "text", "text": "```sh\nls\n```"}]
+        });
+        assert_eq!(adf_to_markdown(&adf), "````markdown\n```sh\nls\n```\n````");
+    }
+
+    // =========================================================================
+    // Round trips
+    // =========================================================================
+
+    #[test]
+    fn test_round_trip_markdown() {
+        let markdown = "# Login fails on Safari\n\n\
+             Steps to **reproduce** with *any* account, see [logs](https://logs.example.com).\n\
+             Happens since `v2.3`.\n\n\
+             - open the app\n- log in\n  - with SSO\n  - with password\n\n\
+             3. third\n4. fourth\n\n\
+             - [x] reproduced\n- [ ] fixed\n  - [ ] regression test\n\n\
+             ```rust\nfn main() {}\n```\n\n\
+             > Quoted from support\n> second line\n\n\
+             > [!WARNING]\n> Affects production\n\n\
+             ---\n\n\
+             | Browser | Result |\n| --- | --- |\n| Safari | ~~ok~~ fails |\n\n\
+             Docs: https://example.com/docs";
+
+        assert_eq!(round_trip(markdown), markdown);
+    }
+
+    #[test]
+    fn test_round_trip_plain_text() {
+        for text in [
+            "Hello world",
+            "First paragraph\n\nSecond paragraph",
+            "Line 1\nLine 2",
+            "snake_case and 2 * 3",
+        ] {
+            assert_eq!(round_trip(text), text);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_adf() {
+        let adf = json!({
+            "version": 1,
+            "type": "doc",
+            "content": [
+                {"type": "heading", "attrs": {"level": 3}, "content": [{"type": "text", "text": "Notes"}]},
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "Call "},
+                    {"type": "text", "text": "retry()", "marks": [{"type": "code"}]},
+                    {"type": "hardBreak"},
+                    {"type": "text", "text": "then", "marks": [{"type": "strong"}]},
+                    {"type": "text", "text": " check "},
+                    {"type": "text", "text": "status", "marks": [{"type": "link", "attrs": {"href": "https://status.example.com"}}]}
+                ]},
+                {"type": "bulletList", "content": [
+                    {"type": "listItem", "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "one"}]}
+                    ]}
+                ]},
+                {"type": "codeBlock", "attrs": {"language": "sh"}, "content": [{"type": "text", "text": "make test"}]},
+                {"type": "panel", "attrs": {"panelType": "info"}, "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "FYI"}]}
+                ]},
+                {"type": "rule"}
+            ]
+        });
+
+        assert_eq!(markdown_to_adf(&adf_to_markdown(&adf)), adf);
+    }
+}
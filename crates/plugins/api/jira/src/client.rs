@@ -3,28 +3,77 @@
 //! Supports both Jira Cloud (API v3) and Jira Self-Hosted/Data Center (API v2).
 //! Flavor is auto-detected from the URL: `*.atlassian.net` → Cloud, otherwise → SelfHosted.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use devboy_core::attachments::{is_same_origin, multipart_file};
+use devboy_core::catalog;
+use devboy_core::custom_fields::{
+    as_list, as_number, as_text, date_to_unix_ms, find_custom_field, invalid_value,
+};
+use devboy_core::keys::{url_path_id, KeyPattern};
+use devboy_core::members::{find_member, is_me, MemberDirectory};
+use devboy_core::time::{now_unix, parse_iso8601, unix_to_iso8601};
 use devboy_core::{
-    Comment, CreateCommentInput, CreateIssueInput, Discussion, Error, FileDiff, Issue, IssueFilter,
-    IssueProvider, MergeRequest, MergeRequestProvider, MrFilter, Provider, Result,
-    UpdateIssueInput, User,
+    AgileProvider, Attachment, Board, Comment, CreateCommentInput, CreateIssueInput, CustomField,
+    Discussion, Error, FileDiff, Issue, IssueFilter, IssueLink, IssueProvider, IssueRef,
+    IssueStatus, IssueTransition, Label, MergeRequest, MergeRequestProvider, Milestone, MrFilter,
+    PipelineProvider, Provider, RepositoryProvider, Result, Sprint, TimeEntry,
+    TimeTrackingProvider, TransitionIssueInput, UpdateIssueInput, User,
 };
+use futures_util::stream::{self, StreamExt};
+use serde_json::Value;
 use tracing::{debug, warn};
 
+use crate::adf::{adf_to_markdown, markdown_to_adf};
 use crate::types::{
-    AddCommentPayload, CreateIssueFields, CreateIssuePayload, CreateIssueResponse, IssueType,
-    JiraCloudSearchResponse, JiraComment, JiraCommentsResponse, JiraIssue, JiraIssueTypeStatuses,
-    JiraPriority, JiraProjectStatus, JiraSearchResponse, JiraStatus, JiraTransition,
-    JiraTransitionsResponse, JiraUser, PriorityName, ProjectKey, TransitionId, TransitionPayload,
-    UpdateIssueFields, UpdateIssuePayload,
+    AddCommentOperation, AddCommentPayload, AddWorklogPayload, CreateIssueFields,
+    CreateIssueLinkRequest, CreateIssuePayload, CreateIssueResponse, IssueKey, IssueType,
+    JiraAgilePage, JiraAttachment, JiraBoard, JiraCloudSearchResponse, JiraComment,
+    JiraCommentsResponse, JiraCreateMetaField, JiraCreateMetaFields, JiraCreateMetaIssueTypes,
+    JiraField, JiraFieldSchema, JiraIssue, JiraIssueLink, JiraIssueLinkType,
+    JiraIssueLinkTypesResponse, JiraIssueRef, JiraIssueTypeStatuses, JiraPriority,
+    JiraProjectStatus, JiraSearchResponse, JiraSprint, JiraStatus, JiraTransition,
+    JiraTransitionField, JiraTransitionsResponse, JiraUser, JiraVersion, JiraVersionIssueCounts,
+    JiraVersionUnresolvedCount, JiraWorklog, JiraWorklogsResponse, LinkTypeName, MoveIssuesPayload,
+    PriorityName, ProjectKey, TimeTrackingFields, TransitionId, TransitionPayload,
+    TransitionUpdate, UpdateIssueFields, UpdateIssuePayload,
 };
+use crate::wiki::{markdown_to_wiki, wiki_to_markdown};
+
+/// Page size for paginated Agile API requests.
+const AGILE_PAGE_SIZE: usize = 50;
+
+/// Page size when fetching the issues of a sprint.
+const SPRINT_ISSUES_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of issues the Agile API moves in one request.
+const MOVE_ISSUES_BATCH_SIZE: usize = 50;
+
+/// Issue type of issues created through the provider.
+const DEFAULT_ISSUE_TYPE: &str = "Task";
+
+/// Page size when fetching the create-screen fields of an issue type.
+const CREATE_META_PAGE_SIZE: u32 = 200;
+
+/// Maximum number of issues fetched as children of an epic.
+const EPIC_CHILDREN_LIMIT: u32 = 100;
+
+/// Self-hosted field linking an issue to its epic.
+const EPIC_LINK_FIELD: &str = "Epic Link";
+
+/// Self-hosted field required when creating an epic.
+const EPIC_NAME_FIELD: &str = "Epic Name";
+
+/// Number of versions whose issues are counted at the same time.
+const COUNT_CONCURRENCY: usize = 4;
 
 /// Jira deployment flavor.
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum JiraFlavor {
     /// Jira Cloud — API v3, ADF format, accountId-based users
     Cloud,
-    /// Jira Self-Hosted / Data Center — API v2, plain text, username-based users
+    /// Jira Self-Hosted / Data Center — API v2, wiki markup, username-based users
     SelfHosted,
 }
 
@@ -35,6 +84,7 @@ pub struct JiraClient {
     email: String,
     token: String,
     flavor: JiraFlavor,
+    members: MemberDirectory,
     client: reqwest::Client,
 }
 
@@ -55,6 +105,7 @@ impl JiraClient {
             email: email.into(),
             token: token.into(),
             flavor,
+            members: MemberDirectory::new(),
             client: reqwest::Client::builder()
                 .user_agent("devboy-tools")
                 .build()
@@ -81,6 +132,7 @@ impl JiraClient {
             } else {
                 JiraFlavor::SelfHosted
             },
+            members: MemberDirectory::new(),
             client: reqwest::Client::builder()
                 .user_agent("devboy-tools")
                 .build()
@@ -127,6 +179,68 @@ impl JiraClient {
         self.handle_response(response).await
     }
 
+    /// Users assignable in the project that match `query`, cached per query.
+    async fn search_members(&self, query: &str) -> Result<Vec<User>> {
+        self.members
+            .get_or_load(query, || async {
+                let url = format!("{}/user/assignable/search", self.base_url);
+                // Cloud searches by `query`, self-hosted by `username` (which also matches names and emails)
+                let query_param = match self.flavor {
+                    JiraFlavor::Cloud => "query",
+                    JiraFlavor::SelfHosted => "username",
+                };
+                let params = [
+                    ("project", self.project_key.as_str()),
+                    (query_param, query),
+                    ("maxResults", "50"),
+                ];
+                let response = self
+                    .request(reqwest::Method::GET, &url)
+                    .query(&params)
+                    .send()
+                    .await
+                    .map_err(|e| Error::Http(e.to_string()))?;
+                let users: Vec<JiraUser> = self.handle_response(response).await?;
+                Ok(users.iter().filter_map(|u| map_user(Some(u))).collect())
+            })
+            .await
+    }
+
+    /// Resolve a username, email or display name to the user reference Jira
+    /// expects: an accountId on Cloud, a username on self-hosted.
+    async fn resolve_user(&self, user: &str) -> Result<String> {
+        if is_me(user) {
+            let me = self
+                .members
+                .current_user(|| self.get_current_user())
+                .await?;
+            return Ok(match self.flavor {
+                JiraFlavor::Cloud => me.id,
+                JiraFlavor::SelfHosted => me.username,
+            });
+        }
+        let user = user.trim().trim_start_matches('@');
+        if self.flavor == JiraFlavor::Cloud && is_account_id(user) {
+            return Ok(user.to_string());
+        }
+        // Self-hosted takes usernames as they are, so a failed lookup
+        // (e.g. a user who cannot be assigned anymore) keeps the given name
+        let found = match self.search_members(user).await {
+            Ok(members) => find_member(&members, user).cloned(),
+            Err(e) => Err(e),
+        };
+        match (found, self.flavor) {
+            (Ok(member), JiraFlavor::Cloud) => Ok(member.id),
+            (Ok(member), JiraFlavor::SelfHosted) => Ok(member.username),
+            (Err(Error::InvalidData(message)), _) => Err(Error::InvalidData(message)),
+            (Err(e), JiraFlavor::SelfHosted) => {
+                debug!(user = user, error = %e, "Jira user lookup failed, using the username");
+                Ok(user.to_string())
+            }
+            (Err(e), JiraFlavor::Cloud) => Err(e),
+        }
+    }
+
     /// Make an authenticated POST request.
     async fn post<T: serde::de::DeserializeOwned, B: serde::Serialize>(
         &self,
@@ -171,6 +285,171 @@ impl JiraClient {
         Ok(())
     }
 
+    /// Make an authenticated POST request that returns 204 No Content.
+    async fn post_no_content<B: serde::Serialize>(&self, url: &str, body: &B) -> Result<()> {
+        debug!(url = url, "Jira POST request");
+
+        let response = self
+            .request(reqwest::Method::POST, url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "Jira API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        Ok(())
+    }
+
+    /// Make an authenticated GET request returning the raw response body.
+    async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        debug!(url = url, "Jira GET bytes request");
+
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "Jira API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::InvalidData(format!("Failed to read response: {}", e)))
+    }
+
+    /// Make an authenticated `multipart/form-data` POST request uploading one file.
+    async fn post_file<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<T> {
+        debug!(url = url, file_name = file_name, "Jira POST file request");
+
+        let (content_type, body) = multipart_file("file", file_name, content);
+        let mut request = self
+            .request(reqwest::Method::POST, url)
+            // Uploads without this header are rejected as XSRF attempts
+            .header("X-Atlassian-Token", "no-check")
+            .body(body)
+            .build()
+            .map_err(|e| Error::Http(e.to_string()))?;
+        // Replace the JSON content type set by `request`
+        let content_type = reqwest::header::HeaderValue::from_str(&content_type)
+            .map_err(|e| Error::InvalidData(e.to_string()))?;
+        request
+            .headers_mut()
+            .insert(reqwest::header::CONTENT_TYPE, content_type);
+
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        self.handle_response(response).await
+    }
+
+    /// Make an authenticated DELETE request (Jira DELETE returns 204 No Content).
+    async fn delete(&self, url: &str) -> Result<()> {
+        debug!(url = url, "Jira DELETE request");
+
+        let response = self
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await
+            .map_err(|e| Error::Http(e.to_string()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let message = response.text().await.unwrap_or_default();
+            warn!(
+                status = status_code,
+                message = message,
+                "Jira API error response"
+            );
+            return Err(Error::from_status(status_code, message));
+        }
+
+        Ok(())
+    }
+
+    /// Build a URL of the Agile API (`/rest/agile/1.0`), which is served next
+    /// to the core REST API for both flavors.
+    fn agile_url(&self, path: &str) -> String {
+        format!(
+            "{}/rest/agile/1.0{}",
+            instance_url_from_base(&self.base_url),
+            path
+        )
+    }
+
+    /// Fetch all pages of a paginated endpoint (the Agile API, `/label`).
+    async fn get_agile_pages<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<Vec<T>> {
+        let separator = if url.contains('?') { '&' } else { '?' };
+        let mut values = Vec::new();
+
+        loop {
+            let page_url = format!(
+                "{}{}startAt={}&maxResults={}",
+                url,
+                separator,
+                values.len(),
+                AGILE_PAGE_SIZE
+            );
+            let page: JiraAgilePage<T> = self.get(&page_url).await?;
+            let count = page.values.len();
+            values.extend(page.values);
+
+            if page.is_last || count == 0 {
+                break;
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Move issues with an Agile API endpoint, in batches the API accepts.
+    async fn move_issues(&self, url: &str, issue_keys: &[String]) -> Result<()> {
+        let keys: Vec<String> = issue_keys
+            .iter()
+            .map(|k| parse_jira_key(k).to_string())
+            .collect();
+
+        for batch in keys.chunks(MOVE_ISSUES_BATCH_SIZE) {
+            let payload = MoveIssuesPayload {
+                issues: batch.to_vec(),
+            };
+            self.post_no_content(url, &payload).await?;
+        }
+
+        Ok(())
+    }
+
     /// Handle response and map errors.
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
@@ -203,7 +482,7 @@ impl JiraClient {
     /// 3. Resolve via project statuses: fetch `GET /project/{key}/statuses`,
     ///    find status matching `target_status` by name or category alias,
     ///    then match against available transitions.
-    async fn transition_issue(&self, key: &str, target_status: &str) -> Result<()> {
+    async fn transition_to_status(&self, key: &str, target_status: &str) -> Result<()> {
         let url = format!("{}/issue/{}/transitions", self.base_url, key);
         let transitions: JiraTransitionsResponse = self.get(&url).await?;
 
@@ -227,54 +506,40 @@ impl JiraClient {
             self.find_transition_by_project_statuses(target_status, &transitions)
                 .await?
                 .ok_or_else(|| {
-                    let available: Vec<String> = transitions
-                        .transitions
-                        .iter()
-                        .map(|t| {
-                            let cat =
-                                t.to.status_category
-                                    .as_ref()
-                                    .map(|sc| sc.key.as_str())
-                                    .unwrap_or("?");
-                            format!("{} [{}]", t.to.name, cat)
-                        })
-                        .collect();
                     Error::InvalidData(format!(
-                        "No transition to status '{}' found for issue {}. Available: {:?}",
-                        target_status, key, available
+                        "No transition to status '{}' found for issue {}. Available: {}",
+                        target_status,
+                        key,
+                        describe_transitions(&transitions.transitions)
                     ))
                 })?
         };
 
-        let payload = TransitionPayload {
-            transition: TransitionId {
-                id: transition.id.clone(),
-            },
-        };
-
-        let post_url = format!("{}/issue/{}/transitions", self.base_url, key);
         debug!(
             issue = key,
             transition_id = transition.id,
             target = target_status,
             "Transitioning issue"
         );
+        let payload = TransitionPayload {
+            transition: TransitionId {
+                id: transition.id.clone(),
+            },
+            fields: serde_json::Map::new(),
+            update: None,
+        };
+        let url = format!("{}/issue/{}/transitions", self.base_url, key);
+        self.post_no_content(&url, &payload).await
+    }
 
-        let response = self
-            .request(reqwest::Method::POST, &post_url)
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|e| Error::Http(e.to_string()))?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let status_code = status.as_u16();
-            let message = response.text().await.unwrap_or_default();
-            return Err(Error::from_status(status_code, message));
-        }
-
-        Ok(())
+    /// Get the transitions of an issue with their screen fields.
+    async fn get_transitions_with_fields(&self, key: &str) -> Result<Vec<JiraTransition>> {
+        let url = format!(
+            "{}/issue/{}/transitions?expand=transitions.fields",
+            self.base_url, key
+        );
+        let response: JiraTransitionsResponse = self.get(&url).await?;
+        Ok(response.transitions)
     }
 
     /// Fetch project statuses and find a matching transition.
@@ -390,6 +655,110 @@ impl JiraClient {
 
         Ok(statuses)
     }
+
+    /// Count the open and closed issues with a version as fix version.
+    async fn count_version_issues(&self, version_id: &str) -> Result<(u32, u32)> {
+        let url = format!(
+            "{}/version/{}/relatedIssueCounts",
+            self.base_url, version_id
+        );
+        let counts: JiraVersionIssueCounts = self.get(&url).await?;
+        let url = format!(
+            "{}/version/{}/unresolvedIssueCount",
+            self.base_url, version_id
+        );
+        let unresolved: JiraVersionUnresolvedCount = self.get(&url).await?;
+        Ok((
+            unresolved.issues_unresolved_count,
+            counts
+                .issues_fixed_count
+                .saturating_sub(unresolved.issues_unresolved_count),
+        ))
+    }
+
+    /// Fetch the issues of an epic: by `parent` on Cloud, by the "Epic Link"
+    /// field on self-hosted.
+    async fn get_epic_children(&self, epic_key: &str) -> Result<Vec<IssueRef>> {
+        let condition = match self.flavor {
+            JiraFlavor::Cloud => format!("parent = {}", epic_key),
+            JiraFlavor::SelfHosted => format!("\"{}\" = {}", EPIC_LINK_FIELD, epic_key),
+        };
+        let issues = self
+            .get_issues(IssueFilter {
+                query: Some(format!("{} ORDER BY created ASC", condition)),
+                limit: Some(EPIC_CHILDREN_LIMIT),
+                ..Default::default()
+            })
+            .await?;
+        Ok(issues.iter().map(IssueRef::from).collect())
+    }
+
+    /// Fetch the metadata of all custom fields (`GET /field`).
+    async fn get_jira_custom_fields(&self) -> Result<Vec<JiraField>> {
+        let url = format!("{}/field", self.base_url);
+        let fields: Vec<JiraField> = self.get(&url).await?;
+        Ok(fields.into_iter().filter(|f| f.custom).collect())
+    }
+
+    /// Fetch the create-screen fields of the issue type used for new issues,
+    /// keyed by field ID.
+    async fn get_create_meta(&self) -> Result<HashMap<String, JiraCreateMetaField>> {
+        let url = format!(
+            "{}/issue/createmeta/{}/issuetypes",
+            self.base_url, self.project_key
+        );
+        let issue_types: JiraCreateMetaIssueTypes = self.get(&url).await?;
+        let Some(issue_type) = issue_types
+            .issue_types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(DEFAULT_ISSUE_TYPE))
+            .or_else(|| issue_types.issue_types.first())
+        else {
+            return Ok(HashMap::new());
+        };
+
+        let url = format!(
+            "{}/issue/createmeta/{}/issuetypes/{}?maxResults={}",
+            self.base_url, self.project_key, issue_type.id, CREATE_META_PAGE_SIZE
+        );
+        let meta: JiraCreateMetaFields = self.get(&url).await?;
+        Ok(meta
+            .fields
+            .into_iter()
+            .map(|f| (f.field_id.clone(), f))
+            .collect())
+    }
+
+    /// Convert custom field values keyed by name or ID to Jira field values
+    /// keyed by field ID.
+    async fn resolve_custom_fields(
+        &self,
+        values: &serde_json::Map<String, Value>,
+    ) -> Result<serde_json::Map<String, Value>> {
+        if values.is_empty() {
+            return Ok(serde_json::Map::new());
+        }
+
+        let jira_fields = self.get_jira_custom_fields().await?;
+        let fields: Vec<CustomField> = jira_fields
+            .iter()
+            .map(|f| map_custom_field(f, None))
+            .collect();
+
+        let mut resolved = serde_json::Map::new();
+        for (key, value) in values {
+            let field = find_custom_field(&fields, key)?;
+            let schema = jira_fields
+                .iter()
+                .find(|f| f.id == field.id)
+                .and_then(|f| f.schema.as_ref());
+            resolved.insert(
+                field.id.clone(),
+                custom_field_to_jira(field, schema, value, self.flavor)?,
+            );
+        }
+        Ok(resolved)
+    }
 }
 
 // =============================================================================
@@ -446,152 +815,38 @@ fn base64_encode(input: &str) -> String {
     result
 }
 
-// =============================================================================
-// ADF (Atlassian Document Format) converters
-// =============================================================================
-
-/// Convert plain text to ADF document (for Jira Cloud API v3).
-///
-/// Splits on `\n\n` for paragraphs, uses `hardBreak` for single `\n`.
-fn text_to_adf(text: &str) -> serde_json::Value {
-    if text.is_empty() {
-        return serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": [{
-                "type": "paragraph",
-                "content": []
-            }]
-        });
+/// Read rich text as Markdown: ADF on Cloud, wiki markup on self-hosted.
+fn read_rich_text(value: &serde_json::Value, flavor: JiraFlavor) -> String {
+    match (value, flavor) {
+        (serde_json::Value::String(s), JiraFlavor::SelfHosted) => wiki_to_markdown(s),
+        _ => adf_to_markdown(value),
     }
-
-    let paragraphs: Vec<&str> = text.split("\n\n").collect();
-    let content: Vec<serde_json::Value> = paragraphs
-        .iter()
-        .map(|para| {
-            let lines: Vec<&str> = para.split('\n').collect();
-            let mut inline_content: Vec<serde_json::Value> = Vec::new();
-
-            for (i, line) in lines.iter().enumerate() {
-                if i > 0 {
-                    inline_content.push(serde_json::json!({ "type": "hardBreak" }));
-                }
-                if !line.is_empty() {
-                    inline_content.push(serde_json::json!({
-                        "type": "text",
-                        "text": *line
-                    }));
-                }
-            }
-
-            serde_json::json!({
-                "type": "paragraph",
-                "content": inline_content
-            })
-        })
-        .collect();
-
-    serde_json::json!({
-        "version": 1,
-        "type": "doc",
-        "content": content
-    })
 }
 
-/// Extract plain text from an ADF document (for Jira Cloud API v3 responses).
-///
-/// Recursively walks the ADF tree extracting text nodes.
-/// Falls back to returning the value as a string if it's not an ADF document.
-fn adf_to_text(value: &serde_json::Value) -> String {
-    match value {
-        serde_json::Value::String(s) => s.clone(),
-        serde_json::Value::Object(obj) => {
-            let doc_type = obj.get("type").and_then(|t| t.as_str());
-
-            // If it's a text node, return the text
-            if doc_type == Some("text") {
-                return obj
-                    .get("text")
-                    .and_then(|t| t.as_str())
-                    .unwrap_or("")
-                    .to_string();
-            }
-
-            // If it's a hardBreak, return newline
-            if doc_type == Some("hardBreak") {
-                return "\n".to_string();
-            }
-
-            // Recurse into content array
-            if let Some(content) = obj.get("content").and_then(|c| c.as_array()) {
-                let texts: Vec<String> = content.iter().map(adf_to_text).collect();
-                let joined = texts.join("");
-
-                // Add paragraph separation for top-level paragraphs
-                if doc_type == Some("paragraph") {
-                    return joined;
-                }
-                if doc_type == Some("doc") {
-                    // Join paragraphs with double newline
-                    let para_texts: Vec<String> = content
-                        .iter()
-                        .map(adf_to_text)
-                        .filter(|s| !s.is_empty())
-                        .collect();
-                    return para_texts.join("\n\n");
-                }
-
-                return joined;
-            }
-
-            String::new()
-        }
-        serde_json::Value::Null => String::new(),
-        other => other.to_string(),
+/// Write Markdown in the flavor's rich text format: ADF on Cloud, wiki markup on self-hosted.
+fn write_rich_text(markdown: &str, flavor: JiraFlavor) -> serde_json::Value {
+    match flavor {
+        JiraFlavor::Cloud => markdown_to_adf(markdown),
+        JiraFlavor::SelfHosted => serde_json::Value::String(markdown_to_wiki(markdown)),
     }
 }
 
-/// Read description from a Jira issue, handling both ADF and plain text.
+/// Read description from a Jira issue as Markdown.
 fn read_description(value: &Option<serde_json::Value>, flavor: JiraFlavor) -> Option<String> {
-    let value = value.as_ref()?;
-    match value {
-        serde_json::Value::Null => None,
-        serde_json::Value::String(s) => {
-            if s.is_empty() {
-                None
-            } else {
-                Some(s.clone())
-            }
-        }
-        _ => {
-            if flavor == JiraFlavor::Cloud {
-                let text = adf_to_text(value);
-                if text.is_empty() {
-                    None
-                } else {
-                    Some(text)
-                }
-            } else {
-                // Self-hosted v2 shouldn't return ADF, but handle gracefully
-                Some(value.to_string())
-            }
-        }
+    let text = read_rich_text(value.as_ref()?, flavor);
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
     }
 }
 
-/// Read comment body from a Jira comment, handling both ADF and plain text.
+/// Read comment body from a Jira comment as Markdown.
 fn read_comment_body(value: &Option<serde_json::Value>, flavor: JiraFlavor) -> String {
-    match value {
-        Some(serde_json::Value::String(s)) => s.clone(),
-        Some(serde_json::Value::Null) | None => String::new(),
-        Some(v) => {
-            if flavor == JiraFlavor::Cloud {
-                adf_to_text(v)
-            } else {
-                v.to_string()
-            }
-        }
-    }
+    value
+        .as_ref()
+        .map(|v| read_rich_text(v, flavor))
+        .unwrap_or_default()
 }
 
 // =============================================================================
@@ -637,34 +892,544 @@ fn map_state(status: Option<&JiraStatus>) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
-/// Parse issue key like "jira#WEB-1" to get the raw Jira key "WEB-1".
+/// Parse issue key like "jira#WEB-1", or an issue URL like
+/// `https://company.atlassian.net/browse/WEB-1`, to get the raw Jira key "WEB-1".
 /// If the key doesn't have a "jira#" prefix, returns it as-is (for internal calls).
 fn parse_jira_key(key: &str) -> &str {
-    key.strip_prefix("jira#").unwrap_or(key)
+    url_path_id(key, "/browse/").unwrap_or_else(|| key.strip_prefix("jira#").unwrap_or(key))
 }
 
-fn map_issue(issue: &JiraIssue, flavor: JiraFlavor, instance_url: &str) -> Issue {
-    Issue {
-        key: format!("jira#{}", issue.key),
-        title: issue.fields.summary.clone().unwrap_or_default(),
-        description: read_description(&issue.fields.description, flavor),
-        state: map_state(issue.fields.status.as_ref()),
-        source: "jira".to_string(),
-        priority: map_priority(issue.fields.priority.as_ref()),
-        labels: issue.fields.labels.clone(),
-        author: map_user(issue.fields.reporter.as_ref()),
-        assignees: issue
-            .fields
-            .assignee
-            .as_ref()
-            .map(|a| vec![map_user(Some(a)).unwrap()])
-            .unwrap_or_default(),
+/// Whether a Jira Cloud user reference is already an accountId, e.g.
+/// `5b10ac8d82e05b22cc7d4ef5` or `557058:f58131cb-b67d-43c7-b30d-6b58d40bd077`.
+fn is_account_id(user: &str) -> bool {
+    (user.contains(':') && !user.contains(' '))
+        || (user.len() == 24 && user.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Map a Jira user reference for the flavor: `accountId` on Cloud, `name` on self-hosted.
+fn user_ref(username: &str, flavor: JiraFlavor) -> Value {
+    match flavor {
+        JiraFlavor::Cloud => serde_json::json!({ "accountId": username }),
+        JiraFlavor::SelfHosted => serde_json::json!({ "name": username }),
+    }
+}
+
+/// Unified value type of a Jira field schema.
+fn custom_field_type(schema: Option<&JiraFieldSchema>) -> &'static str {
+    let Some(schema) = schema else {
+        return "other";
+    };
+    match (schema.field_type.as_str(), schema.items.as_deref()) {
+        ("number", _) => "number",
+        ("string", _) => "text",
+        ("date", _) => "date",
+        ("datetime", _) => "datetime",
+        ("option", _) | ("option-with-child", _) => "option",
+        ("user", _) => "user",
+        ("array", Some("option")) => "multi_option",
+        ("array", Some("user")) => "multi_user",
+        ("array", Some("string")) => "labels",
+        _ => "other",
+    }
+}
+
+fn map_custom_field(field: &JiraField, meta: Option<&JiraCreateMetaField>) -> CustomField {
+    CustomField {
+        id: field.id.clone(),
+        name: field.name.clone(),
+        field_type: custom_field_type(field.schema.as_ref()).to_string(),
+        options: meta
+            .map(|m| {
+                m.allowed_values
+                    .iter()
+                    .filter_map(|v| v.get("value").or_else(|| v.get("name")))
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        required: meta.is_some_and(|m| m.required),
+    }
+}
+
+/// Convert a custom field value to the shape Jira expects for the field type.
+fn custom_field_to_jira(
+    field: &CustomField,
+    schema: Option<&JiraFieldSchema>,
+    value: &Value,
+    flavor: JiraFlavor,
+) -> Result<Value> {
+    if value.is_null() {
+        return Ok(Value::Null);
+    }
+
+    match field.field_type.as_str() {
+        "number" => as_number(value).ok_or_else(|| invalid_value(field, "a number", value)),
+        "text" => {
+            let text = as_text(value).ok_or_else(|| invalid_value(field, "text", value))?;
+            let is_textarea = schema
+                .and_then(|s| s.custom.as_deref())
+                .is_some_and(|c| c.ends_with(":textarea"));
+            if is_textarea {
+                Ok(write_rich_text(&text, flavor))
+            } else {
+                Ok(Value::String(text))
+            }
+        }
+        "date" => as_text(value)
+            .filter(|d| date_to_unix_ms(d).is_some())
+            .map(Value::String)
+            .ok_or_else(|| invalid_value(field, "a date (YYYY-MM-DD)", value)),
+        "datetime" => as_text(value)
+            .map(Value::String)
+            .ok_or_else(|| invalid_value(field, "a date-time", value)),
+        "option" => as_text(value)
+            .map(|v| serde_json::json!({ "value": v }))
+            .ok_or_else(|| invalid_value(field, "an option", value)),
+        "multi_option" => as_list(value)
+            .map(|values| {
+                values
+                    .iter()
+                    .map(|v| serde_json::json!({ "value": v }))
+                    .collect()
+            })
+            .ok_or_else(|| invalid_value(field, "a list of options", value)),
+        "user" => as_text(value)
+            .map(|u| user_ref(&u, flavor))
+            .ok_or_else(|| invalid_value(field, "a user", value)),
+        "multi_user" => as_list(value)
+            .map(|users| users.iter().map(|u| user_ref(u, flavor)).collect())
+            .ok_or_else(|| invalid_value(field, "a list of users", value)),
+        "labels" => as_list(value)
+            .map(|labels| labels.into_iter().map(Value::String).collect())
+            .ok_or_else(|| invalid_value(field, "a list of labels", value)),
+        _ => Ok(value.clone()),
+    }
+}
+
+/// Unified category of a Jira status category key.
+fn status_category(key: Option<&str>) -> &'static str {
+    match key {
+        Some("indeterminate") => "in_progress",
+        Some("done") => "done",
+        _ => "todo",
+    }
+}
+
+/// Describe transitions for errors: "Start Progress → In Progress (id 21), ...".
+fn describe_transitions(transitions: &[JiraTransition]) -> String {
+    transitions
+        .iter()
+        .map(|t| format!("{} → {} (id {})", t.name, t.to.name, t.id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Find a transition by ID, name or target status (case-insensitive).
+fn find_transition<'a>(
+    transitions: &'a [JiraTransition],
+    transition: &str,
+) -> Result<&'a JiraTransition> {
+    transitions
+        .iter()
+        .find(|t| t.id == transition)
+        .or_else(|| {
+            transitions
+                .iter()
+                .find(|t| t.name.eq_ignore_ascii_case(transition))
+        })
+        .or_else(|| {
+            transitions
+                .iter()
+                .find(|t| t.to.name.eq_ignore_ascii_case(transition))
+        })
+        .ok_or_else(|| {
+            let names: Vec<&str> = transitions.iter().map(|t| t.name.as_str()).collect();
+            let hint = catalog::suggest(transition, &names)
+                .map(|s| format!(", did you mean '{}'?", s))
+                .unwrap_or_else(|| ".".to_string());
+            Error::InvalidData(format!(
+                "Unknown transition '{}'{} Available: {}",
+                transition,
+                hint,
+                describe_transitions(transitions)
+            ))
+        })
+}
+
+/// Display name of an allowed value of a choice field.
+fn allowed_value_name(value: &Value) -> Option<&str> {
+    value
+        .get("value")
+        .or_else(|| value.get("name"))
+        .and_then(Value::as_str)
+}
+
+fn map_transition_field(id: &str, meta: &JiraTransitionField) -> CustomField {
+    let is_array = meta
+        .schema
+        .as_ref()
+        .is_some_and(|s| s.field_type == "array");
+    let field_type = match custom_field_type(meta.schema.as_ref()) {
+        "other" if !meta.allowed_values.is_empty() && is_array => "multi_option",
+        "other" if !meta.allowed_values.is_empty() => "option",
+        field_type => field_type,
+    };
+    CustomField {
+        id: id.to_string(),
+        name: if meta.name.is_empty() {
+            id.to_string()
+        } else {
+            meta.name.clone()
+        },
+        field_type: field_type.to_string(),
+        options: meta
+            .allowed_values
+            .iter()
+            .filter_map(allowed_value_name)
+            .map(str::to_string)
+            .collect(),
+        required: meta.required && !meta.has_default_value,
+    }
+}
+
+fn map_transition(transition: &JiraTransition) -> IssueTransition {
+    let mut fields: Vec<CustomField> = transition
+        .fields
+        .iter()
+        .map(|(id, meta)| map_transition_field(id, meta))
+        .collect();
+    fields.sort_by(|a, b| b.required.cmp(&a.required).then(a.name.cmp(&b.name)));
+    IssueTransition {
+        id: transition.id.clone(),
+        name: transition.name.clone(),
+        to_status: transition.to.name.clone(),
+        to_category: status_category(
+            transition
+                .to
+                .status_category
+                .as_ref()
+                .map(|c| c.key.as_str()),
+        )
+        .to_string(),
+        fields,
+    }
+}
+
+/// Convert a transition field value to the shape Jira expects.
+///
+/// Choice fields (resolution, fix versions, components, ...) are referenced by
+/// the ID of the allowed value matching the given name, value or ID.
+fn transition_field_to_jira(
+    field: &CustomField,
+    meta: &JiraTransitionField,
+    value: &Value,
+    flavor: JiraFlavor,
+) -> Result<Value> {
+    if meta.allowed_values.is_empty() || value.is_null() {
+        return custom_field_to_jira(field, meta.schema.as_ref(), value, flavor);
+    }
+
+    let find = |wanted: &str| -> Result<Value> {
+        meta.allowed_values
+            .iter()
+            .find(|v| {
+                allowed_value_name(v).is_some_and(|name| name.eq_ignore_ascii_case(wanted))
+                    || v.get("id").and_then(Value::as_str) == Some(wanted)
+            })
+            .and_then(|v| v.get("id").cloned())
+            .map(|id| serde_json::json!({ "id": id }))
+            .ok_or_else(|| {
+                let names: Vec<&str> = field.options.iter().map(String::as_str).collect();
+                catalog::unknown(&format!("{} value", field.name), wanted, &names)
+            })
+    };
+
+    if field.field_type == "multi_option" {
+        let values =
+            as_list(value).ok_or_else(|| invalid_value(field, "a list of options", value))?;
+        values
+            .iter()
+            .map(|v| find(v))
+            .collect::<Result<Vec<_>>>()
+            .map(Value::Array)
+    } else {
+        let wanted = as_text(value).ok_or_else(|| invalid_value(field, "an option", value))?;
+        find(&wanted)
+    }
+}
+
+/// Map `customfield_*` values to plain JSON keyed by field name.
+fn map_custom_fields(
+    fields: &HashMap<String, Value>,
+    names: &HashMap<String, String>,
+    flavor: JiraFlavor,
+) -> serde_json::Map<String, Value> {
+    let mut ids: Vec<&String> = fields
+        .keys()
+        .filter(|k| k.starts_with("customfield_"))
+        .collect();
+    ids.sort();
+
+    let mut custom_fields = serde_json::Map::new();
+    for id in ids {
+        if let Some(value) = read_custom_field_value(&fields[id], flavor) {
+            let name = names.get(id).unwrap_or(id);
+            custom_fields.insert(name.clone(), value);
+        }
+    }
+    custom_fields
+}
+
+/// Simplify a custom field value: options, users, versions and components
+/// become their value or name, ADF becomes Markdown. Empty values are `None`.
+fn read_custom_field_value(value: &Value, flavor: JiraFlavor) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.is_empty() => None,
+        Value::Array(items) => {
+            let values: Vec<Value> = items
+                .iter()
+                .filter_map(|v| read_custom_field_value(v, flavor))
+                .collect();
+            (!values.is_empty()).then_some(Value::Array(values))
+        }
+        Value::Object(object) => {
+            if object.get("type").and_then(Value::as_str) == Some("doc") {
+                let text = read_rich_text(value, flavor);
+                return (!text.is_empty()).then_some(Value::String(text));
+            }
+            let simple = object
+                .get("value")
+                .or_else(|| object.get("accountId"))
+                .or_else(|| object.get("name"))
+                .or_else(|| object.get("displayName"));
+            match simple {
+                Some(simple) => Some(simple.clone()),
+                None if object.is_empty() => None,
+                None => Some(value.clone()),
+            }
+        }
+        other => Some(other.clone()),
+    }
+}
+
+/// Whether an issue type name denotes a subtask ("Sub-task", "Subtask").
+fn is_subtask_type(name: &str) -> bool {
+    name.to_lowercase().replace('-', "") == "subtask"
+}
+
+fn map_issue_ref(issue: &JiraIssueRef) -> IssueRef {
+    let fields = issue.fields.as_ref();
+    IssueRef {
+        key: format!("jira#{}", issue.key),
+        title: fields.and_then(|f| f.summary.clone()),
+        state: fields
+            .and_then(|f| f.status.as_ref())
+            .map(|s| map_state(Some(s))),
+        issue_type: fields
+            .and_then(|f| f.issuetype.as_ref())
+            .map(|t| t.name.clone()),
+    }
+}
+
+/// Link kind of one direction of a link type.
+///
+/// Well-known link types get the shared kinds of [`IssueLink::kind`]; others
+/// use their relation description ("is cloned by" -> "is_cloned_by").
+fn link_kind(link_type: &JiraIssueLinkType, outward: bool) -> String {
+    let known = match link_type.name.to_lowercase().as_str() {
+        "blocks" => Some(("blocks", "blocked_by")),
+        "duplicate" => Some(("duplicates", "duplicated_by")),
+        "relates" => Some(("relates_to", "relates_to")),
+        _ => None,
+    };
+    match known {
+        Some((outward_kind, inward_kind)) => {
+            if outward { outward_kind } else { inward_kind }.to_string()
+        }
+        None => {
+            let description = if outward {
+                &link_type.outward
+            } else {
+                &link_type.inward
+            };
+            normalize_link_kind(description)
+        }
+    }
+}
+
+/// Normalize a user-supplied link kind ("Blocked by" -> "blocked_by").
+fn normalize_link_kind(kind: &str) -> String {
+    kind.split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Find the link type for a kind, and whether the issue the kind is read from
+/// sits on the inward side of the link (the subject of the outward description).
+fn find_link_type(link_types: &[JiraIssueLinkType], kind: &str) -> Result<(String, bool)> {
+    let wanted = normalize_link_kind(kind);
+    for link_type in link_types {
+        if link_kind(link_type, true) == wanted || normalize_link_kind(&link_type.name) == wanted {
+            return Ok((link_type.name.clone(), true));
+        }
+        if link_kind(link_type, false) == wanted {
+            return Ok((link_type.name.clone(), false));
+        }
+    }
+
+    let mut available: Vec<String> = link_types
+        .iter()
+        .flat_map(|t| [link_kind(t, true), link_kind(t, false)])
+        .collect();
+    available.dedup();
+    Err(Error::InvalidData(format!(
+        "Unknown Jira link kind '{}'. Available: {}",
+        kind,
+        available.join(", ")
+    )))
+}
+
+fn map_issue_link(link: &JiraIssueLink) -> Option<IssueLink> {
+    // The side that is set is the other issue; the outward side means the
+    // issue read from is the subject of the outward description.
+    let (target, outward) = match (&link.outward_issue, &link.inward_issue) {
+        (Some(issue), _) => (issue, true),
+        (None, Some(issue)) => (issue, false),
+        (None, None) => return None,
+    };
+    Some(IssueLink {
+        kind: link_kind(&link.link_type, outward),
+        target: map_issue_ref(target),
+        id: Some(link.id.clone()),
+    })
+}
+
+fn map_attachment(attachment: &JiraAttachment) -> Attachment {
+    Attachment {
+        id: attachment.id.clone(),
+        name: attachment.filename.clone(),
+        size: attachment.size,
+        mime_type: attachment.mime_type.clone(),
+        url: attachment.content.clone(),
+        created_at: attachment.created.clone(),
+    }
+}
+
+fn map_issue(
+    issue: &JiraIssue,
+    flavor: JiraFlavor,
+    instance_url: &str,
+    names: &HashMap<String, String>,
+) -> Issue {
+    let custom_fields = map_custom_fields(&issue.fields.extra, names, flavor);
+    // Self-hosted links issues to epics through a custom field, not `parent`
+    let epic = custom_fields
+        .get(EPIC_LINK_FIELD)
+        .and_then(Value::as_str)
+        .map(|key| IssueRef {
+            key: format!("jira#{}", key),
+            ..Default::default()
+        });
+
+    Issue {
+        key: format!("jira#{}", issue.key),
+        title: issue.fields.summary.clone().unwrap_or_default(),
+        description: read_description(&issue.fields.description, flavor),
+        state: map_state(issue.fields.status.as_ref()),
+        source: "jira".to_string(),
+        priority: map_priority(issue.fields.priority.as_ref()),
+        labels: issue.fields.labels.clone(),
+        author: map_user(issue.fields.reporter.as_ref()),
+        assignees: issue
+            .fields
+            .assignee
+            .as_ref()
+            .map(|a| vec![map_user(Some(a)).unwrap()])
+            .unwrap_or_default(),
         url: Some(format!("{}/browse/{}", instance_url, issue.key)),
         created_at: issue.fields.created.clone(),
         updated_at: issue.fields.updated.clone(),
+        issue_type: issue.fields.issuetype.as_ref().map(|t| t.name.clone()),
+        parent: issue.fields.parent.as_ref().map(map_issue_ref).or(epic),
+        children: issue.fields.subtasks.iter().map(map_issue_ref).collect(),
+        custom_fields,
+        links: issue
+            .fields
+            .issuelinks
+            .iter()
+            .filter_map(map_issue_link)
+            .collect(),
+        attachments: issue.fields.attachment.iter().map(map_attachment).collect(),
+        estimate_seconds: issue.fields.timeoriginalestimate,
+        time_spent_seconds: issue.fields.timespent,
+        // With several fix versions, the next one to be released is the milestone
+        milestone: issue
+            .fields
+            .fix_versions
+            .iter()
+            .find(|v| !v.released)
+            .or(issue.fields.fix_versions.first())
+            .map(|v| {
+                let project = issue.key.split('-').next().unwrap_or_default();
+                map_version(v, instance_url, project)
+            }),
+        checklists: vec![],
+    }
+}
+
+fn map_version(version: &JiraVersion, instance_url: &str, project_key: &str) -> Milestone {
+    Milestone {
+        id: version.id.clone(),
+        title: version.name.clone(),
+        kind: "version".to_string(),
+        state: if version.released || version.archived {
+            "closed".to_string()
+        } else {
+            "open".to_string()
+        },
+        description: version.description.clone().filter(|d| !d.is_empty()),
+        start_date: version.start_date.clone(),
+        due_date: version.release_date.clone(),
+        open_issues: None,
+        closed_issues: None,
+        url: Some(format!(
+            "{}/browse/{}/fixforversion/{}",
+            instance_url, project_key, version.id
+        )),
+    }
+}
+
+fn map_worklog(worklog: &JiraWorklog, flavor: JiraFlavor) -> TimeEntry {
+    TimeEntry {
+        id: worklog.id.clone(),
+        author: map_user(worklog.author.as_ref()),
+        duration_seconds: worklog.time_spent_seconds,
+        started_at: worklog.started.clone(),
+        comment: Some(read_comment_body(&worklog.comment, flavor)).filter(|c| !c.is_empty()),
     }
 }
 
+/// Format a start time the way Jira worklogs expect it ("2024-01-15T10:00:00.000+0000").
+///
+/// `started_at` is an ISO 8601 timestamp; without it the current time is used.
+fn worklog_started(started_at: Option<&str>) -> Result<String> {
+    let secs = match started_at {
+        Some(started_at) => parse_iso8601(started_at).ok_or_else(|| {
+            Error::InvalidData(format!(
+                "Invalid start time '{}', expected ISO 8601 such as 2024-01-15T10:00:00Z",
+                started_at
+            ))
+        })?,
+        None => now_unix(),
+    };
+    let utc = unix_to_iso8601(secs);
+    Ok(format!("{}.000+0000", utc.trim_end_matches('Z')))
+}
+
 fn map_comment(jira_comment: &JiraComment, flavor: JiraFlavor) -> Comment {
     Comment {
         id: jira_comment.id.clone(),
@@ -673,6 +1438,29 @@ fn map_comment(jira_comment: &JiraComment, flavor: JiraFlavor) -> Comment {
         created_at: jira_comment.created.clone(),
         updated_at: jira_comment.updated.clone(),
         position: None,
+        attachments: vec![],
+    }
+}
+
+fn map_board(board: &JiraBoard) -> Board {
+    Board {
+        id: board.id.to_string(),
+        name: board.name.clone(),
+        kind: board.kind.clone(),
+        project_key: board.location.as_ref().and_then(|l| l.project_key.clone()),
+    }
+}
+
+fn map_sprint(sprint: &JiraSprint) -> Sprint {
+    Sprint {
+        id: sprint.id.to_string(),
+        name: sprint.name.clone(),
+        state: sprint.state.clone(),
+        goal: sprint.goal.clone().filter(|g| !g.is_empty()),
+        board_id: sprint.origin_board_id.map(|id| id.to_string()),
+        start_date: sprint.start_date.clone(),
+        end_date: sprint.end_date.clone(),
+        complete_date: sprint.complete_date.clone(),
     }
 }
 
@@ -698,6 +1486,78 @@ fn escape_jql(value: &str) -> String {
     value.replace('\\', "\\\\").replace('"', "\\\"")
 }
 
+/// Validate a raw JQL query and split it into its condition and ORDER BY
+/// clause (both trimmed, the condition may be empty).
+///
+/// Only the structure is checked (closed quotes, balanced parentheses);
+/// Jira reports unknown fields and functions itself.
+fn split_jql(query: &str) -> Result<(String, Option<String>)> {
+    let invalid = |reason: &str| Error::InvalidData(format!("Invalid JQL query: {}", reason));
+
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(invalid("query is empty"));
+    }
+
+    let lower = query.to_ascii_lowercase();
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut depth = 0i32;
+    let mut order_by_at = None;
+
+    for (i, c) in query.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err(invalid("unbalanced parentheses"));
+                }
+            }
+            _ => {
+                let at_word_start = i == 0 || lower[..i].ends_with(char::is_whitespace);
+                if order_by_at.is_none()
+                    && depth == 0
+                    && at_word_start
+                    && lower[i..].starts_with("order by")
+                {
+                    order_by_at = Some(i);
+                }
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return Err(invalid("unclosed quote"));
+    }
+    if depth != 0 {
+        return Err(invalid("unbalanced parentheses"));
+    }
+
+    match order_by_at {
+        Some(pos) => {
+            let order_by = query[pos + "order by".len()..].trim();
+            if order_by.is_empty() {
+                return Err(invalid("ORDER BY without fields"));
+            }
+            Ok((query[..pos].trim().to_string(), Some(order_by.to_string())))
+        }
+        None => Ok((query.to_string(), None)),
+    }
+}
+
 /// This maps user-friendly aliases to the correct category key, used as fallback
 /// when the exact status name is not found in available transitions.
 fn generic_status_to_category(status: &str) -> Option<&'static str> {
@@ -761,22 +1621,46 @@ impl IssueProvider for JiraClient {
         }
 
         if let Some(assignee) = &filter.assignee {
-            jql_parts.push(format!("assignee = \"{}\"", escape_jql(assignee)));
+            if is_me(assignee) {
+                jql_parts.push("assignee = currentUser()".to_string());
+            } else {
+                let assignee = self.resolve_user(assignee).await?;
+                jql_parts.push(format!("assignee = \"{}\"", escape_jql(&assignee)));
+            }
+        }
+
+        if let Some(milestone) = &filter.milestone {
+            jql_parts.push(format!("fixVersion = \"{}\"", escape_jql(milestone)));
+        }
+
+        // Raw JQL is parenthesized so an OR inside cannot lift the project restriction
+        let mut query_order_by = None;
+        if let Some(query) = &filter.query {
+            let (condition, order_by) = split_jql(query)?;
+            if !condition.is_empty() {
+                jql_parts.push(format!("({})", condition));
+            }
+            query_order_by = order_by;
         }
 
         let jql = jql_parts.join(" AND ");
 
         // Add ORDER BY
-        let order_by = match filter.sort_by.as_deref() {
-            Some("created_at" | "created") => "created",
-            Some("priority") => "priority",
-            _ => "updated",
-        };
-        let order = match filter.sort_order.as_deref() {
-            Some("asc") => "ASC",
-            _ => "DESC",
+        let jql_with_order = match query_order_by {
+            Some(order_by) => format!("{} ORDER BY {}", jql, order_by),
+            None => {
+                let order_by = match filter.sort_by.as_deref() {
+                    Some("created_at" | "created") => "created",
+                    Some("priority") => "priority",
+                    _ => "updated",
+                };
+                let order = match filter.sort_order.as_deref() {
+                    Some("asc") => "ASC",
+                    _ => "DESC",
+                };
+                format!("{} ORDER BY {} {}", jql, order_by, order)
+            }
         };
-        let jql_with_order = format!("{} ORDER BY {} {}", jql, order_by, order);
 
         let instance_url = instance_url_from_base(&self.base_url);
 
@@ -794,6 +1678,8 @@ impl IssueProvider for JiraClient {
                     let mut params: Vec<(&str, String)> = vec![
                         ("jql", jql_with_order.clone()),
                         ("maxResults", std::cmp::min(limit, 50).to_string()),
+                        ("fields", "*navigable".to_string()),
+                        ("expand", "names".to_string()),
                     ];
 
                     if let Some(token) = &next_page_token {
@@ -818,7 +1704,12 @@ impl IssueProvider for JiraClient {
                     let page_len = search_resp.issues.len() as u32;
                     for issue in &search_resp.issues {
                         if fetched_count >= offset && all_issues.len() < limit as usize {
-                            all_issues.push(map_issue(issue, self.flavor, &instance_url));
+                            all_issues.push(map_issue(
+                                issue,
+                                self.flavor,
+                                &instance_url,
+                                &search_resp.names,
+                            ));
                         }
                         fetched_count += 1;
                     }
@@ -845,6 +1736,7 @@ impl IssueProvider for JiraClient {
                     ("jql", jql_with_order),
                     ("startAt", offset.to_string()),
                     ("maxResults", limit.to_string()),
+                    ("expand", "names".to_string()),
                 ];
 
                 let param_refs: Vec<(&str, &str)> =
@@ -864,7 +1756,7 @@ impl IssueProvider for JiraClient {
                 let issues = search_resp
                     .issues
                     .iter()
-                    .map(|i| map_issue(i, self.flavor, &instance_url))
+                    .map(|i| map_issue(i, self.flavor, &instance_url, &search_resp.names))
                     .collect();
 
                 Ok(issues)
@@ -874,20 +1766,27 @@ impl IssueProvider for JiraClient {
 
     async fn get_issue(&self, key: &str) -> Result<Issue> {
         let jira_key = parse_jira_key(key);
-        let url = format!("{}/issue/{}", self.base_url, jira_key);
+        let url = format!("{}/issue/{}?expand=names", self.base_url, jira_key);
         let issue: JiraIssue = self.get(&url).await?;
         let instance_url = instance_url_from_base(&self.base_url);
-        Ok(map_issue(&issue, self.flavor, &instance_url))
+        let mut mapped = map_issue(&issue, self.flavor, &instance_url, &issue.names);
+
+        let is_epic = mapped
+            .issue_type
+            .as_deref()
+            .is_some_and(|t| t.eq_ignore_ascii_case("Epic"));
+        if is_epic {
+            match self.get_epic_children(&issue.key).await {
+                Ok(children) => mapped.children.extend(children),
+                Err(e) => warn!(error = %e, key = issue.key, "Failed to fetch epic issues"),
+            }
+        }
+
+        Ok(mapped)
     }
 
     async fn create_issue(&self, input: CreateIssueInput) -> Result<Issue> {
-        let description = input.description.map(|d| {
-            if self.flavor == JiraFlavor::Cloud {
-                text_to_adf(&d)
-            } else {
-                serde_json::Value::String(d)
-            }
-        });
+        let description = input.description.map(|d| write_rich_text(&d, self.flavor));
 
         let labels = if input.labels.is_empty() {
             None
@@ -899,13 +1798,36 @@ impl IssueProvider for JiraClient {
             name: priority_to_jira(p),
         });
 
-        let assignee = input.assignees.first().map(|a| {
-            if self.flavor == JiraFlavor::Cloud {
-                serde_json::json!({ "accountId": a })
+        let assignee = match input.assignees.first() {
+            Some(user) => Some(user_ref(&self.resolve_user(user).await?, self.flavor)),
+            None => None,
+        };
+
+        let issue_type = input
+            .issue_type
+            .unwrap_or_else(|| DEFAULT_ISSUE_TYPE.to_string());
+        let mut custom_field_values = input.custom_fields;
+        let mut parent = None;
+        if let Some(parent_key) = &input.parent {
+            let parent_key = parse_jira_key(parent_key).to_string();
+            if self.flavor == JiraFlavor::Cloud || is_subtask_type(&issue_type) {
+                parent = Some(IssueKey { key: parent_key });
             } else {
-                serde_json::json!({ "name": a })
+                custom_field_values.insert(EPIC_LINK_FIELD.to_string(), Value::String(parent_key));
             }
-        });
+        }
+        let needs_epic_name = self.flavor == JiraFlavor::SelfHosted
+            && issue_type.eq_ignore_ascii_case("Epic")
+            && !custom_field_values
+                .keys()
+                .any(|k| k.eq_ignore_ascii_case(EPIC_NAME_FIELD));
+        if needs_epic_name {
+            custom_field_values.insert(
+                EPIC_NAME_FIELD.to_string(),
+                Value::String(input.title.clone()),
+            );
+        }
+        let custom_fields = self.resolve_custom_fields(&custom_field_values).await?;
 
         let payload = CreateIssuePayload {
             fields: CreateIssueFields {
@@ -913,13 +1835,13 @@ impl IssueProvider for JiraClient {
                     key: self.project_key.clone(),
                 },
                 summary: input.title,
-                issuetype: IssueType {
-                    name: "Task".to_string(),
-                },
+                issuetype: IssueType { name: issue_type },
                 description,
                 labels,
                 priority,
                 assignee,
+                parent,
+                custom_fields,
             },
         };
 
@@ -933,27 +1855,17 @@ impl IssueProvider for JiraClient {
     async fn update_issue(&self, key: &str, input: UpdateIssueInput) -> Result<Issue> {
         let jira_key = parse_jira_key(key);
 
-        let description = input.description.map(|d| {
-            if self.flavor == JiraFlavor::Cloud {
-                text_to_adf(&d)
-            } else {
-                serde_json::Value::String(d)
-            }
-        });
+        let description = input.description.map(|d| write_rich_text(&d, self.flavor));
 
         let priority = input.priority.as_deref().map(|p| PriorityName {
             name: priority_to_jira(p),
         });
 
-        let assignee = input.assignees.as_ref().and_then(|a| {
-            a.first().map(|username| {
-                if self.flavor == JiraFlavor::Cloud {
-                    serde_json::json!({ "accountId": username })
-                } else {
-                    serde_json::json!({ "name": username })
-                }
-            })
-        });
+        let assignee = match input.assignees.as_ref().and_then(|a| a.first()) {
+            Some(user) => Some(user_ref(&self.resolve_user(user).await?, self.flavor)),
+            None => None,
+        };
+        let custom_fields = self.resolve_custom_fields(&input.custom_fields).await?;
 
         let labels = input.labels;
 
@@ -963,6 +1875,8 @@ impl IssueProvider for JiraClient {
             labels,
             priority,
             assignee,
+            timetracking: None,
+            custom_fields,
         };
 
         // Only call PUT if there are field updates
@@ -970,7 +1884,8 @@ impl IssueProvider for JiraClient {
             || fields.description.is_some()
             || fields.labels.is_some()
             || fields.priority.is_some()
-            || fields.assignee.is_some();
+            || fields.assignee.is_some()
+            || !fields.custom_fields.is_empty();
 
         if has_field_updates {
             let url = format!("{}/issue/{}", self.base_url, jira_key);
@@ -980,7 +1895,7 @@ impl IssueProvider for JiraClient {
 
         // Handle status change via transitions
         if let Some(state) = &input.state {
-            self.transition_issue(jira_key, state).await?;
+            self.transition_to_status(jira_key, state).await?;
         }
 
         // Fetch updated issue
@@ -1000,11 +1915,7 @@ impl IssueProvider for JiraClient {
 
     async fn add_comment(&self, issue_key: &str, body: &str) -> Result<Comment> {
         let jira_key = parse_jira_key(issue_key);
-        let comment_body = if self.flavor == JiraFlavor::Cloud {
-            text_to_adf(body)
-        } else {
-            serde_json::Value::String(body.to_string())
-        };
+        let comment_body = write_rich_text(body, self.flavor);
 
         let payload = AddCommentPayload { body: comment_body };
 
@@ -1013,60 +1924,443 @@ impl IssueProvider for JiraClient {
         Ok(map_comment(&jira_comment, self.flavor))
     }
 
-    fn provider_name(&self) -> &'static str {
-        "jira"
-    }
-}
+    async fn get_custom_fields(&self) -> Result<Vec<CustomField>> {
+        let fields = self.get_jira_custom_fields().await?;
+        // Create metadata adds options and required flags; it depends on
+        // project permissions, so fields are still listed without it.
+        let meta = match self.get_create_meta().await {
+            Ok(meta) => meta,
+            Err(e) => {
+                warn!(error = %e, "Failed to fetch Jira create metadata");
+                HashMap::new()
+            }
+        };
 
-#[async_trait]
-impl MergeRequestProvider for JiraClient {
-    async fn get_merge_requests(&self, _filter: MrFilter) -> Result<Vec<MergeRequest>> {
-        Err(Error::ProviderUnsupported {
-            provider: "jira".to_string(),
-            operation: "get_merge_requests".to_string(),
-        })
+        let mut custom_fields: Vec<CustomField> = fields
+            .iter()
+            .map(|f| map_custom_field(f, meta.get(&f.id)))
+            .collect();
+        custom_fields.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(custom_fields)
     }
 
-    async fn get_merge_request(&self, _key: &str) -> Result<MergeRequest> {
-        Err(Error::ProviderUnsupported {
-            provider: "jira".to_string(),
-            operation: "get_merge_request".to_string(),
-        })
+    /// Lists the project versions; released and archived versions are closed.
+    /// Lists the labels used on the site; Jira Server/Data Center has no endpoint for them.
+    async fn list_labels(&self) -> Result<Vec<Label>> {
+        if self.flavor == JiraFlavor::SelfHosted {
+            return Err(Error::ProviderUnsupported {
+                provider: "jira".to_string(),
+                operation: "list_labels on Jira Server/Data Center".to_string(),
+            });
+        }
+        let names: Vec<String> = self
+            .get_agile_pages(&format!("{}/label", self.base_url))
+            .await?;
+        Ok(names
+            .into_iter()
+            .map(|name| Label {
+                name,
+                ..Default::default()
+            })
+            .collect())
     }
 
-    async fn get_discussions(&self, _mr_key: &str) -> Result<Vec<Discussion>> {
-        Err(Error::ProviderUnsupported {
-            provider: "jira".to_string(),
-            operation: "get_discussions".to_string(),
-        })
+    async fn list_statuses(&self) -> Result<Vec<IssueStatus>> {
+        let statuses = self.get_project_statuses().await?;
+        Ok(statuses
+            .into_iter()
+            .map(|s| IssueStatus {
+                category: status_category(s.status_category.as_ref().map(|c| c.key.as_str()))
+                    .to_string(),
+                name: s.name,
+            })
+            .collect())
     }
 
-    async fn get_diffs(&self, _mr_key: &str) -> Result<Vec<FileDiff>> {
-        Err(Error::ProviderUnsupported {
-            provider: "jira".to_string(),
-            operation: "get_diffs".to_string(),
-        })
+    async fn get_transitions(&self, issue_key: &str) -> Result<Vec<IssueTransition>> {
+        let transitions = self
+            .get_transitions_with_fields(parse_jira_key(issue_key))
+            .await?;
+        Ok(transitions.iter().map(map_transition).collect())
     }
 
-    async fn add_comment(&self, _mr_key: &str, _input: CreateCommentInput) -> Result<Comment> {
-        Err(Error::ProviderUnsupported {
-            provider: "jira".to_string(),
-            operation: "add_merge_request_comment".to_string(),
-        })
-    }
+    async fn transition_issue(&self, issue_key: &str, input: TransitionIssueInput) -> Result<()> {
+        let key = parse_jira_key(issue_key);
+        let transitions = self.get_transitions_with_fields(key).await?;
+        let transition = find_transition(&transitions, &input.transition)?;
+        let screen = map_transition(transition);
+
+        let mut fields = serde_json::Map::new();
+        for (name, value) in &input.fields {
+            let field = find_custom_field(&screen.fields, name)?;
+            let meta = &transition.fields[&field.id];
+            fields.insert(
+                field.id.clone(),
+                transition_field_to_jira(field, meta, value, self.flavor)?,
+            );
+        }
 
-    fn provider_name(&self) -> &'static str {
-        "jira"
-    }
-}
+        let missing: Vec<&str> = screen
+            .fields
+            .iter()
+            .filter(|f| f.required && !fields.contains_key(&f.id))
+            .map(|f| f.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(Error::InvalidData(format!(
+                "Transition '{}' requires {}",
+                transition.name,
+                missing.join(", ")
+            )));
+        }
 
-#[async_trait]
+        debug!(
+            issue = key,
+            transition_id = transition.id,
+            "Applying transition"
+        );
+        let payload = TransitionPayload {
+            transition: TransitionId {
+                id: transition.id.clone(),
+            },
+            fields,
+            update: input.comment.map(|comment| TransitionUpdate {
+                comment: vec![AddCommentOperation {
+                    add: AddCommentPayload {
+                        body: write_rich_text(&comment, self.flavor),
+                    },
+                }],
+            }),
+        };
+        let url = format!("{}/issue/{}/transitions", self.base_url, key);
+        self.post_no_content(&url, &payload).await
+    }
+
+    async fn get_milestones(&self, state: Option<&str>) -> Result<Vec<Milestone>> {
+        let url = format!("{}/project/{}/versions", self.base_url, self.project_key);
+        let versions: Vec<JiraVersion> = self.get(&url).await?;
+        let instance_url = instance_url_from_base(&self.base_url);
+
+        let mut counted: Vec<(Milestone, String)> = Vec::new();
+        for version in &versions {
+            let milestone = map_version(version, &instance_url, &self.project_key);
+            let wanted = match state.unwrap_or("open") {
+                "all" => true,
+                "closed" => milestone.state == "closed",
+                _ => milestone.state == "open",
+            };
+            if wanted {
+                counted.push((milestone, version.id.clone()));
+            }
+        }
+
+        // Counts are best-effort: one failed count leaves them unknown
+        // instead of failing the whole listing
+        let milestones = stream::iter(counted)
+            .map(|(mut milestone, version_id)| async move {
+                match self.count_version_issues(&version_id).await {
+                    Ok((open, closed)) => {
+                        milestone.open_issues = Some(open);
+                        milestone.closed_issues = Some(closed);
+                    }
+                    Err(e) => {
+                        debug!(error = %e, milestone = %milestone.title, "Jira issue counts unavailable")
+                    }
+                }
+                milestone
+            })
+            .buffered(COUNT_CONCURRENCY)
+            .collect()
+            .await;
+
+        Ok(milestones)
+    }
+
+    async fn link_issues(&self, key: &str, target_key: &str, kind: &str) -> Result<()> {
+        let url = format!("{}/issueLinkType", self.base_url);
+        let response: JiraIssueLinkTypesResponse = self.get(&url).await?;
+        let (link_type, outward) = find_link_type(&response.issue_link_types, kind)?;
+
+        let (source, target) = (parse_jira_key(key), parse_jira_key(target_key));
+        let (inward_key, outward_key) = if outward {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        let payload = CreateIssueLinkRequest {
+            link_type: LinkTypeName { name: link_type },
+            inward_issue: IssueKey {
+                key: inward_key.to_string(),
+            },
+            outward_issue: IssueKey {
+                key: outward_key.to_string(),
+            },
+        };
+        let url = format!("{}/issueLink", self.base_url);
+        self.post_no_content(&url, &payload).await
+    }
+
+    async fn unlink_issues(&self, key: &str, target_key: &str, kind: Option<&str>) -> Result<()> {
+        let url = format!(
+            "{}/issue/{}?fields=issuelinks",
+            self.base_url,
+            parse_jira_key(key)
+        );
+        let issue: JiraIssue = self.get(&url).await?;
+
+        let target = format!("jira#{}", parse_jira_key(target_key));
+        let kind = kind.map(normalize_link_kind);
+        let links: Vec<IssueLink> = issue
+            .fields
+            .issuelinks
+            .iter()
+            .filter_map(map_issue_link)
+            .filter(|link| link.target.key == target)
+            .filter(|link| kind.as_ref().is_none_or(|k| &link.kind == k))
+            .collect();
+        if links.is_empty() {
+            return Err(Error::NotFound(format!(
+                "No link between {} and {}",
+                key, target_key
+            )));
+        }
+
+        for link in links {
+            if let Some(id) = link.id {
+                let url = format!("{}/issueLink/{}", self.base_url, id);
+                self.delete(&url).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn download_attachment(&self, url: &str) -> Result<Vec<u8>> {
+        if !is_same_origin(url, &self.base_url) {
+            return Err(Error::InvalidData(format!(
+                "{} is not an attachment URL of {}",
+                url,
+                instance_url_from_base(&self.base_url)
+            )));
+        }
+        self.get_bytes(url).await
+    }
+
+    async fn upload_attachment(
+        &self,
+        issue_key: &str,
+        file_name: &str,
+        content: &[u8],
+    ) -> Result<Attachment> {
+        let url = format!(
+            "{}/issue/{}/attachments",
+            self.base_url,
+            parse_jira_key(issue_key)
+        );
+        let attachments: Vec<JiraAttachment> = self.post_file(&url, file_name, content).await?;
+        attachments
+            .first()
+            .map(map_attachment)
+            .ok_or_else(|| Error::InvalidData("Jira returned no attachment".to_string()))
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+#[async_trait]
+impl MergeRequestProvider for JiraClient {
+    async fn get_merge_requests(&self, _filter: MrFilter) -> Result<Vec<MergeRequest>> {
+        Err(Error::ProviderUnsupported {
+            provider: "jira".to_string(),
+            operation: "get_merge_requests".to_string(),
+        })
+    }
+
+    async fn get_merge_request(&self, _key: &str) -> Result<MergeRequest> {
+        Err(Error::ProviderUnsupported {
+            provider: "jira".to_string(),
+            operation: "get_merge_request".to_string(),
+        })
+    }
+
+    async fn get_discussions(&self, _mr_key: &str) -> Result<Vec<Discussion>> {
+        Err(Error::ProviderUnsupported {
+            provider: "jira".to_string(),
+            operation: "get_discussions".to_string(),
+        })
+    }
+
+    async fn get_diffs(&self, _mr_key: &str) -> Result<Vec<FileDiff>> {
+        Err(Error::ProviderUnsupported {
+            provider: "jira".to_string(),
+            operation: "get_diffs".to_string(),
+        })
+    }
+
+    async fn add_comment(&self, _mr_key: &str, _input: CreateCommentInput) -> Result<Comment> {
+        Err(Error::ProviderUnsupported {
+            provider: "jira".to_string(),
+            operation: "add_merge_request_comment".to_string(),
+        })
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+/// Jira has no CI, all pipeline operations are unsupported.
+impl PipelineProvider for JiraClient {
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+/// Jira has no repositories, all repository operations are unsupported.
+impl RepositoryProvider for JiraClient {
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+#[async_trait]
+impl AgileProvider for JiraClient {
+    async fn get_boards(&self) -> Result<Vec<Board>> {
+        let url = self.agile_url(&format!("/board?projectKeyOrId={}", self.project_key));
+        let boards: Vec<JiraBoard> = self.get_agile_pages(&url).await?;
+        Ok(boards.iter().map(map_board).collect())
+    }
+
+    async fn get_sprints(&self, board_id: &str, state: Option<&str>) -> Result<Vec<Sprint>> {
+        let mut url = self.agile_url(&format!("/board/{}/sprint", board_id));
+        if let Some(state) = state {
+            url.push_str(&format!("?state={}", state));
+        }
+        let sprints: Vec<JiraSprint> = self.get_agile_pages(&url).await?;
+        Ok(sprints.iter().map(map_sprint).collect())
+    }
+
+    async fn get_sprint_issues(&self, sprint_id: &str) -> Result<Vec<Issue>> {
+        let instance_url = instance_url_from_base(&self.base_url);
+        let mut issues = Vec::new();
+
+        loop {
+            let url = self.agile_url(&format!(
+                "/sprint/{}/issue?startAt={}&maxResults={}&expand=names",
+                sprint_id,
+                issues.len(),
+                SPRINT_ISSUES_PAGE_SIZE
+            ));
+            let page: JiraSearchResponse = self.get(&url).await?;
+            let count = page.issues.len();
+            issues.extend(
+                page.issues
+                    .iter()
+                    .map(|i| map_issue(i, self.flavor, &instance_url, &page.names)),
+            );
+
+            let total = page.total.unwrap_or(0) as usize;
+            if count == 0 || issues.len() >= total {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+
+    async fn move_issues_to_sprint(&self, sprint_id: &str, issue_keys: &[String]) -> Result<()> {
+        let url = self.agile_url(&format!("/sprint/{}/issue", sprint_id));
+        self.move_issues(&url, issue_keys).await
+    }
+
+    async fn move_issues_to_backlog(&self, issue_keys: &[String]) -> Result<()> {
+        let url = self.agile_url("/backlog/issue");
+        self.move_issues(&url, issue_keys).await
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+#[async_trait]
+impl TimeTrackingProvider for JiraClient {
+    async fn log_time(
+        &self,
+        issue_key: &str,
+        duration_seconds: u64,
+        comment: Option<&str>,
+        started_at: Option<&str>,
+    ) -> Result<()> {
+        let jira_key = parse_jira_key(issue_key);
+        let payload = AddWorklogPayload {
+            time_spent_seconds: duration_seconds,
+            started: worklog_started(started_at)?,
+            comment: comment.map(|c| write_rich_text(c, self.flavor)),
+        };
+
+        let url = format!("{}/issue/{}/worklog", self.base_url, jira_key);
+        let _: JiraWorklog = self.post(&url, &payload).await?;
+        Ok(())
+    }
+
+    async fn get_time_entries(&self, issue_key: &str) -> Result<Vec<TimeEntry>> {
+        let jira_key = parse_jira_key(issue_key);
+        let url = format!("{}/issue/{}/worklog", self.base_url, jira_key);
+        let response: JiraWorklogsResponse = self.get(&url).await?;
+        Ok(response
+            .worklogs
+            .iter()
+            .map(|w| map_worklog(w, self.flavor))
+            .collect())
+    }
+
+    async fn set_time_estimate(&self, issue_key: &str, estimate_seconds: u64) -> Result<()> {
+        let jira_key = parse_jira_key(issue_key);
+        // Jira estimates have minute precision
+        let fields = UpdateIssueFields {
+            timetracking: Some(TimeTrackingFields {
+                original_estimate: format!("{}m", estimate_seconds.div_ceil(60)),
+            }),
+            ..Default::default()
+        };
+
+        let url = format!("{}/issue/{}", self.base_url, jira_key);
+        self.put(&url, &UpdateIssuePayload { fields }).await
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "jira"
+    }
+}
+
+#[async_trait]
 impl Provider for JiraClient {
     async fn get_current_user(&self) -> Result<User> {
         let url = format!("{}/myself", self.base_url);
         let jira_user: JiraUser = self.get(&url).await?;
         Ok(map_user(Some(&jira_user)).unwrap_or_default())
     }
+
+    /// `jira#` and project keys and issue URLs of the instance; keys of other
+    /// projects are claimed as a fallback.
+    fn key_patterns(&self) -> Vec<KeyPattern> {
+        vec![
+            KeyPattern::Prefix("jira#".to_string()),
+            KeyPattern::Project(self.project_key.clone()),
+            KeyPattern::Url(format!(
+                "{}/browse/",
+                instance_url_from_base(&self.base_url)
+            )),
+            KeyPattern::Fallback,
+        ]
+    }
+
+    async fn search_users(&self, query: &str) -> Result<Vec<User>> {
+        self.search_members(query.trim().trim_start_matches('@'))
+            .await
+    }
 }
 
 // =============================================================================
@@ -1219,136 +2513,6 @@ mod tests {
         assert_eq!(base64_encode("abc"), "YWJj");
     }
 
-    // =========================================================================
-    // ADF conversion tests
-    // =========================================================================
-
-    #[test]
-    fn test_text_to_adf_simple() {
-        let adf = text_to_adf("Hello world");
-        assert_eq!(adf["type"], "doc");
-        assert_eq!(adf["version"], 1);
-        let content = adf["content"].as_array().unwrap();
-        assert_eq!(content.len(), 1);
-        assert_eq!(content[0]["type"], "paragraph");
-        let inline = content[0]["content"].as_array().unwrap();
-        assert_eq!(inline.len(), 1);
-        assert_eq!(inline[0]["text"], "Hello world");
-    }
-
-    #[test]
-    fn test_text_to_adf_multi_paragraph() {
-        let adf = text_to_adf("First paragraph\n\nSecond paragraph");
-        let content = adf["content"].as_array().unwrap();
-        assert_eq!(content.len(), 2);
-        assert_eq!(content[0]["content"][0]["text"], "First paragraph");
-        assert_eq!(content[1]["content"][0]["text"], "Second paragraph");
-    }
-
-    #[test]
-    fn test_text_to_adf_with_line_breaks() {
-        let adf = text_to_adf("Line 1\nLine 2\nLine 3");
-        let content = adf["content"].as_array().unwrap();
-        assert_eq!(content.len(), 1);
-        let inline = content[0]["content"].as_array().unwrap();
-        // text, hardBreak, text, hardBreak, text = 5 nodes
-        assert_eq!(inline.len(), 5);
-        assert_eq!(inline[0]["text"], "Line 1");
-        assert_eq!(inline[1]["type"], "hardBreak");
-        assert_eq!(inline[2]["text"], "Line 2");
-        assert_eq!(inline[3]["type"], "hardBreak");
-        assert_eq!(inline[4]["text"], "Line 3");
-    }
-
-    #[test]
-    fn test_text_to_adf_empty() {
-        let adf = text_to_adf("");
-        assert_eq!(adf["type"], "doc");
-        let content = adf["content"].as_array().unwrap();
-        assert_eq!(content.len(), 1);
-        assert_eq!(content[0]["type"], "paragraph");
-        assert!(content[0]["content"].as_array().unwrap().is_empty());
-    }
-
-    #[test]
-    fn test_adf_to_text_simple() {
-        let adf = serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": [{
-                "type": "paragraph",
-                "content": [{
-                    "type": "text",
-                    "text": "Hello world"
-                }]
-            }]
-        });
-        assert_eq!(adf_to_text(&adf), "Hello world");
-    }
-
-    #[test]
-    fn test_adf_to_text_multi() {
-        let adf = serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": [
-                {
-                    "type": "paragraph",
-                    "content": [{
-                        "type": "text",
-                        "text": "First"
-                    }]
-                },
-                {
-                    "type": "paragraph",
-                    "content": [{
-                        "type": "text",
-                        "text": "Second"
-                    }]
-                }
-            ]
-        });
-        assert_eq!(adf_to_text(&adf), "First\n\nSecond");
-    }
-
-    #[test]
-    fn test_adf_to_text_with_hardbreak() {
-        let adf = serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": [{
-                "type": "paragraph",
-                "content": [
-                    {"type": "text", "text": "Line 1"},
-                    {"type": "hardBreak"},
-                    {"type": "text", "text": "Line 2"}
-                ]
-            }]
-        });
-        assert_eq!(adf_to_text(&adf), "Line 1\nLine 2");
-    }
-
-    #[test]
-    fn test_adf_to_text_empty() {
-        let adf = serde_json::json!({
-            "version": 1,
-            "type": "doc",
-            "content": []
-        });
-        assert_eq!(adf_to_text(&adf), "");
-    }
-
-    #[test]
-    fn test_adf_to_text_non_adf_string() {
-        let value = serde_json::Value::String("plain text".to_string());
-        assert_eq!(adf_to_text(&value), "plain text");
-    }
-
-    #[test]
-    fn test_adf_to_text_null() {
-        assert_eq!(adf_to_text(&serde_json::Value::Null), "");
-    }
-
     // =========================================================================
     // Mapping tests
     // =========================================================================
@@ -1453,10 +2617,25 @@ mod tests {
                 labels: vec!["bug".to_string(), "mobile".to_string()],
                 created: Some("2024-01-01T10:00:00.000+0000".to_string()),
                 updated: Some("2024-01-02T15:30:00.000+0000".to_string()),
+                extra: Default::default(),
+                issuetype: None,
+                parent: None,
+                subtasks: vec![],
+                issuelinks: vec![],
+                attachment: vec![],
+                timeoriginalestimate: None,
+                timespent: None,
+                fix_versions: vec![],
             },
+            names: Default::default(),
         };
 
-        let mapped = map_issue(&issue, JiraFlavor::SelfHosted, "https://jira.example.com");
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
         assert_eq!(mapped.key, "jira#PROJ-123");
         assert_eq!(mapped.title, "Fix login bug");
         assert_eq!(
@@ -1508,10 +2687,25 @@ mod tests {
                 labels: vec![],
                 created: None,
                 updated: None,
+                extra: Default::default(),
+                issuetype: None,
+                parent: None,
+                subtasks: vec![],
+                issuelinks: vec![],
+                attachment: vec![],
+                timeoriginalestimate: None,
+                timespent: None,
+                fix_versions: vec![],
             },
+            names: Default::default(),
         };
 
-        let mapped = map_issue(&issue, JiraFlavor::Cloud, "https://test.atlassian.net");
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::Cloud,
+            "https://test.atlassian.net",
+            &issue.names,
+        );
         assert_eq!(mapped.description, Some("ADF description".to_string()));
     }
 
@@ -1530,28 +2724,354 @@ mod tests {
                 labels: vec![],
                 created: None,
                 updated: None,
+                extra: Default::default(),
+                issuetype: None,
+                parent: None,
+                subtasks: vec![],
+                issuelinks: vec![],
+                attachment: vec![],
+                timeoriginalestimate: None,
+                timespent: None,
+                fix_versions: vec![],
             },
+            names: Default::default(),
         };
 
-        let mapped = map_issue(&issue, JiraFlavor::SelfHosted, "https://jira.example.com");
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
         assert_eq!(mapped.description, Some("Plain text desc".to_string()));
     }
 
     #[test]
-    fn test_map_comment() {
-        let comment = JiraComment {
-            id: "100".to_string(),
-            body: Some(serde_json::Value::String("Nice work!".to_string())),
-            author: Some(sample_jira_user_self_hosted()),
-            created: Some("2024-01-01T10:00:00.000+0000".to_string()),
-            updated: Some("2024-01-01T11:00:00.000+0000".to_string()),
-        };
+    fn test_map_issue_hierarchy() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Story",
+                "issuetype": {"name": "Story", "subtask": false},
+                "parent": {
+                    "key": "PROJ-1",
+                    "fields": {
+                        "summary": "Epic",
+                        "status": {"name": "In Progress"},
+                        "issuetype": {"name": "Epic"}
+                    }
+                },
+                "subtasks": [{
+                    "key": "PROJ-3",
+                    "fields": {
+                        "summary": "Write tests",
+                        "status": {"name": "Done"},
+                        "issuetype": {"name": "Sub-task", "subtask": true}
+                    }
+                }]
+            }
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::Cloud,
+            "https://test.atlassian.net",
+            &issue.names,
+        );
 
-        let mapped = map_comment(&comment, JiraFlavor::SelfHosted);
-        assert_eq!(mapped.id, "100");
-        assert_eq!(mapped.body, "Nice work!");
-        assert!(mapped.author.is_some());
-        assert_eq!(mapped.author.unwrap().username, "jdoe");
+        assert_eq!(mapped.issue_type.as_deref(), Some("Story"));
+        assert_eq!(
+            mapped.parent,
+            Some(IssueRef {
+                key: "jira#PROJ-1".to_string(),
+                title: Some("Epic".to_string()),
+                state: Some("In Progress".to_string()),
+                issue_type: Some("Epic".to_string()),
+            })
+        );
+        assert_eq!(mapped.children.len(), 1);
+        assert_eq!(mapped.children[0].key, "jira#PROJ-3");
+        assert_eq!(mapped.children[0].issue_type.as_deref(), Some("Sub-task"));
+    }
+
+    #[test]
+    fn test_map_issue_self_hosted_epic_link() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Story",
+                "customfield_10100": "PROJ-1"
+            },
+            "names": {"customfield_10100": "Epic Link"}
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
+
+        assert_eq!(mapped.parent.unwrap().key, "jira#PROJ-1");
+        assert!(mapped.children.is_empty());
+    }
+
+    #[test]
+    fn test_is_subtask_type() {
+        assert!(is_subtask_type("Sub-task"));
+        assert!(is_subtask_type("Subtask"));
+        assert!(!is_subtask_type("Task"));
+        assert!(!is_subtask_type("Epic"));
+    }
+
+    fn sample_link_types() -> Vec<JiraIssueLinkType> {
+        serde_json::from_value(serde_json::json!([
+            {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+            {"name": "Cloners", "inward": "is cloned by", "outward": "clones"},
+            {"name": "Relates", "inward": "relates to", "outward": "relates to"}
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_map_issue_links() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Story",
+                "issuelinks": [
+                    {
+                        "id": "501",
+                        "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                        "inwardIssue": {
+                            "key": "PROJ-1",
+                            "fields": {"summary": "API", "status": {"name": "In Progress"}}
+                        }
+                    },
+                    {
+                        "id": "502",
+                        "type": {"name": "Cloners", "inward": "is cloned by", "outward": "clones"},
+                        "outwardIssue": {"key": "PROJ-9"}
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::Cloud,
+            "https://test.atlassian.net",
+            &issue.names,
+        );
+
+        assert_eq!(
+            mapped.links,
+            vec![
+                IssueLink {
+                    kind: "blocked_by".to_string(),
+                    target: IssueRef {
+                        key: "jira#PROJ-1".to_string(),
+                        title: Some("API".to_string()),
+                        state: Some("In Progress".to_string()),
+                        issue_type: None,
+                    },
+                    id: Some("501".to_string()),
+                },
+                IssueLink {
+                    kind: "clones".to_string(),
+                    target: IssueRef {
+                        key: "jira#PROJ-9".to_string(),
+                        ..Default::default()
+                    },
+                    id: Some("502".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_issue_attachments() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Crash on start",
+                "attachment": [{
+                    "id": "10500",
+                    "filename": "crash.log",
+                    "size": 2048,
+                    "mimeType": "text/plain",
+                    "content": "https://jira.example.com/secure/attachment/10500/crash.log",
+                    "created": "2024-01-15T10:30:00.000+0000"
+                }]
+            }
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
+
+        assert_eq!(
+            mapped.attachments,
+            vec![Attachment {
+                id: "10500".to_string(),
+                name: "crash.log".to_string(),
+                size: Some(2048),
+                mime_type: Some("text/plain".to_string()),
+                url: "https://jira.example.com/secure/attachment/10500/crash.log".to_string(),
+                created_at: Some("2024-01-15T10:30:00.000+0000".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_map_issue_time_tracking() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Crash on start",
+                "timeoriginalestimate": 14400,
+                "timespent": 5400
+            }
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
+
+        assert_eq!(mapped.estimate_seconds, Some(14400));
+        assert_eq!(mapped.time_spent_seconds, Some(5400));
+        assert!(mapped.custom_fields.is_empty());
+    }
+
+    #[test]
+    fn test_map_issue_fix_version() {
+        let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+            "id": "10001",
+            "key": "PROJ-2",
+            "fields": {
+                "summary": "Crash on start",
+                "fixVersions": [
+                    {"id": "10200", "name": "2.3", "released": true, "releaseDate": "2024-01-10"},
+                    {"id": "10201", "name": "2.4", "released": false, "archived": false, "releaseDate": "2024-03-01"}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let mapped = map_issue(
+            &issue,
+            JiraFlavor::SelfHosted,
+            "https://jira.example.com",
+            &issue.names,
+        );
+
+        let milestone = mapped.milestone.unwrap();
+        assert_eq!(milestone.id, "10201");
+        assert_eq!(milestone.title, "2.4");
+        assert_eq!(milestone.kind, "version");
+        assert_eq!(milestone.state, "open");
+        assert_eq!(milestone.due_date.as_deref(), Some("2024-03-01"));
+        assert_eq!(
+            milestone.url.as_deref(),
+            Some("https://jira.example.com/browse/PROJ/fixforversion/10201")
+        );
+    }
+
+    #[test]
+    fn test_worklog_started() {
+        assert_eq!(
+            worklog_started(Some("2024-01-15T12:00:00+02:00")).unwrap(),
+            "2024-01-15T10:00:00.000+0000"
+        );
+        assert_eq!(
+            worklog_started(Some("2024-01-15")).unwrap(),
+            "2024-01-15T00:00:00.000+0000"
+        );
+        assert!(worklog_started(None).unwrap().ends_with(".000+0000"));
+        assert!(worklog_started(Some("yesterday")).is_err());
+    }
+
+    #[test]
+    fn test_find_link_type() {
+        let types = sample_link_types();
+        assert_eq!(
+            find_link_type(&types, "blocks").unwrap(),
+            ("Blocks".to_string(), true)
+        );
+        assert_eq!(
+            find_link_type(&types, "Blocked by").unwrap(),
+            ("Blocks".to_string(), false)
+        );
+        assert_eq!(
+            find_link_type(&types, "is_cloned_by").unwrap(),
+            ("Cloners".to_string(), false)
+        );
+        assert_eq!(
+            find_link_type(&types, "relates_to").unwrap(),
+            ("Relates".to_string(), true)
+        );
+        assert_eq!(
+            find_link_type(&types, "Cloners").unwrap(),
+            ("Cloners".to_string(), true)
+        );
+
+        let err = find_link_type(&types, "duplicates")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown Jira link kind 'duplicates'"));
+        assert!(err.contains("blocked_by"));
+    }
+
+    #[test]
+    fn test_map_comment() {
+        let comment = JiraComment {
+            id: "100".to_string(),
+            body: Some(serde_json::Value::String("Nice work!".to_string())),
+            author: Some(sample_jira_user_self_hosted()),
+            created: Some("2024-01-01T10:00:00.000+0000".to_string()),
+            updated: Some("2024-01-01T11:00:00.000+0000".to_string()),
+        };
+
+        let mapped = map_comment(&comment, JiraFlavor::SelfHosted);
+        assert_eq!(mapped.id, "100");
+        assert_eq!(mapped.body, "Nice work!");
+        assert!(mapped.author.is_some());
+        assert_eq!(mapped.author.unwrap().username, "jdoe");
+    }
+
+    #[test]
+    fn test_map_comment_self_hosted_wiki() {
+        let comment = JiraComment {
+            id: "101".to_string(),
+            body: Some(serde_json::Value::String(
+                "*Fixed* in {{v2}}, see [PR|https://git.example.com/pr/1]".to_string(),
+            )),
+            author: None,
+            created: None,
+            updated: None,
+        };
+
+        let mapped = map_comment(&comment, JiraFlavor::SelfHosted);
+        assert_eq!(
+            mapped.body,
+            "**Fixed** in `v2`, see [PR](https://git.example.com/pr/1)"
+        );
     }
 
     #[test]
@@ -1667,6 +3187,177 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_jira_key() {
+        assert_eq!(parse_jira_key("jira#PROJ-1"), "PROJ-1");
+        assert_eq!(parse_jira_key("PROJ-1"), "PROJ-1");
+        assert_eq!(
+            parse_jira_key("https://company.atlassian.net/browse/PROJ-1?focusedCommentId=3"),
+            "PROJ-1"
+        );
+    }
+
+    #[test]
+    fn test_key_patterns_claim_instance_urls() {
+        let client = JiraClient::new("https://company.atlassian.net", "PROJ", "a@b.c", "token");
+        let patterns = client.key_patterns();
+        let claims = |key: &str| patterns.iter().any(|p| p.matches(key));
+        assert!(claims("https://company.atlassian.net/browse/OTHER-2"));
+        assert!(!claims("https://other.atlassian.net/browse/PROJ-1"));
+    }
+
+    #[test]
+    fn test_map_custom_fields() {
+        let fields: HashMap<String, Value> = serde_json::from_value(serde_json::json!({
+            "summary": "Ignored",
+            "customfield_10016": 5,
+            "customfield_10020": {"id": "1", "value": "Backend"},
+            "customfield_10030": [{"accountId": "abc"}, {"accountId": "def"}],
+            "customfield_10040": null,
+            "customfield_10050": [],
+            "customfield_10060": {
+                "type": "doc",
+                "version": 1,
+                "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Notes"}]}]
+            }
+        }))
+        .unwrap();
+        let names: HashMap<String, String> = [
+            ("customfield_10016", "Story Points"),
+            ("customfield_10020", "Team"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let mapped = map_custom_fields(&fields, &names, JiraFlavor::Cloud);
+
+        assert_eq!(mapped.len(), 4);
+        assert_eq!(mapped["Story Points"], serde_json::json!(5));
+        assert_eq!(mapped["Team"], serde_json::json!("Backend"));
+        assert_eq!(
+            mapped["customfield_10030"],
+            serde_json::json!(["abc", "def"])
+        );
+        assert_eq!(mapped["customfield_10060"], serde_json::json!("Notes"));
+    }
+
+    #[test]
+    fn test_custom_field_type() {
+        let schema = |field_type: &str, items: Option<&str>| JiraFieldSchema {
+            field_type: field_type.to_string(),
+            items: items.map(str::to_string),
+            custom: None,
+        };
+
+        assert_eq!(custom_field_type(Some(&schema("number", None))), "number");
+        assert_eq!(custom_field_type(Some(&schema("option", None))), "option");
+        assert_eq!(
+            custom_field_type(Some(&schema("array", Some("option")))),
+            "multi_option"
+        );
+        assert_eq!(
+            custom_field_type(Some(&schema("array", Some("user")))),
+            "multi_user"
+        );
+        assert_eq!(custom_field_type(Some(&schema("sd-sla", None))), "other");
+        assert_eq!(custom_field_type(None), "other");
+    }
+
+    #[test]
+    fn test_custom_field_to_jira() {
+        let field = |field_type: &str| CustomField {
+            id: "customfield_1".to_string(),
+            name: "Field".to_string(),
+            field_type: field_type.to_string(),
+            ..Default::default()
+        };
+        let cloud = JiraFlavor::Cloud;
+
+        assert_eq!(
+            custom_field_to_jira(&field("number"), None, &serde_json::json!("3"), cloud).unwrap(),
+            serde_json::json!(3)
+        );
+        assert_eq!(
+            custom_field_to_jira(&field("option"), None, &serde_json::json!("High"), cloud)
+                .unwrap(),
+            serde_json::json!({"value": "High"})
+        );
+        assert_eq!(
+            custom_field_to_jira(&field("multi_option"), None, &serde_json::json!("A"), cloud)
+                .unwrap(),
+            serde_json::json!([{"value": "A"}])
+        );
+        assert_eq!(
+            custom_field_to_jira(&field("user"), None, &serde_json::json!("jdoe"), cloud).unwrap(),
+            serde_json::json!({"accountId": "jdoe"})
+        );
+        assert_eq!(
+            custom_field_to_jira(
+                &field("multi_user"),
+                None,
+                &serde_json::json!(["jdoe"]),
+                JiraFlavor::SelfHosted
+            )
+            .unwrap(),
+            serde_json::json!([{"name": "jdoe"}])
+        );
+        assert_eq!(
+            custom_field_to_jira(
+                &field("date"),
+                None,
+                &serde_json::json!("2024-03-01"),
+                cloud
+            )
+            .unwrap(),
+            serde_json::json!("2024-03-01")
+        );
+        assert_eq!(
+            custom_field_to_jira(&field("option"), None, &Value::Null, cloud).unwrap(),
+            Value::Null
+        );
+
+        let err = custom_field_to_jira(&field("number"), None, &serde_json::json!("lots"), cloud)
+            .unwrap_err();
+        assert!(err.to_string().contains("expects a number"), "{}", err);
+        let err = custom_field_to_jira(&field("date"), None, &serde_json::json!("March 1"), cloud)
+            .unwrap_err();
+        assert!(err.to_string().contains("YYYY-MM-DD"), "{}", err);
+    }
+
+    #[test]
+    fn test_custom_field_to_jira_textarea_uses_adf() {
+        let field = CustomField {
+            id: "customfield_1".to_string(),
+            name: "Notes".to_string(),
+            field_type: "text".to_string(),
+            ..Default::default()
+        };
+        let schema = JiraFieldSchema {
+            field_type: "string".to_string(),
+            items: None,
+            custom: Some("com.atlassian.jira.plugin.system.customfieldtypes:textarea".to_string()),
+        };
+
+        let value = custom_field_to_jira(
+            &field,
+            Some(&schema),
+            &serde_json::json!("**Hi**"),
+            JiraFlavor::Cloud,
+        )
+        .unwrap();
+        assert_eq!(value["type"], "doc");
+
+        let value = custom_field_to_jira(
+            &field,
+            Some(&schema),
+            &serde_json::json!("**Hi**"),
+            JiraFlavor::SelfHosted,
+        )
+        .unwrap();
+        assert_eq!(value, serde_json::json!("*Hi*"));
+    }
+
     // =========================================================================
     // Integration tests with httpmock
     // =========================================================================
@@ -1795,7 +3486,8 @@ mod tests {
                 when.method(GET)
                     .path("/search")
                     .query_param_includes("jql", "labels = \"bug\"")
-                    .query_param_includes("jql", "assignee = \"jdoe\"");
+                    .query_param_includes("jql", "assignee = \"jdoe\"")
+                    .query_param_includes("jql", "fixVersion = \"2.4\"");
                 then.status(200).json_body(serde_json::json!({
                     "issues": [sample_issue_json()],
                     "startAt": 0,
@@ -1809,6 +3501,7 @@ mod tests {
                 .get_issues(IssueFilter {
                     labels: Some(vec!["bug".to_string()]),
                     assignee: Some("jdoe".to_string()),
+                    milestone: Some("2.4".to_string()),
                     ..Default::default()
                 })
                 .await
@@ -1899,6 +3592,10 @@ mod tests {
                     labels: vec![],
                     assignees: vec![],
                     priority: None,
+                    custom_fields: Default::default(),
+                    issue_type: None,
+                    parent: None,
+                    container: None,
                 })
                 .await
                 .unwrap();
@@ -2028,6 +3725,53 @@ mod tests {
             }])
         }
 
+        #[tokio::test]
+        async fn test_list_statuses() {
+            let server = MockServer::start();
+            mock_project_statuses(&server, sample_project_statuses_json());
+
+            let client = create_self_hosted_client(&server);
+            let statuses = client.list_statuses().await.unwrap();
+
+            let names: Vec<(&str, &str)> = statuses
+                .iter()
+                .map(|s| (s.name.as_str(), s.category.as_str()))
+                .collect();
+            assert_eq!(
+                names,
+                vec![
+                    ("Offen", "todo"),
+                    ("In Bearbeitung", "in_progress"),
+                    ("Erledigt", "done"),
+                    ("Abgebrochen", "done")
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_labels() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/label").query_param("startAt", "0");
+                then.status(200).json_body(serde_json::json!({
+                    "values": ["backend", "frontend"],
+                    "isLast": true
+                }));
+            });
+
+            let client = create_cloud_client(&server);
+            let labels = client.list_labels().await.unwrap();
+            assert_eq!(labels.len(), 2);
+            assert_eq!(labels[0].name, "backend");
+
+            let client = create_self_hosted_client(&server);
+            assert!(matches!(
+                client.list_labels().await,
+                Err(Error::ProviderUnsupported { .. })
+            ));
+        }
+
         #[tokio::test]
         async fn test_update_issue_generic_closed_maps_to_done_category() {
             let server = MockServer::start();
@@ -2435,58 +4179,226 @@ mod tests {
         }
 
         #[tokio::test]
-        async fn test_cloud_create_issue_adf() {
+        async fn test_cloud_get_issues_resolves_assignee() {
             let server = MockServer::start();
 
-            // Verify ADF in request body
-            server.mock(|when, then| {
-                when.method(POST)
-                    .path("/issue")
-                    .body_includes("\"type\":\"doc\"")
-                    .body_includes("\"version\":1");
-                then.status(201).json_body(serde_json::json!({
-                    "id": "10003",
-                    "key": "PROJ-3"
-                }));
+            let user_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user/assignable/search")
+                    .query_param("project", "PROJ")
+                    .query_param("query", "jane@example.com");
+                then.status(200).json_body(serde_json::json!([{
+                    "accountId": "5b10ac8d82e05b22cc7d4ef5",
+                    "displayName": "Jane Doe",
+                    "emailAddress": "jane@example.com"
+                }]));
+            });
+            let search_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/jql")
+                    .query_param_includes("jql", "assignee = \"5b10ac8d82e05b22cc7d4ef5\"");
+                then.status(200)
+                    .json_body(serde_json::json!({"issues": []}));
             });
 
-            server.mock(|when, then| {
-                when.method(GET).path("/issue/PROJ-3");
+            let client = create_cloud_client(&server);
+            for assignee in [
+                "jane@example.com",
+                "jane@example.com",
+                "5b10ac8d82e05b22cc7d4ef5",
+            ] {
+                client
+                    .get_issues(IssueFilter {
+                        assignee: Some(assignee.to_string()),
+                        ..Default::default()
+                    })
+                    .await
+                    .unwrap();
+            }
+
+            search_mock.assert_calls(3);
+            // Cached after the first lookup, account IDs are not looked up
+            user_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_me_assignee() {
+            let server = MockServer::start();
+
+            let search_mock = server.mock(|when, then| {
+                when.method(GET)
+                    .path("/search/jql")
+                    .query_param_includes("jql", "assignee = currentUser()");
+                then.status(200)
+                    .json_body(serde_json::json!({"issues": []}));
+            });
+            let myself_mock = server.mock(|when, then| {
+                when.method(GET).path("/myself");
                 then.status(200).json_body(serde_json::json!({
-                    "id": "10003",
-                    "key": "PROJ-3",
-                    "fields": {
-                        "summary": "Cloud task",
-                        "description": {
-                            "version": 1,
-                            "type": "doc",
-                            "content": [{
-                                "type": "paragraph",
-                                "content": [{"type": "text", "text": "Cloud description"}]
-                            }]
-                        },
-                        "status": {"name": "To Do"},
-                        "labels": []
-                    }
+                    "accountId": "5b10ac8d82e05b22cc7d4ef5",
+                    "displayName": "Jane Doe"
                 }));
             });
 
             let client = create_cloud_client(&server);
-            let issue = client
-                .create_issue(CreateIssueInput {
-                    title: "Cloud task".to_string(),
-                    description: Some("Cloud description".to_string()),
+            client
+                .get_issues(IssueFilter {
+                    assignee: Some("@me".to_string()),
                     ..Default::default()
                 })
                 .await
                 .unwrap();
+            search_mock.assert();
+            myself_mock.assert_calls(0);
+
+            for _ in 0..2 {
+                assert_eq!(
+                    client.resolve_user("@me").await.unwrap(),
+                    "5b10ac8d82e05b22cc7d4ef5"
+                );
+            }
+            myself_mock.assert_calls(1);
+        }
 
-            assert_eq!(issue.key, "jira#PROJ-3");
-            assert_eq!(issue.description, Some("Cloud description".to_string()));
+        #[tokio::test]
+        async fn test_search_users() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user/assignable/search")
+                    .query_param("username", "jdoe");
+                then.status(200).json_body(serde_json::json!([
+                    {"name": "jdoe", "displayName": "John Doe"}
+                ]));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let users = client.search_users("@jdoe").await.unwrap();
+
+            assert_eq!(users.len(), 1);
+            assert_eq!(users[0].username, "jdoe");
         }
 
         #[tokio::test]
-        async fn test_cloud_add_comment_adf() {
+        async fn test_cloud_resolve_user_errors() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user/assignable/search")
+                    .query_param("query", "doe");
+                then.status(200).json_body(serde_json::json!([
+                    {"accountId": "557058:1", "displayName": "John Doe"},
+                    {"accountId": "557058:2", "displayName": "Jane Doe"}
+                ]));
+            });
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user/assignable/search")
+                    .query_param("query", "nobody");
+                then.status(200).json_body(serde_json::json!([]));
+            });
+
+            let client = create_cloud_client(&server);
+            let err = client.resolve_user("doe").await.unwrap_err().to_string();
+            assert!(err.contains("matches several users"));
+            assert!(err.contains("557058:1 (John Doe, id 557058:1)"));
+
+            let err = client.resolve_user("nobody").await.unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        #[tokio::test]
+        async fn test_self_hosted_create_issue_resolves_assignee() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/user/assignable/search")
+                    .query_param("username", "Jane Doe");
+                then.status(200).json_body(serde_json::json!([
+                    {"name": "jdoe", "displayName": "Jane Doe"}
+                ]));
+            });
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"assignee\":{\"name\":\"jdoe\"}");
+                then.status(201)
+                    .json_body(serde_json::json!({"id": "10002", "key": "PROJ-2"}));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/PROJ-2");
+                then.status(200).json_body(sample_issue_json());
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .create_issue(CreateIssueInput {
+                    title: "New task".to_string(),
+                    assignees: vec!["Jane Doe".to_string()],
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_cloud_create_issue_adf() {
+            let server = MockServer::start();
+
+            // Verify ADF in request body
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"type\":\"doc\"")
+                    .body_includes("\"version\":1");
+                then.status(201).json_body(serde_json::json!({
+                    "id": "10003",
+                    "key": "PROJ-3"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/PROJ-3");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10003",
+                    "key": "PROJ-3",
+                    "fields": {
+                        "summary": "Cloud task",
+                        "description": {
+                            "version": 1,
+                            "type": "doc",
+                            "content": [{
+                                "type": "paragraph",
+                                "content": [{"type": "text", "text": "Cloud description"}]
+                            }]
+                        },
+                        "status": {"name": "To Do"},
+                        "labels": []
+                    }
+                }));
+            });
+
+            let client = create_cloud_client(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "Cloud task".to_string(),
+                    description: Some("Cloud description".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(issue.key, "jira#PROJ-3");
+            assert_eq!(issue.description, Some("Cloud description".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_cloud_add_comment_adf() {
             let server = MockServer::start();
 
             server.mock(|when, then| {
@@ -2726,6 +4638,161 @@ mod tests {
             );
         }
 
+        fn mock_transitions_with_fields(server: &MockServer) {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/issue/PROJ-1/transitions")
+                    .query_param("expand", "transitions.fields");
+                then.status(200).json_body(serde_json::json!({
+                    "transitions": [
+                        {
+                            "id": "21",
+                            "name": "Start Progress",
+                            "to": {
+                                "name": "In Progress",
+                                "statusCategory": {"key": "indeterminate"}
+                            }
+                        },
+                        {
+                            "id": "31",
+                            "name": "Done",
+                            "to": {
+                                "name": "Done",
+                                "statusCategory": {"key": "done"}
+                            },
+                            "fields": {
+                                "resolution": {
+                                    "required": true,
+                                    "name": "Resolution",
+                                    "schema": {"type": "resolution", "system": "resolution"},
+                                    "allowedValues": [
+                                        {"id": "1", "name": "Fixed"},
+                                        {"id": "2", "name": "Won't Fix"}
+                                    ],
+                                    "hasDefaultValue": false
+                                },
+                                "fixVersions": {
+                                    "required": false,
+                                    "name": "Fix versions",
+                                    "schema": {"type": "array", "items": "version"},
+                                    "allowedValues": [
+                                        {"id": "10000", "name": "1.0"},
+                                        {"id": "10001", "name": "1.1"}
+                                    ],
+                                    "hasDefaultValue": false
+                                }
+                            }
+                        }
+                    ]
+                }));
+            });
+        }
+
+        #[tokio::test]
+        async fn test_get_transitions_with_fields() {
+            let server = MockServer::start();
+            mock_transitions_with_fields(&server);
+
+            let client = create_self_hosted_client(&server);
+            let transitions = client.get_transitions("jira#PROJ-1").await.unwrap();
+
+            assert_eq!(transitions.len(), 2);
+            assert_eq!(transitions[0].to_category, "in_progress");
+            assert!(transitions[0].fields.is_empty());
+
+            let done = &transitions[1];
+            assert_eq!(done.id, "31");
+            assert_eq!(done.to_category, "done");
+            assert_eq!(done.fields.len(), 2);
+            assert_eq!(done.fields[0].id, "resolution");
+            assert_eq!(done.fields[0].field_type, "option");
+            assert!(done.fields[0].required);
+            assert_eq!(done.fields[0].options, vec!["Fixed", "Won't Fix"]);
+            assert_eq!(done.fields[1].name, "Fix versions");
+            assert_eq!(done.fields[1].field_type, "multi_option");
+            assert!(!done.fields[1].required);
+        }
+
+        #[tokio::test]
+        async fn test_transition_issue_with_fields_and_comment() {
+            let server = MockServer::start();
+            mock_transitions_with_fields(&server);
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue/PROJ-1/transitions")
+                    .body_includes("\"transition\":{\"id\":\"31\"}")
+                    .body_includes("\"resolution\":{\"id\":\"1\"}")
+                    .body_includes("\"fixVersions\":[{\"id\":\"10001\"}]")
+                    .body_includes("\"comment\":[{\"add\":{\"body\":\"Released\"}}]");
+                then.status(204);
+            });
+
+            let client = create_self_hosted_client(&server);
+            let mut fields = serde_json::Map::new();
+            fields.insert("Resolution".into(), serde_json::json!("fixed"));
+            fields.insert("fixVersions".into(), serde_json::json!(["1.1"]));
+            client
+                .transition_issue(
+                    "PROJ-1",
+                    TransitionIssueInput {
+                        transition: "done".to_string(),
+                        fields,
+                        comment: Some("Released".to_string()),
+                    },
+                )
+                .await
+                .unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_transition_issue_validation_errors() {
+            let server = MockServer::start();
+            mock_transitions_with_fields(&server);
+            let client = create_self_hosted_client(&server);
+
+            let transition = |name: &str, fields: serde_json::Value| TransitionIssueInput {
+                transition: name.to_string(),
+                fields: fields.as_object().cloned().unwrap_or_default(),
+                comment: None,
+            };
+
+            let err = client
+                .transition_issue("PROJ-1", transition("31", serde_json::json!({})))
+                .await
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains("Transition 'Done' requires Resolution"),
+                "got: {}",
+                err
+            );
+
+            let err = client
+                .transition_issue(
+                    "PROJ-1",
+                    transition("Done", serde_json::json!({"resolution": "Fixd"})),
+                )
+                .await
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains("Unknown Resolution value 'Fixd', did you mean 'Fixed'?"),
+                "got: {}",
+                err
+            );
+
+            let err = client
+                .transition_issue("PROJ-1", transition("Finish", serde_json::json!({})))
+                .await
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("Unknown transition 'Finish'"), "got: {}", err);
+            assert!(err.contains("Done → Done (id 31)"), "got: {}", err);
+        }
+
         #[tokio::test]
         async fn test_cloud_get_issues_pagination_next_page_token() {
             let server = MockServer::start();
@@ -2799,6 +4866,852 @@ mod tests {
             assert_eq!(issues[2].key, "jira#PROJ-3");
         }
 
+        // =================================================================
+        // Agile API tests
+        // =================================================================
+
+        #[tokio::test]
+        async fn test_get_boards() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/rest/agile/1.0/board")
+                    .query_param("projectKeyOrId", "PROJ")
+                    .query_param("startAt", "0");
+                then.status(200).json_body(serde_json::json!({
+                    "isLast": true,
+                    "values": [{
+                        "id": 7,
+                        "name": "PROJ board",
+                        "type": "scrum",
+                        "location": {"projectKey": "PROJ"}
+                    }]
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let boards = client.get_boards().await.unwrap();
+
+            assert_eq!(boards.len(), 1);
+            assert_eq!(boards[0].id, "7");
+            assert_eq!(boards[0].kind, "scrum");
+            assert_eq!(boards[0].project_key, Some("PROJ".to_string()));
+        }
+
+        #[tokio::test]
+        async fn test_get_sprints_paginated() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/rest/agile/1.0/board/7/sprint")
+                    .query_param("state", "active,future")
+                    .query_param("startAt", "0");
+                then.status(200).json_body(serde_json::json!({
+                    "isLast": false,
+                    "values": [{
+                        "id": 37,
+                        "name": "Sprint 12",
+                        "state": "active",
+                        "goal": "Ship login",
+                        "originBoardId": 7,
+                        "startDate": "2024-01-01T09:00:00.000Z",
+                        "endDate": "2024-01-14T18:00:00.000Z"
+                    }]
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/rest/agile/1.0/board/7/sprint")
+                    .query_param("startAt", "1");
+                then.status(200).json_body(serde_json::json!({
+                    "isLast": true,
+                    "values": [{"id": 38, "name": "Sprint 13", "state": "future", "goal": ""}]
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let sprints = client
+                .get_sprints("7", Some("active,future"))
+                .await
+                .unwrap();
+
+            assert_eq!(sprints.len(), 2);
+            assert_eq!(sprints[0].id, "37");
+            assert_eq!(sprints[0].goal, Some("Ship login".to_string()));
+            assert_eq!(sprints[0].board_id, Some("7".to_string()));
+            assert_eq!(sprints[1].state, "future");
+            assert_eq!(sprints[1].goal, None);
+        }
+
+        #[tokio::test]
+        async fn test_get_sprint_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/rest/agile/1.0/sprint/37/issue");
+                then.status(200).json_body(serde_json::json!({
+                    "issues": [sample_issue_json()],
+                    "startAt": 0,
+                    "maxResults": 100,
+                    "total": 1
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let issues = client.get_sprint_issues("37").await.unwrap();
+
+            assert_eq!(issues.len(), 1);
+            assert_eq!(issues[0].key, "jira#PROJ-1");
+        }
+
+        #[tokio::test]
+        async fn test_move_issues_to_sprint() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/rest/agile/1.0/sprint/37/issue")
+                    .json_body(serde_json::json!({"issues": ["PROJ-1", "PROJ-2"]}));
+                then.status(204);
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .move_issues_to_sprint("37", &["jira#PROJ-1".to_string(), "PROJ-2".to_string()])
+                .await
+                .unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_move_issues_to_backlog_in_batches() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST).path("/rest/agile/1.0/backlog/issue");
+                then.status(204);
+            });
+
+            let keys: Vec<String> = (1..=60).map(|i| format!("PROJ-{}", i)).collect();
+            let client = create_self_hosted_client(&server);
+            client.move_issues_to_backlog(&keys).await.unwrap();
+
+            mock.assert_calls(2);
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_with_raw_jql() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(GET).path("/search").query_param(
+                    "jql",
+                    "project = \"PROJ\" AND statusCategory != Done AND (fixVersion = 2.3 OR component = API) ORDER BY rank ASC",
+                );
+                then.status(200).json_body(serde_json::json!({
+                    "issues": [sample_issue_json()],
+                    "startAt": 0,
+                    "maxResults": 20,
+                    "total": 1
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let issues = client
+                .get_issues(IssueFilter {
+                    state: Some("open".to_string()),
+                    query: Some(
+                        "fixVersion = 2.3 OR component = API order by rank ASC".to_string(),
+                    ),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            mock.assert();
+            assert_eq!(issues.len(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_get_issues_with_invalid_jql() {
+            let server = MockServer::start();
+            let client = create_self_hosted_client(&server);
+
+            let result = client
+                .get_issues(IssueFilter {
+                    query: Some("summary ~ \"login".to_string()),
+                    ..Default::default()
+                })
+                .await;
+
+            assert!(matches!(result, Err(Error::InvalidData(_))));
+        }
+
+        #[tokio::test]
+        async fn test_create_subtask() {
+            let server = MockServer::start();
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"issuetype\":{\"name\":\"Sub-task\"}")
+                    .body_includes("\"parent\":{\"key\":\"PROJ-1\"}");
+                then.status(201).json_body(serde_json::json!({
+                    "id": "10002",
+                    "key": "PROJ-2"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/PROJ-2");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10002",
+                    "key": "PROJ-2",
+                    "fields": {
+                        "summary": "Subtask",
+                        "issuetype": {"name": "Sub-task", "subtask": true},
+                        "parent": {"key": "PROJ-1", "fields": {"summary": "Parent"}}
+                    }
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "Subtask".to_string(),
+                    issue_type: Some("Sub-task".to_string()),
+                    parent: Some("jira#PROJ-1".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+            assert_eq!(issue.parent.unwrap().key, "jira#PROJ-1");
+        }
+
+        #[tokio::test]
+        async fn test_create_self_hosted_epic_and_story() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/field");
+                then.status(200).json_body(serde_json::json!([
+                    {
+                        "id": "customfield_10100",
+                        "name": "Epic Link",
+                        "custom": true,
+                        "schema": {"type": "any", "custom": "com.pyxis.greenhopper.jira:gh-epic-link"}
+                    },
+                    {
+                        "id": "customfield_10101",
+                        "name": "Epic Name",
+                        "custom": true,
+                        "schema": {"type": "string", "custom": "com.pyxis.greenhopper.jira:gh-epic-label"}
+                    }
+                ]));
+            });
+
+            let epic_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"issuetype\":{\"name\":\"Epic\"}")
+                    .body_includes("\"customfield_10101\":\"Login revamp\"");
+                then.status(201)
+                    .json_body(serde_json::json!({"id": "10001", "key": "PROJ-1"}));
+            });
+
+            let story_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"issuetype\":{\"name\":\"Story\"}")
+                    .body_includes("\"customfield_10100\":\"PROJ-1\"");
+                then.status(201)
+                    .json_body(serde_json::json!({"id": "10002", "key": "PROJ-2"}));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path_includes("/issue/PROJ-");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10001",
+                    "key": "PROJ-1",
+                    "fields": {"summary": "Login revamp"}
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .create_issue(CreateIssueInput {
+                    title: "Login revamp".to_string(),
+                    issue_type: Some("Epic".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            client
+                .create_issue(CreateIssueInput {
+                    title: "Login form".to_string(),
+                    issue_type: Some("Story".to_string()),
+                    parent: Some("PROJ-1".to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            epic_mock.assert();
+            story_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_epic_with_children() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/search/jql");
+                then.status(200).json_body(serde_json::json!({
+                    "issues": [{
+                        "id": "10002",
+                        "key": "PROJ-2",
+                        "fields": {
+                            "summary": "Login form",
+                            "status": {"name": "To Do"},
+                            "issuetype": {"name": "Story"}
+                        }
+                    }]
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/PROJ-1");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10001",
+                    "key": "PROJ-1",
+                    "fields": {
+                        "summary": "Login revamp",
+                        "issuetype": {"name": "Epic"}
+                    }
+                }));
+            });
+
+            let client = create_cloud_client(&server);
+            let issue = client.get_issue("jira#PROJ-1").await.unwrap();
+
+            assert_eq!(issue.issue_type.as_deref(), Some("Epic"));
+            assert_eq!(
+                issue.children,
+                vec![IssueRef {
+                    key: "jira#PROJ-2".to_string(),
+                    title: Some("Login form".to_string()),
+                    state: Some("To Do".to_string()),
+                    issue_type: Some("Story".to_string()),
+                }]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_link_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issueLinkType");
+                then.status(200).json_body(serde_json::json!({
+                    "issueLinkTypes": [
+                        {"id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks"}
+                    ]
+                }));
+            });
+
+            // "PROJ-2 is blocked by PROJ-1" is stored as "PROJ-1 blocks PROJ-2"
+            let link_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issueLink")
+                    .json_body(serde_json::json!({
+                        "type": {"name": "Blocks"},
+                        "inwardIssue": {"key": "PROJ-1"},
+                        "outwardIssue": {"key": "PROJ-2"}
+                    }));
+                then.status(201);
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .link_issues("jira#PROJ-2", "jira#PROJ-1", "blocked_by")
+                .await
+                .unwrap();
+
+            link_mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_download_attachment() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/secure/attachment/10500/crash.log")
+                    .header("Authorization", "Bearer pat-token");
+                then.status(200).body("panic at main.rs:1");
+            });
+
+            let client = create_self_hosted_client(&server);
+            let url = format!("{}/secure/attachment/10500/crash.log", server.base_url());
+            let content = client.download_attachment(&url).await.unwrap();
+            assert_eq!(content, b"panic at main.rs:1");
+
+            // Credentials are never sent to other hosts
+            let err = client
+                .download_attachment("https://evil.example.com/secure/attachment/1/x")
+                .await
+                .unwrap_err();
+            assert!(err.to_string().contains("is not an attachment URL"));
+        }
+
+        #[tokio::test]
+        async fn test_upload_attachment() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue/PROJ-2/attachments")
+                    .header("X-Atlassian-Token", "no-check")
+                    .header(
+                        "Content-Type",
+                        "multipart/form-data; boundary=devboy-tools-attachment-7d3b0c4f",
+                    )
+                    .body_includes("name=\"file\"; filename=\"shot.png\"")
+                    .body_includes("Content-Type: image/png");
+                then.status(200).json_body(serde_json::json!([{
+                    "id": "10501",
+                    "filename": "shot.png",
+                    "size": 3,
+                    "mimeType": "image/png",
+                    "content": "https://jira.example.com/secure/attachment/10501/shot.png"
+                }]));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let attachment = client
+                .upload_attachment("jira#PROJ-2", "shot.png", b"png")
+                .await
+                .unwrap();
+
+            mock.assert();
+            assert_eq!(attachment.id, "10501");
+            assert_eq!(attachment.name, "shot.png");
+            assert_eq!(attachment.size, Some(3));
+        }
+
+        #[tokio::test]
+        async fn test_log_time() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue/PROJ-2/worklog")
+                    .json_body(serde_json::json!({
+                        "timeSpentSeconds": 5400,
+                        "started": "2024-01-15T10:00:00.000+0000",
+                        "comment": "Fixed *parser*"
+                    }));
+                then.status(201).json_body(serde_json::json!({
+                    "id": "20001",
+                    "timeSpentSeconds": 5400
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .log_time(
+                    "jira#PROJ-2",
+                    5400,
+                    Some("Fixed **parser**"),
+                    Some("2024-01-15T10:00:00Z"),
+                )
+                .await
+                .unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_get_milestones() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/project/PROJ/versions");
+                then.status(200).json_body(serde_json::json!([
+                    {"id": "10200", "name": "2.3", "released": true, "archived": false},
+                    {
+                        "id": "10201",
+                        "name": "2.4",
+                        "description": "Spring release",
+                        "released": false,
+                        "archived": false,
+                        "startDate": "2024-02-01",
+                        "releaseDate": "2024-03-01"
+                    },
+                    {"id": "10202", "name": "2.5", "released": false, "archived": false}
+                ]));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/version/10202/relatedIssueCounts");
+                then.status(500).body("Internal Server Error");
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/version/10201/relatedIssueCounts");
+                then.status(200).json_body(serde_json::json!({
+                    "issuesFixedCount": 12,
+                    "issuesAffectedCount": 1
+                }));
+            });
+            server.mock(|when, then| {
+                when.method(GET).path("/version/10201/unresolvedIssueCount");
+                then.status(200)
+                    .json_body(serde_json::json!({"issuesUnresolvedCount": 3}));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let milestones = client.get_milestones(None).await.unwrap();
+
+            assert_eq!(milestones.len(), 2);
+            assert_eq!(milestones[0].title, "2.4");
+            assert_eq!(milestones[0].start_date.as_deref(), Some("2024-02-01"));
+            assert_eq!(milestones[0].open_issues, Some(3));
+            assert_eq!(milestones[0].closed_issues, Some(9));
+            // A failed count leaves the counts unknown
+            assert_eq!(milestones[1].title, "2.5");
+            assert_eq!(milestones[1].open_issues, None);
+            assert_eq!(milestones[1].closed_issues, None);
+        }
+
+        #[tokio::test]
+        async fn test_get_time_entries() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/PROJ-2/worklog");
+                then.status(200).json_body(serde_json::json!({
+                    "startAt": 0,
+                    "maxResults": 5000,
+                    "total": 2,
+                    "worklogs": [
+                        {
+                            "id": "20001",
+                            "author": {"name": "jdoe", "displayName": "John Doe"},
+                            "comment": "Fixed *parser*",
+                            "started": "2024-01-15T10:00:00.000+0000",
+                            "timeSpentSeconds": 5400
+                        },
+                        {
+                            "id": "20002",
+                            "started": "2024-01-16T09:00:00.000+0000",
+                            "timeSpentSeconds": 1800
+                        }
+                    ]
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let entries = client.get_time_entries("jira#PROJ-2").await.unwrap();
+
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0].id, "20001");
+            assert_eq!(entries[0].duration_seconds, 5400);
+            assert_eq!(entries[0].author.as_ref().unwrap().username, "jdoe");
+            assert_eq!(entries[0].comment.as_deref(), Some("Fixed **parser**"));
+            assert_eq!(
+                entries[0].started_at.as_deref(),
+                Some("2024-01-15T10:00:00.000+0000")
+            );
+            assert_eq!(entries[1].comment, None);
+        }
+
+        #[tokio::test]
+        async fn test_set_time_estimate() {
+            let server = MockServer::start();
+
+            let mock = server.mock(|when, then| {
+                when.method(PUT)
+                    .path("/issue/PROJ-2")
+                    .json_body(serde_json::json!({
+                        "fields": {"timetracking": {"originalEstimate": "90m"}}
+                    }));
+                then.status(204);
+            });
+
+            let client = create_self_hosted_client(&server);
+            client.set_time_estimate("jira#PROJ-2", 5400).await.unwrap();
+
+            mock.assert();
+        }
+
+        #[tokio::test]
+        async fn test_unlink_issues() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/issue/PROJ-2")
+                    .query_param("fields", "issuelinks");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10002",
+                    "key": "PROJ-2",
+                    "fields": {
+                        "issuelinks": [
+                            {
+                                "id": "501",
+                                "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                                "inwardIssue": {"key": "PROJ-1"}
+                            },
+                            {
+                                "id": "502",
+                                "type": {"name": "Relates", "inward": "relates to", "outward": "relates to"},
+                                "outwardIssue": {"key": "PROJ-1"}
+                            },
+                            {
+                                "id": "503",
+                                "type": {"name": "Blocks", "inward": "is blocked by", "outward": "blocks"},
+                                "outwardIssue": {"key": "PROJ-3"}
+                            }
+                        ]
+                    }
+                }));
+            });
+
+            let delete_mock = server.mock(|when, then| {
+                when.method(DELETE).path("/issueLink/501");
+                then.status(204);
+            });
+
+            let client = create_self_hosted_client(&server);
+            client
+                .unlink_issues("jira#PROJ-2", "jira#PROJ-1", Some("blocked_by"))
+                .await
+                .unwrap();
+            delete_mock.assert();
+
+            let err = client
+                .unlink_issues("jira#PROJ-2", "jira#PROJ-4", None)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::NotFound(_)));
+        }
+
+        fn sample_fields_json() -> serde_json::Value {
+            serde_json::json!([
+                {"id": "summary", "name": "Summary", "custom": false, "schema": {"type": "string"}},
+                {
+                    "id": "customfield_10016",
+                    "name": "Story Points",
+                    "custom": true,
+                    "schema": {"type": "number"}
+                },
+                {
+                    "id": "customfield_10020",
+                    "name": "Team",
+                    "custom": true,
+                    "schema": {"type": "option"}
+                }
+            ])
+        }
+
+        #[tokio::test]
+        async fn test_get_custom_fields() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/createmeta/PROJ/issuetypes");
+                then.status(200).json_body(serde_json::json!({
+                    "values": [
+                        {"id": "1", "name": "Bug"},
+                        {"id": "3", "name": "Task"}
+                    ]
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/createmeta/PROJ/issuetypes/3");
+                then.status(200).json_body(serde_json::json!({
+                    "values": [{
+                        "fieldId": "customfield_10020",
+                        "required": true,
+                        "allowedValues": [
+                            {"id": "1", "value": "Backend"},
+                            {"id": "2", "value": "Frontend"}
+                        ]
+                    }]
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let fields = client.get_custom_fields().await.unwrap();
+
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name, "Story Points");
+            assert_eq!(fields[0].field_type, "number");
+            assert!(!fields[0].required);
+            assert_eq!(fields[1].name, "Team");
+            assert_eq!(fields[1].field_type, "option");
+            assert_eq!(fields[1].options, vec!["Backend", "Frontend"]);
+            assert!(fields[1].required);
+        }
+
+        #[tokio::test]
+        async fn test_get_custom_fields_without_create_meta() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            server.mock(|when, then| {
+                when.method(GET).path("/issue/createmeta/PROJ/issuetypes");
+                then.status(403);
+            });
+
+            let client = create_self_hosted_client(&server);
+            let fields = client.get_custom_fields().await.unwrap();
+
+            assert_eq!(fields.len(), 2);
+            assert!(fields[1].options.is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_create_issue_with_custom_fields() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            let create_mock = server.mock(|when, then| {
+                when.method(POST)
+                    .path("/issue")
+                    .body_includes("\"customfield_10016\":5")
+                    .body_includes("\"customfield_10020\":{\"value\":\"Backend\"}");
+                then.status(201).json_body(serde_json::json!({
+                    "id": "10002",
+                    "key": "PROJ-2"
+                }));
+            });
+
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path("/issue/PROJ-2")
+                    .query_param("expand", "names");
+                then.status(200).json_body(serde_json::json!({
+                    "id": "10002",
+                    "key": "PROJ-2",
+                    "fields": {
+                        "summary": "New task",
+                        "labels": [],
+                        "customfield_10016": 5,
+                        "customfield_10020": {"id": "1", "value": "Backend"}
+                    },
+                    "names": {
+                        "customfield_10016": "Story Points",
+                        "customfield_10020": "Team"
+                    }
+                }));
+            });
+
+            let client = create_self_hosted_client(&server);
+            let mut custom_fields = serde_json::Map::new();
+            custom_fields.insert("story points".to_string(), serde_json::json!("5"));
+            custom_fields.insert("Team".to_string(), serde_json::json!("Backend"));
+            let issue = client
+                .create_issue(CreateIssueInput {
+                    title: "New task".to_string(),
+                    custom_fields,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            create_mock.assert();
+            assert_eq!(issue.custom_fields["Story Points"], serde_json::json!(5));
+            assert_eq!(issue.custom_fields["Team"], serde_json::json!("Backend"));
+        }
+
+        #[tokio::test]
+        async fn test_update_issue_with_unknown_custom_field() {
+            let server = MockServer::start();
+
+            server.mock(|when, then| {
+                when.method(GET).path("/field");
+                then.status(200).json_body(sample_fields_json());
+            });
+
+            let client = create_self_hosted_client(&server);
+            let mut custom_fields = serde_json::Map::new();
+            custom_fields.insert("Severity".to_string(), serde_json::json!("High"));
+            let result = client
+                .update_issue(
+                    "jira#PROJ-1",
+                    UpdateIssueInput {
+                        custom_fields,
+                        ..Default::default()
+                    },
+                )
+                .await;
+
+            let err = result.unwrap_err().to_string();
+            assert!(err.contains("Unknown custom field 'Severity'"), "{}", err);
+            assert!(err.contains("Story Points, Team"), "{}", err);
+        }
+
+        #[test]
+        fn test_split_jql() {
+            assert_eq!(
+                split_jql("  fixVersion = 2.3 AND component = API ").unwrap(),
+                ("fixVersion = 2.3 AND component = API".to_string(), None)
+            );
+            assert_eq!(
+                split_jql("updated >= -7d ORDER BY priority DESC").unwrap(),
+                (
+                    "updated >= -7d".to_string(),
+                    Some("priority DESC".to_string())
+                )
+            );
+            assert_eq!(
+                split_jql("ORDER BY created").unwrap(),
+                (String::new(), Some("created".to_string()))
+            );
+            // "order by" inside a string value is not a clause
+            assert_eq!(
+                split_jql("summary ~ \"sort order by date\"").unwrap().1,
+                None
+            );
+        }
+
+        #[test]
+        fn test_split_jql_invalid() {
+            assert!(split_jql("").is_err());
+            assert!(split_jql("summary ~ \"open").is_err());
+            assert!(split_jql("(a = 1").is_err());
+            assert!(split_jql("a = 1) OR (b = 2").is_err());
+            assert!(split_jql("a = 1 ORDER BY").is_err());
+        }
+
+        #[test]
+        fn test_is_account_id() {
+            assert!(is_account_id("5b10ac8d82e05b22cc7d4ef5"));
+            assert!(is_account_id("557058:f58131cb-b67d-43c7-b30d-6b58d40bd077"));
+            assert!(!is_account_id("jdoe"));
+            assert!(!is_account_id("jane@example.com"));
+        }
+
         #[test]
         fn test_escape_jql() {
             assert_eq!(escape_jql("simple"), "simple");
@@ -5,8 +5,10 @@
 //! Jira does not have merge requests, so MR operations return
 //! `ProviderUnsupported` errors.
 
+mod adf;
 mod client;
 mod types;
+mod wiki;
 
 pub use client::JiraClient;
 pub use types::*;
@@ -3,6 +3,8 @@
 //! These types represent the raw JSON responses from Jira API v2/v3.
 //! They are deserialized and then mapped to unified types.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -39,6 +41,9 @@ pub struct JiraIssue {
     pub key: String,
     /// Issue fields
     pub fields: JiraIssueFields,
+    /// Field names by field ID (requested with `expand=names`)
+    #[serde(default)]
+    pub names: HashMap<String, String>,
 }
 
 /// Jira issue fields.
@@ -71,6 +76,33 @@ pub struct JiraIssueFields {
     /// Updated timestamp
     #[serde(default)]
     pub updated: Option<String>,
+    /// Issue type
+    #[serde(default)]
+    pub issuetype: Option<JiraIssueType>,
+    /// Parent issue (parent of a subtask; also the epic on Cloud)
+    #[serde(default)]
+    pub parent: Option<JiraIssueRef>,
+    /// Subtasks
+    #[serde(default)]
+    pub subtasks: Vec<JiraIssueRef>,
+    /// Links to other issues
+    #[serde(default)]
+    pub issuelinks: Vec<JiraIssueLink>,
+    /// Attached files
+    #[serde(default)]
+    pub attachment: Vec<JiraAttachment>,
+    /// Original estimate in seconds
+    #[serde(default)]
+    pub timeoriginalestimate: Option<u64>,
+    /// Time logged in seconds
+    #[serde(default)]
+    pub timespent: Option<u64>,
+    /// Versions the issue is planned to be fixed in
+    #[serde(default, rename = "fixVersions")]
+    pub fix_versions: Vec<JiraVersion>,
+    /// Remaining fields, including custom fields (`customfield_10016`)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 /// Jira issue status.
@@ -116,6 +148,9 @@ pub struct JiraSearchResponse {
     /// Total number of results
     #[serde(default)]
     pub total: Option<u32>,
+    /// Field names by field ID (requested with `expand=names`)
+    #[serde(default)]
+    pub names: HashMap<String, String>,
 }
 
 /// Search response from Jira Cloud (API v3, GET /search/jql).
@@ -126,12 +161,36 @@ pub struct JiraCloudSearchResponse {
     /// Token for next page
     #[serde(default, rename = "nextPageToken")]
     pub next_page_token: Option<String>,
+    /// Field names by field ID (requested with `expand=names`)
+    #[serde(default)]
+    pub names: HashMap<String, String>,
 }
 
 // =============================================================================
 // Comment
 // =============================================================================
 
+/// Jira attachment, as listed in the `attachment` field and returned by uploads.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraAttachment {
+    /// Attachment ID
+    pub id: String,
+    /// File name
+    pub filename: String,
+    /// Size in bytes
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// MIME type
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// Download URL of the file content
+    pub content: String,
+    /// Created timestamp
+    #[serde(default)]
+    pub created: Option<String>,
+}
+
 /// Jira comment representation.
 #[derive(Debug, Clone, Deserialize)]
 pub struct JiraComment {
@@ -171,6 +230,30 @@ pub struct JiraTransition {
     pub name: String,
     /// Target status
     pub to: JiraStatus,
+    /// Transition screen fields by field ID (with `expand=transitions.fields`)
+    #[serde(default)]
+    pub fields: HashMap<String, JiraTransitionField>,
+}
+
+/// Field of a transition screen.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraTransitionField {
+    /// Whether the field must be set
+    #[serde(default)]
+    pub required: bool,
+    /// Field name
+    #[serde(default)]
+    pub name: String,
+    /// Field value type
+    #[serde(default)]
+    pub schema: Option<JiraFieldSchema>,
+    /// Allowed values of choice fields (`{"id", "name"}` or `{"id", "value"}`)
+    #[serde(default)]
+    pub allowed_values: Vec<serde_json::Value>,
+    /// Whether Jira fills the field when it is not set
+    #[serde(default)]
+    pub has_default_value: bool,
 }
 
 /// Response from GET /issue/{key}/transitions.
@@ -191,6 +274,81 @@ pub struct CreateIssuePayload {
     pub fields: CreateIssueFields,
 }
 
+/// Jira issue type.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraIssueType {
+    /// Issue type name (e.g., "Bug", "Epic", "Sub-task")
+    pub name: String,
+    /// Whether this is a subtask type
+    #[serde(default)]
+    pub subtask: bool,
+}
+
+/// Related issue embedded in `parent` and `subtasks`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraIssueRef {
+    /// Issue key (e.g., "PROJ-123")
+    pub key: String,
+    /// Summary, status and type of the related issue
+    #[serde(default)]
+    pub fields: Option<JiraIssueRefFields>,
+}
+
+/// Fields of a related issue.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraIssueRefFields {
+    /// Summary (title)
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Status
+    #[serde(default)]
+    pub status: Option<JiraStatus>,
+    /// Issue type
+    #[serde(default)]
+    pub issuetype: Option<JiraIssueType>,
+}
+
+/// Link between two issues, as listed in the `issuelinks` field.
+///
+/// Exactly one of `inward_issue` / `outward_issue` is set: the other side is
+/// the issue the link was read from.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssueLink {
+    /// Link ID
+    pub id: String,
+    /// Link type
+    #[serde(rename = "type")]
+    pub link_type: JiraIssueLinkType,
+    /// Linked issue on the inward side (e.g., the blocking issue of "is blocked by")
+    #[serde(default)]
+    pub inward_issue: Option<JiraIssueRef>,
+    /// Linked issue on the outward side (e.g., the blocked issue of "blocks")
+    #[serde(default)]
+    pub outward_issue: Option<JiraIssueRef>,
+}
+
+/// Issue link type.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraIssueLinkType {
+    /// Link type name (e.g., "Blocks")
+    pub name: String,
+    /// Inward description (e.g., "is blocked by")
+    #[serde(default)]
+    pub inward: String,
+    /// Outward description (e.g., "blocks")
+    #[serde(default)]
+    pub outward: String,
+}
+
+/// Response from `/issueLinkType`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssueLinkTypesResponse {
+    /// Configured link types
+    pub issue_link_types: Vec<JiraIssueLinkType>,
+}
+
 /// Fields for creating an issue.
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateIssueFields {
@@ -212,6 +370,42 @@ pub struct CreateIssueFields {
     /// Assignee
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<serde_json::Value>,
+    /// Parent issue (subtask parent, or epic on Cloud)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<IssueKey>,
+    /// Custom field values by field ID
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Issue key reference.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueKey {
+    /// Issue key (e.g., "PROJ-123")
+    pub key: String,
+}
+
+/// Request body for creating an issue link.
+///
+/// The inward issue is the subject of the outward description:
+/// `{"type": "Blocks", "inwardIssue": A, "outwardIssue": B}` means "A blocks B".
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIssueLinkRequest {
+    /// Link type
+    #[serde(rename = "type")]
+    pub link_type: LinkTypeName,
+    /// Issue the outward description applies to
+    pub inward_issue: IssueKey,
+    /// Issue the inward description applies to
+    pub outward_issue: IssueKey,
+}
+
+/// Link type reference by name.
+#[derive(Debug, Clone, Serialize)]
+pub struct LinkTypeName {
+    /// Link type name (e.g., "Blocks")
+    pub name: String,
 }
 
 /// Project key reference.
@@ -260,6 +454,19 @@ pub struct UpdateIssueFields {
     /// Assignee
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<serde_json::Value>,
+    /// Time tracking estimates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timetracking: Option<TimeTrackingFields>,
+    /// Custom field values by field ID
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Time tracking estimates of an issue, in Jira duration format ("90m").
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeTrackingFields {
+    pub original_estimate: String,
 }
 
 /// Request body for transitioning an issue.
@@ -267,6 +474,24 @@ pub struct UpdateIssueFields {
 pub struct TransitionPayload {
     /// Transition to execute
     pub transition: TransitionId,
+    /// Transition screen fields by field ID
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+    /// Comment added with the transition
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<TransitionUpdate>,
+}
+
+/// Operations applied with a transition.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitionUpdate {
+    pub comment: Vec<AddCommentOperation>,
+}
+
+/// Add-comment operation of a transition.
+#[derive(Debug, Clone, Serialize)]
+pub struct AddCommentOperation {
+    pub add: AddCommentPayload,
 }
 
 /// Transition ID reference.
@@ -292,6 +517,94 @@ pub struct AddCommentPayload {
     pub body: serde_json::Value,
 }
 
+// =============================================================================
+// Worklogs
+// =============================================================================
+
+/// Time logged on an issue.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraWorklog {
+    /// Worklog ID
+    pub id: String,
+    /// User who logged the time
+    #[serde(default)]
+    pub author: Option<JiraUser>,
+    /// Comment — plain text (v2) or ADF document (v3)
+    #[serde(default)]
+    pub comment: Option<serde_json::Value>,
+    /// When the work started ("2024-01-15T10:00:00.000+0000")
+    #[serde(default)]
+    pub started: Option<String>,
+    /// Logged time in seconds
+    pub time_spent_seconds: u64,
+}
+
+/// Response from GET /issue/{key}/worklog.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraWorklogsResponse {
+    /// Worklogs
+    #[serde(default)]
+    pub worklogs: Vec<JiraWorklog>,
+}
+
+/// Request body for logging time.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddWorklogPayload {
+    /// Logged time in seconds
+    pub time_spent_seconds: u64,
+    /// When the work started ("2024-01-15T10:00:00.000+0000")
+    pub started: String,
+    /// Comment — plain text (v2) or ADF (v3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<serde_json::Value>,
+}
+
+// =============================================================================
+// Versions
+// =============================================================================
+
+/// Project version (release), used as fix version of issues.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraVersion {
+    /// Version ID
+    pub id: String,
+    /// Version name (e.g., "2.4")
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub released: bool,
+    #[serde(default)]
+    pub archived: bool,
+    /// Start date ("2024-02-01")
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Release date ("2024-03-01")
+    #[serde(default)]
+    pub release_date: Option<String>,
+}
+
+/// Response from GET /version/{id}/relatedIssueCounts.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraVersionIssueCounts {
+    /// Issues with the version as fix version
+    #[serde(default)]
+    pub issues_fixed_count: u32,
+}
+
+/// Response from GET /version/{id}/unresolvedIssueCount.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraVersionUnresolvedCount {
+    /// Unresolved issues with the version as fix version
+    #[serde(default)]
+    pub issues_unresolved_count: u32,
+}
+
 // =============================================================================
 // Project Statuses
 // =============================================================================
@@ -321,3 +634,153 @@ pub struct JiraProjectStatus {
     #[serde(default)]
     pub status_category: Option<JiraStatusCategory>,
 }
+
+// =============================================================================
+// Fields
+// =============================================================================
+
+/// Field metadata from GET /field.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraField {
+    /// Field ID (e.g., "customfield_10016")
+    pub id: String,
+    /// Field name (e.g., "Story Points")
+    pub name: String,
+    /// Whether this is a custom field
+    #[serde(default)]
+    pub custom: bool,
+    /// Value schema
+    #[serde(default)]
+    pub schema: Option<JiraFieldSchema>,
+}
+
+/// Value schema of a field.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JiraFieldSchema {
+    /// Value type (e.g., "number", "string", "option", "array")
+    #[serde(default, rename = "type")]
+    pub field_type: String,
+    /// Item type of array fields (e.g., "option", "user")
+    #[serde(default)]
+    pub items: Option<String>,
+    /// Custom field type key (e.g., "com.atlassian.jira.plugin.system.customfieldtypes:textarea")
+    #[serde(default)]
+    pub custom: Option<String>,
+}
+
+/// Response from GET /issue/createmeta/{project}/issuetypes.
+/// Cloud returns `issueTypes`, Server / Data Center returns `values`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraCreateMetaIssueTypes {
+    #[serde(default, rename = "issueTypes", alias = "values")]
+    pub issue_types: Vec<JiraCreateMetaIssueType>,
+}
+
+/// Issue type of a project.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraCreateMetaIssueType {
+    /// Issue type ID
+    pub id: String,
+    /// Issue type name (e.g., "Task")
+    pub name: String,
+}
+
+/// Response from GET /issue/createmeta/{project}/issuetypes/{id}.
+/// Cloud returns `fields`, Server / Data Center returns `values`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraCreateMetaFields {
+    #[serde(default, alias = "values")]
+    pub fields: Vec<JiraCreateMetaField>,
+}
+
+/// Field of the create screen of an issue type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraCreateMetaField {
+    /// Field ID
+    pub field_id: String,
+    /// Whether the field is required on create
+    #[serde(default)]
+    pub required: bool,
+    /// Allowed values of option fields (`{"value": ...}` or `{"name": ...}`)
+    #[serde(default)]
+    pub allowed_values: Vec<serde_json::Value>,
+}
+
+// =============================================================================
+// Agile (boards and sprints)
+// =============================================================================
+
+/// Paginated response of the Agile API (`/board`, `/board/{id}/sprint`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraAgilePage<T> {
+    /// Items of the page
+    #[serde(default = "Vec::new")]
+    pub values: Vec<T>,
+    /// Whether this is the last page
+    #[serde(default = "default_true")]
+    pub is_last: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Jira agile board.
+#[derive(Debug, Clone, Deserialize)]
+pub struct JiraBoard {
+    /// Board ID
+    pub id: u64,
+    /// Board name
+    pub name: String,
+    /// Board type ("scrum", "kanban", "simple")
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Project the board belongs to
+    #[serde(default)]
+    pub location: Option<JiraBoardLocation>,
+}
+
+/// Location (project) of a Jira board.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraBoardLocation {
+    /// Project key
+    #[serde(default)]
+    pub project_key: Option<String>,
+}
+
+/// Jira sprint.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraSprint {
+    /// Sprint ID
+    pub id: u64,
+    /// Sprint name
+    pub name: String,
+    /// State ("active", "future", "closed")
+    pub state: String,
+    /// Sprint goal
+    #[serde(default)]
+    pub goal: Option<String>,
+    /// Board the sprint was created on
+    #[serde(default)]
+    pub origin_board_id: Option<u64>,
+    /// Start date
+    #[serde(default)]
+    pub start_date: Option<String>,
+    /// Planned end date
+    #[serde(default)]
+    pub end_date: Option<String>,
+    /// Completion date
+    #[serde(default)]
+    pub complete_date: Option<String>,
+}
+
+/// Request body for moving issues to a sprint or the backlog.
+#[derive(Debug, Clone, Serialize)]
+pub struct MoveIssuesPayload {
+    /// Issue keys (at most 50 per request)
+    pub issues: Vec<String>,
+}
@@ -0,0 +1,993 @@
+//! Jira wiki markup ⇄ Markdown conversion for Jira Server / Data Center.
+//!
+//! Self-hosted Jira (API v2) stores descriptions and comments as wiki markup
+//! (`h1.`, `*bold*`, `{code}`, `[text|url]`). Both directions go through ADF,
+//! so self-hosted and Cloud produce the same Markdown: wiki markup is parsed
+//! into an ADF tree and rendered by [`adf_to_markdown`], and Markdown is parsed
+//! by [`markdown_to_adf`] and rendered as wiki markup.
+
+use serde_json::{json, Value};
+
+use crate::adf::{
+    adf_to_markdown, attr, attr_str, children, is_inline, is_url, markdown_to_adf, node_type,
+    panel_type_from_alert, paragraph, push_text, url_end, Marks,
+};
+
+/// Wiki markup line break (`\\`), used where a newline would end the block.
+const LINE_BREAK: &str = "\\\\";
+
+/// Convert Jira wiki markup to Markdown.
+pub fn wiki_to_markdown(wiki: &str) -> String {
+    adf_to_markdown(&wiki_to_adf(wiki))
+}
+
+/// Convert Markdown to Jira wiki markup.
+pub fn markdown_to_wiki(markdown: &str) -> String {
+    adf_to_wiki(&markdown_to_adf(markdown))
+}
+
+// =============================================================================
+// ADF -> wiki markup
+// =============================================================================
+
+fn adf_to_wiki(doc: &Value) -> String {
+    blocks_to_wiki(children(doc))
+}
+
+fn blocks_to_wiki(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(block_to_wiki)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn block_to_wiki(node: &Value) -> String {
+    match node_type(node) {
+        "paragraph" => inlines_to_wiki(children(node), "\n"),
+        "heading" => {
+            let level = attr(node, "level")
+                .and_then(Value::as_u64)
+                .unwrap_or(1)
+                .clamp(1, 6);
+            format!(
+                "h{}. {}",
+                level,
+                inlines_to_wiki(children(node), LINE_BREAK)
+            )
+        }
+        "bulletList" | "orderedList" | "taskList" => list_to_wiki(node, ""),
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|n| n.get("text").and_then(Value::as_str))
+                .collect();
+            match attr_str(node, "language").filter(|l| !l.is_empty()) {
+                Some(language) => format!("{{code:{}}}\n{}\n{{code}}", language, code),
+                None => format!("{{code}}\n{}\n{{code}}", code),
+            }
+        }
+        "blockquote" => format!("{{quote}}\n{}\n{{quote}}", blocks_to_wiki(children(node))),
+        "panel" => {
+            let title = panel_title(attr_str(node, "panelType").unwrap_or("info"));
+            format!(
+                "{{panel:title={}}}\n{}\n{{panel}}",
+                title,
+                blocks_to_wiki(children(node))
+            )
+        }
+        "rule" => "----".to_string(),
+        "table" => table_to_wiki(node),
+        _ if is_inline(node) => inlines_to_wiki(std::slice::from_ref(node), "\n"),
+        _ => blocks_to_wiki(children(node)),
+    }
+}
+
+/// Panel title for a panel type ("warning" -> "Warning").
+fn panel_title(panel_type: &str) -> String {
+    let mut chars = panel_type.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Render a list with wiki prefixes: `*` bullets, `#` numbers, nested as `#*`.
+fn list_to_wiki(node: &Value, prefix: &str) -> String {
+    let marker = if node_type(node) == "orderedList" {
+        '#'
+    } else {
+        '*'
+    };
+    let prefix = format!("{}{}", prefix, marker);
+
+    let mut lines = Vec::new();
+    for item in children(node) {
+        match node_type(item) {
+            // Wiki markup has no checkboxes, keep the state as text
+            "taskItem" => {
+                let check = if attr_str(item, "state") == Some("DONE") {
+                    "x"
+                } else {
+                    " "
+                };
+                lines.push(format!(
+                    "{} [{}] {}",
+                    prefix,
+                    check,
+                    inlines_to_wiki(children(item), LINE_BREAK)
+                ));
+            }
+            "bulletList" | "orderedList" | "taskList" => {
+                lines.push(list_to_wiki(item, &prefix[..prefix.len() - 1]))
+            }
+            _ => {
+                let mut text = Vec::new();
+                let mut nested = Vec::new();
+                for block in children(item) {
+                    match node_type(block) {
+                        "bulletList" | "orderedList" | "taskList" => {
+                            nested.push(list_to_wiki(block, &prefix))
+                        }
+                        "paragraph" => text.push(inlines_to_wiki(children(block), LINE_BREAK)),
+                        _ => text.push(block_to_wiki(block)),
+                    }
+                }
+                lines.push(format!("{} {}", prefix, text.join(LINE_BREAK)));
+                lines.extend(nested);
+            }
+        }
+    }
+    lines.join("\n")
+}
+
+fn table_to_wiki(node: &Value) -> String {
+    children(node)
+        .iter()
+        .filter(|row| node_type(row) == "tableRow")
+        .map(|row| {
+            let mut line = String::new();
+            let mut delimiter = "|";
+            for cell in children(row) {
+                delimiter = if node_type(cell) == "tableHeader" {
+                    "||"
+                } else {
+                    "|"
+                };
+                let text = children(cell)
+                    .iter()
+                    .map(|block| match node_type(block) {
+                        "paragraph" => inlines_to_wiki(children(block), LINE_BREAK),
+                        _ => block_to_wiki(block),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(LINE_BREAK)
+                    .replace('|', "\\|");
+                let text = if text.is_empty() {
+                    " ".to_string()
+                } else {
+                    text
+                };
+                line.push_str(delimiter);
+                line.push_str(&text);
+            }
+            line.push_str(delimiter);
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn inlines_to_wiki(nodes: &[Value], line_break: &str) -> String {
+    nodes
+        .iter()
+        .map(|node| match node_type(node) {
+            "text" => {
+                let text = node.get("text").and_then(Value::as_str).unwrap_or_default();
+                let marks = node
+                    .get("marks")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or(&[]);
+                apply_wiki_marks(text, marks)
+            }
+            "hardBreak" => line_break.to_string(),
+            "mention" => match attr_str(node, "id") {
+                Some(id) => format!("[~{}]", id),
+                None => adf_to_markdown(node),
+            },
+            _ => adf_to_markdown(node),
+        })
+        .collect()
+}
+
+fn apply_wiki_marks(text: &str, marks: &[Value]) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let (mut strong, mut em, mut strike, mut underline, mut code) =
+        (false, false, false, false, false);
+    let mut href = None;
+    for mark in marks {
+        match node_type(mark) {
+            "strong" => strong = true,
+            "em" => em = true,
+            "strike" => strike = true,
+            "underline" => underline = true,
+            "code" => code = true,
+            "link" => href = attr_str(mark, "href"),
+            _ => {}
+        }
+    }
+
+    if code {
+        let code = format!("{{{{{}}}}}", text);
+        return match href {
+            Some(href) => format!("[{}|{}]", code, href),
+            None => code,
+        };
+    }
+
+    // Effect markers must hug the text: "*bold* " rather than "*bold *"
+    let core = text.trim();
+    if core.is_empty() {
+        return text.to_string();
+    }
+    let lead = &text[..text.len() - text.trim_start().len()];
+    let trail = &text[text.trim_end().len()..];
+
+    let mut out = escape_wiki(core);
+    for (active, marker) in [(strike, '-'), (underline, '+'), (em, '_'), (strong, '*')] {
+        if active {
+            out = format!("{}{}{}", marker, out, marker);
+        }
+    }
+    if let Some(href) = href {
+        out = if core == href {
+            format!("[{}]", href)
+        } else {
+            format!("[{}|{}]", out, href)
+        };
+    }
+
+    format!("{}{}{}", lead, out, trail)
+}
+
+/// Escape characters that would start a macro or a link in plain text.
+fn escape_wiki(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == '{' || c == '[' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+// =============================================================================
+// Wiki markup -> ADF
+// =============================================================================
+
+fn wiki_to_adf(wiki: &str) -> Value {
+    let lines: Vec<&str> = wiki.lines().map(str::trim_end).collect();
+    json!({
+        "version": 1,
+        "type": "doc",
+        "content": parse_blocks(&lines)
+    })
+}
+
+/// Block macros that wrap several lines (`{code}...{code}`).
+const BLOCK_MACROS: &[&str] = &[
+    "code", "noformat", "quote", "panel", "info", "note", "tip", "warning",
+];
+
+/// An opening block macro: name, parameters and the text after the tag.
+struct BlockMacro<'a> {
+    name: &'a str,
+    params: &'a str,
+    rest: &'a str,
+}
+
+fn parse_block_macro(trimmed: &str) -> Option<BlockMacro<'_>> {
+    let tag = trimmed.strip_prefix('{')?;
+    let end = tag.find('}')?;
+    let (name, params) = match tag[..end].split_once(':') {
+        Some((name, params)) => (name, params),
+        None => (&tag[..end], ""),
+    };
+    BLOCK_MACROS.contains(&name).then_some(BlockMacro {
+        name,
+        params,
+        rest: &tag[end + 1..],
+    })
+}
+
+/// Look up a macro parameter (`{panel:title=Notes|borderStyle=solid}`).
+fn macro_param<'a>(params: &'a str, key: &str) -> Option<&'a str> {
+    params.split('|').find_map(|param| {
+        let (k, v) = param.split_once('=')?;
+        (k.trim() == key).then_some(v.trim())
+    })
+}
+
+fn is_list_line(trimmed: &str) -> bool {
+    list_markers(trimmed).is_some()
+}
+
+/// The marker prefix and text of a list line ("#* item" -> ("#*", "item")).
+fn list_markers(trimmed: &str) -> Option<(&str, &str)> {
+    let len = if trimmed.starts_with("- ") {
+        1
+    } else {
+        trimmed
+            .chars()
+            .take_while(|c| *c == '*' || *c == '#')
+            .count()
+    };
+    if len == 0 {
+        return None;
+    }
+    let rest = trimmed[len..].strip_prefix(' ')?;
+    Some((&trimmed[..len], rest.trim_start()))
+}
+
+fn parse_heading(trimmed: &str) -> Option<(u32, &str)> {
+    let rest = trimmed.strip_prefix('h')?;
+    let level = rest.chars().next()?.to_digit(10)?;
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let text = rest[1..].strip_prefix('.')?;
+    if !text.is_empty() && !text.starts_with(' ') {
+        return None;
+    }
+    Some((level, text.trim()))
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    trimmed.len() >= 4 && trimmed.chars().all(|c| c == '-')
+}
+
+fn starts_block(trimmed: &str) -> bool {
+    parse_block_macro(trimmed).is_some()
+        || parse_heading(trimmed).is_some()
+        || trimmed.starts_with("bq. ")
+        || is_rule(trimmed)
+        || is_list_line(trimmed)
+        || trimmed.starts_with('|')
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+
+        if trimmed.is_empty() {
+            i += 1;
+        } else if let Some(block_macro) = parse_block_macro(trimmed) {
+            let (block, next) = parse_macro_block(lines, i, block_macro);
+            blocks.push(block);
+            i = next;
+        } else if let Some((level, text)) = parse_heading(trimmed) {
+            blocks.push(json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": parse_inline(text)
+            }));
+            i += 1;
+        } else if let Some(text) = trimmed.strip_prefix("bq. ") {
+            blocks.push(json!({
+                "type": "blockquote",
+                "content": [paragraph(parse_inline(text))]
+            }));
+            i += 1;
+        } else if is_rule(trimmed) {
+            blocks.push(json!({ "type": "rule" }));
+            i += 1;
+        } else if is_list_line(trimmed) {
+            let start = i;
+            while i < lines.len() && is_list_line(lines[i].trim()) {
+                i += 1;
+            }
+            let items: Vec<(&str, &str)> = lines[start..i]
+                .iter()
+                .filter_map(|line| list_markers(line.trim()))
+                .collect();
+            let mut consumed = 0;
+            while consumed < items.len() {
+                let (list, n) = build_list(&items[consumed..], 1);
+                blocks.push(list);
+                consumed += n.max(1);
+            }
+        } else if trimmed.starts_with('|') {
+            let start = i;
+            while i < lines.len() && lines[i].trim().starts_with('|') {
+                i += 1;
+            }
+            blocks.push(parse_table(&lines[start..i]));
+        } else {
+            let start = i;
+            i += 1;
+            while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines[i].trim()) {
+                i += 1;
+            }
+            let mut content = Vec::new();
+            for (n, line) in lines[start..i].iter().enumerate() {
+                if n > 0 {
+                    content.push(json!({ "type": "hardBreak" }));
+                }
+                content.extend(parse_inline(line.trim()));
+            }
+            blocks.push(paragraph(content));
+        }
+    }
+
+    blocks
+}
+
+/// Parse a `{name}...{name}` block starting at line `start`.
+fn parse_macro_block(lines: &[&str], start: usize, block_macro: BlockMacro) -> (Value, usize) {
+    let closing = format!("{{{}}}", block_macro.name);
+    let mut body: Vec<&str> = Vec::new();
+    let mut i = start + 1;
+
+    match block_macro.rest.find(&closing) {
+        // Single-line block: {code}x = 1{code}
+        Some(end) => body.push(&block_macro.rest[..end]),
+        None => {
+            if !block_macro.rest.trim().is_empty() {
+                body.push(block_macro.rest);
+            }
+            while i < lines.len() {
+                if let Some(end) = lines[i].find(&closing) {
+                    if !lines[i][..end].trim().is_empty() {
+                        body.push(&lines[i][..end]);
+                    }
+                    i += 1;
+                    break;
+                }
+                body.push(lines[i]);
+                i += 1;
+            }
+        }
+    }
+
+    let block = match block_macro.name {
+        "code" | "noformat" => {
+            let language = block_macro
+                .params
+                .split('|')
+                .find(|p| !p.contains('=') && !p.trim().is_empty())
+                .or_else(|| macro_param(block_macro.params, "language"))
+                .map(str::trim);
+            let code = body.join("\n");
+            let mut block = json!({ "type": "codeBlock", "content": [] });
+            if let Some(language) = language.filter(|_| block_macro.name == "code") {
+                block["attrs"] = json!({ "language": language });
+            }
+            if !code.is_empty() {
+                block["content"] = json!([{ "type": "text", "text": code }]);
+            }
+            block
+        }
+        "quote" => json!({ "type": "blockquote", "content": non_empty(parse_blocks(&body)) }),
+        name => {
+            let title = macro_param(block_macro.params, "title");
+            let panel_type = match name {
+                "panel" => title.and_then(panel_type_from_alert),
+                other => panel_type_from_alert(other),
+            };
+            let mut content = parse_blocks(&body);
+            // Keep a free-form panel title as a bold first line
+            if let Some(title) = title.filter(|t| panel_type_from_alert(t).is_none()) {
+                let strong = Marks {
+                    strong: true,
+                    ..Marks::default()
+                };
+                let mut heading = Vec::new();
+                push_text(&mut heading, title, &strong);
+                content.insert(0, paragraph(heading));
+            }
+            json!({
+                "type": "panel",
+                "attrs": { "panelType": panel_type.unwrap_or("info") },
+                "content": non_empty(content)
+            })
+        }
+    };
+
+    (block, i)
+}
+
+fn non_empty(mut blocks: Vec<Value>) -> Vec<Value> {
+    if blocks.is_empty() {
+        blocks.push(paragraph(Vec::new()));
+    }
+    blocks
+}
+
+/// Build a (possibly nested) list from marker-prefixed items at `depth`.
+/// Returns the list and the number of items consumed.
+fn build_list(items: &[(&str, &str)], depth: usize) -> (Value, usize) {
+    let ordered = items[0].0.as_bytes().get(depth - 1) == Some(&b'#');
+    let mut list_items: Vec<Value> = Vec::new();
+    let mut i = 0;
+
+    while i < items.len() {
+        let (markers, text) = items[i];
+        if markers.len() < depth {
+            break;
+        }
+        if markers.len() == depth {
+            if (markers.as_bytes()[depth - 1] == b'#') != ordered {
+                break;
+            }
+            list_items.push(json!({
+                "type": "listItem",
+                "content": [paragraph(parse_inline(text))]
+            }));
+            i += 1;
+        } else {
+            let (nested, n) = build_list(&items[i..], depth + 1);
+            if list_items.is_empty() {
+                list_items.push(json!({
+                    "type": "listItem",
+                    "content": [paragraph(Vec::new())]
+                }));
+            }
+            if let Some(content) = list_items
+                .last_mut()
+                .and_then(|item| item["content"].as_array_mut())
+            {
+                content.push(nested);
+            }
+            i += n.max(1);
+        }
+    }
+
+    let list_type = if ordered { "orderedList" } else { "bulletList" };
+    (json!({ "type": list_type, "content": list_items }), i)
+}
+
+fn parse_table(lines: &[&str]) -> Value {
+    let rows: Vec<Value> = lines
+        .iter()
+        .map(|line| {
+            let cells: Vec<Value> = split_table_row(line.trim())
+                .into_iter()
+                .map(|(header, text)| {
+                    let lines: Vec<&str> = text.split(LINE_BREAK).collect();
+                    let mut content = Vec::new();
+                    for (n, line) in lines.iter().enumerate() {
+                        if n > 0 {
+                            content.push(json!({ "type": "hardBreak" }));
+                        }
+                        content.extend(parse_inline(line.trim()));
+                    }
+                    json!({
+                        "type": if header { "tableHeader" } else { "tableCell" },
+                        "attrs": {},
+                        "content": [paragraph(content)]
+                    })
+                })
+                .collect();
+            json!({ "type": "tableRow", "content": cells })
+        })
+        .collect();
+
+    json!({
+        "type": "table",
+        "attrs": { "isNumberColumnEnabled": false, "layout": "default" },
+        "content": rows
+    })
+}
+
+/// Split a table row into `(is_header, text)` cells. Pipes inside links and
+/// `{{monospace}}` do not split cells.
+fn split_table_row(line: &str) -> Vec<(bool, String)> {
+    let mut cells: Vec<(bool, String)> = Vec::new();
+    let mut bracket_depth = 0;
+    let mut in_code = false;
+    let mut i = 0;
+
+    while i < line.len() {
+        let rest = &line[i..];
+        let c = rest.chars().next().expect("index is a char boundary");
+
+        if !in_code && bracket_depth == 0 && c == '|' {
+            let header = rest.starts_with("||");
+            cells.push((header, String::new()));
+            i += if header { 2 } else { 1 };
+            continue;
+        }
+
+        if rest.starts_with("\\|") {
+            if let Some((_, cell)) = cells.last_mut() {
+                cell.push('|');
+            }
+            i += 2;
+            continue;
+        }
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            in_code = rest.starts_with("{{");
+        } else if c == '[' {
+            bracket_depth += 1;
+        } else if c == ']' && bracket_depth > 0 {
+            bracket_depth -= 1;
+        }
+
+        if let Some((_, cell)) = cells.last_mut() {
+            cell.push(c);
+        }
+        i += c.len_utf8();
+    }
+
+    // The closing delimiter opens an empty trailing cell
+    if cells.last().is_some_and(|(_, text)| text.trim().is_empty()) {
+        cells.pop();
+    }
+    cells
+        .into_iter()
+        .map(|(header, text)| (header, text.trim().to_string()))
+        .collect()
+}
+
+// =============================================================================
+// Inline wiki markup -> ADF
+// =============================================================================
+
+fn parse_inline(text: &str) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    parse_inline_into(text, &Marks::default(), &mut nodes);
+    nodes
+}
+
+fn parse_inline_into(text: &str, marks: &Marks, nodes: &mut Vec<Value>) {
+    let mut plain = String::new();
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().expect("index is a char boundary");
+
+        match c {
+            '\\' => {
+                if rest.starts_with(LINE_BREAK) {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    nodes.push(json!({ "type": "hardBreak" }));
+                    i += LINE_BREAK.len();
+                    continue;
+                }
+                if let Some(next) = rest[1..].chars().next().filter(|c| !c.is_alphanumeric()) {
+                    plain.push(next);
+                    i += 1 + next.len_utf8();
+                    continue;
+                }
+            }
+            '{' => {
+                if let Some(code) = rest.strip_prefix("{{") {
+                    if let Some(end) = code.find("}}") {
+                        push_text(nodes, &plain, marks);
+                        plain.clear();
+                        let code_marks = Marks {
+                            code: true,
+                            link: marks.link.clone(),
+                            ..Marks::default()
+                        };
+                        push_text(nodes, &code[..end], &code_marks);
+                        i += 2 + end + 2;
+                        continue;
+                    }
+                }
+                // {color:red}text{color} keeps only the text
+                if rest.starts_with("{color") {
+                    if let Some(end) = rest.find('}') {
+                        i += end + 1;
+                        continue;
+                    }
+                }
+            }
+            '[' => {
+                if let Some(end) = rest.find(']') {
+                    let inner = &rest[1..end];
+                    if let Some(consumed) = parse_link(inner, marks, &mut plain, nodes) {
+                        if consumed {
+                            i += end + 1;
+                            continue;
+                        }
+                    }
+                }
+            }
+            '!' => {
+                // Embedded attachment: !screenshot.png! or !screenshot.png|thumbnail!
+                if let Some(end) = rest[1..].find('!') {
+                    let inner = &rest[1..1 + end];
+                    let name = inner.split('|').next().unwrap_or_default();
+                    if !name.is_empty() && !name.contains(char::is_whitespace) && name.contains('.')
+                    {
+                        push_text(nodes, &plain, marks);
+                        plain.clear();
+                        nodes.push(json!({
+                            "type": "mediaInline",
+                            "attrs": { "alt": name }
+                        }));
+                        i += end + 2;
+                        continue;
+                    }
+                }
+            }
+            '*' | '_' | '-' | '+' => {
+                let prev = text[..i].chars().last();
+                if let Some(inner) = parse_effect(rest, prev) {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    let mut inner_marks = marks.clone();
+                    match c {
+                        '*' => inner_marks.strong = true,
+                        '_' => inner_marks.em = true,
+                        '-' => inner_marks.strike = true,
+                        _ => inner_marks.underline = true,
+                    }
+                    parse_inline_into(inner, &inner_marks, nodes);
+                    i += inner.len() + 2;
+                    continue;
+                }
+            }
+            'h' if marks.link.is_none() && is_url(rest) => {
+                let at_word_start = text[..i]
+                    .chars()
+                    .last()
+                    .is_none_or(|p| p.is_whitespace() || p == '(');
+                let end = url_end(rest);
+                if at_word_start && end > "https://".len() {
+                    push_text(nodes, &plain, marks);
+                    plain.clear();
+                    let url = &rest[..end];
+                    push_text(nodes, url, &marks.with_link(url));
+                    i += end;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        plain.push(c);
+        i += c.len_utf8();
+    }
+
+    push_text(nodes, &plain, marks);
+}
+
+/// Handle `[...]` link syntax. Returns `Some(true)` if the link was consumed.
+fn parse_link(
+    inner: &str,
+    marks: &Marks,
+    plain: &mut String,
+    nodes: &mut Vec<Value>,
+) -> Option<bool> {
+    // [~username] mentions a user
+    if let Some(user) = inner.strip_prefix('~') {
+        push_text(nodes, plain, marks);
+        plain.clear();
+        nodes.push(json!({
+            "type": "mention",
+            "attrs": { "id": user, "text": format!("@{}", user) }
+        }));
+        return Some(true);
+    }
+    // [^attachment.txt] links an attachment
+    if let Some(name) = inner.strip_prefix('^') {
+        push_text(nodes, plain, marks);
+        plain.clear();
+        nodes.push(json!({ "type": "mediaInline", "attrs": { "alt": name } }));
+        return Some(true);
+    }
+
+    let (label, href) = match inner.split_once('|') {
+        Some((label, rest)) => (label, rest.split('|').next().unwrap_or_default().trim()),
+        None => (inner, inner.trim()),
+    };
+    if !is_url(href) && !href.starts_with("mailto:") {
+        return Some(false);
+    }
+
+    push_text(nodes, plain, marks);
+    plain.clear();
+    parse_inline_into(label, &marks.with_link(href), nodes);
+    Some(true)
+}
+
+/// Parse a text effect (`*strong*`, `_em_`, `-strike-`, `+underline+`) at the
+/// start of `text`. Effects start and end at word boundaries.
+fn parse_effect(text: &str, prev: Option<char>) -> Option<&str> {
+    let c = text.chars().next()?;
+    if prev.is_some_and(char::is_alphanumeric) {
+        return None;
+    }
+
+    let after = &text[1..];
+    if after.is_empty() || after.starts_with(char::is_whitespace) || after.starts_with(c) {
+        return None;
+    }
+
+    let mut from = 0;
+    while let Some(pos) = after[from..].find(c) {
+        let k = from + pos;
+        let before = after[..k].chars().last();
+        let next = after[k + 1..].chars().next();
+        if before.is_some_and(|b| !b.is_whitespace() && b != '\\')
+            && next.is_none_or(|n| !n.is_alphanumeric())
+        {
+            return Some(&after[..k]);
+        }
+        from = k + 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wiki_to_markdown_plain_text() {
+        assert_eq!(wiki_to_markdown("Plain text desc"), "Plain text desc");
+        assert_eq!(wiki_to_markdown("Line 1\nLine 2"), "Line 1\nLine 2");
+        assert_eq!(wiki_to_markdown(""), "");
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_text_effects() {
+        assert_eq!(
+            wiki_to_markdown("A *bold*, _em_, -gone- and +under+ word with {{code}}"),
+            "A **bold**, *em*, ~~gone~~ and under word with `code`"
+        );
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_literal_delimiters() {
+        assert_eq!(
+            wiki_to_markdown("well-known snake_case_name, 2 * 3 * 4 and a -- b"),
+            "well-known snake_case_name, 2 * 3 * 4 and a -- b"
+        );
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_links_and_mentions() {
+        assert_eq!(
+            wiki_to_markdown(
+                "See [the docs|https://docs.example.com], [https://example.com] and ask [~jdoe]"
+            ),
+            "See [the docs](https://docs.example.com), https://example.com and ask @jdoe"
+        );
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_blocks() {
+        let wiki = "h2. Steps\n\
+             # Open the app\n\
+             #* on mobile\n\
+             # Log in\n\
+             \n\
+             {code:java}\n\
+             int x = 1;\n\
+             {code}\n\
+             \n\
+             bq. Quoted\n\
+             \n\
+             {panel:title=Warning}\n\
+             Breaking change\n\
+             {panel}\n\
+             \n\
+             ----\n\
+             ||OS||Result||\n\
+             |iOS|fails\\\\always|\n\
+             |[link|https://x.io]|{{a|b}}|";
+
+        assert_eq!(
+            wiki_to_markdown(wiki),
+            "## Steps\n\n\
+             1. Open the app\n   - on mobile\n2. Log in\n\n\
+             ```java\nint x = 1;\n```\n\n\
+             > Quoted\n\n\
+             > [!WARNING]\n> Breaking change\n\n\
+             ---\n\n\
+             | OS | Result |\n| --- | --- |\n| iOS | fails<br>always |\n\
+             | [link](https://x.io) | `a\\|b` |"
+        );
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_noformat_and_single_line_code() {
+        assert_eq!(
+            wiki_to_markdown("{noformat}\n*not bold*\n{noformat}"),
+            "```\n*not bold*\n```"
+        );
+        assert_eq!(wiki_to_markdown("{code}x = 1{code}"), "```\nx = 1\n```");
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_custom_panel_title() {
+        assert_eq!(
+            wiki_to_markdown("{panel:title=Release notes}\nShipped\n{panel}"),
+            "> [!INFO]\n> **Release notes**\n>\n> Shipped"
+        );
+    }
+
+    #[test]
+    fn test_wiki_to_markdown_color_and_attachments() {
+        assert_eq!(
+            wiki_to_markdown("{color:red}Important{color} see !screenshot.png|thumbnail!"),
+            "Important see [attachment: screenshot.png]"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_wiki() {
+        let markdown = "# Login fails\n\n\
+             Use **bold**, *em*, ~~old~~ and `cargo test`, see [docs](https://x.io) or https://y.io\n\
+             Second line\n\n\
+             - one\n  1. nested\n- two\n\n\
+             ```rust\nfn main() {}\n```\n\n\
+             > quoted\n\n\
+             > [!WARNING]\n> careful\n\n\
+             ---\n\n\
+             | A | B |\n| --- | --- |\n| x | {y} |";
+
+        assert_eq!(
+            markdown_to_wiki(markdown),
+            "h1. Login fails\n\n\
+             Use *bold*, _em_, -old- and {{cargo test}}, see [docs|https://x.io] or [https://y.io]\n\
+             Second line\n\n\
+             * one\n*# nested\n* two\n\n\
+             {code:rust}\nfn main() {}\n{code}\n\n\
+             {quote}\nquoted\n{quote}\n\n\
+             {panel:title=Warning}\ncareful\n{panel}\n\n\
+             ----\n\n\
+             ||A||B||\n|x|\\{y}|"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_wiki_task_list() {
+        assert_eq!(
+            markdown_to_wiki("- [x] done\n- [ ] todo"),
+            "* [x] done\n* [ ] todo"
+        );
+    }
+
+    #[test]
+    fn test_round_trip_markdown() {
+        let markdown = "## Summary\n\n\
+             The **login** page *crashes* with `NullPointerException`, see [logs](https://logs.example.com).\n\
+             Reported by support.\n\n\
+             - open the app\n  - on mobile\n- log in\n\n\
+             1. first\n2. second\n\n\
+             ```sql\nSELECT 1;\n```\n\n\
+             > customer quote\n\n\
+             > [!NOTE]\n> internal only\n\n\
+             ---\n\n\
+             | Browser | Result |\n| --- | --- |\n| Safari | ~~ok~~ fails |";
+
+        assert_eq!(wiki_to_markdown(&markdown_to_wiki(markdown)), markdown);
+    }
+
+    #[test]
+    fn test_round_trip_wiki() {
+        let wiki = "h3. Notes\n\n\
+             Call {{retry()}} then *check* [status|https://status.example.com]\n\n\
+             * one\n** nested\n* two\n\n\
+             {code:sh}\nmake test\n{code}\n\n\
+             ----";
+
+        assert_eq!(markdown_to_wiki(&wiki_to_markdown(wiki)), wiki);
+    }
+}
@@ -0,0 +1,390 @@
+//! CI job log extraction.
+//!
+//! Full CI logs are far too large for an agent's context window. This module
+//! cleans raw logs (ANSI escape codes, timestamps, GitLab section markers) and
+//! extracts only the failing sections: error lines with surrounding context,
+//! test failure summaries and compiler errors.
+
+/// Default number of context lines kept around each error line.
+pub const DEFAULT_CONTEXT_LINES: usize = 5;
+
+/// Lines always kept from the end of the log (exit code, final summary).
+const TAIL_LINES: usize = 10;
+
+/// Lines shown when no error line was found at all.
+const FALLBACK_TAIL_LINES: usize = 50;
+
+/// Words (lowercase) that mark a line as part of a failure. They only count
+/// as whole words, so `thiserror`, `-Werror` or `error_chain` do not match;
+/// plurals (`2 errors`) and CamelCase suffixes (`TypeError`) do.
+const ERROR_WORDS: &[&str] = &["error", "failed", "failure", "fatal"];
+
+/// Substrings (lowercase) that mark a line as part of a failure.
+const ERROR_PATTERNS: &[&str] = &[
+    "panicked at",
+    "exception",
+    "traceback",
+    "npm err!",
+    "✕",
+    "✗",
+];
+
+/// Prefixes (lowercase, after trimming) that start a failure summary block.
+const SUMMARY_PREFIXES: &[&str] = &["failures:", "short test summary info", "● ", "--- fail:"];
+
+/// Result of extracting the failing sections from a log.
+#[derive(Debug, Clone)]
+pub struct LogExtract {
+    /// Extracted text
+    pub content: String,
+    /// Number of lines in the cleaned log
+    pub total_lines: usize,
+    /// Number of log lines included in the extract
+    pub included_lines: usize,
+}
+
+/// Clean a raw CI log: strip ANSI codes, timestamps and GitLab section markers.
+///
+/// Carriage-return progress output is collapsed to the last rewrite of the line.
+pub fn clean_log(raw: &str) -> String {
+    let stripped = strip_ansi(raw);
+
+    stripped
+        .lines()
+        .map(|line| {
+            // Terminals overwrite the line on '\r', keep what was visible last
+            let line = line
+                .trim_end_matches('\r')
+                .rsplit('\r')
+                .next()
+                .unwrap_or_default();
+            strip_timestamp(strip_section_marker(line))
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Remove ANSI escape sequences (colors, cursor movement) from a string.
+pub fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            // CSI: ESC [ params... final byte in '@'..='~'
+            Some('[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ESC ] ... terminated by BEL or ESC \
+            Some(']') => {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            // Two-character escape
+            Some(_) => {
+                chars.next();
+            }
+            None => {}
+        }
+    }
+
+    result
+}
+
+/// Drop GitLab `section_start:<ts>:<name>` / `section_end:<ts>:<name>` markers.
+fn strip_section_marker(line: &str) -> &str {
+    let trimmed = line.trim_start();
+    if !(trimmed.starts_with("section_start:") || trimmed.starts_with("section_end:")) {
+        return line;
+    }
+
+    // Marker format: section_start:1700000000:name[collapsed=true] followed by the header text
+    let mut parts = trimmed.splitn(3, ':');
+    parts.next();
+    parts.next();
+    let rest = parts.next().unwrap_or_default();
+    match rest.find(char::is_whitespace) {
+        Some(pos) => rest[pos..].trim_start(),
+        None => "",
+    }
+}
+
+/// Remove a leading ISO 8601 timestamp (GitHub Actions, GitLab log timestamps).
+///
+/// GitLab also prefixes each timestamped line with a stream marker like `00O`.
+fn strip_timestamp(line: &str) -> &str {
+    let Some((first, rest)) = line.split_once(' ') else {
+        return line;
+    };
+    if !is_timestamp(first) {
+        return line;
+    }
+
+    match rest.split_once(' ') {
+        Some((marker, after)) if is_stream_marker(marker) => after,
+        _ if is_stream_marker(rest) => "",
+        _ => rest,
+    }
+}
+
+/// Check for `YYYY-MM-DDTHH:MM:SS...` prefix.
+fn is_timestamp(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    bytes.len() >= 19
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[7] == b'-'
+        && bytes[10] == b'T'
+        && bytes[13] == b':'
+        && bytes[16] == b':'
+}
+
+/// GitLab stream markers: two digits followed by `O`/`E`, optionally `+`.
+fn is_stream_marker(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    (bytes.len() == 3 || (bytes.len() == 4 && bytes[3] == b'+'))
+        && bytes[..2].iter().all(u8::is_ascii_digit)
+        && (bytes[2] == b'O' || bytes[2] == b'E')
+}
+
+/// Check whether a cleaned log line indicates a failure.
+pub fn is_error_line(line: &str) -> bool {
+    // ASCII lowercasing keeps byte offsets aligned with `line`
+    let lower = remove_zero_counts(&line.to_ascii_lowercase());
+    let trimmed = lower.trim_start();
+
+    SUMMARY_PREFIXES.iter().any(|p| trimmed.starts_with(p))
+        || ERROR_PATTERNS.iter().any(|p| lower.contains(p))
+        || ERROR_WORDS.iter().any(|w| contains_word(line, &lower, w))
+}
+
+/// Whether `lower` (the lowercased `line`) contains `word` as a whole word.
+fn contains_word(line: &str, lower: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    lower.match_indices(word).any(|(start, _)| {
+        let before = lower[..start].chars().next_back();
+        let camel_case = before.is_some_and(|c| c.is_ascii_lowercase())
+            && line[start..].starts_with(|c: char| c.is_ascii_uppercase());
+        let mut after = lower[start + word.len()..].chars();
+        let word_end = match after.next() {
+            Some('s') => after.next().is_none_or(|c| !is_word_char(c)),
+            next => next.is_none_or(|c| !is_word_char(c)),
+        };
+        (before.is_none_or(|c| !is_word_char(c)) || camel_case) && word_end
+    })
+}
+
+/// Remove "0 failed" / "0 errors" style phrases so that success summaries
+/// like `test result: ok. 10 passed; 0 failed` do not count as errors.
+fn remove_zero_counts(line: &str) -> String {
+    let mut result = line.to_string();
+    for phrase in [
+        "0 failed",
+        "0 errors",
+        "0 error",
+        "failures: 0",
+        "errors: 0",
+    ] {
+        let mut search_from = 0;
+        while let Some(pos) = result[search_from..].find(phrase) {
+            let start = search_from + pos;
+            let preceded_by_digit = result[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_ascii_digit());
+            if phrase.starts_with('0') && preceded_by_digit {
+                search_from = start + phrase.len();
+                continue;
+            }
+            // Blank out rather than remove, keeping offsets aligned with the line
+            result.replace_range(start..start + phrase.len(), &" ".repeat(phrase.len()));
+            search_from = start + phrase.len();
+        }
+    }
+    result
+}
+
+/// Extract the failing sections of a cleaned log.
+///
+/// Keeps every error line with `context_lines` lines around it, merges
+/// overlapping windows and always includes the last lines of the log. When no
+/// error line is found, the tail of the log is returned instead.
+pub fn extract_failures(log: &str, context_lines: usize) -> LogExtract {
+    let lines: Vec<&str> = log.lines().collect();
+    let total_lines = lines.len();
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if is_error_line(line) {
+            let start = i.saturating_sub(context_lines);
+            let end = (i + context_lines + 1).min(total_lines);
+            push_range(&mut ranges, start, end);
+        }
+    }
+
+    let tail = if ranges.is_empty() {
+        FALLBACK_TAIL_LINES
+    } else {
+        TAIL_LINES
+    };
+    push_range(&mut ranges, total_lines.saturating_sub(tail), total_lines);
+
+    let mut content = String::new();
+    let mut included_lines = 0;
+    for (start, end) in ranges {
+        if start >= end {
+            continue;
+        }
+        if !content.is_empty() {
+            content.push('\n');
+        }
+        content.push_str(&format!("--- lines {}-{} ---\n", start + 1, end));
+        content.push_str(&lines[start..end].join("\n"));
+        content.push('\n');
+        included_lines += end - start;
+    }
+
+    LogExtract {
+        content,
+        total_lines,
+        included_lines,
+    }
+}
+
+/// Add a line range, merging it with the previous one when they touch.
+fn push_range(ranges: &mut Vec<(usize, usize)>, start: usize, end: usize) {
+    if let Some(last) = ranges.last_mut() {
+        if start <= last.1 {
+            last.1 = last.1.max(end);
+            return;
+        }
+    }
+    ranges.push((start, end));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[31;1merror\x1b[0m: boom"), "error: boom");
+        assert_eq!(strip_ansi("\x1b[0Kplain"), "plain");
+        assert_eq!(strip_ansi("\x1b]0;title\x07text"), "text");
+        assert_eq!(strip_ansi("no escapes ✓"), "no escapes ✓");
+    }
+
+    #[test]
+    fn test_clean_log_github_timestamps() {
+        let raw = "2024-01-15T10:30:00.1234567Z ##[group]Run cargo test\n\
+                   2024-01-15T10:30:01.0000000Z \x1b[32mCompiling\x1b[0m foo v0.1.0";
+        assert_eq!(
+            clean_log(raw),
+            "##[group]Run cargo test\nCompiling foo v0.1.0"
+        );
+    }
+
+    #[test]
+    fn test_clean_log_gitlab_sections_and_markers() {
+        let raw = "\x1b[0Ksection_start:1700000000:build_script\r\x1b[0K\x1b[0K\x1b[36;1mExecuting step_script\x1b[0;m\n\
+                   2024-01-15T10:30:00.123456Z 00O $ cargo build\n\
+                   \x1b[0Ksection_end:1700000010:build_script\r\x1b[0K";
+        assert_eq!(clean_log(raw), "Executing step_script\n$ cargo build");
+    }
+
+    #[test]
+    fn test_clean_log_carriage_return_progress() {
+        let raw = "Downloading 10%\rDownloading 50%\rDownloading 100%\nDone";
+        assert_eq!(clean_log(raw), "Downloading 100%\nDone");
+    }
+
+    #[test]
+    fn test_is_error_line() {
+        assert!(is_error_line(
+            "error[E0425]: cannot find value `x` in this scope"
+        ));
+        assert!(is_error_line("thread 'main' panicked at src/lib.rs:10:5"));
+        assert!(is_error_line("test result: FAILED. 9 passed; 1 failed"));
+        assert!(is_error_line(
+            "##[error]Process completed with exit code 1."
+        ));
+        assert!(is_error_line("failures:"));
+        assert!(is_error_line("ERROR: Job failed: exit code 1"));
+        assert!(!is_error_line(
+            "test result: ok. 10 passed; 0 failed; 0 ignored"
+        ));
+        assert!(!is_error_line("Compiling foo v0.1.0"));
+        assert!(is_error_line("10 failed, 2 passed"));
+        assert!(is_error_line(
+            "error: could not compile `foo` due to 2 previous errors"
+        ));
+        assert!(is_error_line(
+            "TypeError: 'NoneType' object is not subscriptable"
+        ));
+        assert!(is_error_line("1 failed, 3 errors in 2.10s"));
+    }
+
+    #[test]
+    fn test_is_error_line_ignores_error_in_identifiers() {
+        assert!(!is_error_line("   Compiling thiserror v2.0.12"));
+        assert!(!is_error_line("   Compiling thiserror-impl v2.0.12"));
+        assert!(!is_error_line("cc -Wall -Werror -o main main.c"));
+        assert!(!is_error_line("   Compiling error_chain v0.12.4"));
+    }
+
+    #[test]
+    fn test_extract_failures_keeps_context_and_tail() {
+        let mut lines: Vec<String> = (1..=100).map(|i| format!("line {}", i)).collect();
+        lines[49] = "error: something broke".to_string();
+        let log = lines.join("\n");
+
+        let extract = extract_failures(&log, 2);
+
+        assert_eq!(extract.total_lines, 100);
+        assert!(extract.content.contains("--- lines 48-52 ---"));
+        assert!(extract
+            .content
+            .contains("line 48\nline 49\nerror: something broke"));
+        assert!(extract.content.contains("--- lines 91-100 ---"));
+        assert!(!extract.content.contains("line 47\n"));
+        assert_eq!(extract.included_lines, 15);
+    }
+
+    #[test]
+    fn test_extract_failures_merges_overlapping_windows() {
+        let log = "a\nerror one\nb\nerror two\nc";
+        let extract = extract_failures(log, 1);
+        assert_eq!(extract.content.matches("--- lines").count(), 1);
+        assert_eq!(extract.included_lines, 5);
+    }
+
+    #[test]
+    fn test_extract_failures_without_errors_returns_tail() {
+        let log: String = (1..=80)
+            .map(|i| format!("ok {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let extract = extract_failures(&log, DEFAULT_CONTEXT_LINES);
+        assert!(extract.content.starts_with("--- lines 31-80 ---"));
+        assert_eq!(extract.included_lines, 50);
+    }
+}
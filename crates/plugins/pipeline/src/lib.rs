@@ -4,6 +4,7 @@
 //!
 //! - **Truncation**: Limit output size with pagination hints for the agent
 //! - **Markdown**: Convert JSON to Markdown for token savings (~50-70% reduction)
+//! - **Job logs**: Extract the failing sections of CI job logs
 //!
 //! # Example
 //!
@@ -18,13 +19,17 @@
 //! let output = pipeline.transform_issues(issues)?;
 //! ```
 
+pub mod job_log;
 pub mod markdown;
 pub mod truncation;
 
 pub use markdown::MarkdownPlugin;
 pub use truncation::TruncationPlugin;
 
-use devboy_core::{Comment, Discussion, FileDiff, Issue, MergeRequest, Result};
+use devboy_core::{
+    Board, CiPipeline, Comment, Commit, Container, CustomField, Discussion, Error, FileDiff, Issue,
+    MergeRequest, Milestone, RepositoryFile, Result, Sprint, TimeEntry, TreeEntry, User,
+};
 
 /// Output from a pipeline transformation.
 ///
@@ -209,6 +214,29 @@ impl Pipeline {
         Ok(self.apply_char_limit(output))
     }
 
+    /// Transform a list of commits.
+    pub fn transform_commits(&self, commits: Vec<Commit>) -> Result<TransformOutput> {
+        let total = commits.len();
+        let truncated_commits = self.truncate_items(commits);
+        let included = truncated_commits.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&truncated_commits)?,
+            OutputFormat::Markdown => markdown::commits_to_markdown(&truncated_commits),
+            OutputFormat::Compact => markdown::commits_to_compact(&truncated_commits),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = self.create_pagination_hint("commits", total, included, None);
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
     /// Transform a list of comments.
     pub fn transform_comments(&self, comments: Vec<Comment>) -> Result<TransformOutput> {
         let total = comments.len();
@@ -255,6 +283,313 @@ impl Pipeline {
         Ok(self.apply_char_limit(output))
     }
 
+    /// Transform a list of CI pipelines.
+    pub fn transform_pipelines(&self, pipelines: Vec<CiPipeline>) -> Result<TransformOutput> {
+        let total = pipelines.len();
+        let truncated_pipelines = self.truncate_items(pipelines);
+        let included = truncated_pipelines.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&truncated_pipelines)?,
+            OutputFormat::Markdown => markdown::pipelines_to_markdown(&truncated_pipelines),
+            OutputFormat::Compact => markdown::pipelines_to_compact(&truncated_pipelines),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = self.create_pagination_hint("pipelines", total, included, None);
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of custom field definitions.
+    pub fn transform_custom_fields(&self, fields: Vec<CustomField>) -> Result<TransformOutput> {
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&fields)?,
+            OutputFormat::Markdown => markdown::custom_fields_to_markdown(&fields),
+            OutputFormat::Compact => markdown::custom_fields_to_compact(&fields),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = fields.len();
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of milestones.
+    pub fn transform_milestones(&self, milestones: Vec<Milestone>) -> Result<TransformOutput> {
+        let total = milestones.len();
+        let milestones = self.truncate_items(milestones);
+        let included = milestones.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&milestones)?,
+            OutputFormat::Markdown => markdown::milestones_to_markdown(&milestones),
+            OutputFormat::Compact => markdown::milestones_to_compact(&milestones),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = format!(
+                "📊 Showing {}/{} milestones. Filter by `state` to narrow down the results.",
+                included, total
+            );
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of hierarchy containers.
+    pub fn transform_containers(&self, containers: Vec<Container>) -> Result<TransformOutput> {
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&containers)?,
+            OutputFormat::Markdown => markdown::containers_to_markdown(&containers),
+            OutputFormat::Compact => markdown::containers_to_compact(&containers),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = containers.len();
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of users.
+    pub fn transform_users(&self, users: Vec<User>) -> Result<TransformOutput> {
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&users)?,
+            OutputFormat::Markdown => markdown::users_to_markdown(&users),
+            OutputFormat::Compact => markdown::users_to_compact(&users),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = users.len();
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of time entries (worklogs).
+    pub fn transform_time_entries(&self, entries: Vec<TimeEntry>) -> Result<TransformOutput> {
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&entries)?,
+            OutputFormat::Markdown => markdown::time_entries_to_markdown(&entries),
+            OutputFormat::Compact => markdown::time_entries_to_compact(&entries),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = entries.len();
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of agile boards.
+    pub fn transform_boards(&self, boards: Vec<Board>) -> Result<TransformOutput> {
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&boards)?,
+            OutputFormat::Markdown => markdown::boards_to_markdown(&boards),
+            OutputFormat::Compact => markdown::boards_to_compact(&boards),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = boards.len();
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a list of sprints.
+    pub fn transform_sprints(&self, sprints: Vec<Sprint>) -> Result<TransformOutput> {
+        let total = sprints.len();
+        let truncated_sprints = self.truncate_items(sprints);
+        let included = truncated_sprints.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&truncated_sprints)?,
+            OutputFormat::Markdown => markdown::sprints_to_markdown(&truncated_sprints),
+            OutputFormat::Compact => markdown::sprints_to_compact(&truncated_sprints),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = format!(
+                "📊 Showing {}/{} sprints. Filter by `state` to narrow down the results.",
+                included, total
+            );
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a sprint together with its issues.
+    pub fn transform_sprint(&self, sprint: Sprint, issues: Vec<Issue>) -> Result<TransformOutput> {
+        let total = issues.len();
+        let truncated_issues = self.truncate_items(issues);
+        let included = truncated_issues.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&serde_json::json!({
+                "sprint": sprint,
+                "issues": truncated_issues,
+            }))?,
+            OutputFormat::Markdown => format!(
+                "{}\n{}",
+                markdown::sprint_to_markdown(&sprint),
+                markdown::issues_to_markdown(&truncated_issues)
+            ),
+            OutputFormat::Compact => format!(
+                "{}\n{}",
+                markdown::sprints_to_compact(std::slice::from_ref(&sprint)),
+                markdown::issues_to_compact(&truncated_issues)
+            ),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = format!("📊 Showing {}/{} issues of the sprint.", included, total);
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a raw CI job log.
+    ///
+    /// By default only the failing sections are kept (see [`job_log::extract_failures`]).
+    /// With `full` the whole cleaned log is returned, subject to the character limit.
+    pub fn transform_job_log(
+        &self,
+        raw_log: &str,
+        full: bool,
+        context_lines: usize,
+    ) -> TransformOutput {
+        let log = job_log::clean_log(raw_log);
+
+        if full {
+            return self.apply_char_limit(TransformOutput::new(log));
+        }
+
+        let extract = job_log::extract_failures(&log, context_lines);
+        let mut output = TransformOutput::new(truncation::truncate_string(
+            &extract.content,
+            self.config.max_chars,
+        ));
+        output.included_count = extract.included_lines;
+
+        if extract.included_lines < extract.total_lines && self.config.include_hints {
+            let hint = format!(
+                "📋 Showing failure-focused excerpt: {}/{} log lines. Use `full: true` to get the whole log.",
+                extract.included_lines, extract.total_lines
+            );
+            output = output.with_truncation(extract.total_lines, extract.included_lines, hint);
+        }
+
+        output
+    }
+
+    /// Transform a repository file, optionally restricted to a line range.
+    ///
+    /// Lines are 1-based and inclusive. When the selected range does not fit
+    /// into the character limit, it is cut at a line boundary and the hint
+    /// tells the agent where to continue.
+    pub fn transform_file(
+        &self,
+        file: RepositoryFile,
+        start_line: Option<usize>,
+        end_line: Option<usize>,
+    ) -> Result<TransformOutput> {
+        let lines: Vec<&str> = file.content.lines().collect();
+        let total_lines = lines.len();
+
+        let first = start_line.unwrap_or(1).max(1);
+        let last = end_line.unwrap_or(total_lines).min(total_lines);
+        if !file.binary && total_lines > 0 && first > total_lines {
+            return Err(Error::InvalidData(format!(
+                "start_line {} is beyond the end of {} ({} lines)",
+                first, file.path, total_lines
+            )));
+        }
+
+        // Keep whole lines while they fit into the character budget
+        let selected: &[&str] = if first <= last {
+            &lines[first - 1..last]
+        } else {
+            &[]
+        };
+        let mut used = 0;
+        let mut fitting = 0;
+        for line in selected {
+            // Line number prefix and newline
+            used += line.len() + 10;
+            if used > self.config.max_chars && fitting > 0 {
+                break;
+            }
+            fitting += 1;
+        }
+        let shown = &selected[..fitting];
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&RepositoryFile {
+                content: shown.join("\n"),
+                ..file.clone()
+            })?,
+            OutputFormat::Markdown => markdown::file_to_markdown(&file, shown, first, total_lines),
+            OutputFormat::Compact => markdown::file_to_compact(&file, shown, first),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = shown.len();
+
+        if shown.len() < selected.len() && self.config.include_hints {
+            let next = first + shown.len();
+            let hint = format!(
+                "📄 Showing lines {}-{} of {}. Use `start_line: {}` to continue.",
+                first,
+                next - 1,
+                total_lines,
+                next
+            );
+            output = output.with_truncation(selected.len(), shown.len(), hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
+    /// Transform a repository directory listing.
+    pub fn transform_tree(&self, entries: Vec<TreeEntry>) -> Result<TransformOutput> {
+        let total = entries.len();
+        let truncated_entries = self.truncate_items(entries);
+        let included = truncated_entries.len();
+
+        let content = match self.config.format {
+            OutputFormat::Json => serde_json::to_string_pretty(&truncated_entries)?,
+            OutputFormat::Markdown => markdown::tree_to_markdown(&truncated_entries),
+            OutputFormat::Compact => markdown::tree_to_compact(&truncated_entries),
+        };
+
+        let mut output = TransformOutput::new(content);
+        output.included_count = included;
+
+        if included < total && self.config.include_hints {
+            let hint = format!(
+                "📊 Showing {}/{} entries. List a subdirectory to narrow down the results.",
+                included, total
+            );
+            output = output.with_truncation(total, included, hint);
+        }
+
+        Ok(self.apply_char_limit(output))
+    }
+
     /// Truncate a vector to max_items.
     fn truncate_items<T>(&self, items: Vec<T>) -> Vec<T> {
         items.into_iter().take(self.config.max_items).collect()
@@ -329,6 +664,16 @@ mod tests {
                 url: Some(format!("https://github.com/test/repo/issues/{}", i)),
                 created_at: Some("2024-01-01T00:00:00Z".to_string()),
                 updated_at: Some("2024-01-02T00:00:00Z".to_string()),
+                custom_fields: Default::default(),
+                issue_type: None,
+                parent: None,
+                children: vec![],
+                links: vec![],
+                attachments: vec![],
+                estimate_seconds: None,
+                time_spent_seconds: None,
+                milestone: None,
+                checklists: vec![],
             })
             .collect()
     }
@@ -415,6 +760,44 @@ mod tests {
         assert_eq!(parsed.len(), 2);
     }
 
+    #[test]
+    fn test_transform_job_log_extracts_failures() {
+        let pipeline = Pipeline::new();
+        let mut lines: Vec<String> = (1..=200)
+            .map(|i| format!("2024-01-15T10:30:00.0000000Z step {}", i))
+            .collect();
+        lines[100] = "\x1b[31merror[E0308]: mismatched types\x1b[0m".to_string();
+        let log = lines.join("\n");
+
+        let output = pipeline.transform_job_log(&log, false, 3);
+
+        assert!(output.content.contains("error[E0308]: mismatched types"));
+        assert!(!output.content.contains("\x1b["));
+        assert!(!output.content.contains("2024-01-15T"));
+        assert!(!output.content.contains("step 50\n"));
+        assert!(output.truncated);
+        assert_eq!(output.included_count, 17);
+        assert!(output.to_string_with_hints().contains("17/200 log lines"));
+    }
+
+    #[test]
+    fn test_transform_job_log_full_applies_char_limit() {
+        let pipeline = Pipeline::with_config(PipelineConfig {
+            max_chars: 100,
+            ..Default::default()
+        });
+        let log = (1..=100)
+            .map(|i| format!("step {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let output = pipeline.transform_job_log(&log, true, 3);
+
+        assert!(output.content.len() <= 100);
+        assert!(output.content.starts_with("step 1\n"));
+        assert!(output.truncated);
+    }
+
     #[test]
     fn test_char_limit_applied() {
         let pipeline = Pipeline::with_config(PipelineConfig {
@@ -451,6 +834,8 @@ mod tests {
                 created_at: Some("2024-01-01T00:00:00Z".to_string()),
                 updated_at: Some("2024-01-02T00:00:00Z".to_string()),
                 draft: false,
+                pipelines: vec![],
+                milestone: None,
             })
             .collect()
     }
@@ -479,6 +864,7 @@ mod tests {
                 created_at: Some("2024-01-01T00:00:00Z".to_string()),
                 updated_at: None,
                 position: None,
+                attachments: vec![],
             })
             .collect()
     }
@@ -496,6 +882,7 @@ mod tests {
                     created_at: None,
                     updated_at: None,
                     position: None,
+                    attachments: vec![],
                 }],
                 position: None,
             })
@@ -714,6 +1101,54 @@ mod tests {
         assert!(output.to_string_with_hints().contains("hint text"));
     }
 
+    #[test]
+    fn test_transform_sprint_markdown() {
+        let pipeline = Pipeline::with_config(PipelineConfig {
+            max_items: 2,
+            max_chars: 100000,
+            ..Default::default()
+        });
+        let sprint = Sprint {
+            id: "37".to_string(),
+            name: "Sprint 12".to_string(),
+            state: "active".to_string(),
+            ..Default::default()
+        };
+
+        let output = pipeline.transform_sprint(sprint, sample_issues()).unwrap();
+
+        assert!(output.content.contains("Sprint 12"));
+        assert_eq!(output.included_count, 2);
+        assert!(output.truncated);
+        assert!(output
+            .agent_hint
+            .as_deref()
+            .unwrap()
+            .contains("issues of the sprint"));
+    }
+
+    #[test]
+    fn test_transform_sprint_json() {
+        let pipeline = Pipeline::with_config(PipelineConfig {
+            format: OutputFormat::Json,
+            ..Default::default()
+        });
+        let sprint = Sprint {
+            id: "37".to_string(),
+            name: "Sprint 12".to_string(),
+            state: "active".to_string(),
+            ..Default::default()
+        };
+
+        let output = pipeline
+            .transform_sprint(sprint, sample_issues().into_iter().take(1).collect())
+            .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output.content).unwrap();
+
+        assert_eq!(parsed["sprint"]["id"], "37");
+        assert_eq!(parsed["issues"].as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_pipeline_default() {
         let pipeline = Pipeline::default();
@@ -739,4 +1174,54 @@ mod tests {
         assert!(!output.truncated);
         assert!(output.agent_hint.is_none());
     }
+
+    fn sample_file(lines: usize) -> RepositoryFile {
+        RepositoryFile {
+            path: "src/lib.rs".to_string(),
+            content: (1..=lines)
+                .map(|i| format!("line {}", i))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_transform_file_range() {
+        let pipeline = Pipeline::new();
+        let output = pipeline
+            .transform_file(sample_file(50), Some(10), Some(12))
+            .unwrap();
+
+        assert!(output.content.contains("Lines 10-12 of 50"));
+        assert!(output
+            .content
+            .contains("10 | line 10\n11 | line 11\n12 | line 12\n"));
+        assert!(!output.content.contains("line 13"));
+        assert!(!output.truncated);
+    }
+
+    #[test]
+    fn test_transform_file_cuts_at_char_limit() {
+        let pipeline = Pipeline::with_config(PipelineConfig {
+            max_chars: 200,
+            ..Default::default()
+        });
+        let output = pipeline
+            .transform_file(sample_file(100), None, None)
+            .unwrap();
+
+        assert!(output.truncated);
+        let shown = output.included_count;
+        assert!(shown > 0 && shown < 100);
+        let hint = output.agent_hint.unwrap();
+        assert!(hint.contains(&format!("Use `start_line: {}` to continue", shown + 1)));
+    }
+
+    #[test]
+    fn test_transform_file_start_beyond_end() {
+        let pipeline = Pipeline::new();
+        let result = pipeline.transform_file(sample_file(5), Some(10), None);
+        assert!(result.is_err());
+    }
 }
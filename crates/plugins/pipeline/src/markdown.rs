@@ -11,7 +11,12 @@
 //! | Markdown | ~500          | LLM reading, human readable  |
 //! | Compact  | ~200          | Quick overview, listing      |
 
-use devboy_core::{Comment, Discussion, FileDiff, Issue, MergeRequest};
+use devboy_core::time;
+use devboy_core::{
+    Attachment, Board, BulkUpdateReport, CiPipeline, Comment, Commit, Container, CustomField,
+    Discussion, FileDiff, Issue, IssueRef, IssueStatus, IssueTransition, Label, MergeRequest,
+    Milestone, RepositoryFile, Sprint, TimeEntry, TreeEntry, User,
+};
 
 /// Configuration for markdown output.
 #[derive(Debug, Clone)]
@@ -101,13 +106,27 @@ fn issue_to_markdown(issue: &Issue) -> String {
         output.push_str(&format!(" | **Priority:** {}", priority));
     }
 
+    if let Some(issue_type) = &issue.issue_type {
+        output.push_str(&format!(" | **Type:** {}", issue_type));
+    }
+
     output.push('\n');
 
+    // Parent
+    if let Some(parent) = &issue.parent {
+        output.push_str(&format!("**Parent:** {}\n", issue_ref_line(parent)));
+    }
+
     // Labels
     if !issue.labels.is_empty() {
         output.push_str(&format!("**Labels:** {}\n", issue.labels.join(", ")));
     }
 
+    // Milestone
+    if let Some(milestone) = &issue.milestone {
+        output.push_str(&format!("**Milestone:** {}\n", milestone_ref(milestone)));
+    }
+
     // Author
     if let Some(author) = &issue.author {
         output.push_str(&format!("**Author:** @{}\n", author.username));
@@ -123,6 +142,87 @@ fn issue_to_markdown(issue: &Issue) -> String {
         output.push_str(&format!("**Assignees:** {}\n", assignees.join(", ")));
     }
 
+    // Custom fields
+    if !issue.custom_fields.is_empty() {
+        let fields: Vec<String> = issue
+            .custom_fields
+            .iter()
+            .map(|(name, value)| format!("{}: {}", name, custom_field_value(value)))
+            .collect();
+        output.push_str(&format!("**Fields:** {}\n", fields.join(" · ")));
+    }
+
+    // Time tracking
+    match (issue.time_spent_seconds, issue.estimate_seconds) {
+        (Some(spent), Some(estimate)) => output.push_str(&format!(
+            "**Time:** {} spent of {} estimated\n",
+            time::format_duration(spent),
+            time::format_duration(estimate)
+        )),
+        (Some(spent), None) => output.push_str(&format!(
+            "**Time:** {} spent\n",
+            time::format_duration(spent)
+        )),
+        (None, Some(estimate)) => output.push_str(&format!(
+            "**Time:** {} estimated\n",
+            time::format_duration(estimate)
+        )),
+        (None, None) => {}
+    }
+
+    // Children as a tree
+    if !issue.children.is_empty() {
+        output.push_str("**Children:**\n");
+        for (i, child) in issue.children.iter().enumerate() {
+            let branch = if i + 1 == issue.children.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            output.push_str(&format!("{} {}\n", branch, issue_ref_line(child)));
+        }
+    }
+
+    // Links, with the relation as words ("blocked_by" -> "blocked by")
+    if !issue.links.is_empty() {
+        output.push_str("**Links:**\n");
+        for link in &issue.links {
+            output.push_str(&format!(
+                "- {} {}\n",
+                link.kind.replace('_', " "),
+                issue_ref_line(&link.target)
+            ));
+        }
+    }
+
+    // Checklists with their progress
+    for checklist in &issue.checklists {
+        let done = checklist.items.iter().filter(|i| i.resolved).count();
+        output.push_str(&format!(
+            "**Checklist:** {} ({}/{})\n",
+            checklist.name,
+            done,
+            checklist.items.len()
+        ));
+        for item in &checklist.items {
+            let mark = if item.resolved { 'x' } else { ' ' };
+            let assignee = item
+                .assignee
+                .as_ref()
+                .map(|a| format!(" @{}", a.username))
+                .unwrap_or_default();
+            output.push_str(&format!("- [{}] {}{}\n", mark, item.name, assignee));
+        }
+    }
+
+    // Attachments
+    if !issue.attachments.is_empty() {
+        output.push_str("**Attachments:**\n");
+        for attachment in &issue.attachments {
+            output.push_str(&attachment_line(attachment));
+        }
+    }
+
     // Description (truncated)
     if let Some(desc) = &issue.description {
         if !desc.is_empty() {
@@ -139,26 +239,494 @@ fn issue_to_markdown(issue: &Issue) -> String {
     output
 }
 
-/// Convert issues to compact format (one line per issue).
-pub fn issues_to_compact(issues: &[Issue]) -> String {
-    if issues.is_empty() {
-        return "No issues found.".to_string();
+/// Format an attachment as a list item: "- [name](url) (1.5 KB, image/png)".
+fn attachment_line(attachment: &Attachment) -> String {
+    let details: Vec<String> = attachment
+        .size
+        .map(format_size)
+        .into_iter()
+        .chain(attachment.mime_type.clone())
+        .collect();
+    if details.is_empty() {
+        format!("- [{}]({})\n", attachment.name, attachment.url)
+    } else {
+        format!(
+            "- [{}]({}) ({})\n",
+            attachment.name,
+            attachment.url,
+            details.join(", ")
+        )
+    }
+}
+
+/// Format a custom field value: strings without quotes, lists comma-separated.
+fn custom_field_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(custom_field_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// Convert custom field definitions to Markdown format.
+pub fn custom_fields_to_markdown(fields: &[CustomField]) -> String {
+    if fields.is_empty() {
+        return "No custom fields found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Custom fields ({})\n\n", fields.len()));
+
+    for field in fields {
+        let required = if field.required { ", required" } else { "" };
+        let options = if field.options.is_empty() {
+            String::new()
+        } else {
+            format!(" · options: {}", field.options.join(", "))
+        };
+        output.push_str(&format!(
+            "- **{}** ({}, id {}{}){}\n",
+            field.name, field.field_type, field.id, required, options
+        ));
     }
 
-    issues
+    output
+}
+
+/// Convert custom field definitions to compact format.
+pub fn custom_fields_to_compact(fields: &[CustomField]) -> String {
+    if fields.is_empty() {
+        return "No custom fields found.".to_string();
+    }
+
+    fields
         .iter()
-        .map(|issue| {
-            let labels = if issue.labels.is_empty() {
+        .map(|f| format!("{} {} [{}]", f.id, f.name, f.field_type))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format a milestone as "title (due date)".
+fn milestone_ref(milestone: &Milestone) -> String {
+    match &milestone.due_date {
+        Some(due) => format!("{} (due {})", milestone.title, due),
+        None => milestone.title.clone(),
+    }
+}
+
+/// Format milestone progress as "9/12 done (75%), 3 open".
+fn milestone_progress(milestone: &Milestone) -> Option<String> {
+    let open = milestone.open_issues?;
+    let closed = milestone.closed_issues?;
+    let total = open + closed;
+    if total == 0 {
+        return Some("no issues".to_string());
+    }
+    Some(format!(
+        "{}/{} done ({}%), {} open",
+        closed,
+        total,
+        closed * 100 / total,
+        open
+    ))
+}
+
+/// Convert milestones to Markdown format with their progress.
+pub fn milestones_to_markdown(milestones: &[Milestone]) -> String {
+    if milestones.is_empty() {
+        return "No milestones found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Milestones ({})\n\n", milestones.len()));
+
+    for milestone in milestones {
+        let mut line = format!(
+            "- **{}** ({}, {})",
+            milestone.title, milestone.kind, milestone.state
+        );
+        match (&milestone.start_date, &milestone.due_date) {
+            (Some(start), Some(due)) => line.push_str(&format!(" · {} → {}", start, due)),
+            (None, Some(due)) => line.push_str(&format!(" · due {}", due)),
+            (Some(start), None) => line.push_str(&format!(" · from {}", start)),
+            (None, None) => {}
+        }
+        if let Some(progress) = milestone_progress(milestone) {
+            line.push_str(&format!(" · {}", progress));
+        }
+        output.push_str(&line);
+        output.push('\n');
+        // First line of the description only, to keep the list a list
+        if let Some(description) = milestone
+            .description
+            .as_deref()
+            .and_then(|d| d.lines().next())
+        {
+            output.push_str(&format!("  {}\n", truncate_text(description, 200)));
+        }
+    }
+
+    output
+}
+
+/// Convert milestones to compact format.
+pub fn milestones_to_compact(milestones: &[Milestone]) -> String {
+    if milestones.is_empty() {
+        return "No milestones found.".to_string();
+    }
+
+    milestones
+        .iter()
+        .map(|m| {
+            let progress = match (m.open_issues, m.closed_issues) {
+                (Some(open), Some(closed)) => format!(" {}/{}", closed, open + closed),
+                _ => String::new(),
+            };
+            let due = m
+                .due_date
+                .as_ref()
+                .map(|d| format!(" due {}", d))
+                .unwrap_or_default();
+            format!("{} [{}]{}{}", m.title, m.state, progress, due)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert hierarchy containers (workspaces, spaces, folders, lists) to Markdown format.
+pub fn containers_to_markdown(containers: &[Container]) -> String {
+    if containers.is_empty() {
+        return "No containers found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Containers ({})\n\n", containers.len()));
+
+    for container in containers {
+        let archived = if container.archived {
+            " · archived"
+        } else {
+            ""
+        };
+        output.push_str(&format!(
+            "- **{}** ({}, id {}){}\n",
+            container.name, container.kind, container.id, archived
+        ));
+    }
+
+    output
+}
+
+/// Convert hierarchy containers to compact format.
+pub fn containers_to_compact(containers: &[Container]) -> String {
+    if containers.is_empty() {
+        return "No containers found.".to_string();
+    }
+
+    containers
+        .iter()
+        .map(|c| format!("{} {} [{}]", c.id, c.name, c.kind))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert a provider's status and label catalog to Markdown format;
+/// `None` marks a catalog the provider cannot list.
+pub fn catalog_to_markdown(statuses: Option<&[IssueStatus]>, labels: Option<&[Label]>) -> String {
+    let statuses = match statuses {
+        None => "not available".to_string(),
+        Some([]) => "none".to_string(),
+        Some(statuses) => statuses
+            .iter()
+            .map(|s| format!("{} [{}]", s.name, s.category))
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    let labels = match labels {
+        None => "not available".to_string(),
+        Some([]) => "none".to_string(),
+        Some(labels) => labels
+            .iter()
+            .map(|l| l.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+    format!("**Statuses:** {}\n**Labels:** {}", statuses, labels)
+}
+
+/// Convert the available transitions of an issue to Markdown format.
+pub fn transitions_to_markdown(issue_key: &str, transitions: &[IssueTransition]) -> String {
+    if transitions.is_empty() {
+        return format!("No transitions available for {}.", issue_key);
+    }
+
+    let mut output = format!(
+        "# Transitions of {} ({})
+
+",
+        issue_key,
+        transitions.len()
+    );
+    for transition in transitions {
+        output.push_str(&format!(
+            "- **{}** → {} [{}] (id {})\n",
+            transition.name, transition.to_status, transition.to_category, transition.id
+        ));
+        for field in &transition.fields {
+            let required = if field.required { ", required" } else { "" };
+            let options = if field.options.is_empty() {
                 String::new()
             } else {
-                format!(" [{}]", issue.labels.join(", "))
+                format!(" · options: {}", field.options.join(", "))
             };
-            format!("{} [{}] {}{}", issue.key, issue.state, issue.title, labels)
+            output.push_str(&format!(
+                "  - {} ({}, id {}{}){}\n",
+                field.name, field.field_type, field.id, required, options
+            ));
+        }
+    }
+
+    output
+}
+
+/// Convert a bulk update report to Markdown format.
+pub fn bulk_report_to_markdown(report: &BulkUpdateReport) -> String {
+    let (title, verb) = if report.dry_run {
+        ("Dry run", "would be updated")
+    } else {
+        ("Bulk update", "updated")
+    };
+    let mut output = format!(
+        "# {}: {} of {} issues {}\n\n",
+        title,
+        report.succeeded(),
+        report.items.len(),
+        verb
+    );
+
+    for item in &report.items {
+        let mut outcome = match &item.error {
+            Some(error) => format!("failed: {}", error),
+            None if item.changes.is_empty() && item.skipped.is_empty() => "no changes".to_string(),
+            None => item.changes.join("; "),
+        };
+        if !item.skipped.is_empty() {
+            if !item.changes.is_empty() {
+                outcome.push_str("; ");
+            }
+            outcome.push_str(&format!(
+                "not applied by the provider: {}",
+                item.skipped.join(", ")
+            ));
+        }
+        output.push_str(&format!("- {}: {}\n", item.key, outcome));
+    }
+
+    if !report.duplicates.is_empty() {
+        output.push_str(&format!(
+            "\nDuplicate keys updated once: {}\n",
+            report.duplicates.join(", ")
+        ));
+    }
+
+    output
+}
+
+/// Convert users to Markdown format.
+pub fn users_to_markdown(users: &[User]) -> String {
+    if users.is_empty() {
+        return "No users found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!(
+        "# Users ({})
+
+",
+        users.len()
+    ));
+
+    for user in users {
+        let details: Vec<&str> = user
+            .name
+            .as_deref()
+            .filter(|name| *name != user.username)
+            .into_iter()
+            .chain(user.email.as_deref())
+            .collect();
+        let details = if details.is_empty() {
+            String::new()
+        } else {
+            format!("{}, ", details.join(", "))
+        };
+        output.push_str(&format!(
+            "- **{}** ({}id {})\n",
+            user.username, details, user.id
+        ));
+    }
+
+    output
+}
+
+/// Convert users to compact format.
+pub fn users_to_compact(users: &[User]) -> String {
+    if users.is_empty() {
+        return "No users found.".to_string();
+    }
+
+    users
+        .iter()
+        .map(|u| format!("{} {}", u.id, u.username))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert time entries (worklogs) to Markdown format.
+pub fn time_entries_to_markdown(entries: &[TimeEntry]) -> String {
+    if entries.is_empty() {
+        return "No time logged.".to_string();
+    }
+
+    let total: u64 = entries.iter().map(|e| e.duration_seconds).sum();
+    let mut output = String::new();
+    output.push_str(&format!(
+        "# Time entries ({}, {} total)\n\n",
+        entries.len(),
+        time::format_duration(total)
+    ));
+
+    for entry in entries {
+        let mut line = format!("- **{}**", time::format_duration(entry.duration_seconds));
+        if let Some(author) = &entry.author {
+            line.push_str(&format!(" by @{}", author.username));
+        }
+        if let Some(started_at) = &entry.started_at {
+            line.push_str(&format!(" on {}", started_at));
+        }
+        if let Some(comment) = entry.comment.as_deref().filter(|c| !c.is_empty()) {
+            line.push_str(&format!(": {}", comment));
+        }
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Convert time entries to compact format.
+pub fn time_entries_to_compact(entries: &[TimeEntry]) -> String {
+    if entries.is_empty() {
+        return "No time logged.".to_string();
+    }
+
+    entries
+        .iter()
+        .map(|e| {
+            let author = e
+                .author
+                .as_ref()
+                .map(|a| format!(" @{}", a.username))
+                .unwrap_or_default();
+            let date = e
+                .started_at
+                .as_deref()
+                .map(|s| format!(" {}", s.get(..10).unwrap_or(s)))
+                .unwrap_or_default();
+            format!(
+                "{}{}{}",
+                time::format_duration(e.duration_seconds),
+                author,
+                date
+            )
         })
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Format a related issue as "KEY [state] title", leaving out unknown parts.
+fn issue_ref_line(issue: &IssueRef) -> String {
+    let mut line = issue.key.clone();
+    if let Some(state) = &issue.state {
+        line.push_str(&format!(" [{}]", state));
+    }
+    if let Some(title) = &issue.title {
+        line.push_str(&format!(" {}", title));
+    }
+    line
+}
+
+/// Convert issues to compact format (one line per issue).
+///
+/// Issues whose parent is also in the list are nested under it as a tree.
+pub fn issues_to_compact(issues: &[Issue]) -> String {
+    if issues.is_empty() {
+        return "No issues found.".to_string();
+    }
+
+    let parent_index = |issue: &Issue| {
+        let parent = issue.parent.as_ref()?;
+        issues
+            .iter()
+            .position(|i| i.key == parent.key && i.key != issue.key)
+    };
+
+    let mut lines = Vec::new();
+    let mut printed = vec![false; issues.len()];
+    for (index, issue) in issues.iter().enumerate() {
+        if parent_index(issue).is_none() {
+            push_issue_tree(issues, index, 0, &parent_index, &mut printed, &mut lines);
+        }
+    }
+    // Parent cycles have no root; list them flat
+    for index in 0..issues.len() {
+        if !printed[index] {
+            push_issue_tree(issues, index, 0, &parent_index, &mut printed, &mut lines);
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn push_issue_tree(
+    issues: &[Issue],
+    index: usize,
+    depth: usize,
+    parent_index: &dyn Fn(&Issue) -> Option<usize>,
+    printed: &mut [bool],
+    lines: &mut Vec<String>,
+) {
+    if printed[index] {
+        return;
+    }
+    printed[index] = true;
+
+    let issue = &issues[index];
+    let labels = if issue.labels.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", issue.labels.join(", "))
+    };
+    let indent = if depth == 0 {
+        String::new()
+    } else {
+        format!("{}└ ", "  ".repeat(depth - 1))
+    };
+    lines.push(format!(
+        "{}{} [{}] {}{}",
+        indent, issue.key, issue.state, issue.title, labels
+    ));
+
+    for (child, candidate) in issues.iter().enumerate() {
+        if parent_index(candidate) == Some(index) {
+            push_issue_tree(issues, child, depth + 1, parent_index, printed, lines);
+        }
+    }
+}
+
 // ============================================================================
 // Merge Requests
 // ============================================================================
@@ -205,6 +773,11 @@ fn merge_request_to_markdown(mr: &MergeRequest) -> String {
         output.push_str(&format!("**Labels:** {}\n", mr.labels.join(", ")));
     }
 
+    // Milestone
+    if let Some(milestone) = &mr.milestone {
+        output.push_str(&format!("**Milestone:** {}\n", milestone_ref(milestone)));
+    }
+
     // Author
     if let Some(author) = &mr.author {
         output.push_str(&format!("**Author:** @{}\n", author.username));
@@ -230,6 +803,9 @@ fn merge_request_to_markdown(mr: &MergeRequest) -> String {
         output.push_str(&format!("**Reviewers:** {}\n", reviewers.join(", ")));
     }
 
+    // CI summary
+    output.push_str(&ci_summary_to_markdown(&mr.pipelines));
+
     // Description (truncated)
     if let Some(desc) = &mr.description {
         if !desc.is_empty() {
@@ -255,15 +831,193 @@ pub fn merge_requests_to_compact(mrs: &[MergeRequest]) -> String {
     mrs.iter()
         .map(|mr| {
             let draft = if mr.draft { " [DRAFT]" } else { "" };
+            let ci = mr
+                .pipelines
+                .first()
+                .map(|p| format!(" CI:{}", p.status))
+                .unwrap_or_default();
+            format!(
+                "{} [{}]{} {} ({} → {}){}",
+                mr.key, mr.state, draft, mr.title, mr.source_branch, mr.target_branch, ci
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ============================================================================
+// CI Pipelines
+// ============================================================================
+
+/// Convert pipelines to markdown, listing every job.
+pub fn pipelines_to_markdown(pipelines: &[CiPipeline]) -> String {
+    if pipelines.is_empty() {
+        return "No pipelines found.".to_string();
+    }
+
+    pipelines
+        .iter()
+        .map(pipeline_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n---\n\n")
+}
+
+fn pipeline_to_markdown(pipeline: &CiPipeline) -> String {
+    let mut output = String::new();
+
+    let name = pipeline.name.as_deref().unwrap_or("Pipeline");
+    output.push_str(&format!("## {} #{}\n\n", name, pipeline.id));
+
+    output.push_str(&format!(
+        "**Status:** {} {}",
+        ci_status_icon(&pipeline.status),
+        pipeline.status
+    ));
+    if let Some(duration) = pipeline.duration {
+        output.push_str(&format!(" | **Duration:** {}", format_duration(duration)));
+    }
+    output.push('\n');
+
+    if let Some(ref_name) = &pipeline.ref_name {
+        output.push_str(&format!("**Ref:** `{}`", ref_name));
+        if let Some(sha) = &pipeline.sha {
+            output.push_str(&format!(" @ {}", short_sha(sha)));
+        }
+        output.push('\n');
+    }
+
+    if !pipeline.jobs.is_empty() {
+        output.push_str("\n**Jobs:**\n");
+        for job in &pipeline.jobs {
+            let stage = job
+                .stage
+                .as_ref()
+                .map(|s| format!("[{}] ", s))
+                .unwrap_or_default();
+            let duration = job
+                .duration
+                .map(|d| format!(" ({})", format_duration(d)))
+                .unwrap_or_default();
+            let allowed = if job.allow_failure {
+                " (allowed to fail)"
+            } else {
+                ""
+            };
+            output.push_str(&format!(
+                "- {} {}{} — {}{}{} (job {})\n",
+                ci_status_icon(&job.status),
+                stage,
+                job.name,
+                job.status,
+                duration,
+                allowed,
+                job.id
+            ));
+        }
+    }
+
+    if let Some(url) = &pipeline.url {
+        output.push_str(&format!("\n🔗 {}\n", url));
+    }
+
+    output
+}
+
+/// Convert pipelines to compact format.
+pub fn pipelines_to_compact(pipelines: &[CiPipeline]) -> String {
+    if pipelines.is_empty() {
+        return "No pipelines found.".to_string();
+    }
+
+    pipelines
+        .iter()
+        .map(|p| {
+            let failed = failed_job_names(p);
+            let failed = if failed.is_empty() {
+                String::new()
+            } else {
+                format!(" failed: {}", failed.join(", "))
+            };
             format!(
-                "{} [{}]{} {} ({} → {})",
-                mr.key, mr.state, draft, mr.title, mr.source_branch, mr.target_branch
+                "#{} [{}] {}{}",
+                p.id,
+                p.status,
+                p.ref_name.as_deref().unwrap_or("-"),
+                failed
             )
         })
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Compact CI summary embedded in merge request output.
+///
+/// One line per pipeline, followed by the failed jobs (if any).
+fn ci_summary_to_markdown(pipelines: &[CiPipeline]) -> String {
+    let mut output = String::new();
+
+    for pipeline in pipelines {
+        let name = pipeline.name.as_deref().unwrap_or("pipeline");
+        output.push_str(&format!(
+            "**CI:** {} {} — {} #{}",
+            ci_status_icon(&pipeline.status),
+            pipeline.status,
+            name,
+            pipeline.id
+        ));
+        if let Some(duration) = pipeline.duration {
+            output.push_str(&format!(" ({})", format_duration(duration)));
+        }
+        if !pipeline.jobs.is_empty() {
+            let passed = pipeline
+                .jobs
+                .iter()
+                .filter(|j| j.status == "success" || j.status == "skipped")
+                .count();
+            output.push_str(&format!(
+                " | {}/{} jobs passed",
+                passed,
+                pipeline.jobs.len()
+            ));
+        }
+        output.push('\n');
+
+        let failed: Vec<String> = pipeline
+            .jobs
+            .iter()
+            .filter(|j| j.status == "failed" && !j.allow_failure)
+            .map(|j| format!("`{}` (job {})", j.name, j.id))
+            .collect();
+        if !failed.is_empty() {
+            output.push_str(&format!("  Failed jobs: {}\n", failed.join(", ")));
+        }
+    }
+
+    output
+}
+
+fn failed_job_names(pipeline: &CiPipeline) -> Vec<&str> {
+    pipeline
+        .jobs
+        .iter()
+        .filter(|j| j.status == "failed" && !j.allow_failure)
+        .map(|j| j.name.as_str())
+        .collect()
+}
+
+fn ci_status_icon(status: &str) -> &'static str {
+    match status {
+        "success" => "✅",
+        "failed" => "❌",
+        "running" => "🔄",
+        "pending" => "⏳",
+        "canceled" => "⛔",
+        "skipped" => "⏭️",
+        "manual" => "✋",
+        _ => "•",
+    }
+}
+
 // ============================================================================
 // File Diffs
 // ============================================================================
@@ -310,54 +1064,388 @@ fn diff_to_markdown(diff: &FileDiff) -> String {
         }
     }
 
-    // Stats
-    if let (Some(adds), Some(dels)) = (diff.additions, diff.deletions) {
-        output.push_str(&format!("+{} -{}\n\n", adds, dels));
+    // Stats
+    if let (Some(adds), Some(dels)) = (diff.additions, diff.deletions) {
+        output.push_str(&format!("+{} -{}\n\n", adds, dels));
+    }
+
+    // Diff content (in code block)
+    if !diff.diff.is_empty() {
+        output.push_str("```diff\n");
+        output.push_str(&diff.diff);
+        if !diff.diff.ends_with('\n') {
+            output.push('\n');
+        }
+        output.push_str("```\n");
+    }
+
+    output
+}
+
+/// Convert diffs to compact format.
+pub fn diffs_to_compact(diffs: &[FileDiff]) -> String {
+    if diffs.is_empty() {
+        return "No file changes.".to_string();
+    }
+
+    diffs
+        .iter()
+        .map(|diff| {
+            let status = if diff.new_file {
+                "A"
+            } else if diff.deleted_file {
+                "D"
+            } else if diff.renamed_file {
+                "R"
+            } else {
+                "M"
+            };
+
+            let stats = match (diff.additions, diff.deletions) {
+                (Some(a), Some(d)) => format!(" (+{} -{}", a, d),
+                _ => String::new(),
+            };
+
+            format!("[{}] {}{}", status, diff.file_path, stats)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ============================================================================
+// Commits
+// ============================================================================
+
+/// Convert commits to Markdown format.
+pub fn commits_to_markdown(commits: &[Commit]) -> String {
+    if commits.is_empty() {
+        return "No commits.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Commits ({})\n\n", commits.len()));
+
+    for (i, commit) in commits.iter().enumerate() {
+        output.push_str(&commit_to_markdown(commit, i + 1));
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Convert a single commit to Markdown.
+fn commit_to_markdown(commit: &Commit, index: usize) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "## {}. `{}` {}\n\n",
+        index,
+        short_sha(&commit.sha),
+        commit.title
+    ));
+
+    // Metadata
+    let mut meta = Vec::new();
+    if let Some(author) = &commit.author {
+        meta.push(format!("**Author:** @{}", author.username));
+    }
+    if let Some(authored) = &commit.authored_at {
+        meta.push(format_timestamp(authored));
+    }
+    if !meta.is_empty() {
+        output.push_str(&meta.join(" · "));
+        output.push('\n');
+    }
+    if let Some(url) = &commit.url {
+        output.push_str(&format!("**URL:** {}\n", url));
+    }
+
+    // Message body (everything after the title line)
+    let body = commit
+        .message
+        .split_once('\n')
+        .map(|(_, rest)| rest.trim())
+        .unwrap_or_default();
+    if !body.is_empty() {
+        output.push('\n');
+        output.push_str(body);
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Convert commits to compact format.
+pub fn commits_to_compact(commits: &[Commit]) -> String {
+    if commits.is_empty() {
+        return "No commits.".to_string();
+    }
+
+    commits
+        .iter()
+        .map(|c| {
+            let author = c
+                .author
+                .as_ref()
+                .map(|a| format!(" (@{})", a.username))
+                .unwrap_or_default();
+            format!("{} {}{}", short_sha(&c.sha), c.title, author)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// ============================================================================
+// Repository Files
+// ============================================================================
+
+/// Convert a range of file lines to Markdown, with line numbers.
+///
+/// `lines` are the selected lines, starting at line number `first_line`.
+pub fn file_to_markdown(
+    file: &RepositoryFile,
+    lines: &[&str],
+    first_line: usize,
+    total_lines: usize,
+) -> String {
+    let mut output = String::new();
+
+    // Header
+    output.push_str(&format!("## 📄 {}", file.path));
+    if let Some(ref_name) = &file.ref_name {
+        output.push_str(&format!(" @ `{}`", ref_name));
+    }
+    output.push_str("\n\n");
+
+    if file.binary {
+        output.push_str(&format!(
+            "Binary file ({}), content not shown.\n",
+            format_size(file.size)
+        ));
+        return output;
+    }
+
+    if lines.is_empty() {
+        output.push_str("Empty file.\n");
+        return output;
+    }
+
+    let last_line = first_line + lines.len() - 1;
+    output.push_str(&format!(
+        "Lines {}-{} of {}\n\n",
+        first_line, last_line, total_lines
+    ));
+
+    let language = file
+        .path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext)
+        .filter(|ext| !ext.contains('/'))
+        .unwrap_or_default();
+    output.push_str(&format!("```{}\n", language));
+    output.push_str(&number_lines(lines, first_line));
+    output.push_str("```\n");
+
+    output
+}
+
+/// Convert a range of file lines to compact format (numbered lines only).
+pub fn file_to_compact(file: &RepositoryFile, lines: &[&str], first_line: usize) -> String {
+    if file.binary {
+        return format!("Binary file: {} ({})", file.path, format_size(file.size));
+    }
+
+    number_lines(lines, first_line)
+}
+
+/// Convert a directory listing to Markdown format.
+pub fn tree_to_markdown(entries: &[TreeEntry]) -> String {
+    if entries.is_empty() {
+        return "Empty directory.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Directory ({} entries)\n\n", entries.len()));
+
+    for entry in sorted_tree(entries) {
+        let line = match entry.kind.as_str() {
+            "dir" => format!("📁 {}/", entry.path),
+            "submodule" => format!("📦 {} (submodule)", entry.path),
+            "symlink" => format!("🔗 {}", entry.path),
+            _ => match entry.size {
+                Some(size) => format!("📄 {} ({})", entry.path, format_size(size)),
+                None => format!("📄 {}", entry.path),
+            },
+        };
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Convert a directory listing to compact format.
+pub fn tree_to_compact(entries: &[TreeEntry]) -> String {
+    if entries.is_empty() {
+        return "Empty directory.".to_string();
+    }
+
+    sorted_tree(entries)
+        .into_iter()
+        .map(|entry| {
+            if entry.kind == "dir" {
+                format!("{}/", entry.path)
+            } else {
+                entry.path.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Directories first, then by path.
+fn sorted_tree(entries: &[TreeEntry]) -> Vec<&TreeEntry> {
+    let mut sorted: Vec<&TreeEntry> = entries.iter().collect();
+    sorted.sort_by(|a, b| {
+        (a.kind != "dir")
+            .cmp(&(b.kind != "dir"))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    sorted
+}
+
+/// Prefix lines with right-aligned line numbers ("  9 | text").
+fn number_lines(lines: &[&str], first_line: usize) -> String {
+    let width = (first_line + lines.len()).to_string().len();
+    let mut output = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        output.push_str(&format!(
+            "{:>width$} | {}\n",
+            first_line + i,
+            line,
+            width = width
+        ));
+    }
+    output
+}
+
+// ============================================================================
+// Agile (boards and sprints)
+// ============================================================================
+
+/// Convert boards to Markdown format.
+pub fn boards_to_markdown(boards: &[Board]) -> String {
+    if boards.is_empty() {
+        return "No boards found.".to_string();
+    }
+
+    let mut output = String::new();
+    output.push_str(&format!("# Boards ({})\n\n", boards.len()));
+
+    for board in boards {
+        let project = board
+            .project_key
+            .as_ref()
+            .map(|k| format!(" · project {}", k))
+            .unwrap_or_default();
+        output.push_str(&format!(
+            "- **{}** ({}, id {}){}\n",
+            board.name, board.kind, board.id, project
+        ));
+    }
+
+    output
+}
+
+/// Convert boards to compact format.
+pub fn boards_to_compact(boards: &[Board]) -> String {
+    if boards.is_empty() {
+        return "No boards found.".to_string();
+    }
+
+    boards
+        .iter()
+        .map(|b| format!("{} {} [{}]", b.id, b.name, b.kind))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert sprints to Markdown format.
+pub fn sprints_to_markdown(sprints: &[Sprint]) -> String {
+    if sprints.is_empty() {
+        return "No sprints found.".to_string();
     }
 
-    // Diff content (in code block)
-    if !diff.diff.is_empty() {
-        output.push_str("```diff\n");
-        output.push_str(&diff.diff);
-        if !diff.diff.ends_with('\n') {
-            output.push('\n');
-        }
-        output.push_str("```\n");
+    sprints
+        .iter()
+        .map(sprint_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Convert a single sprint to Markdown format.
+pub fn sprint_to_markdown(sprint: &Sprint) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!(
+        "## {} {}\n\n",
+        sprint_state_icon(&sprint.state),
+        sprint.name
+    ));
+    output.push_str(&format!(
+        "**State:** {} | **ID:** {}\n",
+        sprint.state, sprint.id
+    ));
+
+    if let Some(dates) = sprint_dates(sprint) {
+        output.push_str(&format!("**Dates:** {}\n", dates));
+    }
+    if let Some(goal) = &sprint.goal {
+        output.push_str(&format!("**Goal:** {}\n", goal));
     }
 
     output
 }
 
-/// Convert diffs to compact format.
-pub fn diffs_to_compact(diffs: &[FileDiff]) -> String {
-    if diffs.is_empty() {
-        return "No file changes.".to_string();
+/// Convert sprints to compact format.
+pub fn sprints_to_compact(sprints: &[Sprint]) -> String {
+    if sprints.is_empty() {
+        return "No sprints found.".to_string();
     }
 
-    diffs
+    sprints
         .iter()
-        .map(|diff| {
-            let status = if diff.new_file {
-                "A"
-            } else if diff.deleted_file {
-                "D"
-            } else if diff.renamed_file {
-                "R"
-            } else {
-                "M"
-            };
-
-            let stats = match (diff.additions, diff.deletions) {
-                (Some(a), Some(d)) => format!(" (+{} -{}", a, d),
-                _ => String::new(),
-            };
-
-            format!("[{}] {}{}", status, diff.file_path, stats)
+        .map(|s| {
+            let dates = sprint_dates(s)
+                .map(|d| format!(" {}", d))
+                .unwrap_or_default();
+            format!("{} {} [{}]{}", s.id, s.name, s.state, dates)
         })
         .collect::<Vec<_>>()
         .join("\n")
 }
 
+/// Format the date range of a sprint ("2024-01-01 → 2024-01-14").
+fn sprint_dates(sprint: &Sprint) -> Option<String> {
+    match (&sprint.start_date, &sprint.end_date) {
+        (None, None) => None,
+        (start, end) => Some(format!(
+            "{} → {}",
+            start.as_deref().map(format_timestamp).unwrap_or_default(),
+            end.as_deref().map(format_timestamp).unwrap_or_default()
+        )),
+    }
+}
+
+fn sprint_state_icon(state: &str) -> &'static str {
+    match state {
+        "active" => "🏃",
+        "future" => "📅",
+        "closed" => "✅",
+        _ => "❓",
+    }
+}
+
 // ============================================================================
 // Comments
 // ============================================================================
@@ -402,6 +1490,15 @@ fn comment_to_markdown(comment: &Comment) -> String {
     output.push_str(&comment.body);
     output.push_str("\n\n");
 
+    // Attachments
+    if !comment.attachments.is_empty() {
+        output.push_str("**Attachments:**\n");
+        for attachment in &comment.attachments {
+            output.push_str(&attachment_line(attachment));
+        }
+        output.push('\n');
+    }
+
     output
 }
 
@@ -517,6 +1614,34 @@ fn truncate_text(text: &str, max_len: usize) -> String {
     format!("{}...", truncated)
 }
 
+/// Format a duration in seconds as "1h 2m", "4m 12s" or "35s".
+fn format_duration(seconds: u64) -> String {
+    let (h, m, s) = (seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+    if h > 0 {
+        format!("{}h {}m", h, m)
+    } else if m > 0 {
+        format!("{}m {}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+/// Format a size in bytes as "512 B", "1.5 KB" or "2.0 MB".
+fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// Shorten a commit SHA to 8 characters.
+fn short_sha(sha: &str) -> &str {
+    sha.get(..8).unwrap_or(sha)
+}
+
 /// Format a timestamp to a shorter form.
 fn format_timestamp(ts: &str) -> String {
     // Just extract the date part if it's ISO format
@@ -530,7 +1655,7 @@ fn format_timestamp(ts: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use devboy_core::User;
+    use devboy_core::{BulkUpdateItem, Checklist, ChecklistItem, CiJob, IssueLink, User};
 
     fn sample_user() -> User {
         User {
@@ -556,6 +1681,16 @@ mod tests {
             url: Some("https://github.com/test/repo/issues/42".to_string()),
             created_at: Some("2024-01-15T10:30:00Z".to_string()),
             updated_at: Some("2024-01-16T14:00:00Z".to_string()),
+            custom_fields: Default::default(),
+            issue_type: None,
+            parent: None,
+            children: vec![],
+            links: vec![],
+            attachments: vec![],
+            estimate_seconds: None,
+            time_spent_seconds: None,
+            milestone: None,
+            checklists: vec![],
         }
     }
 
@@ -583,6 +1718,139 @@ mod tests {
         assert!(compact.contains("[bug, urgent]"));
     }
 
+    #[test]
+    fn test_issues_to_compact_tree() {
+        let parent_ref = |key: &str| IssueRef {
+            key: key.to_string(),
+            ..Default::default()
+        };
+        let issue = |key: &str, parent: Option<&str>| Issue {
+            key: key.to_string(),
+            title: format!("Title {}", key),
+            state: "open".to_string(),
+            parent: parent.map(parent_ref),
+            ..Default::default()
+        };
+        let issues = vec![
+            issue("PROJ-3", Some("PROJ-2")),
+            issue("PROJ-1", None),
+            issue("PROJ-2", Some("PROJ-1")),
+            issue("PROJ-4", Some("PROJ-99")),
+        ];
+
+        assert_eq!(
+            issues_to_compact(&issues),
+            "PROJ-1 [open] Title PROJ-1\n\
+             └ PROJ-2 [open] Title PROJ-2\n\
+             \x20 └ PROJ-3 [open] Title PROJ-3\n\
+             PROJ-4 [open] Title PROJ-4"
+        );
+
+        // A parent cycle has no root but every issue is still listed
+        let cycle = vec![issue("A-1", Some("A-2")), issue("A-2", Some("A-1"))];
+        assert_eq!(
+            issues_to_compact(&cycle),
+            "A-1 [open] Title A-1\n└ A-2 [open] Title A-2"
+        );
+    }
+
+    #[test]
+    fn test_issue_to_markdown_hierarchy() {
+        let mut issue = sample_issue();
+        issue.issue_type = Some("Story".to_string());
+        issue.parent = Some(IssueRef {
+            key: "PROJ-1".to_string(),
+            title: Some("Epic".to_string()),
+            ..Default::default()
+        });
+        issue.children = vec![
+            IssueRef {
+                key: "PROJ-3".to_string(),
+                title: Some("First".to_string()),
+                state: Some("closed".to_string()),
+                issue_type: None,
+            },
+            IssueRef {
+                key: "PROJ-4".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        let md = issue_to_markdown(&issue);
+        assert!(md.contains("| **Type:** Story\n"));
+        assert!(md.contains("**Parent:** PROJ-1 Epic\n"));
+        assert!(md.contains("**Children:**\n├── PROJ-3 [closed] First\n└── PROJ-4\n"));
+    }
+
+    #[test]
+    fn test_issue_to_markdown_links() {
+        let mut issue = sample_issue();
+        issue.links = vec![
+            IssueLink {
+                kind: "blocked_by".to_string(),
+                target: IssueRef {
+                    key: "gitlab#41".to_string(),
+                    title: Some("Schema migration".to_string()),
+                    state: Some("opened".to_string()),
+                    issue_type: None,
+                },
+                id: Some("7".to_string()),
+            },
+            IssueLink {
+                kind: "closed_by".to_string(),
+                target: IssueRef {
+                    key: "mr#50".to_string(),
+                    title: Some("Fix it".to_string()),
+                    state: Some("merged".to_string()),
+                    issue_type: Some("merge_request".to_string()),
+                },
+                id: None,
+            },
+        ];
+
+        let md = issue_to_markdown(&issue);
+        assert!(md.contains(
+            "**Links:**\n- blocked by gitlab#41 [opened] Schema migration\n- closed by mr#50 [merged] Fix it\n"
+        ));
+    }
+
+    #[test]
+    fn test_issue_to_markdown_attachments() {
+        let mut issue = sample_issue();
+        issue.attachments = vec![
+            Attachment {
+                id: "10001".to_string(),
+                name: "screenshot.png".to_string(),
+                size: Some(1536),
+                mime_type: Some("image/png".to_string()),
+                url: "https://jira.example.com/secure/attachment/10001/screenshot.png".to_string(),
+                created_at: None,
+            },
+            Attachment {
+                id: "/uploads/abc/notes".to_string(),
+                name: "notes".to_string(),
+                size: None,
+                mime_type: None,
+                url: "/uploads/abc/notes".to_string(),
+                created_at: None,
+            },
+        ];
+
+        let md = issue_to_markdown(&issue);
+        assert!(md.contains(
+            "**Attachments:**\n\
+             - [screenshot.png](https://jira.example.com/secure/attachment/10001/screenshot.png) (1.5 KB, image/png)\n\
+             - [notes](/uploads/abc/notes)\n"
+        ));
+
+        let mut comment = sample_comment();
+        comment.attachments = issue.attachments[1..].to_vec();
+        let md = comment_to_markdown(&comment);
+        assert!(
+            md.contains("This looks good!\n\n**Attachments:**\n- [notes](/uploads/abc/notes)\n")
+        );
+    }
+
     #[test]
     fn test_empty_issues() {
         let md = issues_to_markdown(&[]);
@@ -681,6 +1949,8 @@ mod tests {
             created_at: Some("2024-01-15T10:30:00Z".to_string()),
             updated_at: Some("2024-01-16T14:00:00Z".to_string()),
             draft: false,
+            pipelines: vec![],
+            milestone: None,
         }
     }
 
@@ -692,6 +1962,7 @@ mod tests {
             created_at: Some("2024-01-15T10:30:00Z".to_string()),
             updated_at: None,
             position: None,
+            attachments: vec![],
         }
     }
 
@@ -708,6 +1979,7 @@ mod tests {
                 line_type: "new".to_string(),
                 commit_sha: None,
             }),
+            attachments: vec![],
         }
     }
 
@@ -838,6 +2110,7 @@ mod tests {
             created_at: None,
             updated_at: None,
             position: None,
+            attachments: vec![],
         };
         let compact = comments_to_compact(&[comment]);
 
@@ -1066,6 +2339,16 @@ mod tests {
             url: None,
             created_at: None,
             updated_at: None,
+            custom_fields: Default::default(),
+            issue_type: None,
+            parent: None,
+            children: vec![],
+            links: vec![],
+            attachments: vec![],
+            estimate_seconds: None,
+            time_spent_seconds: None,
+            milestone: None,
+            checklists: vec![],
         };
 
         let md = issue_to_markdown(&issue);
@@ -1091,6 +2374,16 @@ mod tests {
             url: None,
             created_at: None,
             updated_at: None,
+            custom_fields: Default::default(),
+            issue_type: None,
+            parent: None,
+            children: vec![],
+            links: vec![],
+            attachments: vec![],
+            estimate_seconds: None,
+            time_spent_seconds: None,
+            milestone: None,
+            checklists: vec![],
         };
 
         let md = issue_to_markdown(&issue);
@@ -1116,6 +2409,8 @@ mod tests {
             created_at: None,
             updated_at: None,
             draft: false,
+            pipelines: vec![],
+            milestone: None,
         };
 
         let md = merge_request_to_markdown(&mr);
@@ -1127,6 +2422,569 @@ mod tests {
         assert!(!md.contains("[DRAFT]"));
     }
 
+    fn sample_commit() -> Commit {
+        Commit {
+            sha: "aaa111bbb222ccc333".to_string(),
+            title: "Add login".to_string(),
+            message: "Add login\n\nSupports OAuth tokens.".to_string(),
+            author: Some(sample_user()),
+            authored_at: Some("2024-01-01T10:00:00Z".to_string()),
+            committed_at: Some("2024-01-01T11:00:00Z".to_string()),
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_commits_to_markdown() {
+        let commit = Commit {
+            sha: "ddd444".to_string(),
+            title: "Fix typo".to_string(),
+            message: "Fix typo".to_string(),
+            author: None,
+            ..Default::default()
+        };
+        let md = commits_to_markdown(&[sample_commit(), commit]);
+
+        assert!(md.contains("# Commits (2)"));
+        assert!(md.contains("## 1. `aaa111bb` Add login"));
+        assert!(md.contains("**Author:** @testuser · 2024-01-01"));
+        assert!(md.contains("Supports OAuth tokens."));
+        assert!(md.contains("## 2. `ddd444` Fix typo"));
+    }
+
+    #[test]
+    fn test_commits_to_compact() {
+        let compact = commits_to_compact(&[sample_commit()]);
+        assert_eq!(compact, "aaa111bb Add login (@testuser)");
+        assert_eq!(commits_to_compact(&[]), "No commits.");
+    }
+
+    #[test]
+    fn test_file_to_markdown() {
+        let file = RepositoryFile {
+            path: "src/main.rs".to_string(),
+            ref_name: Some("main".to_string()),
+            content: String::new(),
+            size: 40,
+            ..Default::default()
+        };
+        let md = file_to_markdown(&file, &["fn main() {", "}"], 9, 20);
+
+        assert!(md.contains("## 📄 src/main.rs @ `main`"));
+        assert!(md.contains("Lines 9-10 of 20"));
+        assert!(md.contains("```rs\n 9 | fn main() {\n10 | }\n```"));
+    }
+
+    #[test]
+    fn test_file_to_markdown_binary() {
+        let file = RepositoryFile {
+            path: "logo.png".to_string(),
+            size: 2048,
+            binary: true,
+            ..Default::default()
+        };
+        let md = file_to_markdown(&file, &[], 1, 0);
+        assert!(md.contains("Binary file (2.0 KB), content not shown."));
+        assert_eq!(
+            file_to_compact(&file, &[], 1),
+            "Binary file: logo.png (2.0 KB)"
+        );
+    }
+
+    fn sample_sprint() -> Sprint {
+        Sprint {
+            id: "37".to_string(),
+            name: "Sprint 12".to_string(),
+            state: "active".to_string(),
+            goal: Some("Ship login".to_string()),
+            start_date: Some("2024-01-01T09:00:00.000Z".to_string()),
+            end_date: Some("2024-01-14T18:00:00.000Z".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sprints_to_markdown() {
+        let md = sprints_to_markdown(&[sample_sprint()]);
+
+        assert!(md.contains("## 🏃 Sprint 12"));
+        assert!(md.contains("**State:** active | **ID:** 37"));
+        assert!(md.contains("**Dates:** 2024-01-01 → 2024-01-14"));
+        assert!(md.contains("**Goal:** Ship login"));
+        assert_eq!(sprints_to_markdown(&[]), "No sprints found.");
+    }
+
+    #[test]
+    fn test_sprints_to_compact() {
+        let future = Sprint {
+            id: "38".to_string(),
+            name: "Sprint 13".to_string(),
+            state: "future".to_string(),
+            ..Default::default()
+        };
+        let compact = sprints_to_compact(&[sample_sprint(), future]);
+
+        assert_eq!(
+            compact,
+            "37 Sprint 12 [active] 2024-01-01 → 2024-01-14\n38 Sprint 13 [future]"
+        );
+    }
+
+    #[test]
+    fn test_issue_to_markdown_custom_fields() {
+        let mut issue = sample_issue();
+        issue
+            .custom_fields
+            .insert("Story Points".to_string(), serde_json::json!(5));
+        issue
+            .custom_fields
+            .insert("Team".to_string(), serde_json::json!(["API", "UI"]));
+
+        let md = issue_to_markdown(&issue);
+        assert!(md.contains("**Fields:** Story Points: 5 · Team: API, UI"));
+    }
+
+    #[test]
+    fn test_custom_fields_to_markdown() {
+        let fields = vec![
+            CustomField {
+                id: "customfield_10016".to_string(),
+                name: "Story Points".to_string(),
+                field_type: "number".to_string(),
+                ..Default::default()
+            },
+            CustomField {
+                id: "customfield_10020".to_string(),
+                name: "Team".to_string(),
+                field_type: "option".to_string(),
+                options: vec!["Backend".to_string(), "Frontend".to_string()],
+                required: true,
+            },
+        ];
+
+        let md = custom_fields_to_markdown(&fields);
+        assert!(md.contains("# Custom fields (2)"));
+        assert!(md.contains("- **Story Points** (number, id customfield_10016)\n"));
+        assert!(md.contains(
+            "- **Team** (option, id customfield_10020, required) · options: Backend, Frontend"
+        ));
+        assert_eq!(
+            custom_fields_to_compact(&fields),
+            "customfield_10016 Story Points [number]\ncustomfield_10020 Team [option]"
+        );
+        assert_eq!(custom_fields_to_markdown(&[]), "No custom fields found.");
+    }
+
+    #[test]
+    fn test_issue_to_markdown_time_tracking() {
+        let mut issue = sample_issue();
+        issue.time_spent_seconds = Some(5400);
+        issue.estimate_seconds = Some(4 * 3600);
+        assert!(issue_to_markdown(&issue).contains("**Time:** 1h 30m spent of 4h estimated\n"));
+
+        issue.time_spent_seconds = None;
+        assert!(issue_to_markdown(&issue).contains("**Time:** 4h estimated\n"));
+    }
+
+    #[test]
+    fn test_milestones_to_markdown() {
+        let milestones = vec![
+            Milestone {
+                id: "10201".to_string(),
+                title: "2.4".to_string(),
+                kind: "version".to_string(),
+                state: "open".to_string(),
+                description: Some("Spring release\nDetails follow".to_string()),
+                due_date: Some("2024-03-01".to_string()),
+                open_issues: Some(3),
+                closed_issues: Some(9),
+                ..Default::default()
+            },
+            Milestone {
+                id: "7".to_string(),
+                title: "Sprint 5".to_string(),
+                kind: "iteration".to_string(),
+                state: "open".to_string(),
+                start_date: Some("2024-02-01".to_string()),
+                due_date: Some("2024-02-14".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let md = milestones_to_markdown(&milestones);
+        assert!(md.contains("# Milestones (2)"));
+        assert!(md.contains(
+            "- **2.4** (version, open) · due 2024-03-01 · 9/12 done (75%), 3 open\n  Spring release\n"
+        ));
+        assert!(md.contains("- **Sprint 5** (iteration, open) · 2024-02-01 → 2024-02-14\n"));
+        assert_eq!(
+            milestones_to_compact(&milestones),
+            "2.4 [open] 9/12 due 2024-03-01\nSprint 5 [open] due 2024-02-14"
+        );
+        assert_eq!(milestones_to_markdown(&[]), "No milestones found.");
+    }
+
+    #[test]
+    fn test_containers_to_markdown() {
+        let containers = vec![
+            Container {
+                id: "55".to_string(),
+                name: "Sprints".to_string(),
+                kind: "folder".to_string(),
+                parent_id: Some("7".to_string()),
+                ..Default::default()
+            },
+            Container {
+                id: "901".to_string(),
+                name: "Backlog".to_string(),
+                kind: "list".to_string(),
+                parent_id: Some("7".to_string()),
+                archived: true,
+            },
+        ];
+
+        let md = containers_to_markdown(&containers);
+        assert!(md.contains("# Containers (2)"));
+        assert!(md.contains("- **Sprints** (folder, id 55)\n"));
+        assert!(md.contains("- **Backlog** (list, id 901) · archived\n"));
+        assert_eq!(
+            containers_to_compact(&containers),
+            "55 Sprints [folder]\n901 Backlog [list]"
+        );
+        assert_eq!(containers_to_markdown(&[]), "No containers found.");
+    }
+
+    #[test]
+    fn test_transitions_to_markdown() {
+        let transitions = vec![
+            IssueTransition {
+                id: "21".to_string(),
+                name: "Start Progress".to_string(),
+                to_status: "In Progress".to_string(),
+                to_category: "in_progress".to_string(),
+                fields: vec![],
+            },
+            IssueTransition {
+                id: "31".to_string(),
+                name: "Done".to_string(),
+                to_status: "Done".to_string(),
+                to_category: "done".to_string(),
+                fields: vec![CustomField {
+                    id: "resolution".to_string(),
+                    name: "Resolution".to_string(),
+                    field_type: "option".to_string(),
+                    options: vec!["Fixed".to_string(), "Won't Fix".to_string()],
+                    required: true,
+                }],
+            },
+        ];
+
+        let md = transitions_to_markdown("jira#PROJ-1", &transitions);
+        assert!(md.contains("# Transitions of jira#PROJ-1 (2)"));
+        assert!(md.contains("- **Start Progress** → In Progress [in_progress] (id 21)\n"));
+        assert!(md.contains(
+            "- **Done** → Done [done] (id 31)\n  - Resolution (option, id resolution, required) · options: Fixed, Won't Fix\n"
+        ));
+        assert_eq!(
+            transitions_to_markdown("gh#1", &[]),
+            "No transitions available for gh#1."
+        );
+    }
+
+    #[test]
+    fn test_bulk_report_to_markdown() {
+        let mut report = BulkUpdateReport {
+            dry_run: true,
+            items: vec![
+                BulkUpdateItem {
+                    key: "gh#1".to_string(),
+                    changes: vec![
+                        "state: open → closed".to_string(),
+                        "labels: bug → none".to_string(),
+                    ],
+                    ..Default::default()
+                },
+                BulkUpdateItem {
+                    key: "gh#2".to_string(),
+                    ..Default::default()
+                },
+                BulkUpdateItem {
+                    key: "gh#404".to_string(),
+                    error: Some("Not found: gh#404".to_string()),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            bulk_report_to_markdown(&report),
+            "# Dry run: 2 of 3 issues would be updated\n\n\
+             - gh#1: state: open → closed; labels: bug → none\n\
+             - gh#2: no changes\n\
+             - gh#404: failed: Not found: gh#404\n"
+        );
+
+        report.dry_run = false;
+        report.items[1].skipped = vec!["assignees".to_string()];
+        let md = bulk_report_to_markdown(&report);
+        assert!(md.starts_with("# Bulk update: 2 of 3 issues updated"));
+        assert!(md.contains("- gh#2: not applied by the provider: assignees\n"));
+
+        report.duplicates = vec!["gh#1".to_string()];
+        let md = bulk_report_to_markdown(&report);
+        assert!(md.ends_with("\nDuplicate keys updated once: gh#1\n"));
+    }
+
+    #[test]
+    fn test_catalog_to_markdown() {
+        let statuses = vec![
+            IssueStatus {
+                name: "To Do".to_string(),
+                category: "todo".to_string(),
+            },
+            IssueStatus {
+                name: "In Review".to_string(),
+                category: "in_progress".to_string(),
+            },
+        ];
+        let labels = vec![Label {
+            name: "bug".to_string(),
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            catalog_to_markdown(Some(&statuses), Some(&labels)),
+            "**Statuses:** To Do [todo], In Review [in_progress]\n**Labels:** bug"
+        );
+        assert_eq!(
+            catalog_to_markdown(Some(&[]), None),
+            "**Statuses:** none\n**Labels:** not available"
+        );
+    }
+
+    #[test]
+    fn test_users_to_markdown() {
+        let users = vec![
+            User {
+                id: "1".to_string(),
+                username: "jdoe".to_string(),
+                name: Some("John Doe".to_string()),
+                email: Some("john@example.com".to_string()),
+                avatar_url: None,
+            },
+            User {
+                id: "2".to_string(),
+                username: "bot".to_string(),
+                name: Some("bot".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let md = users_to_markdown(&users);
+        assert!(md.contains("# Users (2)"));
+        assert!(md.contains("- **jdoe** (John Doe, john@example.com, id 1)\n"));
+        assert!(md.contains("- **bot** (id 2)\n"));
+        assert_eq!(users_to_compact(&users), "1 jdoe\n2 bot");
+        assert_eq!(users_to_markdown(&[]), "No users found.");
+    }
+
+    #[test]
+    fn test_issue_to_markdown_checklists() {
+        let mut issue = sample_issue();
+        issue.checklists = vec![Checklist {
+            id: "cl1".to_string(),
+            name: "Acceptance criteria".to_string(),
+            items: vec![
+                ChecklistItem {
+                    id: "it1".to_string(),
+                    name: "API returns 201".to_string(),
+                    resolved: true,
+                    assignee: None,
+                },
+                ChecklistItem {
+                    id: "it2".to_string(),
+                    name: "Docs updated".to_string(),
+                    resolved: false,
+                    assignee: Some(sample_user()),
+                },
+            ],
+        }];
+
+        let md = issues_to_markdown(&[issue]);
+        assert!(md.contains(
+            "**Checklist:** Acceptance criteria (1/2)\n- [x] API returns 201\n- [ ] Docs updated @testuser\n"
+        ));
+    }
+
+    #[test]
+    fn test_issue_to_markdown_milestone() {
+        let mut issue = sample_issue();
+        issue.milestone = Some(Milestone {
+            title: "2.4".to_string(),
+            due_date: Some("2024-03-01".to_string()),
+            ..Default::default()
+        });
+        assert!(issue_to_markdown(&issue).contains("**Milestone:** 2.4 (due 2024-03-01)\n"));
+    }
+
+    #[test]
+    fn test_time_entries_to_markdown() {
+        let entries = vec![
+            TimeEntry {
+                id: "10100".to_string(),
+                author: Some(User {
+                    username: "alice".to_string(),
+                    ..Default::default()
+                }),
+                duration_seconds: 5400,
+                started_at: Some("2024-01-15T10:00:00Z".to_string()),
+                comment: Some("Code review".to_string()),
+            },
+            TimeEntry {
+                id: "10101".to_string(),
+                duration_seconds: 1800,
+                ..Default::default()
+            },
+        ];
+
+        let md = time_entries_to_markdown(&entries);
+        assert!(md.contains("# Time entries (2, 2h total)"));
+        assert!(md.contains("- **1h 30m** by @alice on 2024-01-15T10:00:00Z: Code review\n"));
+        assert!(md.contains("- **30m**\n"));
+        assert_eq!(
+            time_entries_to_compact(&entries),
+            "1h 30m @alice 2024-01-15\n30m"
+        );
+        assert_eq!(time_entries_to_markdown(&[]), "No time logged.");
+    }
+
+    #[test]
+    fn test_boards_to_markdown() {
+        let board = Board {
+            id: "7".to_string(),
+            name: "PROJ board".to_string(),
+            kind: "scrum".to_string(),
+            project_key: Some("PROJ".to_string()),
+        };
+
+        let md = boards_to_markdown(std::slice::from_ref(&board));
+        assert!(md.contains("# Boards (1)"));
+        assert!(md.contains("- **PROJ board** (scrum, id 7) · project PROJ"));
+        assert_eq!(boards_to_compact(&[board]), "7 PROJ board [scrum]");
+    }
+
+    #[test]
+    fn test_tree_to_markdown() {
+        let entries = vec![
+            TreeEntry {
+                path: "src/main.rs".to_string(),
+                name: "main.rs".to_string(),
+                kind: "file".to_string(),
+                size: Some(300),
+            },
+            TreeEntry {
+                path: "src/bin".to_string(),
+                name: "bin".to_string(),
+                kind: "dir".to_string(),
+                size: None,
+            },
+        ];
+
+        let md = tree_to_markdown(&entries);
+        assert!(md.contains("# Directory (2 entries)"));
+        assert!(md.contains("📁 src/bin/\n📄 src/main.rs (300 B)"));
+        assert_eq!(tree_to_compact(&entries), "src/bin/\nsrc/main.rs");
+        assert_eq!(tree_to_compact(&[]), "Empty directory.");
+    }
+
+    fn sample_pipeline() -> CiPipeline {
+        CiPipeline {
+            id: "101".to_string(),
+            name: None,
+            status: "failed".to_string(),
+            ref_name: Some("feature".to_string()),
+            sha: Some("abc123def4567890".to_string()),
+            duration: Some(252),
+            url: Some("https://gitlab.com/group/project/-/pipelines/101".to_string()),
+            jobs: vec![
+                CiJob {
+                    id: "11".to_string(),
+                    name: "build".to_string(),
+                    stage: Some("build".to_string()),
+                    status: "success".to_string(),
+                    allow_failure: false,
+                    duration: Some(80),
+                    url: None,
+                },
+                CiJob {
+                    id: "12".to_string(),
+                    name: "test".to_string(),
+                    stage: Some("test".to_string()),
+                    status: "failed".to_string(),
+                    allow_failure: false,
+                    duration: Some(120),
+                    url: None,
+                },
+                CiJob {
+                    id: "13".to_string(),
+                    name: "audit".to_string(),
+                    stage: Some("test".to_string()),
+                    status: "failed".to_string(),
+                    allow_failure: true,
+                    duration: None,
+                    url: None,
+                },
+            ],
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_mr_with_ci_summary() {
+        let mut mr = sample_mr();
+        mr.pipelines = vec![sample_pipeline()];
+
+        let md = merge_request_to_markdown(&mr);
+        assert!(md.contains("**CI:** ❌ failed — pipeline #101 (4m 12s) | 1/3 jobs passed"));
+        assert!(md.contains("Failed jobs: `test` (job 12)"));
+        assert!(!md.contains("`audit`"));
+
+        let compact = merge_requests_to_compact(&[mr]);
+        assert!(compact.ends_with("CI:failed"));
+    }
+
+    #[test]
+    fn test_mr_without_pipelines_has_no_ci_line() {
+        let md = merge_request_to_markdown(&sample_mr());
+        assert!(!md.contains("**CI:**"));
+    }
+
+    #[test]
+    fn test_pipelines_to_markdown() {
+        let md = pipelines_to_markdown(&[sample_pipeline()]);
+        assert!(md.contains("## Pipeline #101"));
+        assert!(md.contains("**Status:** ❌ failed | **Duration:** 4m 12s"));
+        assert!(md.contains("**Ref:** `feature` @ abc123de"));
+        assert!(md.contains("- ❌ [test] test — failed (2m 0s) (job 12)"));
+        assert!(md.contains("audit — failed (allowed to fail) (job 13)"));
+    }
+
+    #[test]
+    fn test_pipelines_to_compact() {
+        let compact = pipelines_to_compact(&[sample_pipeline()]);
+        assert_eq!(compact, "#101 [failed] feature failed: test");
+        assert_eq!(pipelines_to_compact(&[]), "No pipelines found.");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(35), "35s");
+        assert_eq!(format_duration(252), "4m 12s");
+        assert_eq!(format_duration(3720), "1h 2m");
+    }
+
     #[test]
     fn test_markdown_vs_json_size() {
         let issues: Vec<Issue> = (1..=5)
@@ -1143,6 +3001,16 @@ mod tests {
                 url: None,
                 created_at: None,
                 updated_at: None,
+                custom_fields: Default::default(),
+                issue_type: None,
+                parent: None,
+                children: vec![],
+                links: vec![],
+                attachments: vec![],
+                estimate_seconds: None,
+                time_spent_seconds: None,
+                milestone: None,
+                checklists: vec![],
             })
             .collect();
 
@@ -18,7 +18,12 @@ pub fn truncate_string(s: &str, max_chars: usize) -> String {
         return "...".to_string();
     }
 
-    let truncated = &s[..content_limit.min(s.len())];
+    // Never cut inside a multi-byte character
+    let mut end = content_limit.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    let truncated = &s[..end];
 
     // Try to break at newline first
     if let Some(pos) = truncated.rfind('\n') {
@@ -171,6 +176,14 @@ mod tests {
         assert_eq!(truncate_string(s, 100), s);
     }
 
+    #[test]
+    fn test_truncate_string_multibyte() {
+        let s = "✓ passed ✓ passed ✓ passed";
+        let result = truncate_string(s, 12);
+        assert!(result.ends_with("..."));
+        assert!(result.len() <= 12);
+    }
+
     #[test]
     fn test_truncate_string_at_word() {
         let s = "Hello world this is a test";